@@ -3,17 +3,48 @@ use mythologic::examples::{
     create_egyptian_ontology, create_celtic_ontology, 
     create_hindu_ontology, create_artifacts_ontology,
     create_heroes_ontology, create_creatures_ontology,
-    create_locations_ontology, create_concepts_ontology
+    create_locations_ontology, create_concepts_ontology, create_motif_catalogue
 };
+use mythologic::core::{ClaimResolver, MythEntity, MythId, MythOntology, ResolutionPolicy};
+use mythologic::cultural::Chronology;
+use mythologic::entities::{Narrative, NarrativeRole};
+use mythologic::formats::{
+    entity_to_jsonld, export_tables, import_tables, ontology_to_dot, ontology_to_gexf, ontology_to_graphml,
+    ontology_to_jsonld, ontology_to_turtle, subgraph_to_dot, subgraph_to_gexf, subgraph_to_graphml,
+    DotClustering, DotOptions, JsonLdOptions, RdfOptions, TabularFormat,
+};
+use mythologic::motifs::{MotifCatalogue, MotifCode, Motifs};
+use mythologic::query::{AnalogueFinder, QueryEngine};
 use mythologic::utils::generate_html_visualization;
 use std::env;
 use std::process;
 use std::path::Path;
+use std::sync::OnceLock;
+
+/// The policy given with `--resolution`, applied to every ontology loaded
+static RESOLUTION: OnceLock<ResolutionPolicy> = OnceLock::new();
 
 fn print_usage() {
     println!("Mythologic Explorer - A tool to visualize mythological ontologies");
     println!("\nUsage:");
     println!("  mythologic_explorer <ontology_name> [output_path]");
+    println!("  mythologic_explorer query <ontology_name|file.json> <query>");
+    println!("  mythologic_explorer search <ontology_name|file.json> <name>");
+    println!("  mythologic_explorer text <ontology_name|file.json> <words or \"phrase\">");
+    println!("  mythologic_explorer analogues <ontology_name|file.json>[,...] <name> [--culture <culture>] [--limit <n>]");
+    println!("  mythologic_explorer motifs <ontology_name|file.json>[,...] <motif code|name> [--catalogue <file.json|file.tsv>]");
+    println!("  mythologic_explorer narratives <ontology_name|file.json>[,...] [<name>] [--role <role>]");
+    println!("  mythologic_explorer timeline <ontology_name|file.json>[,...] [--culture <culture>]");
+    println!("  mythologic_explorer sources <ontology_name|file.json>[,...] [<title>]");
+    println!("  mythologic_explorer dot <ontology_name|file.json> [output.dot] [--cluster culture|pantheon] [--query <query>]");
+    println!("  mythologic_explorer graphml|gexf <ontology_name|file.json> [output] [--query <query>]");
+    println!("  mythologic_explorer rdf <ontology_name|file.json> [output.ttl] [--base <iri>] [--locale <language>] [--no-schema]");
+    println!("  mythologic_explorer jsonld <ontology_name|file.json> [output.jsonld] [--entity <name>] [--base <iri>] [--locale <language>] [--context-url <url>]");
+    println!("  mythologic_explorer tables export <ontology_name|file.json> <directory> [--tsv]");
+    println!("  mythologic_explorer tables import <directory> <output.json> [--tsv]");
+    println!("  mythologic_explorer variants <ontology_name|file.json>[,...]");
+    println!("\nEvery command accepts --resolution <policy> to keep only the preferred variant claims,");
+    println!("where <policy> is one of {}.", ResolutionPolicy::VARIANT_NAMES.join(", "));
     println!("\nAvailable ontologies:");
     println!("  greek      - Greek mythology");
     println!("  norse      - Norse mythology");
@@ -30,16 +61,871 @@ fn print_usage() {
     println!("  mythologic_explorer greek");
     println!("  mythologic_explorer norse ./norse_myths.html");
     println!("  mythologic_explorer all ./visualizations/");
+    println!("  mythologic_explorer query greek 'type:Deity AND (name~ze OR domain:Sea)'");
+    println!("  mythologic_explorer search greek Herakles");
+    println!("  mythologic_explorer search egyptian Οσιρις");
+    println!("  mythologic_explorer text greek '\"twelve labors\" monster'");
+    println!("  mythologic_explorer analogues greek,norse,egyptian,hindu Thor");
+    println!("  mythologic_explorer motifs greek,norse,locations E481");
+    println!("  mythologic_explorer motifs greek,heroes Heracles");
+    println!("  mythologic_explorer narratives norse Loki --role Antagonist");
+    println!("  mythologic_explorer timeline greek,norse --culture Norse");
+    println!("  mythologic_explorer sources norse 'Poetic Edda'");
+    println!("  mythologic_explorer variants greek");
+    println!("  mythologic_explorer rdf greek greek.ttl --resolution PreferEarliest");
+    println!("  mythologic_explorer jsonld greek --entity Ζεύς --locale 'Ancient Greek'");
+    println!("  mythologic_explorer dot greek greek.dot --cluster pantheon");
+    println!("  mythologic_explorer tables export greek ./greek_sheets");
+}
+
+/// Load a built-in ontology by name, or a saved ontology from a `.json` file
+///
+/// Several names separated by commas are loaded and merged into one ontology.
+/// Variant claims are resolved by the `--resolution` policy, if one was given.
+fn load_ontology(name: &str) -> Result<MythOntology, String> {
+    load_unresolved(name).map(apply_resolution)
+}
+
+/// Keep only the claims preferred by the `--resolution` policy, if one was given
+fn apply_resolution(ontology: MythOntology) -> MythOntology {
+    match RESOLUTION.get() {
+        Some(&policy) => ontology.resolved(policy),
+        None => ontology,
+    }
+}
+
+fn load_unresolved(name: &str) -> Result<MythOntology, String> {
+    if name.contains(',') {
+        let mut merged = MythOntology::new();
+        for part in name.split(',').filter(|part| !part.is_empty()) {
+            let ontology = load_unresolved(part)?;
+            for (id, source) in ontology.bibliography().iter() {
+                merged.bibliography_mut().insert(id.clone(), source.clone());
+            }
+            for entity in ontology.entities() {
+                merged.add_entity(entity.clone());
+            }
+        }
+        return Ok(merged);
+    }
+    
+    if name.ends_with(".json") {
+        return MythOntology::load(Path::new(name))
+            .map_err(|e| format!("Error loading ontology from {}: {}", name, e));
+    }
+    
+    match name.to_lowercase().as_str() {
+        "greek" => Ok(create_greek_ontology()),
+        "norse" => Ok(create_norse_ontology()),
+        "egyptian" => Ok(create_egyptian_ontology()),
+        "celtic" => Ok(create_celtic_ontology()),
+        "hindu" => Ok(create_hindu_ontology()),
+        "artifacts" => Ok(create_artifacts_ontology()),
+        "heroes" => Ok(create_heroes_ontology()),
+        "creatures" => Ok(create_creatures_ontology()),
+        "locations" => Ok(create_locations_ontology()),
+        "concepts" => Ok(create_concepts_ontology()),
+        _ => Err(format!("Unknown ontology: {}", name)),
+    }
+}
+
+/// Run a text query against an ontology and print the matching entities
+fn run_query(args: &[String]) {
+    if args.len() < 2 {
+        eprintln!("Usage: mythologic_explorer query <ontology_name|file.json> <query>");
+        process::exit(1);
+    }
+    
+    let ontology = load_ontology(&args[0]).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let query = args[1..].join(" ");
+    let engine = QueryEngine::new(&ontology);
+    let mut results = engine.query_str(&query).unwrap_or_else(|e| {
+        eprintln!("Invalid query: {}", e.render(&query));
+        process::exit(1);
+    });
+    
+    results.sort_by_name();
+    for result in &results.results {
+        println!("{:<12} {}", result.entity_type, result.name);
+    }
+    println!("{} result(s)", results.count());
+}
+
+/// Search names and alternative names, printing scored matches with the matched
+/// part of each name highlighted
+fn run_search(args: &[String]) {
+    if args.len() < 2 {
+        eprintln!("Usage: mythologic_explorer search <ontology_name|file.json> <name>");
+        process::exit(1);
+    }
+    
+    let ontology = load_ontology(&args[0]).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let name = args[1..].join(" ");
+    let matches = QueryEngine::new(&ontology).search_names(&name);
+    for m in &matches {
+        let via = if m.alternative { format!(" (as {})", m.highlighted("[", "]")) } else { String::new() };
+        let shown = if m.alternative { m.name.clone() } else { m.highlighted("[", "]") };
+        println!("{:.2}  {:<12} {}{}", m.score, m.entity_type, shown, via);
+    }
+    println!("{} match(es)", matches.len());
+}
+
+/// Run a full-text search over descriptions and other free text, printing ranked
+/// hits with snippets
+fn run_text(args: &[String]) {
+    if args.len() < 2 {
+        eprintln!("Usage: mythologic_explorer text <ontology_name|file.json> <words or \"phrase\">");
+        process::exit(1);
+    }
+    
+    let ontology = load_ontology(&args[0]).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let query = args[1..].join(" ");
+    let hits = QueryEngine::new(&ontology).search_text(&query);
+    for hit in &hits {
+        println!("{:>6.2}  {:<12} {}", hit.score, hit.entity_type, hit.name);
+        for snippet in &hit.snippets {
+            println!("        {}: {}", snippet.field, snippet.highlighted("[", "]"));
+        }
+    }
+    println!("{} hit(s)", hits.len());
+}
+
+/// Parse a `--role` value: one of the named roles, or a custom role played by
+/// some participant of a narrative in the ontology
+fn parse_role(ontology: &MythOntology, text: &str) -> Result<NarrativeRole, String> {
+    let Ok(role) = text.parse::<NarrativeRole>();
+    let known = !matches!(role, NarrativeRole::Other(_)) || ontology.entities_of_type("Narrative").any(|entity| {
+        matches!(entity, MythEntity::Narrative(narrative) if narrative.episodes().iter()
+            .flat_map(|episode| &episode.participants)
+            .any(|participant| participant.role == role))
+    });
+    if known {
+        Ok(role)
+    } else {
+        Err(format!(
+            "unknown role `{}`; expected one of {} or a role played in the ontology's narratives",
+            text,
+            NarrativeRole::VARIANT_NAMES.join(", "),
+        ))
+    }
+}
+
+/// Find entities in other cultures resembling a named entity, printing each
+/// analogue with the features that matched
+fn run_analogues(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer analogues <ontology_name|file.json>[,...] <name> [--culture <culture>] [--limit <n>]");
+        process::exit(1);
+    };
+    
+    let (Some(ontology_name), Some(entity_name)) = (args.first(), args.get(1)) else {
+        usage();
+        return;
+    };
+    
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let Some(entity) = ontology.entities_named(entity_name).next() else {
+        eprintln!("No entity named '{}'", entity_name);
+        process::exit(1);
+    };
+    
+    let mut finder = AnalogueFinder::new(&ontology).limit(10);
+    let mut rest = args[2..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--culture" => match rest.next() {
+                Some(culture) => finder = finder.in_culture(culture.as_str().into()),
+                None => usage(),
+            },
+            "--limit" => match rest.next().and_then(|n| n.parse().ok()) {
+                Some(limit) => finder = finder.limit(limit),
+                None => usage(),
+            },
+            _ => usage(),
+        }
+    }
+    
+    let analogues = finder.find(entity.id());
+    for analogue in &analogues {
+        println!("{:.2}  {} ({})", analogue.score, analogue.name, analogue.culture.value());
+        for feature in analogue.features.iter().filter(|f| f.score > 0.0) {
+            println!("      {:<13} {:.2}  {}", feature.feature, feature.score, feature.explanation);
+        }
+    }
+    println!("{} analogue(s) of {}", analogues.len(), entity.name());
+}
+
+/// Print the entities and cultures tagged with a motif, or the entities sharing
+/// motifs with a named entity
+fn run_motifs(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer motifs <ontology_name|file.json>[,...] <motif code|name> [--catalogue <file.json|file.tsv>]");
+        process::exit(1);
+    };
+    
+    let (Some(ontology_name), Some(target)) = (args.first(), args.get(1)) else {
+        usage();
+        return;
+    };
+    
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let catalogue = match args[2..] {
+        [] => create_motif_catalogue(),
+        [ref flag, ref path] if flag == "--catalogue" => MotifCatalogue::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("Error loading motif catalogue from {}: {}", path, e);
+            process::exit(1);
+        }),
+        _ => {
+            usage();
+            return;
+        },
+    };
+    let motifs = Motifs::new(&ontology).with_catalogue(&catalogue);
+    
+    if let Ok(code) = MotifCode::new(target) {
+        println!("{}", catalogue.label(&code));
+        let tagged = motifs.tagged_with(&code);
+        for (entity, tag) in &tagged {
+            let note = tag.note.as_deref().map(|note| format!(" - {}", note)).unwrap_or_default();
+            println!("  {:<24} {:<12} {}{}", catalogue.label(&tag.code), entity.entity_type(), entity.name(), note);
+        }
+        let cultures = motifs.cultures_with(&code);
+        let cultures: Vec<&str> = cultures.iter().map(|c| c.value()).collect();
+        println!("{} tag(s) in {} culture(s): {}", tagged.len(), cultures.len(), cultures.join(", "));
+        return;
+    }
+    
+    let Some(entity) = ontology.entities_named(target).next() else {
+        eprintln!("'{}' is neither a motif code nor an entity name", target);
+        process::exit(1);
+    };
+    for tag in &entity.metadata().motifs {
+        println!("{}", catalogue.label(&tag.code));
+    }
+    let sharing = motifs.sharing_with(entity.id());
+    for shared in &sharing {
+        let codes: Vec<String> = shared.shared.iter().map(|code| catalogue.label(code)).collect();
+        println!("  {:<12} {}: {}", shared.entity_type, shared.name, codes.join("; "));
+    }
+    println!("{} match(es) sharing motifs with {}", sharing.len(), entity.name());
+}
+
+/// Print narratives episode by episode: every narrative, the one with a given
+/// title, or those a named entity takes part in
+fn run_narratives(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer narratives <ontology_name|file.json>[,...] [<name>] [--role <role>]");
+        process::exit(1);
+    };
+    
+    let Some(ontology_name) = args.first() else {
+        usage();
+        return;
+    };
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let mut name: Option<&str> = None;
+    let mut role: Option<NarrativeRole> = None;
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--role" => match rest.next() {
+                Some(r) => match parse_role(&ontology, r) {
+                    Ok(r) => role = Some(r),
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        usage();
+                    },
+                },
+                None => usage(),
+            },
+            _ if name.is_none() => name = Some(arg),
+            _ => usage(),
+        }
+    }
+    
+    let mut narratives: Vec<&Narrative> = match name {
+        None => ontology.entities_of_type("Narrative")
+            .filter_map(|entity| match entity {
+                MythEntity::Narrative(narrative) => Some(narrative),
+                _ => None,
+            })
+            .collect(),
+        Some(name) => {
+            let Some(entity) = ontology.entities_named(name).next() else {
+                eprintln!("No entity named '{}'", name);
+                process::exit(1);
+            };
+            match entity {
+                MythEntity::Narrative(narrative) => vec![narrative],
+                _ => QueryEngine::new(&ontology).find_narratives(entity.id(), role.clone()).results.iter()
+                    .filter_map(|result| match ontology.get_entity(&result.id) {
+                        Some(MythEntity::Narrative(narrative)) => Some(narrative),
+                        _ => None,
+                    })
+                    .collect(),
+            }
+        },
+    };
+    narratives.sort_by(|a, b| a.name.cmp(&b.name));
+    
+    let names = |ids: &[MythId]| -> String {
+        ids.iter()
+            .map(|id| ontology.get_entity(id).map_or_else(|| id.to_string(), |entity| entity.name().to_string()))
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    for narrative in &narratives {
+        println!("{} ({})", narrative.name, narrative.culture.value());
+        for (number, episode) in narrative.episodes().iter().enumerate() {
+            println!("  {}. {}", number + 1, episode.title);
+            let participants: Vec<String> = episode.participants.iter()
+                .map(|p| format!("{} ({})", names(std::slice::from_ref(&p.id)), p.role))
+                .collect();
+            if !participants.is_empty() {
+                println!("     participants: {}", participants.join(", "));
+            }
+            for (label, ids) in [("at", &episode.locations), ("using", &episode.artifacts), ("establishes", &episode.establishes)] {
+                if !ids.is_empty() {
+                    println!("     {}: {}", label, names(ids));
+                }
+            }
+        }
+    }
+    println!("{} narrative(s)", narratives.len());
+}
+
+/// Print the events of an ontology in chronological order, numbering each step;
+/// events sharing a number are not ordered relative to each other
+fn run_timeline(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer timeline <ontology_name|file.json>[,...] [--culture <culture>]");
+        process::exit(1);
+    };
+    
+    let Some(ontology_name) = args.first() else {
+        usage();
+        return;
+    };
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let chronology = match &args[1..] {
+        [] => Chronology::new(&ontology),
+        [flag, culture] if flag == "--culture" => Chronology::new(&ontology).in_culture(culture.as_str().into()),
+        _ => {
+            usage();
+            return;
+        },
+    };
+    
+    let timeline = chronology.timeline().unwrap_or_else(|e| {
+        eprintln!("Error: {}", e);
+        process::exit(1);
+    });
+    let name = |id: &MythId| ontology.get_entity(id).map_or_else(|| id.to_string(), |entity| entity.name().to_string());
+    for (number, step) in timeline.steps().iter().enumerate() {
+        for id in step {
+            let Some(MythEntity::Event(event)) = ontology.get_entity(id) else {
+                continue;
+            };
+            let mut details = vec![event.culture.value().to_string()];
+            details.extend(event.era.as_ref().map(&name));
+            details.extend(event.location.as_ref().map(|location| format!("at {}", name(location))));
+            println!("{:>3}. {} ({})", number + 1, event.name, details.join(", "));
+            if !event.participants.is_empty() {
+                let participants: Vec<String> = event.participants.iter().map(&name).collect();
+                println!("     participants: {}", participants.join(", "));
+            }
+        }
+    }
+    let order = if timeline.is_linear() { "linear" } else { "partial" };
+    println!("{} event(s) in {} step(s), {} order", timeline.len(), timeline.steps().len(), order);
+}
+
+/// List the sources of an ontology's bibliography, or print what the sources
+/// matching a title attest, citation by citation
+fn run_sources(args: &[String]) {
+    let (ontology_name, title) = match args {
+        [ontology_name] => (ontology_name, None),
+        [ontology_name, title] => (ontology_name, Some(title)),
+        _ => {
+            eprintln!("Usage: mythologic_explorer sources <ontology_name|file.json>[,...] [<title>]");
+            process::exit(1);
+        },
+    };
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let sources = ontology.bibliography().find_by_title(title.map_or("", String::as_str));
+    for (id, source) in &sources {
+        let author = source.author.as_ref().map(|author| format!(", {}", author)).unwrap_or_default();
+        let date = source.date.as_ref().map(|date| format!(" ({})", date)).unwrap_or_default();
+        let citations = ontology.cited_by(id);
+        println!("{}{}{} [{:?}]: {} citation(s)", source.title, author, date, source.source_type, citations.len());
+        if title.is_none() {
+            continue;
+        }
+        for (entity, citation) in citations {
+            let mut details = Vec::new();
+            if !citation.locator.is_empty() {
+                details.push(citation.locator.to_string());
+            }
+            details.extend(citation.field.as_ref().map(|field| format!("supports {}", field)));
+            let details = if details.is_empty() { String::new() } else { format!(" ({})", details.join("; ")) };
+            println!("  {:<12} {}{}", entity.entity_type(), entity.name(), details);
+        }
+    }
+    println!("{} source(s)", sources.len());
+}
+
+/// Print every contested question and field with its competing claims, marking
+/// the claims each resolution policy prefers
+fn run_variants(args: &[String]) {
+    let [ontology_name] = args else {
+        eprintln!("Usage: mythologic_explorer variants <ontology_name|file.json>[,...]");
+        process::exit(1);
+    };
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let resolvers = ResolutionPolicy::PREFERENCES.map(|policy| ClaimResolver::new(&ontology, policy));
+    let describe = |claim: &mythologic::core::Claim, preferred_by: Vec<String>| {
+        let mut details: Vec<String> = claim.tradition.iter().cloned().collect();
+        let source = ontology.source(&claim.citation.source).map_or("unknown source", |source| source.title.as_str());
+        details.push(if claim.citation.locator.is_empty() {
+            source.to_string()
+        } else {
+            format!("{}, {}", source, claim.citation.locator)
+        });
+        let preferred = if preferred_by.is_empty() { String::new() } else { format!(" [{}]", preferred_by.join(", ")) };
+        format!("{}{}", details.join("; "), preferred)
+    };
+    
+    let questions = resolvers[0].questions();
+    for (question, variants) in &questions {
+        println!("{}", question);
+        let preferred: Vec<Vec<&MythEntity>> = resolvers.iter().map(|resolver| resolver.preferred_variants(variants)).collect();
+        for variant in variants {
+            let preferred_by = resolvers.iter().zip(&preferred)
+                .filter(|(_, preferred)| preferred.iter().any(|e| e.id() == variant.id()))
+                .map(|(resolver, _)| resolver.policy().to_string())
+                .collect();
+            let claim = &variant.metadata().variant.as_ref().expect("grouped by variant").claim;
+            println!("  {:<32} {}", variant.name(), describe(claim, preferred_by));
+        }
+    }
+    
+    let mut claimed: Vec<&MythEntity> = ontology.entities().filter(|e| !e.metadata().claims.is_empty()).collect();
+    claimed.sort_by(|a, b| a.name().cmp(b.name()));
+    let mut fields = 0;
+    for entity in claimed {
+        let preferred: Vec<_> = resolvers.iter().map(|resolver| resolver.preferred_claims(entity.metadata())).collect();
+        for field in preferred[0].keys() {
+            fields += 1;
+            println!("{}: {}", entity.name(), field);
+            for claim in entity.metadata().claims_for(field) {
+                let preferred_by = resolvers.iter().zip(&preferred)
+                    .filter(|(_, preferred)| preferred[field].iter().any(|c| std::ptr::eq(*c, claim)))
+                    .map(|(resolver, _)| resolver.policy().to_string())
+                    .collect();
+                println!("  {:<32} {}", claim.value, describe(&claim.claim, preferred_by));
+            }
+        }
+    }
+    println!("{} contested question(s), {} contested field(s)", questions.len(), fields);
+}
+
+/// Export an ontology, or the result of a query against it, as Graphviz DOT
+fn run_dot(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer dot <ontology_name|file.json> [output.dot] [--cluster culture|pantheon] [--query <query>]");
+        process::exit(1);
+    };
+    
+    let Some(name) = args.first() else {
+        usage();
+        return;
+    };
+    
+    let mut output_path = None;
+    let mut options = DotOptions::new().graph_name(name.trim_end_matches(".json"));
+    let mut query = None;
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--cluster" => {
+                options = options.clustering(match rest.next().map(|c| c.to_lowercase()).as_deref() {
+                    Some("culture") => DotClustering::Culture,
+                    Some("pantheon") => DotClustering::Pantheon,
+                    Some("none") => DotClustering::None,
+                    _ => {
+                        usage();
+                        return;
+                    },
+                });
+            },
+            "--query" => match rest.next() {
+                Some(q) => query = Some(q.clone()),
+                None => usage(),
+            },
+            _ if output_path.is_none() => output_path = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+    
+    let ontology = load_ontology(name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let dot = match query {
+        Some(query) => {
+            let results = QueryEngine::new(&ontology).query_str(&query).unwrap_or_else(|e| {
+                eprintln!("Invalid query: {}", e.render(&query));
+                process::exit(1);
+            });
+            subgraph_to_dot(&ontology, &results.entity_ids(), &options)
+        },
+        None => ontology_to_dot(&ontology, &options),
+    };
+    
+    match output_path {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, dot) {
+                eprintln!("Error writing {}: {}", path, e);
+                process::exit(1);
+            }
+            println!("DOT graph written to {}", path);
+        },
+        None => print!("{}", dot),
+    }
+}
+
+/// Export an ontology, or a query result, as GraphML or GEXF for network analysis tools
+fn run_network(format: &str, args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer {} <ontology_name|file.json> [output] [--query <query>]", format);
+        process::exit(1);
+    };
+    
+    let Some(name) = args.first() else {
+        usage();
+        return;
+    };
+    
+    let mut output_path = None;
+    let mut query = None;
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--query" => match rest.next() {
+                Some(q) => query = Some(q.clone()),
+                None => usage(),
+            },
+            _ if output_path.is_none() => output_path = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+    
+    let ontology = load_ontology(name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    
+    let ids = query.map(|query| {
+        QueryEngine::new(&ontology).query_str(&query).unwrap_or_else(|e| {
+            eprintln!("Invalid query: {}", e.render(&query));
+            process::exit(1);
+        }).entity_ids()
+    });
+    let graph = match (format, ids) {
+        ("gexf", Some(ids)) => subgraph_to_gexf(&ontology, &ids),
+        ("gexf", None) => ontology_to_gexf(&ontology),
+        (_, Some(ids)) => subgraph_to_graphml(&ontology, &ids),
+        (_, None) => ontology_to_graphml(&ontology),
+    };
+    
+    match output_path {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, graph) {
+                eprintln!("Error writing {}: {}", path, e);
+                process::exit(1);
+            }
+            println!("{} graph written to {}", if format == "gexf" { "GEXF" } else { "GraphML" }, path);
+        },
+        None => print!("{}", graph),
+    }
+}
+
+/// Export an ontology as RDF/Turtle with its OWL schema
+fn run_rdf(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer rdf <ontology_name|file.json> [output.ttl] [--base <iri>] [--locale <language>] [--no-schema]");
+        process::exit(1);
+    };
+    
+    let Some(name) = args.first() else {
+        usage();
+        return;
+    };
+    
+    let mut output_path = None;
+    let mut options = RdfOptions::new();
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--base" => match rest.next() {
+                Some(base) => options = options.base_iri(base),
+                None => usage(),
+            },
+            "--locale" => match rest.next() {
+                Some(language) => options = options.locale(language),
+                None => usage(),
+            },
+            "--no-schema" => options = options.include_schema(false),
+            _ if output_path.is_none() => output_path = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+    
+    let ontology = load_ontology(name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let turtle = ontology_to_turtle(&ontology, &options);
+    
+    match output_path {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, turtle) {
+                eprintln!("Error writing {}: {}", path, e);
+                process::exit(1);
+            }
+            println!("Turtle written to {}", path);
+        },
+        None => print!("{}", turtle),
+    }
+}
+
+/// Export an ontology, or a single entity, as JSON-LD mapped to CIDOC-CRM and schema.org
+fn run_jsonld(args: &[String]) {
+    let usage = || {
+        eprintln!("Usage: mythologic_explorer jsonld <ontology_name|file.json> [output.jsonld] [--entity <name>] [--base <iri>] [--locale <language>] [--context-url <url>]");
+        process::exit(1);
+    };
+    
+    let Some(name) = args.first() else {
+        usage();
+        return;
+    };
+    
+    let mut output_path = None;
+    let mut entity_name = None;
+    let mut options = JsonLdOptions::new();
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        match arg.as_str() {
+            "--entity" => match rest.next() {
+                Some(entity) => entity_name = Some(entity.clone()),
+                None => usage(),
+            },
+            "--base" => match rest.next() {
+                Some(base) => options = options.base_iri(base),
+                None => usage(),
+            },
+            "--locale" => match rest.next() {
+                Some(language) => options = options.locale(language),
+                None => usage(),
+            },
+            "--context-url" => match rest.next() {
+                Some(url) => options = options.context_url(url),
+                None => usage(),
+            },
+            _ if output_path.is_none() => output_path = Some(arg.clone()),
+            _ => usage(),
+        }
+    }
+    
+    let ontology = load_ontology(name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(1);
+    });
+    let document = match &entity_name {
+        Some(entity_name) => match ontology.entities_named(entity_name).next() {
+            Some(entity) => entity_to_jsonld(&ontology, entity, &options),
+            None => {
+                eprintln!("No entity named '{}'", entity_name);
+                process::exit(1);
+            },
+        },
+        None => ontology_to_jsonld(&ontology, &options),
+    };
+    let json = serde_json::to_string_pretty(&document).expect("JSON values always serialize");
+    
+    match output_path {
+        Some(path) => {
+            if let Err(e) = std::fs::write(&path, json) {
+                eprintln!("Error writing {}: {}", path, e);
+                process::exit(1);
+            }
+            println!("JSON-LD written to {}", path);
+        },
+        None => println!("{}", json),
+    }
+}
+
+/// Export an ontology as one CSV/TSV sheet per entity kind, or import such sheets
+/// into a JSON ontology file
+fn run_tables(args: &[String]) {
+    let format = if args.iter().any(|a| a == "--tsv") { TabularFormat::Tsv } else { TabularFormat::Csv };
+    let args: Vec<&String> = args.iter().filter(|a| *a != "--tsv").collect();
+    
+    match args.as_slice() {
+        [command, name, dir] if command.as_str() == "export" => {
+            let ontology = load_ontology(name).unwrap_or_else(|e| {
+                eprintln!("{}", e);
+                process::exit(1);
+            });
+            if let Err(e) = export_tables(&ontology, Path::new(dir.as_str()), format) {
+                eprintln!("Error writing sheets to {}: {}", dir, e);
+                process::exit(1);
+            }
+            println!("Sheets written to {}", dir);
+        },
+        [command, dir, output_path] if command.as_str() == "import" => {
+            let (ontology, errors) = import_tables(Path::new(dir.as_str()), format).unwrap_or_else(|e| {
+                eprintln!("Error reading sheets from {}: {}", dir, e);
+                process::exit(1);
+            });
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            if let Err(e) = ontology.save(Path::new(output_path.as_str())) {
+                eprintln!("Error writing {}: {}", output_path, e);
+                process::exit(1);
+            }
+            println!("Imported {} entities into {} ({} row error(s))", ontology.entity_count(), output_path, errors.len());
+        },
+        _ => {
+            eprintln!("Usage: mythologic_explorer tables export <ontology_name|file.json> <directory> [--tsv]");
+            eprintln!("       mythologic_explorer tables import <directory> <output.json> [--tsv]");
+            process::exit(1);
+        },
+    }
 }
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().collect();
+    if let Some(position) = args.iter().position(|arg| arg == "--resolution") {
+        let Some(policy) = args.get(position + 1).and_then(|policy| policy.parse::<ResolutionPolicy>().ok()) else {
+            eprintln!("--resolution must be followed by one of {}", ResolutionPolicy::VARIANT_NAMES.join(", "));
+            process::exit(1);
+        };
+        args.drain(position..position + 2);
+        let _ = RESOLUTION.set(policy);
+    }
     
     if args.len() < 2 || args[1] == "--help" || args[1] == "-h" {
         print_usage();
         process::exit(if args.len() < 2 { 1 } else { 0 });
     }
     
+    if args[1] == "query" {
+        run_query(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "search" {
+        run_search(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "text" {
+        run_text(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "analogues" {
+        run_analogues(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "motifs" {
+        run_motifs(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "narratives" {
+        run_narratives(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "timeline" {
+        run_timeline(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "sources" {
+        run_sources(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "variants" {
+        run_variants(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "dot" {
+        run_dot(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "graphml" || args[1] == "gexf" {
+        run_network(&args[1], &args[2..]);
+        return;
+    }
+    
+    if args[1] == "rdf" {
+        run_rdf(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "jsonld" {
+        run_jsonld(&args[2..]);
+        return;
+    }
+    
+    if args[1] == "tables" {
+        run_tables(&args[2..]);
+        return;
+    }
+    
     let ontology_name = &args[1].to_lowercase();
     
     // Determine output path
@@ -75,7 +961,8 @@ fn main() {
             ("concepts", create_concepts_ontology()),
         ];
         
-        for (name, ontology) in ontologies.iter() {
+        for (name, ontology) in ontologies {
+            let ontology = apply_resolution(ontology);
             // If output_path is a directory, put files inside it
             // Otherwise, append the ontology name to the path
             let file_path = if args.len() > 2 {
@@ -86,7 +973,7 @@ fn main() {
             
             println!("  Generating {} ontology visualization at {}", name, file_path);
             
-            if let Err(e) = generate_html_visualization(ontology, Path::new(&file_path)) {
+            if let Err(e) = generate_html_visualization(&ontology, Path::new(&file_path)) {
                 eprintln!("Error generating visualization for {} ontology: {}", name, e);
             }
         }
@@ -98,23 +985,11 @@ fn main() {
     // Generate a single ontology visualization
     println!("Generating {} ontology visualization at {}", ontology_name, output_path);
     
-    let ontology = match ontology_name.as_str() {
-        "greek" => create_greek_ontology(),
-        "norse" => create_norse_ontology(),
-        "egyptian" => create_egyptian_ontology(),
-        "celtic" => create_celtic_ontology(),
-        "hindu" => create_hindu_ontology(),
-        "artifacts" => create_artifacts_ontology(),
-        "heroes" => create_heroes_ontology(),
-        "creatures" => create_creatures_ontology(),
-        "locations" => create_locations_ontology(),
-        "concepts" => create_concepts_ontology(),
-        _ => {
-            eprintln!("Unknown ontology: {}", ontology_name);
-            print_usage();
-            process::exit(1);
-        }
-    };
+    let ontology = load_ontology(ontology_name).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        print_usage();
+        process::exit(1);
+    });
     
     match generate_html_visualization(&ontology, Path::new(&output_path)) {
         Ok(_) => println!("Visualization generated successfully!"),
@@ -0,0 +1,147 @@
+//! # Relationship Edges
+//!
+//! Relationship entities (`Relationship`, `FamilyRelationship`, `AllianceRelationship`,
+//! `ConflictRelationship`, `TransformationRelationship`, and `SyncretismRelationship`)
+//! act as edges between the other entities of a [`MythOntology`]. This module
+//! provides the [`Edge`] view of those relationships and methods for listing the
+//! edges incident to an entity.
+
+use crate::core::{MythId, MythOntology};
+use crate::relationships::{Relationship, RelationshipType};
+
+/// Direction of an edge relative to the entity it was looked up from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeDirection {
+    /// The entity is the relationship's source
+    Outgoing,
+    /// The entity is the relationship's target
+    Incoming,
+}
+
+/// A relationship entity seen as an edge from one of its endpoints
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    /// ID of the relationship entity
+    pub relationship_id: MythId,
+    /// Source entity ID
+    pub source_id: MythId,
+    /// Target entity ID
+    pub target_id: MythId,
+    /// Type of relationship
+    pub relationship_type: RelationshipType,
+    /// Whether the relationship is bidirectional
+    pub bidirectional: bool,
+    /// Strength of the relationship (0.0-1.0)
+    pub strength: f32,
+    /// Direction relative to the entity the edge was looked up from
+    pub direction: EdgeDirection,
+}
+
+impl Edge {
+    /// Create an edge view of a relationship
+    pub fn new(relationship: &Relationship, direction: EdgeDirection) -> Self {
+        Self {
+            relationship_id: relationship.id.clone(),
+            source_id: relationship.source_id.clone(),
+            target_id: relationship.target_id.clone(),
+            relationship_type: relationship.relationship_type.clone(),
+            bidirectional: relationship.bidirectional,
+            strength: relationship.strength,
+            direction,
+        }
+    }
+    
+    /// Get the entity this edge was looked up from
+    pub fn origin(&self) -> &MythId {
+        match self.direction {
+            EdgeDirection::Outgoing => &self.source_id,
+            EdgeDirection::Incoming => &self.target_id,
+        }
+    }
+    
+    /// Get the entity at the far end of this edge
+    pub fn neighbor(&self) -> &MythId {
+        match self.direction {
+            EdgeDirection::Outgoing => &self.target_id,
+            EdgeDirection::Incoming => &self.source_id,
+        }
+    }
+    
+    /// Check whether this edge can be followed away from the entity it was looked up from
+    pub fn is_outgoing(&self) -> bool {
+        self.bidirectional || self.direction == EdgeDirection::Outgoing
+    }
+    
+    /// Check whether this edge can be followed towards the entity it was looked up from
+    pub fn is_incoming(&self) -> bool {
+        self.bidirectional || self.direction == EdgeDirection::Incoming
+    }
+}
+
+impl MythOntology {
+    /// Get every edge incident to an entity
+    ///
+    /// A self-referencing relationship is listed once, as outgoing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::core::{MythOntology, MythEntity, EdgeDirection};
+    /// use mythologic::entities::Deity;
+    /// use mythologic::relationships::{FamilyRelationship, FamilyRelationshipType, RelationshipType};
+    ///
+    /// let mut ontology = MythOntology::new();
+    /// let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+    /// let athena = Deity::new("Athena", "Goddess of wisdom", "Greek");
+    /// let (zeus_id, athena_id) = (zeus.id.clone(), athena.id.clone());
+    /// ontology.add_entity(MythEntity::Deity(zeus));
+    /// ontology.add_entity(MythEntity::Deity(athena));
+    /// ontology.add_entity(MythEntity::FamilyRelationship(FamilyRelationship::new(
+    ///     "Zeus fathers Athena", "", zeus_id.clone(), athena_id.clone(), FamilyRelationshipType::Parent,
+    /// )));
+    ///
+    /// let edges = ontology.edges(&athena_id);
+    /// assert_eq!(edges.len(), 1);
+    /// assert_eq!(edges[0].direction, EdgeDirection::Incoming);
+    /// assert_eq!(edges[0].relationship_type, RelationshipType::Family);
+    /// assert_eq!(edges[0].neighbor(), &zeus_id);
+    /// ```
+    pub fn edges(&self, id: &MythId) -> Vec<Edge> {
+        let outgoing = self.relationships_from(id)
+            .filter_map(|entity| entity.relationship())
+            .map(|relationship| Edge::new(relationship, EdgeDirection::Outgoing));
+        let incoming = self.relationships_to(id)
+            .filter_map(|entity| entity.relationship())
+            .filter(|relationship| &relationship.source_id != id)
+            .map(|relationship| Edge::new(relationship, EdgeDirection::Incoming));
+        
+        outgoing.chain(incoming).collect()
+    }
+    
+    /// Get the edges that can be followed away from an entity
+    ///
+    /// This includes relationships where the entity is the source, and
+    /// bidirectional relationships where it is the target.
+    pub fn outgoing_edges(&self, id: &MythId) -> Vec<Edge> {
+        self.edges(id).into_iter().filter(Edge::is_outgoing).collect()
+    }
+    
+    /// Get the edges that lead into an entity
+    ///
+    /// This includes relationships where the entity is the target, and
+    /// bidirectional relationships where it is the source.
+    pub fn incoming_edges(&self, id: &MythId) -> Vec<Edge> {
+        self.edges(id).into_iter().filter(Edge::is_incoming).collect()
+    }
+    
+    /// Get the IDs of the entities directly connected to an entity by an edge
+    pub fn neighbors(&self, id: &MythId) -> Vec<MythId> {
+        let mut neighbors: Vec<MythId> = Vec::new();
+        for edge in self.edges(id) {
+            if !neighbors.contains(edge.neighbor()) {
+                neighbors.push(edge.neighbor().clone());
+            }
+        }
+        neighbors
+    }
+}
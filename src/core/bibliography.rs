@@ -0,0 +1,299 @@
+//! # Bibliography and Citations
+//!
+//! Sources are shared records in an ontology's [`Bibliography`], each identified by
+//! a [`SourceId`]. Entities refer to them through [`Citation`]s in their
+//! [`Metadata`](crate::core::Metadata), so a text cited by many entities is stored
+//! once. A citation can carry a [`Locator`] pointing into the source (book, chapter,
+//! line, page) and name the field of the entity it supports; citing a relationship
+//! entity attests the relationship itself.
+//!
+//! ```
+//! use mythologic::core::{Citation, MythEntity, MythOntology, Source, SourceType};
+//! use mythologic::entities::Deity;
+//!
+//! let mut ontology = MythOntology::new();
+//! let edda = ontology.add_source(Source {
+//!     title: "Poetic Edda".to_string(),
+//!     author: None,
+//!     date: None,
+//!     source_type: SourceType::PrimaryText,
+//!     url: None,
+//!     notes: None,
+//! });
+//!
+//! let mut odin = Deity::new("Odin", "All-father of the Aesir", "Norse");
+//! odin.add_domain("Wisdom");
+//! odin.metadata.add_citation(Citation::new(edda.clone()).chapter("Hávamál").line("138-141").field("domains"));
+//! ontology.add_entity(MythEntity::Deity(odin));
+//!
+//! let (entity, citation) = ontology.cited_by(&edda)[0];
+//! assert_eq!(entity.name(), "Odin");
+//! assert_eq!(citation.locator.to_string(), "chapter Hávamál, line 138-141");
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use uuid::Uuid;
+use crate::core::{MythEntity, MythOntology, Source};
+
+/// Namespace of the name-based IDs given to sources by [`SourceId::from_content`]
+const SOURCE_NAMESPACE: Uuid = Uuid::from_u128(0xb870058c_028e_482f_91f9_2ca185c6edc1);
+
+/// A unique identifier for a source in a [`Bibliography`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct SourceId(Uuid);
+
+impl SourceId {
+    /// Create a new random SourceId
+    pub fn new() -> Self {
+        SourceId(Uuid::new_v4())
+    }
+    
+    /// Create a SourceId derived from a source's content, for IDs that must be the
+    /// same every time they are generated and differ between different sources
+    pub(crate) fn from_content(content: &str) -> Self {
+        SourceId(Uuid::new_v5(&SOURCE_NAMESPACE, content.as_bytes()))
+    }
+    
+    /// Create a SourceId from a string representation
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &str) -> Result<Self, uuid::Error> {
+        Ok(SourceId(Uuid::parse_str(s)?))
+    }
+}
+
+impl fmt::Display for SourceId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Default for SourceId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Where in a source a citation points
+///
+/// Each part is free text, so ranges (`"100-120"`) and named divisions
+/// (`"Völuspá"`) are both allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Locator {
+    /// Book or other major division
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub book: Option<String>,
+    /// Chapter, poem, or section
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub chapter: Option<String>,
+    /// Line or verse
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<String>,
+    /// Page of the edition cited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page: Option<String>,
+}
+
+impl Locator {
+    /// Check whether the locator points anywhere more specific than the whole source
+    pub fn is_empty(&self) -> bool {
+        self.book.is_none() && self.chapter.is_none() && self.line.is_none() && self.page.is_none()
+    }
+}
+
+impl fmt::Display for Locator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts = [("book", &self.book), ("chapter", &self.chapter), ("line", &self.line), ("page", &self.page)];
+        let mut first = true;
+        for (label, value) in parts {
+            if let Some(value) = value {
+                if !first {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{} {}", label, value)?;
+                first = false;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A reference from an entity to a source in the bibliography
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Citation {
+    /// The source cited
+    pub source: SourceId,
+    /// Where in the source
+    #[serde(default, skip_serializing_if = "Locator::is_empty")]
+    pub locator: Locator,
+    /// The field of the entity the source supports, or `None` for the entity as a whole
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub field: Option<String>,
+    /// Additional notes about the citation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Citation {
+    /// Cite a whole source
+    pub fn new(source: SourceId) -> Self {
+        Self {
+            source,
+            locator: Locator::default(),
+            field: None,
+            note: None,
+        }
+    }
+    
+    /// Point the citation at a book of the source
+    pub fn book(mut self, book: &str) -> Self {
+        self.locator.book = Some(book.to_string());
+        self
+    }
+    
+    /// Point the citation at a chapter, poem, or section of the source
+    pub fn chapter(mut self, chapter: &str) -> Self {
+        self.locator.chapter = Some(chapter.to_string());
+        self
+    }
+    
+    /// Point the citation at a line or verse of the source
+    pub fn line(mut self, line: &str) -> Self {
+        self.locator.line = Some(line.to_string());
+        self
+    }
+    
+    /// Point the citation at a page of the source
+    pub fn page(mut self, page: &str) -> Self {
+        self.locator.page = Some(page.to_string());
+        self
+    }
+    
+    /// Attach the citation to a single field of the entity
+    pub fn field(mut self, field: &str) -> Self {
+        self.field = Some(field.to_string());
+        self
+    }
+    
+    /// Set a note on the citation
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+}
+
+/// The shared sources of an ontology
+#[derive(Debug, Clone, Default)]
+pub struct Bibliography {
+    sources: HashMap<SourceId, Source>,
+    /// IDs of the sources with each title, so duplicates are found without a scan
+    by_title: HashMap<String, Vec<SourceId>>,
+}
+
+impl Bibliography {
+    /// Create an empty bibliography
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add a source, returning its ID
+    ///
+    /// A source equal to one already in the bibliography is not added again; the
+    /// existing source's ID is returned instead.
+    pub fn add(&mut self, source: Source) -> SourceId {
+        let existing = self.by_title.get(&source.title).into_iter()
+            .flatten()
+            .find(|id| self.sources.get(*id) == Some(&source));
+        if let Some(id) = existing {
+            return id.clone();
+        }
+        let id = SourceId::new();
+        self.insert(id.clone(), source);
+        id
+    }
+    
+    /// Add or replace the source with a given ID
+    pub fn insert(&mut self, id: SourceId, source: Source) {
+        self.by_title.entry(source.title.clone()).or_default().push(id.clone());
+        if let Some(previous) = self.sources.insert(id.clone(), source)
+            && let Some(ids) = self.by_title.get_mut(&previous.title)
+        {
+            // The new entry is last in its title's list; drop the earlier one
+            if let Some(position) = ids.iter().position(|existing| existing == &id) {
+                ids.remove(position);
+            }
+            if ids.is_empty() {
+                self.by_title.remove(&previous.title);
+            }
+        }
+    }
+    
+    /// Get a source by ID
+    pub fn get(&self, id: &SourceId) -> Option<&Source> {
+        self.sources.get(id)
+    }
+    
+    /// Check whether the bibliography contains a source
+    pub fn contains(&self, id: &SourceId) -> bool {
+        self.sources.contains_key(id)
+    }
+    
+    /// Iterate over all sources
+    pub fn iter(&self) -> impl Iterator<Item = (&SourceId, &Source)> {
+        self.sources.iter()
+    }
+    
+    /// Find sources whose title contains a substring, ignoring case
+    pub fn find_by_title(&self, title: &str) -> Vec<(&SourceId, &Source)> {
+        let title = title.to_lowercase();
+        let mut found: Vec<_> = self.sources.iter()
+            .filter(|(_, source)| source.title.to_lowercase().contains(&title))
+            .collect();
+        found.sort_by(|a, b| a.1.title.cmp(&b.1.title));
+        found
+    }
+    
+    /// Get the number of sources
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+    
+    /// Check whether the bibliography is empty
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+}
+
+impl MythOntology {
+    /// Add a source to the bibliography, returning its ID
+    ///
+    /// Adding a source equal to one already present returns the existing ID.
+    pub fn add_source(&mut self, source: Source) -> SourceId {
+        self.bibliography_mut().add(source)
+    }
+    
+    /// Get a source from the bibliography
+    pub fn source(&self, id: &SourceId) -> Option<&Source> {
+        self.bibliography().get(id)
+    }
+    
+    /// Find every citation of a source, with the entity or relationship citing it
+    ///
+    /// Citations made by motif tags and variant claims are included. Results are
+    /// sorted by entity name.
+    pub fn cited_by(&self, id: &SourceId) -> Vec<(&MythEntity, &Citation)> {
+        let mut citations: Vec<_> = self.entities()
+            .flat_map(|entity| {
+                let metadata = entity.metadata();
+                metadata.citations.iter()
+                    .chain(metadata.motifs.iter().filter_map(|tag| tag.citation.as_ref()))
+                    .chain(metadata.all_claims().map(|claim| &claim.citation))
+                    .filter(|citation| &citation.source == id)
+                    .map(move |citation| (entity, citation))
+            })
+            .collect();
+        citations.sort_by(|a, b| a.0.name().cmp(b.0.name()).then_with(|| a.0.id().to_string().cmp(&b.0.id().to_string())));
+        citations
+    }
+}
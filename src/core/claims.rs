@@ -0,0 +1,591 @@
+//! # Variant Claims
+//!
+//! Sources often disagree: Aphrodite is the daughter of Zeus and Dione in Homer but
+//! born of Ouranos in Hesiod. Rather than choosing one version when the data is
+//! entered, each version is recorded as a [`Claim`] tied to the source and tradition
+//! telling it:
+//!
+//! - A relationship told only by some sources carries a [`Variant`] in its metadata,
+//!   naming the contested question it answers. Relationships with the same question
+//!   are competing variants; relationships told by the same source are not in
+//!   competition with each other.
+//! - A contested field value is a [`FieldClaim`] in the metadata of the entity,
+//!   naming the field and the value claimed.
+//!
+//! A [`ClaimResolver`] ranks competing claims by a [`ResolutionPolicy`], and
+//! [`MythOntology::resolved`] builds the view of an ontology in which only the
+//! preferred claims remain and contested fields take the preferred value, for
+//! querying and exporting.
+//!
+//! ```
+//! use mythologic::core::{Citation, Claim, MythEntity, MythOntology, ResolutionPolicy, Source, SourceType, HistoricalDate};
+//! use mythologic::entities::Deity;
+//! use mythologic::relationships::{FamilyRelationship, FamilyRelationshipType};
+//!
+//! let mut ontology = MythOntology::new();
+//! let source = |title: &str, year| Source {
+//!     title: title.to_string(),
+//!     author: None,
+//!     date: Some(HistoricalDate::circa(year)),
+//!     source_type: SourceType::PrimaryText,
+//!     url: None,
+//!     notes: None,
+//! };
+//! let iliad = ontology.add_source(source("Iliad", -750));
+//! let theogony = ontology.add_source(source("Theogony", -700));
+//!
+//! let aphrodite = Deity::new("Aphrodite", "Goddess of love", "Greek");
+//! let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+//! let ouranos = Deity::new("Ouranos", "The sky", "Greek");
+//! for (parent, claim) in [
+//!     (&zeus, Claim::new(Citation::new(iliad).book("5").line("370")).tradition("Homeric")),
+//!     (&ouranos, Claim::new(Citation::new(theogony).line("188-200")).tradition("Hesiodic")),
+//! ] {
+//!     let description = format!("{} is Aphrodite's parent", parent.name);
+//!     let relationship = FamilyRelationship::builder(
+//!         "Parent of Aphrodite", &description, parent.id.clone(), aphrodite.id.clone(), FamilyRelationshipType::Parent,
+//!     ).build();
+//!     let mut relationship = MythEntity::FamilyRelationship(relationship);
+//!     relationship.metadata_mut().set_variant("parentage of Aphrodite", claim);
+//!     ontology.add_entity(relationship);
+//! }
+//! ontology.add_entity(MythEntity::Deity(aphrodite));
+//! ontology.add_entity(MythEntity::Deity(zeus));
+//! ontology.add_entity(MythEntity::Deity(ouranos));
+//!
+//! let earliest = ontology.resolved(ResolutionPolicy::PreferEarliest);
+//! let parents: Vec<&str> = earliest.entities_of_type("FamilyRelationship")
+//!     .filter_map(|e| earliest.get_entity(&e.relationship()?.source_id))
+//!     .map(|parent| parent.name())
+//!     .collect();
+//! assert_eq!(parents, ["Zeus"]);
+//! assert_eq!(ontology.resolved(ResolutionPolicy::ShowAll).entity_count(), 5);
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+use crate::core::{variant_names, Citation, Metadata, MythEntity, MythId, MythOntology, SourceType};
+
+/// One version of a contested fact, as told by a source
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Claim {
+    /// The passage telling this version
+    pub citation: Citation,
+    /// The tradition the version belongs to, such as "Homeric" or "Orphic"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tradition: Option<String>,
+    /// Additional notes about the claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl Claim {
+    /// Create a claim made by a cited source
+    pub fn new(citation: Citation) -> Self {
+        Self {
+            citation,
+            tradition: None,
+            note: None,
+        }
+    }
+    
+    /// Set the tradition the claim belongs to
+    pub fn tradition(mut self, tradition: &str) -> Self {
+        self.tradition = Some(tradition.to_string());
+        self
+    }
+    
+    /// Set a note on the claim
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+}
+
+/// Marks a relationship as one version of a contested question
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Variant {
+    /// The contested question, such as "parentage of Aphrodite"; relationships with
+    /// the same question compete
+    pub question: String,
+    /// Who tells this version
+    pub claim: Claim,
+}
+
+/// A contested value of one of an entity's fields
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FieldClaim {
+    /// The field the claim is about, either a structured field or a metadata attribute
+    pub field: String,
+    /// The value claimed
+    pub value: String,
+    /// Who makes the claim
+    pub claim: Claim,
+}
+
+/// How to choose between competing claims
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum ResolutionPolicy {
+    /// Prefer claims made in primary texts, then the earliest of those
+    PreferPrimaryText,
+    /// Prefer the claims of the earliest dated sources, then primary texts
+    PreferEarliest,
+    /// Keep every claim
+    #[default]
+    ShowAll,
+}
+
+variant_names!(ResolutionPolicy { PreferPrimaryText, PreferEarliest, ShowAll });
+
+impl ResolutionPolicy {
+    /// The policies that choose between claims, leaving out [`ResolutionPolicy::ShowAll`]
+    pub const PREFERENCES: [ResolutionPolicy; 2] = [Self::PreferPrimaryText, Self::PreferEarliest];
+}
+
+impl Metadata {
+    /// Mark this relationship as one version of a contested question
+    pub fn set_variant(&mut self, question: &str, claim: Claim) {
+        self.variant = Some(Variant {
+            question: question.to_string(),
+            claim,
+        });
+    }
+    
+    /// Record a source's claim about the value of a field
+    pub fn add_claim(&mut self, field: &str, value: &str, claim: Claim) {
+        self.claims.push(FieldClaim {
+            field: field.to_string(),
+            value: value.to_string(),
+            claim,
+        });
+    }
+    
+    /// Get the claims about a field
+    pub fn claims_for<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a FieldClaim> + 'a {
+        self.claims.iter().filter(move |claim| claim.field == field)
+    }
+    
+    /// Iterate over every claim made in this metadata, the variant's included
+    pub fn all_claims(&self) -> impl Iterator<Item = &Claim> {
+        self.variant.iter().map(|variant| &variant.claim).chain(self.claims.iter().map(|claim| &claim.claim))
+    }
+    
+    /// Write a claimed value to the field it is about, leaving a structured field
+    /// alone if the value does not parse
+    fn set_claimed_value(&mut self, field: &str, value: String) {
+        match field {
+            "first_attested" => if let Ok(date) = value.parse() {
+                self.first_attested = Some(date);
+            },
+            "last_attested" => if let Ok(date) = value.parse() {
+                self.last_attested = Some(date);
+            },
+            "confidence" => if let Ok(confidence) = value.parse() {
+                self.confidence = Some(confidence);
+            },
+            _ => {
+                self.attributes.insert(field.to_string(), value);
+            },
+        }
+    }
+}
+
+impl MythEntity {
+    /// Write a claimed value to the field it is about: a typed field of the entity
+    /// such as a deity's `gender` or a creature's `creature_type`, otherwise a field
+    /// of the metadata. A typed field is left alone if the value does not parse.
+    fn set_claimed_value(&mut self, field: &str, value: String) {
+        fn parse<T: FromStr>(slot: &mut T, value: &str) {
+            if let Ok(parsed) = value.parse() {
+                *slot = parsed;
+            }
+        }
+        
+        match (self, field) {
+            (Self::Deity(deity), "gender") => parse(&mut deity.gender, &value),
+            (Self::Deity(deity), "importance") => parse(&mut deity.importance, &value),
+            (Self::Deity(deity), "pantheon") => deity.set_pantheon(&value),
+            (Self::Hero(hero), "origin") => parse(&mut hero.origin, &value),
+            (Self::Creature(creature), "creature_type") => parse(&mut creature.creature_type, &value),
+            (Self::Artifact(artifact), "artifact_type") => parse(&mut artifact.artifact_type, &value),
+            (Self::Artifact(artifact), "creator") => artifact.set_creator(&value),
+            (Self::Artifact(artifact), "owner") => artifact.set_owner(&value),
+            (Self::Location(location), "location_type") => parse(&mut location.location_type, &value),
+            (Self::Concept(concept), "concept_type") => parse(&mut concept.concept_type, &value),
+            (Self::Relationship(relationship), "relationship_type") => parse(&mut relationship.relationship_type, &value),
+            (Self::FamilyRelationship(family), "family_type") => parse(&mut family.family_type, &value),
+            (Self::AllianceRelationship(alliance), "alliance_type") => parse(&mut alliance.alliance_type, &value),
+            (Self::ConflictRelationship(conflict), "conflict_type") => parse(&mut conflict.conflict_type, &value),
+            (Self::TransformationRelationship(transformation), "transformation_type") => {
+                parse(&mut transformation.transformation_type, &value)
+            },
+            (Self::SyncretismRelationship(syncretism), "syncretism_type") => parse(&mut syncretism.syncretism_type, &value),
+            (entity, field) => entity.metadata_mut().set_claimed_value(field, value),
+        }
+    }
+}
+
+/// Chooses between competing claims in an ontology according to a policy
+///
+/// Claims are ranked by the source they cite: whether it is a primary text, and the
+/// earliest year its date allows. Sources missing from the bibliography or without a
+/// date rank last. Claims that tie for the best rank are all kept.
+pub struct ClaimResolver<'a> {
+    ontology: &'a MythOntology,
+    policy: ResolutionPolicy,
+}
+
+impl<'a> ClaimResolver<'a> {
+    /// Create a resolver for an ontology
+    pub fn new(ontology: &'a MythOntology, policy: ResolutionPolicy) -> Self {
+        Self { ontology, policy }
+    }
+    
+    /// The policy claims are resolved by
+    pub fn policy(&self) -> ResolutionPolicy {
+        self.policy
+    }
+    
+    /// The rank of a claim under the policy; lower ranks are preferred
+    fn rank(&self, claim: &Claim) -> (i64, i64) {
+        let source = self.ontology.source(&claim.citation.source);
+        let secondary = i64::from(source.is_none_or(|source| source.source_type != SourceType::PrimaryText));
+        let year = source
+            .and_then(|source| source.date.as_ref())
+            .and_then(|date| {
+                let extent = date.extent();
+                extent.earliest.or(extent.latest)
+            })
+            .map_or(i64::MAX, i64::from);
+        match self.policy {
+            ResolutionPolicy::PreferPrimaryText => (secondary, year),
+            ResolutionPolicy::PreferEarliest => (year, secondary),
+            ResolutionPolicy::ShowAll => (0, 0),
+        }
+    }
+    
+    /// Keep the items whose claims tie for the best rank
+    fn best<'c, T: Copy>(&self, items: &[T], claim: impl Fn(T) -> &'c Claim) -> Vec<T> {
+        let best = items.iter().map(|item| self.rank(claim(*item))).min();
+        items.iter().copied().filter(|item| Some(self.rank(claim(*item))) == best).collect()
+    }
+    
+    /// The competing variants of every contested question, sorted by question and
+    /// then by relationship name
+    pub fn questions(&self) -> BTreeMap<&'a str, Vec<&'a MythEntity>> {
+        let mut questions: BTreeMap<&str, Vec<&MythEntity>> = BTreeMap::new();
+        for entity in self.ontology.entities() {
+            if let Some(variant) = &entity.metadata().variant {
+                questions.entry(variant.question.as_str()).or_default().push(entity);
+            }
+        }
+        for variants in questions.values_mut() {
+            variants.sort_by(|a, b| a.name().cmp(b.name()).then_with(|| a.id().to_string().cmp(&b.id().to_string())));
+        }
+        questions
+    }
+    
+    /// The preferred variants among relationships answering the same question
+    pub fn preferred_variants(&self, variants: &[&'a MythEntity]) -> Vec<&'a MythEntity> {
+        let claimed: Vec<&MythEntity> = variants.iter().copied().filter(|e| e.metadata().variant.is_some()).collect();
+        self.best(&claimed, |entity| &entity.metadata().variant.as_ref().expect("filtered to variants").claim)
+    }
+    
+    /// The IDs of the relationships whose variant is not preferred
+    pub fn rejected_variants(&self) -> HashSet<MythId> {
+        let mut rejected = HashSet::new();
+        for variants in self.questions().values() {
+            let preferred: HashSet<&MythId> = self.preferred_variants(variants).iter().map(|e| e.id()).collect();
+            rejected.extend(variants.iter().map(|e| e.id()).filter(|id| !preferred.contains(id)).cloned());
+        }
+        rejected
+    }
+    
+    /// The preferred claims about each contested field of an entity, keyed by field
+    pub fn preferred_claims<'m>(&self, metadata: &'m Metadata) -> BTreeMap<&'m str, Vec<&'m FieldClaim>> {
+        let mut fields: BTreeMap<&str, Vec<&FieldClaim>> = BTreeMap::new();
+        for claim in &metadata.claims {
+            fields.entry(claim.field.as_str()).or_default().push(claim);
+        }
+        fields.into_iter()
+            .map(|(field, claims)| (field, self.best(&claims, |claim| &claim.claim)))
+            .collect()
+    }
+    
+    /// The positions in `metadata.claims` of the claims that are not preferred
+    pub fn rejected_claims(&self, metadata: &Metadata) -> Vec<usize> {
+        let mut best: HashMap<&str, (i64, i64)> = HashMap::new();
+        for claim in &metadata.claims {
+            let rank = self.rank(&claim.claim);
+            best.entry(claim.field.as_str()).and_modify(|b| *b = (*b).min(rank)).or_insert(rank);
+        }
+        metadata.claims.iter().enumerate()
+            .filter(|(_, claim)| best[claim.field.as_str()] != self.rank(&claim.claim))
+            .map(|(position, _)| position)
+            .collect()
+    }
+}
+
+impl MythOntology {
+    /// Build the view of this ontology in which only the claims preferred by a
+    /// policy remain
+    ///
+    /// Relationships whose variant is not preferred are removed, and so are field
+    /// claims that are not preferred. Where the preferred claims about a field agree
+    /// on a value, that value is written to the field: the entity's typed fields
+    /// (`gender`, `importance`, `origin`, `creature_type`, ...) and the attestation
+    /// dates and confidence of the metadata by name, any other field as a metadata
+    /// attribute. Preferred claims that tie with different values, and values that
+    /// do not parse as the field's type, leave the field as it is.
+    /// With [`ResolutionPolicy::ShowAll`] the view is a copy of the ontology.
+    pub fn resolved(&self, policy: ResolutionPolicy) -> MythOntology {
+        let mut resolved = self.clone();
+        if policy == ResolutionPolicy::ShowAll {
+            return resolved;
+        }
+        
+        let resolver = ClaimResolver::new(self, policy);
+        for id in resolver.rejected_variants() {
+            resolved.remove_entity(&id);
+        }
+        for entity in self.entities().filter(|entity| !entity.metadata().claims.is_empty()) {
+            let rejected = resolver.rejected_claims(entity.metadata());
+            let values: Vec<(String, String)> = resolver.preferred_claims(entity.metadata()).into_iter()
+                .filter_map(|(field, claims)| {
+                    let value = &claims.first()?.value;
+                    claims.iter().all(|claim| &claim.value == value).then(|| (field.to_string(), value.clone()))
+                })
+                .collect();
+            if let Some(mut entity) = resolved.get_entity_mut(entity.id()) {
+                let mut position = 0;
+                entity.metadata_mut().claims.retain(|_| {
+                    position += 1;
+                    !rejected.contains(&(position - 1))
+                });
+                for (field, value) in values {
+                    entity.set_claimed_value(&field, value);
+                }
+            }
+        }
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{HistoricalDate, Source, SourceId};
+    use crate::entities::{Deity, DeityImportance, Gender};
+    use crate::query::QueryFilter;
+    use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+    
+    fn source(ontology: &mut MythOntology, title: &str, date: Option<&str>, source_type: SourceType) -> SourceId {
+        ontology.add_source(Source {
+            title: title.to_string(),
+            author: None,
+            date: date.map(|date| date.parse().unwrap()),
+            source_type,
+            url: None,
+            notes: None,
+        })
+    }
+    
+    fn claim(source: &SourceId) -> Claim {
+        Claim::new(Citation::new(source.clone()))
+    }
+    
+    fn variant(ontology: &mut MythOntology, parent: &str, claim: Claim) -> MythId {
+        let relationship = FamilyRelationship::new(
+            &format!("{} parent of Aphrodite", parent),
+            &format!("{} is a parent of Aphrodite.", parent),
+            MythId::new(),
+            MythId::new(),
+            FamilyRelationshipType::Parent,
+        );
+        let mut entity = MythEntity::FamilyRelationship(relationship);
+        entity.metadata_mut().set_variant("parentage of Aphrodite", claim);
+        let id = entity.id().clone();
+        ontology.add_entity(entity);
+        id
+    }
+    
+    fn preferred(ontology: &MythOntology, policy: ResolutionPolicy) -> Vec<&str> {
+        let resolver = ClaimResolver::new(ontology, policy);
+        let questions = resolver.questions();
+        let mut names: Vec<&str> = resolver.preferred_variants(&questions["parentage of Aphrodite"]).iter()
+            .map(|entity| entity.name())
+            .collect();
+        names.sort();
+        names
+    }
+    
+    #[test]
+    fn prefers_claims_by_policy() {
+        let mut ontology = MythOntology::new();
+        let hymn = source(&mut ontology, "Homeric Hymn", Some("6th century BCE"), SourceType::PrimaryText);
+        let iliad = source(&mut ontology, "Iliad", Some("c. 750 BCE"), SourceType::PrimaryText);
+        let commentary = source(&mut ontology, "Commentary", Some("c. 900 BCE"), SourceType::Book);
+        variant(&mut ontology, "Zeus", claim(&iliad));
+        variant(&mut ontology, "Ouranos", claim(&hymn));
+        variant(&mut ontology, "Kronos", claim(&commentary));
+        
+        assert_eq!(preferred(&ontology, ResolutionPolicy::PreferPrimaryText), ["Zeus parent of Aphrodite"]);
+        assert_eq!(preferred(&ontology, ResolutionPolicy::PreferEarliest), ["Kronos parent of Aphrodite"]);
+        assert_eq!(preferred(&ontology, ResolutionPolicy::ShowAll).len(), 3);
+        assert_eq!(ClaimResolver::new(&ontology, ResolutionPolicy::PreferEarliest).rejected_variants().len(), 2);
+        assert_eq!(ontology.resolved(ResolutionPolicy::PreferPrimaryText).entity_count(), 1);
+        assert_eq!(ontology.resolved(ResolutionPolicy::ShowAll).entity_count(), 3);
+    }
+    
+    #[test]
+    fn keeps_claims_that_tie() {
+        let mut ontology = MythOntology::new();
+        let iliad = source(&mut ontology, "Iliad", Some("c. 750 BCE"), SourceType::PrimaryText);
+        let odyssey = source(&mut ontology, "Odyssey", Some("c. 750 BCE"), SourceType::PrimaryText);
+        variant(&mut ontology, "Zeus", claim(&iliad));
+        variant(&mut ontology, "Dione", claim(&odyssey));
+        
+        for policy in ResolutionPolicy::PREFERENCES {
+            assert_eq!(preferred(&ontology, policy), ["Dione parent of Aphrodite", "Zeus parent of Aphrodite"]);
+            assert!(ClaimResolver::new(&ontology, policy).rejected_variants().is_empty());
+        }
+    }
+    
+    #[test]
+    fn ranks_unknown_and_undated_sources_last() {
+        let mut ontology = MythOntology::new();
+        let undated = source(&mut ontology, "Scholia", None, SourceType::PrimaryText);
+        let late = source(&mut ontology, "Nonnus", Some("5th century CE"), SourceType::PrimaryText);
+        variant(&mut ontology, "Zeus", claim(&undated));
+        variant(&mut ontology, "Ouranos", claim(&late));
+        variant(&mut ontology, "Kronos", claim(&SourceId::new()));
+        
+        for policy in ResolutionPolicy::PREFERENCES {
+            assert_eq!(preferred(&ontology, policy), ["Ouranos parent of Aphrodite"]);
+        }
+        
+        // An undated primary text still beats a source missing from the bibliography
+        let mut unknown = MythOntology::new();
+        let undated = source(&mut unknown, "Scholia", None, SourceType::PrimaryText);
+        variant(&mut unknown, "Zeus", claim(&undated));
+        variant(&mut unknown, "Kronos", claim(&SourceId::new()));
+        assert_eq!(preferred(&unknown, ResolutionPolicy::PreferPrimaryText), ["Zeus parent of Aphrodite"]);
+        assert_eq!(preferred(&unknown, ResolutionPolicy::PreferEarliest), ["Zeus parent of Aphrodite"]);
+    }
+    
+    #[test]
+    fn keeps_variants_told_by_the_same_source() {
+        let mut ontology = MythOntology::new();
+        let theogony = source(&mut ontology, "Theogony", Some("c. 700 BCE"), SourceType::PrimaryText);
+        let iliad = source(&mut ontology, "Iliad", Some("c. 750 BCE"), SourceType::PrimaryText);
+        variant(&mut ontology, "Ouranos", Claim::new(Citation::new(theogony.clone()).line("188")));
+        variant(&mut ontology, "Gaia", Claim::new(Citation::new(theogony).line("190")));
+        variant(&mut ontology, "Zeus", claim(&iliad));
+        
+        let resolved = ontology.resolved(ResolutionPolicy::PreferPrimaryText);
+        let mut names: Vec<&str> = resolved.entities().map(|entity| entity.name()).collect();
+        names.sort();
+        assert_eq!(names, ["Zeus parent of Aphrodite"]);
+        
+        let resolved = ontology.resolved(ResolutionPolicy::PreferEarliest);
+        assert_eq!(resolved.entity_count(), 1);
+        
+        let mut later = MythOntology::new();
+        let theogony = source(&mut later, "Theogony", Some("c. 700 BCE"), SourceType::PrimaryText);
+        let nonnus = source(&mut later, "Nonnus", Some("5th century CE"), SourceType::PrimaryText);
+        variant(&mut later, "Ouranos", claim(&theogony));
+        variant(&mut later, "Gaia", claim(&theogony));
+        variant(&mut later, "Zeus", claim(&nonnus));
+        assert_eq!(preferred(&later, ResolutionPolicy::PreferEarliest), ["Gaia parent of Aphrodite", "Ouranos parent of Aphrodite"]);
+    }
+    
+    #[test]
+    fn finds_rejected_claims_by_position() {
+        let mut ontology = MythOntology::new();
+        let theogony = source(&mut ontology, "Theogony", Some("c. 700 BCE"), SourceType::PrimaryText);
+        let odes = source(&mut ontology, "Odes", Some("23 BCE"), SourceType::PrimaryText);
+        let handbook = source(&mut ontology, "Handbook", Some("1st century CE"), SourceType::Book);
+        
+        let mut metadata = Metadata::new();
+        metadata.add_claim("heads", "100", claim(&odes));
+        metadata.add_claim("heads", "50", claim(&theogony));
+        metadata.add_claim("home", "Tartarus", claim(&handbook));
+        metadata.add_claim("heads", "3", claim(&handbook));
+        metadata.add_claim("home", "Hades", claim(&theogony));
+        metadata.add_claim("heads", "fifty", claim(&theogony));
+        
+        let resolver = ClaimResolver::new(&ontology, ResolutionPolicy::PreferEarliest);
+        assert_eq!(resolver.rejected_claims(&metadata), [0, 2, 3]);
+        let preferred = resolver.preferred_claims(&metadata);
+        let values = |field: &str| preferred[field].iter().map(|claim| claim.value.as_str()).collect::<Vec<_>>();
+        assert_eq!(values("heads"), ["50", "fifty"]);
+        assert_eq!(values("home"), ["Hades"]);
+        
+        let mut cerberus = Deity::new("Cerberus", "Hound of Hades", "Greek");
+        cerberus.metadata.claims = metadata.claims.clone();
+        let id = cerberus.id.clone();
+        ontology.add_entity(MythEntity::Deity(cerberus));
+        let resolved = ontology.resolved(ResolutionPolicy::PreferEarliest);
+        let kept: Vec<&str> = resolved.get_entity(&id).unwrap().metadata().claims.iter()
+            .map(|claim| claim.value.as_str())
+            .collect();
+        assert_eq!(kept, ["50", "Hades", "fifty"]);
+        assert!(ClaimResolver::new(&ontology, ResolutionPolicy::ShowAll).rejected_claims(&metadata).is_empty());
+    }
+    
+    #[test]
+    fn writes_preferred_values_to_fields() {
+        let mut ontology = MythOntology::new();
+        let theogony = source(&mut ontology, "Theogony", Some("c. 700 BCE"), SourceType::PrimaryText);
+        let odes = source(&mut ontology, "Odes", Some("23 BCE"), SourceType::PrimaryText);
+        
+        let mut cerberus = Deity::new("Cerberus", "Hound of Hades", "Greek");
+        cerberus.metadata.attributes.insert("heads".to_string(), "3".to_string());
+        cerberus.metadata.add_claim("heads", "50", claim(&theogony));
+        cerberus.metadata.add_claim("heads", "100", claim(&odes));
+        cerberus.metadata.add_claim("first_attested", "c. 700 BCE", claim(&theogony));
+        cerberus.metadata.add_claim("first_attested", "23 BCE", claim(&odes));
+        cerberus.metadata.add_claim("confidence", "a lot", claim(&theogony));
+        cerberus.metadata.add_claim("gender", "male", claim(&theogony));
+        cerberus.metadata.add_claim("gender", "female", claim(&theogony));
+        let id = cerberus.id.clone();
+        ontology.add_entity(MythEntity::Deity(cerberus));
+        
+        let resolved = ontology.resolved(ResolutionPolicy::PreferEarliest);
+        let metadata = resolved.get_entity(&id).unwrap().metadata();
+        assert_eq!(metadata.attributes["heads"], "50");
+        assert_eq!(metadata.first_attested, Some(HistoricalDate::circa(-700)));
+        // Values that do not parse and ties between different values are left alone
+        assert_eq!(metadata.confidence, None);
+        assert!(!metadata.attributes.contains_key("gender"));
+        
+        let all = ontology.resolved(ResolutionPolicy::ShowAll);
+        assert_eq!(all.get_entity(&id).unwrap().metadata().attributes["heads"], "3");
+    }
+    
+    #[test]
+    fn writes_preferred_values_to_typed_fields() {
+        let mut ontology = MythOntology::new();
+        let theogony = source(&mut ontology, "Theogony", Some("c. 700 BCE"), SourceType::PrimaryText);
+        let orphic = source(&mut ontology, "Orphic Hymns", Some("3rd century CE"), SourceType::PrimaryText);
+        
+        let mut eros = Deity::new("Eros", "Primordial love", "Greek");
+        eros.metadata.add_claim("gender", "Female", claim(&theogony));
+        eros.metadata.add_claim("gender", "Male", claim(&orphic));
+        eros.metadata.add_claim("importance", "Supreme", claim(&theogony));
+        eros.metadata.add_claim("importance", "not a level", claim(&orphic));
+        let id = eros.id.clone();
+        ontology.add_entity(MythEntity::Deity(eros));
+        
+        let female: QueryFilter = "gender:Female".parse().unwrap();
+        let resolved = ontology.resolved(ResolutionPolicy::PreferEarliest);
+        let MythEntity::Deity(eros) = resolved.get_entity(&id).unwrap() else { panic!("Eros is a deity") };
+        assert_eq!(eros.gender, Gender::Female);
+        assert_eq!(eros.importance, DeityImportance::Supreme);
+        assert!(!eros.metadata.attributes.contains_key("gender"));
+        assert_eq!(resolved.entities().filter(|entity| female.matches(entity)).count(), 1);
+        
+        assert_eq!(ontology.entities().filter(|entity| female.matches(entity)).count(), 0);
+    }
+}
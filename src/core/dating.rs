@@ -0,0 +1,446 @@
+//! # Historical Dating
+//!
+//! Dates in the history of a tradition, such as when a text was written or a deity
+//! first attested, are rarely known to the year. [`HistoricalDate`] records a date
+//! at the precision the evidence allows: an exact year, a year "circa", a century, a
+//! terminus ante or post quem, or a range between two such dates.
+//!
+//! Years are historical: negative years are BCE and there is no year zero, so
+//! `-1` is 1 BCE and `1` is 1 CE.
+//!
+//! Every date covers a range of possible years, its [`extent`](HistoricalDate::extent).
+//! Comparisons between dates report whether they certainly, possibly, or cannot
+//! overlap, so that queries can respect the uncertainty. A query reads its date as
+//! a window of years, so "before 1500 BCE" is every year up to 1501 BCE:
+//!
+//! ```
+//! use mythologic::core::{DateMatch, HistoricalDate};
+//!
+//! let new_kingdom: HistoricalDate = "c. 1550 BCE to c. 1070 BCE".parse().unwrap();
+//! let before_1500: HistoricalDate = "before 1500 BCE".parse().unwrap();
+//! assert_eq!(new_kingdom.falls_within(&before_1500), DateMatch::Certain);
+//! assert_eq!(new_kingdom.overlaps(&before_1500), DateMatch::Possible);
+//!
+//! let pyramid_texts: HistoricalDate = "24th century BCE".parse().unwrap();
+//! assert_eq!(pyramid_texts.to_string(), "24th century BCE");
+//! assert_eq!(pyramid_texts.extent().earliest, Some(-2400));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+
+/// The margin of a [`HistoricalDate::Circa`] date written without one
+pub const DEFAULT_CIRCA_MARGIN: u32 = 25;
+
+/// A date known to some degree of precision
+///
+/// Dates are written and parsed as text: `700 BCE`, `c. 1200 BCE`,
+/// `c. 1200 BCE ±50`, `5th century CE`, `before 1500 BCE`, `after 793 CE`, and
+/// `c. 1550 BCE to 1069 BCE`. `BC`/`AD` are accepted for `BCE`/`CE`, `ca.` and
+/// `circa` for `c.`, and `taq`/`tpq` for `before`/`after`; a bare number is a year,
+/// negative for BCE.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum HistoricalDate {
+    /// An exact year
+    Year(i32),
+    /// A year known within a margin of years either side
+    Circa {
+        /// The estimated year
+        year: i32,
+        /// How many years either side the date may fall
+        margin: u32,
+    },
+    /// A century, numbered from 1; negative centuries are BCE, so `-15` is the
+    /// 15th century BCE (1500–1401 BCE)
+    Century(i32),
+    /// Some time before a year (terminus ante quem)
+    Before(i32),
+    /// Some time after a year (terminus post quem)
+    After(i32),
+    /// A period from one date to another
+    Between(Box<HistoricalDate>, Box<HistoricalDate>),
+}
+
+/// The years a date may fall in; `None` leaves that end open
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct YearRange {
+    /// The earliest possible year
+    pub earliest: Option<i32>,
+    /// The latest possible year
+    pub latest: Option<i32>,
+}
+
+/// How sure a comparison between uncertain dates is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DateMatch {
+    /// The dates cannot overlap
+    Impossible,
+    /// The dates may overlap, depending on where in their ranges they fall
+    Possible,
+    /// The dates overlap however the uncertainty is resolved
+    Certain,
+}
+
+/// Error returned when text is not a valid historical date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateParseError {
+    /// The text that was rejected
+    pub text: String,
+    /// What is wrong with it
+    pub message: String,
+}
+
+impl fmt::Display for DateParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date `{}`: {}", self.text, self.message)
+    }
+}
+
+impl std::error::Error for DateParseError {}
+
+impl HistoricalDate {
+    /// A year known within the default margin
+    pub fn circa(year: i32) -> Self {
+        Self::Circa { year, margin: DEFAULT_CIRCA_MARGIN }
+    }
+    
+    /// A period from one date to another
+    ///
+    /// The dates are not checked to be in order; a reversed period is saved and
+    /// loaded as it is, though parsing it from text is an error.
+    pub fn between(start: HistoricalDate, end: HistoricalDate) -> Self {
+        Self::Between(Box::new(start), Box::new(end))
+    }
+    
+    /// The period from `start` to `end`, or whichever of them is known
+    pub fn span(start: Option<&HistoricalDate>, end: Option<&HistoricalDate>) -> Option<Self> {
+        match (start, end) {
+            (Some(start), Some(end)) => Some(Self::between(start.clone(), end.clone())),
+            (start, end) => start.or(end).cloned(),
+        }
+    }
+    
+    /// The years this date may fall in
+    pub fn extent(&self) -> YearRange {
+        match self {
+            Self::Year(year) => YearRange::new(*year, *year),
+            Self::Circa { year, margin } => YearRange::new(add_years(*year, -(*margin as i64)), add_years(*year, *margin as i64)),
+            Self::Century(century) => {
+                let (first, last) = century_years(*century);
+                YearRange::new(first, last)
+            },
+            Self::Before(year) => YearRange { earliest: None, latest: Some(add_years(*year, -1)) },
+            Self::After(year) => YearRange { earliest: Some(add_years(*year, 1)), latest: None },
+            Self::Between(start, end) => YearRange { earliest: start.extent().earliest, latest: end.extent().latest },
+        }
+    }
+    
+    /// The years this date certainly covers when read as a period, if any
+    ///
+    /// A year or century covers its whole extent. A date "circa" covers none of it
+    /// for certain, and neither does a terminus ante or post quem, which only bounds
+    /// one end of the date. A range covers the years from the latest its start may
+    /// be to the earliest its end may be.
+    pub fn certain_extent(&self) -> Option<YearRange> {
+        match self {
+            Self::Circa { .. } | Self::Before(_) | Self::After(_) => None,
+            Self::Between(start, end) => {
+                let range = YearRange { earliest: start.extent().latest, latest: end.extent().earliest };
+                // An open end here means the start may be arbitrarily late or the end arbitrarily early
+                (range.earliest.is_some() && range.latest.is_some() && !range.is_empty()).then_some(range)
+            },
+            _ => Some(self.extent()),
+        }
+    }
+    
+    /// Check whether this date and another, read as periods, overlap
+    pub fn overlaps(&self, other: &HistoricalDate) -> DateMatch {
+        if let (Some(this), Some(that)) = (self.certain_extent(), other.certain_extent())
+            && this.intersects(&that)
+        {
+            DateMatch::Certain
+        } else if self.extent().intersects(&other.extent()) {
+            DateMatch::Possible
+        } else {
+            DateMatch::Impossible
+        }
+    }
+    
+    /// Check whether this date, read as a period, falls in a window of years
+    ///
+    /// Unlike [`overlaps`](Self::overlaps), the window is its whole extent, so
+    /// "before 1500 BCE" as a window holds every year up to 1501 BCE.
+    pub fn falls_within(&self, window: &HistoricalDate) -> DateMatch {
+        if self.certain_extent().is_some_and(|this| this.intersects(&window.extent())) {
+            DateMatch::Certain
+        } else if self.extent().intersects(&window.extent()) {
+            DateMatch::Possible
+        } else {
+            DateMatch::Impossible
+        }
+    }
+}
+
+impl YearRange {
+    fn new(earliest: i32, latest: i32) -> Self {
+        Self { earliest: Some(earliest), latest: Some(latest) }
+    }
+    
+    /// Check whether the range holds no years
+    pub fn is_empty(&self) -> bool {
+        matches!((self.earliest, self.latest), (Some(earliest), Some(latest)) if earliest > latest)
+    }
+    
+    /// Check whether the range contains a year
+    pub fn contains(&self, year: i32) -> bool {
+        self.earliest.is_none_or(|earliest| earliest <= year) && self.latest.is_none_or(|latest| year <= latest)
+    }
+    
+    /// Check whether two ranges share at least one year
+    pub fn intersects(&self, other: &YearRange) -> bool {
+        let earliest = self.earliest.max(other.earliest);
+        let latest = match (self.latest, other.latest) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        };
+        !Self { earliest, latest }.is_empty()
+    }
+}
+
+impl DateMatch {
+    /// Check whether the match is at least possible
+    pub fn is_possible(self) -> bool {
+        self != Self::Impossible
+    }
+}
+
+/// Add years to a historical year, skipping the year zero
+fn add_years(year: i32, years: i64) -> i32 {
+    let astronomical = if year < 0 { year as i64 + 1 } else { year as i64 };
+    let shifted = astronomical + years;
+    let historical = if shifted <= 0 { shifted - 1 } else { shifted };
+    historical.clamp(i32::MIN as i64, i32::MAX as i64) as i32
+}
+
+/// The first and last years of a century
+fn century_years(century: i32) -> (i32, i32) {
+    if century < 0 {
+        (century * 100, (century + 1) * 100 - 1)
+    } else {
+        ((century - 1) * 100 + 1, century * 100)
+    }
+}
+
+struct DisplayYear(i32);
+
+impl fmt::Display for DisplayYear {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 < 0 {
+            write!(f, "{} BCE", -(self.0 as i64))
+        } else {
+            write!(f, "{} CE", self.0)
+        }
+    }
+}
+
+impl fmt::Display for HistoricalDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Year(year) => write!(f, "{}", DisplayYear(*year)),
+            Self::Circa { year, margin } => {
+                write!(f, "c. {}", DisplayYear(*year))?;
+                if *margin != DEFAULT_CIRCA_MARGIN {
+                    write!(f, " ±{}", margin)?;
+                }
+                Ok(())
+            },
+            Self::Century(century) => {
+                let number = century.unsigned_abs();
+                let suffix = match (number % 10, number % 100) {
+                    (_, 11..=13) => "th",
+                    (1, _) => "st",
+                    (2, _) => "nd",
+                    (3, _) => "rd",
+                    _ => "th",
+                };
+                write!(f, "{}{} century {}", number, suffix, if *century < 0 { "BCE" } else { "CE" })
+            },
+            Self::Before(year) => write!(f, "before {}", DisplayYear(*year)),
+            Self::After(year) => write!(f, "after {}", DisplayYear(*year)),
+            Self::Between(start, end) => {
+                let part = |date: &HistoricalDate| match date {
+                    Self::Between(..) => format!("({})", date),
+                    _ => date.to_string(),
+                };
+                write!(f, "{} to {}", part(start), part(end))
+            },
+        }
+    }
+}
+
+impl FromStr for HistoricalDate {
+    type Err = DateParseError;
+    
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_date(text, true)
+    }
+}
+
+/// Parse a date; when `strict` is false, year and century zero and ranges that end
+/// before they start are accepted, so that every date that can be printed reads back
+fn parse_date(text: &str, strict: bool) -> Result<HistoricalDate, DateParseError> {
+    let error = |message: &str| DateParseError { text: text.to_string(), message: message.to_string() };
+    let parse_year = |text: &str| parse_year(text, strict).map_err(|message| error(&message));
+    let lower = text.trim().to_lowercase();
+    
+    if let Some((start, end)) = split_range(&lower) {
+        let (start, end) = (parse_date(unparenthesize(start), strict)?, parse_date(unparenthesize(end), strict)?);
+        if strict
+            && let (Some(earliest), Some(latest)) = (start.extent().earliest, end.extent().latest)
+            && earliest > latest
+        {
+            return Err(error("the range ends before it starts"));
+        }
+        return Ok(HistoricalDate::between(start, end));
+    }
+    
+    for (prefixes, make) in [
+        (["before ", "taq "], HistoricalDate::Before as fn(i32) -> HistoricalDate),
+        (["after ", "tpq "], HistoricalDate::After),
+    ] {
+        if let Some(rest) = prefixes.iter().find_map(|prefix| lower.strip_prefix(prefix)) {
+            return parse_year(rest).map(make);
+        }
+    }
+    
+    if let Some(rest) = ["c. ", "c.", "ca. ", "circa "].iter().find_map(|prefix| lower.strip_prefix(prefix)) {
+        let (year, margin) = match rest.split_once('±').or_else(|| rest.split_once("+/-")) {
+            Some((year, margin)) => {
+                let margin = margin.trim().parse().map_err(|_| error("the margin must be a whole number of years"))?;
+                (year, margin)
+            },
+            None => (rest, DEFAULT_CIRCA_MARGIN),
+        };
+        return Ok(HistoricalDate::Circa { year: parse_year(year)?, margin });
+    }
+    
+    if let Some((number, era)) = lower.split_once(" century") {
+        let number = number.trim().trim_end_matches(|c: char| c.is_ascii_alphabetic());
+        let number: i64 = number.parse().ok().filter(|n| *n > 0 || (!strict && *n == 0))
+            .ok_or_else(|| error("expected a century like `5th century BCE`"))?;
+        let century = match era.trim() {
+            "bce" | "bc" => -number,
+            "" | "ce" | "ad" => number,
+            _ => return Err(error("a century must be BCE or CE")),
+        };
+        return i32::try_from(century).map(HistoricalDate::Century).map_err(|_| error("the century is out of range"));
+    }
+    
+    parse_year(&lower).map(HistoricalDate::Year)
+}
+
+/// Split a range at its top-level ` to `, outside parentheses
+fn split_range(text: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (index, c) in text.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ' ' if depth == 0 && text[index..].starts_with(" to ") => return Some((&text[..index], &text[index + 4..])),
+            _ => {},
+        }
+    }
+    None
+}
+
+fn unparenthesize(text: &str) -> &str {
+    let text = text.trim();
+    text.strip_prefix('(').and_then(|t| t.strip_suffix(')')).unwrap_or(text)
+}
+
+/// Parse a year such as `700 BCE`, `AD 793`, `1200 CE`, or `-700`, rejecting year
+/// zero when `strict`
+fn parse_year(text: &str, strict: bool) -> Result<i32, String> {
+    let text = text.trim();
+    let (number, bce) = if let Some(number) = text.strip_suffix("bce").or_else(|| text.strip_suffix("bc")) {
+        (number, true)
+    } else if let Some(number) = text.strip_suffix("ce").or_else(|| text.strip_suffix("ad")).or_else(|| text.strip_prefix("ad ")) {
+        (number, false)
+    } else {
+        (text, false)
+    };
+    
+    let year: i64 = number.trim().parse().map_err(|_| format!("`{}` is not a year", text))?;
+    if strict && year == 0 {
+        return Err("there is no year zero".to_string());
+    }
+    if bce && year < 0 {
+        return Err("a BCE year must not be negative".to_string());
+    }
+    i32::try_from(if bce { -year } else { year }).map_err(|_| format!("`{}` is out of range", text))
+}
+
+impl TryFrom<String> for HistoricalDate {
+    type Error = DateParseError;
+    
+    /// Read a date as saved, accepting every date that can be printed, including
+    /// ones that [`FromStr`] rejects such as year zero or a reversed range
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        parse_date(&text, false)
+    }
+}
+
+impl From<HistoricalDate> for String {
+    fn from(date: HistoricalDate) -> Self {
+        date.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn date(text: &str) -> HistoricalDate {
+        text.parse().unwrap()
+    }
+    
+    #[test]
+    fn parses_and_prints_dates() {
+        for text in [
+            "700 BCE", "1200 CE", "c. 1200 BCE", "c. 5 BCE ±10", "1st century BCE", "12th century CE",
+            "before 1500 BCE", "after 793 CE", "c. 1550 BCE to 1069 BCE", "(2nd century BCE to 1st century BCE) to 30 CE",
+        ] {
+            assert_eq!(date(text).to_string(), text);
+        }
+        assert_eq!(date("-700"), HistoricalDate::Year(-700));
+        assert_eq!(date("AD 793"), HistoricalDate::Year(793));
+        assert_eq!(date("ca. 1200 bc +/- 50"), HistoricalDate::Circa { year: -1200, margin: 50 });
+        assert_eq!(date("tpq 1000"), HistoricalDate::After(1000));
+        
+        for invalid in ["0", "year 5", "0th century", "30 CE to 30 BCE", "c. 100 BCE ±a lot"] {
+            assert!(invalid.parse::<HistoricalDate>().is_err(), "{}", invalid);
+        }
+    }
+    
+    #[test]
+    fn compares_uncertain_dates() {
+        assert_eq!(date("c. 10 BCE ±20").extent(), YearRange::new(-30, 11));
+        assert_eq!(date("1st century BCE").extent(), YearRange::new(-100, -1));
+        
+        let before = date("before 1500 BCE");
+        assert_eq!(date("1600 BCE").falls_within(&before), DateMatch::Certain);
+        assert_eq!(date("c. 1510 BCE").falls_within(&before), DateMatch::Possible);
+        assert_eq!(date("1500 BCE").falls_within(&before), DateMatch::Impossible);
+        assert_eq!(date("c. 1500 BCE to 1000 BCE").falls_within(&before), DateMatch::Possible);
+        assert_eq!(date("16th century BCE to 1000 BCE").falls_within(&before), DateMatch::Certain);
+        assert_eq!(date("after 2000 BCE").falls_within(&before), DateMatch::Possible);
+        
+        // A terminus only bounds one end, so it certainly overlaps no period
+        assert_eq!(date("1600 BCE").overlaps(&before), DateMatch::Possible);
+        assert_eq!(date("after 300 CE").overlaps(&date("5th century CE")), DateMatch::Possible);
+        assert_eq!(date("after 2000 BCE").overlaps(&before), DateMatch::Possible);
+        assert_eq!(date("5th century CE").overlaps(&date("c. 450 CE to 500 CE")), DateMatch::Certain);
+    }
+}
@@ -0,0 +1,588 @@
+//! # Versioned Ontology Documents
+//!
+//! This module defines the on-disk representation of a [`MythOntology`]. A document
+//! records the format version it was written with, the non-relationship entities,
+//! and the relationship entities as two separate lists, followed by the sources of
+//! the bibliography keyed by ID. Every entity is stored with
+//! its variant tag so that all [`MythEntity`] kinds round-trip losslessly.
+//!
+//! ## Versioning
+//!
+//! When the format changes, [`FORMAT_VERSION`] is bumped and a [`Migration`] is
+//! appended to [`MIGRATIONS`]. Loading a document runs every migration from the
+//! document's version up to the current one on the raw JSON before it is
+//! deserialized, so files written by older versions keep loading. Purely additive
+//! fields should prefer `#[serde(default)]` and do not need a migration.
+
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::collections::BTreeMap;
+use std::path::Path;
+use serde::{Serialize, Deserialize, Serializer, Deserializer};
+use serde::de::Error as _;
+use serde_json::{Map, Value};
+use crate::core::{HistoricalDate, MythEntity, MythOntology, Source, SourceId};
+
+/// The format version written by this version of the crate.
+pub const FORMAT_VERSION: u32 = 3;
+
+/// A single upgrade step applied to a raw document.
+///
+/// A migration receives the document as JSON written with `from_version` and must
+/// rewrite it in place into the shape expected by `from_version + 1`.
+pub struct Migration {
+    /// The version this migration upgrades from
+    pub from_version: u32,
+    /// Short human-readable summary of the change
+    pub description: &'static str,
+    /// The upgrade function
+    pub apply: fn(&mut Value) -> Result<(), String>,
+}
+
+/// All known migrations, ordered by `from_version`.
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        from_version: 1,
+        description: "replace exact years on sources and time periods with historical dates",
+        apply: replace_exact_years,
+    },
+    Migration {
+        from_version: 2,
+        description: "move embedded sources into a shared bibliography referenced by citations",
+        apply: extract_bibliography,
+    },
+];
+
+/// The serialized form of a [`MythOntology`].
+///
+/// # Examples
+///
+/// ```
+/// use mythologic::core::{MythOntology, MythEntity, OntologyDocument, FORMAT_VERSION};
+/// use mythologic::entities::Deity;
+///
+/// let mut ontology = MythOntology::new();
+/// ontology.add_entity(MythEntity::Deity(Deity::new("Zeus", "King of the gods", "Greek")));
+///
+/// let document = OntologyDocument::from_ontology(&ontology);
+/// assert_eq!(document.format_version, FORMAT_VERSION);
+/// assert_eq!(document.entities.len(), 1);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OntologyDocument {
+    /// Version of the format this document was written with
+    pub format_version: u32,
+    /// All entities that are not relationships
+    pub entities: Vec<MythEntity>,
+    /// All relationship entities
+    pub relationships: Vec<MythEntity>,
+    /// The bibliography, keyed by source ID
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub sources: BTreeMap<SourceId, Source>,
+}
+
+impl OntologyDocument {
+    /// Build a document from an ontology, sorting entities by id for stable output
+    pub fn from_ontology(ontology: &MythOntology) -> Self {
+        let mut entities: Vec<MythEntity> = ontology.all_entities().into_iter().cloned().collect();
+        entities.sort_by_key(|e| e.id().to_string());
+        let (relationships, entities) = entities.into_iter().partition(|e| e.is_relationship());
+        let sources = ontology.bibliography().iter()
+            .map(|(id, source)| (id.clone(), source.clone()))
+            .collect();
+        
+        Self {
+            format_version: FORMAT_VERSION,
+            entities,
+            relationships,
+            sources,
+        }
+    }
+    
+    /// Convert this document into an ontology
+    ///
+    /// Entities are added before relationships.
+    pub fn into_ontology(self) -> MythOntology {
+        let mut ontology = MythOntology::new();
+        for (id, source) in self.sources {
+            ontology.bibliography_mut().insert(id, source);
+        }
+        for entity in self.entities.into_iter().chain(self.relationships) {
+            ontology.add_entity(entity);
+        }
+        ontology
+    }
+    
+    /// Parse a document from raw JSON, migrating it to the current format first
+    pub fn from_value(mut value: Value) -> Result<Self, String> {
+        migrate_document(&mut value)?;
+        serde_json::from_value(value).map_err(|e| e.to_string())
+    }
+}
+
+/// Upgrade a raw document in place to [`FORMAT_VERSION`].
+///
+/// Returns an error if the document has no `format_version`, was written by a newer
+/// version of the crate, or a migration step fails.
+pub fn migrate_document(value: &mut Value) -> Result<(), String> {
+    let mut version = document_version(value)?;
+    
+    if version > FORMAT_VERSION {
+        return Err(format!(
+            "document format version {} is newer than the supported version {}",
+            version, FORMAT_VERSION
+        ));
+    }
+    
+    while version < FORMAT_VERSION {
+        let migration = MIGRATIONS.iter()
+            .find(|m| m.from_version == version)
+            .ok_or_else(|| format!("no migration available from format version {}", version))?;
+        
+        (migration.apply)(value).map_err(|e| {
+            format!("migration from version {} ({}) failed: {}", version, migration.description, e)
+        })?;
+        
+        version += 1;
+        value["format_version"] = Value::from(version);
+    }
+    
+    Ok(())
+}
+
+/// Version 1 stored `year` on sources and `start_year` / `end_year` on time
+/// periods as plain integers; version 2 stores them as [`HistoricalDate`] text.
+///
+/// Sources were embedded in each entity's metadata `sources` and in motif tags'
+/// `source`, and time periods in a culture's `time_periods`.
+fn replace_exact_years(value: &mut Value) -> Result<(), String> {
+    for_each_metadata(value, |metadata| {
+        if let Some(Value::Array(sources)) = metadata.get_mut("sources") {
+            for source in sources.iter_mut().filter_map(Value::as_object_mut) {
+                replace_exact_year(source, "year", "date")?;
+            }
+        }
+        if let Some(Value::Array(motifs)) = metadata.get_mut("motifs") {
+            for source in motifs.iter_mut().filter_map(|tag| tag.get_mut("source")?.as_object_mut()) {
+                replace_exact_year(source, "year", "date")?;
+            }
+        }
+        Ok(())
+    })?;
+    
+    for entity in entities_mut(value).filter(|entity| entity["type"] == "Culture") {
+        if let Some(Value::Array(periods)) = entity.pointer_mut("/data/time_periods") {
+            for period in periods.iter_mut().filter_map(Value::as_object_mut) {
+                replace_exact_year(period, "start_year", "start")?;
+                replace_exact_year(period, "end_year", "end")?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn replace_exact_year(object: &mut Map<String, Value>, old: &str, new: &str) -> Result<(), String> {
+    let date = match object.remove(old) {
+        None | Some(Value::Null) => Value::Null,
+        Some(year) => {
+            let year = year.as_i64()
+                .and_then(|year| i32::try_from(year).ok())
+                .filter(|year| *year != 0)
+                .ok_or_else(|| format!("invalid {} {}", old, year))?;
+            Value::from(HistoricalDate::Year(year).to_string())
+        },
+    };
+    object.insert(new.to_string(), date);
+    Ok(())
+}
+
+/// Version 2 embedded full copies of sources in each entity's metadata `sources`
+/// and in motif tags' `source`; version 3 stores each distinct source once in the
+/// document's `sources` and cites it by ID.
+///
+/// Each source's ID is derived from its content, so migrating the same document
+/// twice gives the same IDs, and documents migrated separately can be merged
+/// without their sources colliding.
+fn extract_bibliography(value: &mut Value) -> Result<(), String> {
+    let mut sources: Map<String, Value> = Map::new();
+    let mut cite = |source: Value| {
+        // Object keys are sorted, so equal sources serialize the same way
+        let id = SourceId::from_content(&source.to_string()).to_string();
+        sources.insert(id.clone(), source);
+        serde_json::json!({ "source": id })
+    };
+    
+    for_each_metadata(value, |metadata| {
+        if let Some(Value::Array(embedded)) = metadata.remove("sources") {
+            let citations: Vec<Value> = embedded.into_iter().map(&mut cite).collect();
+            metadata.insert("citations".to_string(), Value::Array(citations));
+        }
+        if let Some(Value::Array(motifs)) = metadata.get_mut("motifs") {
+            for tag in motifs.iter_mut().filter_map(Value::as_object_mut) {
+                if let Some(source) = tag.remove("source")
+                    && !source.is_null()
+                {
+                    tag.insert("citation".to_string(), cite(source));
+                }
+            }
+        }
+        Ok(())
+    })?;
+    
+    let object = value.as_object_mut().ok_or("document is not an object")?;
+    object.insert("sources".to_string(), Value::Object(sources));
+    Ok(())
+}
+
+/// Iterate over the entities and relationships of a raw document
+fn entities_mut(value: &mut Value) -> impl Iterator<Item = &mut Value> {
+    let object = value.as_object_mut();
+    object.into_iter()
+        .flat_map(|object| object.iter_mut())
+        .filter(|(list, _)| *list == "entities" || *list == "relationships")
+        .filter_map(|(_, entities)| entities.as_array_mut())
+        .flatten()
+}
+
+/// Apply a change to the metadata of every entity and relationship in a raw document
+fn for_each_metadata(
+    value: &mut Value,
+    mut apply: impl FnMut(&mut Map<String, Value>) -> Result<(), String>,
+) -> Result<(), String> {
+    for entity in entities_mut(value) {
+        let metadata = match entity.pointer("/data/relationship") {
+            Some(_) => entity.pointer_mut("/data/relationship/metadata"),
+            None => entity.pointer_mut("/data/metadata"),
+        };
+        if let Some(Value::Object(metadata)) = metadata {
+            apply(metadata)?;
+        }
+    }
+    Ok(())
+}
+
+fn document_version(value: &Value) -> Result<u32, String> {
+    value.get("format_version")
+        .and_then(Value::as_u64)
+        .and_then(|v| u32::try_from(v).ok())
+        .ok_or_else(|| "missing or invalid format_version".to_string())
+}
+
+impl MythOntology {
+    /// Convert this ontology into a versioned document
+    pub fn to_document(&self) -> OntologyDocument {
+        OntologyDocument::from_ontology(self)
+    }
+    
+    /// Save this ontology to a JSON file in the versioned document format
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        let mut file = File::create(path)?;
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+    
+    /// Load an ontology from a JSON file, migrating older formats as needed
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)?;
+        let ontology = serde_json::from_str(&contents)?;
+        Ok(ontology)
+    }
+}
+
+impl Serialize for MythOntology {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_document().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MythOntology {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let document = OntologyDocument::from_value(value).map_err(D::Error::custom)?;
+        Ok(document.into_ontology())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use crate::core::SourceType;
+    use crate::core::{Citation, HistoricalDate};
+    use crate::entities::*;
+    use crate::cultural::*;
+    use crate::relationships::*;
+    
+    fn every_variant() -> MythOntology {
+        let mut ontology = MythOntology::new();
+        
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.add_domain("Sky");
+        zeus.add_alternative_name("Jupiter");
+        zeus.set_importance(DeityImportance::Supreme);
+        zeus.set_gender(Gender::Other("Sky father".to_string()));
+        let theogony = ontology.add_source(Source {
+            title: "Theogony".to_string(),
+            author: Some("Hesiod".to_string()),
+            date: Some(HistoricalDate::Year(-700)),
+            source_type: SourceType::PrimaryText,
+            url: None,
+            notes: None,
+        });
+        zeus.metadata.add_citation(Citation::new(theogony).line("453-506"));
+        zeus.metadata.confidence = Some(0.9);
+        let zeus_id = zeus.id.clone();
+        
+        let mut heracles = Hero::new("Heracles", "Son of Zeus", "Greek");
+        heracles.set_origin(HeroOrigin::Demigod);
+        let heracles_id = heracles.id.clone();
+        
+        let mut hydra = Creature::new("Hydra", "Many-headed serpent", "Greek");
+        hydra.add_habitat("Lerna");
+        let mut club = Artifact::new("Club", "Olive-wood club", "Greek");
+        club.set_artifact_type(ArtifactType::Weapon);
+        let olympus = Location::new("Olympus", "Home of the gods", "Greek");
+        let fate = Concept::new("Moira", "Fate", "Greek");
+        
+        let mut pantheon = Pantheon::new("Olympian", "The twelve", "Greek");
+        pantheon.add_primary_deity(zeus_id.clone());
+        let mut culture = Culture::new("Greek", "Ancient Greece");
+        culture.add_language("Ancient Greek");
+        let mut era = MythologicalEra::new("Age of Heroes", "Heroic age", "Greek");
+        era.set_sequence_order(4);
+        let mut war = Event::new("Trojan War", "The siege of Troy", "Greek");
+        war.add_participant(heracles_id.clone());
+        war.set_location(olympus.id.clone());
+        war.set_era(era.id.clone());
+        era.set_ending(&war);
+        let mut region = MythologicalRegion::new("Peloponnese", "Southern Greece", None);
+        region.add_culture("Greek");
+        
+        let plain = Relationship::new("Worship", "Heracles honours Zeus", heracles_id.clone(), zeus_id.clone(), RelationshipType::Worship);
+        let family = FamilyRelationship::new("Father", "Zeus fathers Heracles", zeus_id.clone(), heracles_id.clone(), FamilyRelationshipType::Parent);
+        let alliance = AllianceRelationship::new("Patronage", "Zeus protects Heracles", zeus_id.clone(), heracles_id.clone(), AllianceType::Patronage, "Protection");
+        let mut conflict = ConflictRelationship::new("Labour", "Heracles fights the Hydra", heracles_id.clone(), hydra.id.clone(), ConflictType::Battle);
+        conflict.set_outcome(ConflictOutcome {
+            description: "The Hydra is slain".to_string(),
+            victor_id: Some(heracles_id.clone()),
+            consequences: vec!["Poisoned arrows".to_string()],
+        });
+        let mut labour = Episode::new("The Hydra", "Heracles kills the Hydra with Iolaus");
+        labour.add_participant(heracles_id.clone(), NarrativeRole::Protagonist);
+        labour.add_participant(hydra.id.clone(), NarrativeRole::Other("Monster".to_string()));
+        labour.add_artifact(club.id.clone());
+        labour.add_established_relationship(conflict.id().clone());
+        let mut labours = Narrative::new("Labours of Heracles", "Twelve labours", "Greek");
+        labours.add_episode(labour);
+        let transformation = TransformationRelationship::new("Apotheosis", "Heracles becomes a god", heracles_id.clone(), zeus_id, TransformationType::Apotheosis, "Death on the pyre");
+        let hercules = Hero::new("Hercules", "Roman Heracles", "Roman");
+        let syncretism = SyncretismRelationship::new("Hercules", "Hercules is the Roman Heracles", hercules.id.clone(), heracles_id.clone(), SyncretismType::Equivalence);
+        
+        for entity in [
+            MythEntity::Deity(zeus),
+            MythEntity::Hero(heracles),
+            MythEntity::Hero(hercules),
+            MythEntity::Creature(hydra),
+            MythEntity::Artifact(club),
+            MythEntity::Location(olympus),
+            MythEntity::Concept(fate),
+            MythEntity::Narrative(labours),
+            MythEntity::Event(war),
+            MythEntity::Relationship(plain),
+            MythEntity::FamilyRelationship(family),
+            MythEntity::AllianceRelationship(alliance),
+            MythEntity::ConflictRelationship(conflict),
+            MythEntity::TransformationRelationship(transformation),
+            MythEntity::SyncretismRelationship(syncretism),
+            MythEntity::Culture(culture),
+            MythEntity::Pantheon(pantheon),
+            MythEntity::MythologicalEra(era),
+            MythEntity::MythologicalRegion(region),
+        ] {
+            ontology.add_entity(entity);
+        }
+        
+        ontology
+    }
+    
+    #[test]
+    fn round_trips_every_variant() {
+        let ontology = every_variant();
+        let types: HashSet<_> = ontology.all_entities().iter().map(|e| e.entity_type()).collect();
+        assert_eq!(types.len(), 18);
+        
+        let json = serde_json::to_string(&ontology).unwrap();
+        let loaded: MythOntology = serde_json::from_str(&json).unwrap();
+        
+        assert_eq!(loaded.entity_count(), ontology.entity_count());
+        for entity in ontology.all_entities() {
+            let reloaded = loaded.get_entity(entity.id()).expect("entity should survive a round trip");
+            assert_eq!(
+                serde_json::to_value(entity).unwrap(),
+                serde_json::to_value(reloaded).unwrap(),
+            );
+        }
+    }
+    
+    #[test]
+    fn splits_entities_and_relationships() {
+        let document = every_variant().to_document();
+        assert_eq!(document.entities.len(), 13);
+        assert_eq!(document.relationships.len(), 6);
+        assert!(document.relationships.iter().all(|e| e.is_relationship()));
+    }
+    
+    #[test]
+    fn rejects_newer_and_unversioned_documents() {
+        let mut value = serde_json::to_value(every_variant()).unwrap();
+        value["format_version"] = Value::from(FORMAT_VERSION + 1);
+        assert!(serde_json::from_value::<MythOntology>(value.clone()).is_err());
+        
+        value.as_object_mut().unwrap().remove("format_version");
+        assert!(serde_json::from_value::<MythOntology>(value).is_err());
+    }
+    
+    #[test]
+    fn saves_and_loads_files() {
+        let ontology = every_variant();
+        let path = std::env::temp_dir().join(format!("mythologic-{}.json", crate::core::MythId::new()));
+        ontology.save(&path).unwrap();
+        let loaded = MythOntology::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.entity_count(), 19);
+    }
+    
+    #[test]
+    fn saves_and_loads_dates_the_parser_rejects() {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.metadata.first_attested = Some(HistoricalDate::between(HistoricalDate::Year(-500), HistoricalDate::Year(-700)));
+        zeus.metadata.last_attested = Some(HistoricalDate::Year(0));
+        let zeus_id = zeus.id.clone();
+        let source = ontology.add_source(Source {
+            title: "Scholia".to_string(),
+            author: None,
+            date: Some(HistoricalDate::Century(0)),
+            source_type: SourceType::PrimaryText,
+            url: None,
+            notes: None,
+        });
+        ontology.add_entity(MythEntity::Deity(zeus));
+        
+        let path = std::env::temp_dir().join(format!("mythologic-{}.json", crate::core::MythId::new()));
+        ontology.save(&path).unwrap();
+        let loaded = MythOntology::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        let loaded = loaded.unwrap();
+        
+        let metadata = loaded.get_entity(&zeus_id).unwrap().metadata();
+        assert_eq!(metadata.first_attested, ontology.get_entity(&zeus_id).unwrap().metadata().first_attested);
+        assert_eq!(metadata.last_attested, Some(HistoricalDate::Year(0)));
+        assert_eq!(loaded.source(&source).unwrap().date, Some(HistoricalDate::Century(0)));
+        // Text typed by a user is still checked
+        assert!("500 BCE to 700 BCE".parse::<HistoricalDate>().is_err());
+    }
+    
+    /// Serialize the example ontology as a version 2 document, with each source
+    /// embedded in the metadata citing it
+    fn version_2_document() -> Value {
+        let mut value = serde_json::to_value(every_variant()).unwrap();
+        value["format_version"] = Value::from(2);
+        let sources = value.as_object_mut().unwrap().remove("sources").unwrap();
+        let zeus = value["entities"].as_array_mut().unwrap().iter_mut()
+            .find(|entity| entity["data"]["name"] == "Zeus")
+            .unwrap();
+        let metadata = zeus["data"]["metadata"].as_object_mut().unwrap();
+        let citations = metadata.remove("citations").unwrap();
+        let embedded: Vec<Value> = citations.as_array().unwrap().iter()
+            .map(|citation| sources[citation["source"].as_str().unwrap()].clone())
+            .collect();
+        metadata.insert("sources".to_string(), Value::from(embedded));
+        value
+    }
+    
+    #[test]
+    fn migrates_exact_years_to_dates() {
+        let mut value = version_2_document();
+        value["format_version"] = Value::from(1);
+        let zeus = value["entities"].as_array_mut().unwrap().iter_mut()
+            .find(|entity| entity["data"]["name"] == "Zeus")
+            .unwrap();
+        let source = &mut zeus["data"]["metadata"]["sources"][0];
+        source.as_object_mut().unwrap().remove("date");
+        source["year"] = Value::from(-700);
+        let mut motif_source = source.clone();
+        motif_source["title"] = Value::from("Homeric Hymns");
+        zeus["data"]["metadata"]["motifs"] = serde_json::json!([{ "code": "A102", "source": motif_source }]);
+        // Attributes that happen to share a name with a migrated field are left alone
+        zeus["data"]["metadata"]["attributes"] = serde_json::json!({ "start_year": "c. 800", "year": "unknown" });
+        
+        let culture = value["entities"].as_array_mut().unwrap().iter_mut()
+            .find(|entity| entity["type"] == "Culture")
+            .unwrap();
+        culture["data"]["time_periods"] = serde_json::json!([
+            { "name": "Archaic", "start_year": -800, "end_year": null, "description": null }
+        ]);
+        
+        let loaded: MythOntology = serde_json::from_value(value).unwrap();
+        let zeus = loaded.entities_named("Zeus").next().unwrap();
+        let source = loaded.source(&zeus.metadata().citations[0].source).unwrap();
+        assert_eq!(source.date, Some(HistoricalDate::Year(-700)));
+        let hymns = zeus.metadata().motifs[0].citation.as_ref().and_then(|citation| loaded.source(&citation.source)).unwrap();
+        assert_eq!(hymns.date, Some(HistoricalDate::Year(-700)));
+        assert_eq!(zeus.metadata().attributes["start_year"], "c. 800");
+        assert_eq!(zeus.metadata().attributes["year"], "unknown");
+        let Some(MythEntity::Culture(culture)) = loaded.all_entities().into_iter().find(|e| e.entity_type() == "Culture") else {
+            panic!("culture should survive the migration");
+        };
+        assert_eq!(culture.time_periods[0].start, Some(HistoricalDate::Year(-800)));
+        assert_eq!(culture.time_periods[0].end, None);
+    }
+    
+    #[test]
+    fn migrates_embedded_sources_to_bibliography() {
+        let mut value = version_2_document();
+        let theogony = value["entities"].as_array().unwrap().iter()
+            .find(|entity| entity["data"]["name"] == "Zeus")
+            .map(|zeus| zeus["data"]["metadata"]["sources"][0].clone())
+            .unwrap();
+        let hydra = value["entities"].as_array_mut().unwrap().iter_mut()
+            .find(|entity| entity["data"]["name"] == "Hydra")
+            .unwrap();
+        hydra["data"]["metadata"]["sources"] = Value::from(vec![theogony.clone()]);
+        hydra["data"]["metadata"]["motifs"] = serde_json::json!([
+            { "code": "B11", "source": theogony }
+        ]);
+        
+        hydra["data"]["metadata"]["attributes"] = serde_json::json!({ "code": "B11", "source": "Theogony" });
+        
+        let mut migrated = value.clone();
+        migrate_document(&mut migrated).unwrap();
+        let mut again = value.clone();
+        migrate_document(&mut again).unwrap();
+        assert_eq!(migrated, again);
+        
+        let loaded: MythOntology = serde_json::from_value(value).unwrap();
+        let hydra = loaded.entities_named("Hydra").next().unwrap();
+        assert_eq!(hydra.metadata().attributes["source"], "Theogony");
+        assert!(hydra.metadata().motifs[0].citation.is_some());
+        assert_eq!(loaded.bibliography().len(), 1);
+        let (id, source) = loaded.bibliography().iter().next().unwrap();
+        assert_eq!(source.title, "Theogony");
+        let citing: Vec<&str> = loaded.cited_by(id).into_iter().map(|(entity, _)| entity.name()).collect();
+        assert_eq!(citing, vec!["Hydra", "Hydra", "Zeus"]);
+        
+        // Different sources in separately migrated documents get different IDs
+        let mut other = version_2_document();
+        let zeus = other["entities"].as_array_mut().unwrap().iter_mut()
+            .find(|entity| entity["data"]["name"] == "Zeus")
+            .unwrap();
+        zeus["data"]["metadata"]["sources"][0]["title"] = Value::from("Iliad");
+        let other: MythOntology = serde_json::from_value(other).unwrap();
+        let (other_id, _) = other.bibliography().iter().next().unwrap();
+        assert_ne!(other_id, id);
+    }
+}
@@ -84,28 +84,10 @@ impl MythId {
     ///
     /// let id = MythId::from_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Result<Self, uuid::Error> {
         Ok(MythId(Uuid::parse_str(s)?))
     }
-    
-    /// Get the string representation of this MythId.
-    ///
-    /// # Returns
-    ///
-    /// A standard hyphenated UUID string.
-    ///
-    /// # Examples
-    ///
-    /// ```
-    /// use mythologic::core::MythId;
-    ///
-    /// let id = MythId::new();
-    /// let id_str = id.to_string();
-    /// // id_str will be something like "550e8400-e29b-41d4-a716-446655440000"
-    /// ```
-    pub fn to_string(&self) -> String {
-        self.0.to_string()
-    }
 }
 
 impl fmt::Display for MythId {
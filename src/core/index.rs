@@ -0,0 +1,138 @@
+//! # Secondary Indexes
+//!
+//! This module provides [`OntologyIndex`], the set of lookup tables that
+//! [`MythOntology`](crate::core::MythOntology) maintains alongside its entity map.
+//! The indexes are updated incrementally whenever an entity is added, removed, or
+//! modified through the ontology, so lookups by type, culture, name, and
+//! relationship endpoint never need to scan every entity. The full-text
+//! [`TextIndex`] is maintained the same way.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use crate::core::{MythId, MythEntity, CultureId, TextIndex};
+
+/// Secondary indexes over the entities of an ontology.
+#[derive(Debug, Clone, Default)]
+pub struct OntologyIndex {
+    by_type: HashMap<&'static str, HashSet<MythId>>,
+    by_culture: HashMap<CultureId, HashSet<MythId>>,
+    by_name: BTreeMap<String, HashSet<MythId>>,
+    by_source: HashMap<MythId, HashSet<MythId>>,
+    by_target: HashMap<MythId, HashSet<MythId>>,
+    text: TextIndex,
+}
+
+impl OntologyIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Record an entity in every applicable index
+    pub(crate) fn insert(&mut self, entity: &MythEntity) {
+        let id = entity.id();
+        
+        self.by_type.entry(entity.entity_type()).or_default().insert(id.clone());
+        
+        if let Some(culture) = entity.culture() {
+            self.by_culture.entry(culture.clone()).or_default().insert(id.clone());
+        }
+        
+        for name in index_names(entity) {
+            self.by_name.entry(name).or_default().insert(id.clone());
+        }
+        
+        if let Some(relationship) = entity.relationship() {
+            self.by_source.entry(relationship.source_id.clone()).or_default().insert(id.clone());
+            self.by_target.entry(relationship.target_id.clone()).or_default().insert(id.clone());
+        }
+        
+        self.text.insert(entity);
+    }
+    
+    /// Remove an entity from every index it was recorded in
+    pub(crate) fn remove(&mut self, entity: &MythEntity) {
+        let id = entity.id();
+        
+        remove_from(&mut self.by_type, &entity.entity_type(), id);
+        
+        if let Some(culture) = entity.culture() {
+            remove_from(&mut self.by_culture, culture, id);
+        }
+        
+        for name in index_names(entity) {
+            if let Some(ids) = self.by_name.get_mut(&name) {
+                ids.remove(id);
+                if ids.is_empty() {
+                    self.by_name.remove(&name);
+                }
+            }
+        }
+        
+        if let Some(relationship) = entity.relationship() {
+            remove_from(&mut self.by_source, &relationship.source_id, id);
+            remove_from(&mut self.by_target, &relationship.target_id, id);
+        }
+        
+        self.text.remove(entity);
+    }
+    
+    /// Get the ids of all entities of the given type (as returned by `entity_type()`)
+    pub fn ids_of_type(&self, entity_type: &str) -> Option<&HashSet<MythId>> {
+        self.by_type.get(entity_type)
+    }
+    
+    /// Get the ids of all entities belonging to the given culture
+    pub fn ids_in_culture(&self, culture: &CultureId) -> Option<&HashSet<MythId>> {
+        self.by_culture.get(culture)
+    }
+    
+    /// Get the ids of entities with a name or alternative name equal to `name` (case-insensitive)
+    pub fn ids_named(&self, name: &str) -> Option<&HashSet<MythId>> {
+        self.by_name.get(&name.to_lowercase())
+    }
+    
+    /// Get the ids of entities with a name or alternative name containing `substring` (case-insensitive)
+    pub fn ids_with_name_containing(&self, substring: &str) -> HashSet<MythId> {
+        let needle = substring.to_lowercase();
+        self.by_name.iter()
+            .filter(|(name, _)| name.contains(&needle))
+            .flat_map(|(_, ids)| ids.iter().cloned())
+            .collect()
+    }
+    
+    /// Get the ids of relationship entities whose `source_id` is the given entity
+    pub fn relationships_from(&self, id: &MythId) -> Option<&HashSet<MythId>> {
+        self.by_source.get(id)
+    }
+    
+    /// Get the ids of relationship entities whose `target_id` is the given entity
+    pub fn relationships_to(&self, id: &MythId) -> Option<&HashSet<MythId>> {
+        self.by_target.get(id)
+    }
+    
+    /// Get the full-text index over entity names, descriptions, and other free text
+    pub fn text(&self) -> &TextIndex {
+        &self.text
+    }
+}
+
+/// The lowercase names under which an entity is indexed
+fn index_names(entity: &MythEntity) -> HashSet<String> {
+    std::iter::once(entity.name())
+        .chain(entity.alternative_names())
+        .map(|name| name.to_lowercase())
+        .collect()
+}
+
+fn remove_from<K, Q>(index: &mut HashMap<K, HashSet<MythId>>, key: &Q, id: &MythId)
+where
+    K: std::borrow::Borrow<Q> + std::hash::Hash + Eq,
+    Q: std::hash::Hash + Eq + ?Sized,
+{
+    if let Some(ids) = index.get_mut(key) {
+        ids.remove(id);
+        if ids.is_empty() {
+            index.remove(key);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! # Multilingual Labels
+//!
+//! Any entity can carry names in several languages through [`Metadata::labels`]:
+//! the name in the original language and script, its transliterations, the names
+//! other languages give it, and epithets. Each [`Label`] records its language as a
+//! [`LanguageId`], the script it is written in, and the [`NameKind`].
+//!
+//! [`Metadata::preferred_label`] picks the label to show for a locale: a label
+//! marked as preferred if there is one, otherwise an original name before a
+//! transliteration before an exonym. Epithets are only shown when marked as
+//! preferred. [`MythEntity::name_in`] falls back to the primary name, and every
+//! label is also an alternative name, so name search finds entities in any language.
+//!
+//! ```
+//! use mythologic::core::{Label, LanguageId, MythEntity, NameKind};
+//! use mythologic::entities::Deity;
+//!
+//! let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+//! zeus.metadata.add_label(Label::new("Ζεύς", "Ancient Greek", NameKind::Original).script("Grek"));
+//! zeus.metadata.add_label(Label::new("Zeús", "Ancient Greek", NameKind::Transliteration).script("Latn"));
+//! zeus.metadata.add_label(Label::new("Kronides", "Ancient Greek", NameKind::Epithet));
+//! let zeus = MythEntity::Deity(zeus);
+//!
+//! assert_eq!(zeus.name_in(&LanguageId::new("Ancient Greek")), "Ζεύς");
+//! assert_eq!(zeus.name_in(&LanguageId::new("Latin")), "Zeus");
+//! assert!(zeus.alternative_names().contains(&"Kronides"));
+//! ```
+
+use serde::{Serialize, Deserialize};
+use crate::core::{variant_names, LanguageId, Metadata, MythEntity};
+
+/// The kind of name a label is
+///
+/// Variants are ordered by preference when choosing a label for a language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum NameKind {
+    /// The name in the entity's own language and script
+    Original,
+    /// The original name written in another script
+    Transliteration,
+    /// The name another language gives the entity, such as Latin "Hercules"
+    Exonym,
+    /// A descriptive or honorific name, such as "the Thunderer"
+    Epithet,
+}
+
+variant_names!(NameKind { Original, Transliteration, Exonym, Epithet });
+
+/// A name of an entity in one language
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Label {
+    /// The name as written
+    pub text: String,
+    /// The language of the name
+    pub language: LanguageId,
+    /// The script the name is written in, as an ISO 15924 code such as "Grek" or "Latn"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    /// What kind of name this is
+    pub kind: NameKind,
+    /// Whether this is the label to show for its language
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub preferred: bool,
+}
+
+impl Label {
+    /// Create a label in a language
+    pub fn new(text: &str, language: &str, kind: NameKind) -> Self {
+        Self {
+            text: text.to_string(),
+            language: LanguageId::new(language),
+            script: None,
+            kind,
+            preferred: false,
+        }
+    }
+    
+    /// Set the script the label is written in
+    pub fn script(mut self, script: &str) -> Self {
+        self.script = Some(script.to_string());
+        self
+    }
+    
+    /// Mark the label as the one to show for its language
+    pub fn preferred(mut self) -> Self {
+        self.preferred = true;
+        self
+    }
+}
+
+impl Metadata {
+    /// Add a name in some language
+    pub fn add_label(&mut self, label: Label) {
+        self.labels.push(label);
+    }
+    
+    /// Get the labels in a language
+    pub fn labels_in<'a>(&'a self, language: &'a LanguageId) -> impl Iterator<Item = &'a Label> + 'a {
+        self.labels.iter().filter(move |label| &label.language == language)
+    }
+    
+    /// The label to show for a language, if the entity has one
+    ///
+    /// A label marked as preferred wins; otherwise original names come before
+    /// transliterations and exonyms, and epithets are never chosen.
+    pub fn preferred_label(&self, language: &LanguageId) -> Option<&Label> {
+        let labels = self.labels.iter().filter(|label| &label.language == language);
+        labels.clone().find(|label| label.preferred)
+            .or_else(|| labels.filter(|label| label.kind != NameKind::Epithet).min_by_key(|label| label.kind))
+    }
+    
+    /// Check whether a label is the one to show for its language
+    ///
+    /// Labels are compared by text, language and kind, so a copy of the preferred
+    /// label counts as preferred too.
+    pub fn is_preferred_label(&self, label: &Label) -> bool {
+        self.preferred_label(&label.language).is_some_and(|preferred| {
+            preferred.text == label.text && preferred.language == label.language && preferred.kind == label.kind
+        })
+    }
+}
+
+impl MythEntity {
+    /// The name to show for a locale: the preferred label in that language, or the
+    /// primary name if there is none
+    pub fn name_in(&self, locale: &LanguageId) -> &str {
+        self.metadata().preferred_label(locale).map_or(self.name(), |label| label.text.as_str())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MythOntology;
+    use crate::entities::Hero;
+    use crate::query::QueryEngine;
+    
+    fn heracles() -> MythEntity {
+        let mut heracles = Hero::new("Heracles", "Son of Zeus and Alcmene", "Greek");
+        heracles.metadata.add_label(Label::new("Hēraklês", "Ancient Greek", NameKind::Transliteration).script("Latn"));
+        heracles.metadata.add_label(Label::new("Ἡρακλῆς", "Ancient Greek", NameKind::Original).script("Grek"));
+        heracles.metadata.add_label(Label::new("Alcides", "Ancient Greek", NameKind::Epithet));
+        heracles.metadata.add_label(Label::new("Hercules", "Latin", NameKind::Exonym));
+        heracles.metadata.add_label(Label::new("Alcides", "Latin", NameKind::Epithet));
+        heracles.metadata.add_label(Label::new("Herakles", "German", NameKind::Exonym));
+        heracles.metadata.add_label(Label::new("Herkules", "German", NameKind::Exonym));
+        MythEntity::Hero(heracles)
+    }
+    
+    #[test]
+    fn prefers_labels_by_flag_then_kind() {
+        let mut heracles = heracles();
+        let metadata = heracles.metadata();
+        let preferred = |language: &str| metadata.preferred_label(&LanguageId::new(language)).map(|label| label.text.as_str());
+        // Original names come before transliterations, and exonyms before epithets
+        assert_eq!(preferred("Ancient Greek"), Some("Ἡρακλῆς"));
+        assert_eq!(preferred("Latin"), Some("Hercules"));
+        // Labels of the same kind tie, and the first one added wins
+        assert_eq!(preferred("German"), Some("Herakles"));
+        assert_eq!(preferred("French"), None);
+        assert_eq!(metadata.labels_in(&LanguageId::new("Latin")).count(), 2);
+        assert!(metadata.is_preferred_label(&metadata.labels[1]));
+        assert!(!metadata.is_preferred_label(&metadata.labels[0]));
+        assert!(metadata.is_preferred_label(&Label::new("Hercules", "Latin", NameKind::Exonym)));
+        assert!(!metadata.is_preferred_label(&Label::new("Hercules", "Latin", NameKind::Epithet)));
+        
+        // A label marked as preferred wins, even an epithet
+        heracles.metadata_mut().add_label(Label::new("Alkeidēs", "Ancient Greek", NameKind::Epithet).preferred());
+        assert_eq!(heracles.name_in(&LanguageId::new("Ancient Greek")), "Alkeidēs");
+        
+        let mut alcides = MythEntity::Hero(Hero::new("Alcides", "", "Greek"));
+        alcides.metadata_mut().add_label(Label::new("Ἀλκείδης", "Ancient Greek", NameKind::Epithet));
+        assert_eq!(alcides.metadata().preferred_label(&LanguageId::new("Ancient Greek")), None);
+    }
+    
+    #[test]
+    fn falls_back_to_the_primary_name() {
+        let heracles = heracles();
+        assert_eq!(heracles.name_in(&LanguageId::new("Latin")), "Hercules");
+        assert_eq!(heracles.name_in(&LanguageId::new("French")), "Heracles");
+        
+        let mut cerberus = MythEntity::Hero(Hero::new("Cerberus", "", "Greek"));
+        cerberus.metadata_mut().add_label(Label::new("Cerberus", "Latin", NameKind::Exonym));
+        assert_eq!(cerberus.name_in(&LanguageId::new("Latin")), "Cerberus");
+        assert!(cerberus.alternative_names().is_empty(), "labels matching the name are not alternative names");
+    }
+    
+    #[test]
+    fn finds_entities_by_label() {
+        let heracles = heracles();
+        let names = heracles.alternative_names();
+        for label in ["Ἡρακλῆς", "Hēraklês", "Hercules", "Alcides", "Herkules"] {
+            assert!(names.contains(&label), "{} should be an alternative name", label);
+        }
+        
+        let mut ontology = MythOntology::new();
+        ontology.add_entity(heracles);
+        let found = |name: &str| ontology.entities_named(name).map(|entity| entity.name()).collect::<Vec<_>>();
+        assert_eq!(found("Hercules"), ["Heracles"]);
+        assert_eq!(found("Ἡρακλῆς"), ["Heracles"]);
+        
+        let engine = QueryEngine::new(&ontology);
+        let hits = engine.search_text("hercules");
+        assert_eq!(hits.iter().map(|hit| hit.name.as_str()).collect::<Vec<_>>(), ["Heracles"]);
+        assert_eq!(engine.search_text("ηρακλης").len(), 1);
+        
+        let query = |query: &str| engine.query_str(query).unwrap().results.len();
+        assert_eq!(query("any_name~herkul"), 1);
+        assert_eq!(query("alt_name~ἡρακλ"), 1);
+        assert_eq!(query("name~herkul"), 0, "name~ only looks at the primary name");
+    }
+}
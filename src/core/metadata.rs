@@ -1,6 +1,8 @@
 use chrono::{DateTime, Utc};
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
+use crate::core::{Citation, FieldClaim, HistoricalDate, Label, SourceId, Variant};
+use crate::motifs::{MotifCode, MotifTag};
 
 /// Metadata that can be attached to any mythological entity
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -9,12 +11,32 @@ pub struct Metadata {
     pub created_at: DateTime<Utc>,
     /// When this entity was last modified in the ontology
     pub updated_at: DateTime<Utc>,
-    /// The source of information for this entity
-    pub sources: Vec<Source>,
+    /// Citations of the sources in the ontology's bibliography supporting this entity
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub citations: Vec<Citation>,
     /// Additional attributes that don't fit elsewhere
     pub attributes: HashMap<String, String>,
     /// Confidence level in the accuracy of this information (0.0-1.0)
     pub confidence: Option<f32>,
+    /// Folklore motifs and tale types this entity is classified under
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub motifs: Vec<MotifTag>,
+    /// Earliest known attestation of this entity in the historical record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub first_attested: Option<HistoricalDate>,
+    /// Latest known attestation of this entity in the historical record
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_attested: Option<HistoricalDate>,
+    /// Competing claims about the values of this entity's fields
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub claims: Vec<FieldClaim>,
+    /// For a relationship told only by some sources, the contested question it
+    /// answers and the source telling it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<Variant>,
+    /// Names of this entity in other languages and scripts
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<Label>,
 }
 
 impl Metadata {
@@ -24,15 +46,31 @@ impl Metadata {
         Self {
             created_at: now,
             updated_at: now,
-            sources: Vec::new(),
+            citations: Vec::new(),
             attributes: HashMap::new(),
             confidence: None,
+            motifs: Vec::new(),
+            first_attested: None,
+            last_attested: None,
+            claims: Vec::new(),
+            variant: None,
+            labels: Vec::new(),
         }
     }
     
-    /// Add a source to this metadata
-    pub fn add_source(&mut self, source: Source) {
-        self.sources.push(source);
+    /// Cite a source supporting this entity or one of its fields
+    pub fn add_citation(&mut self, citation: Citation) {
+        self.citations.push(citation);
+    }
+    
+    /// Get the citations supporting a field, including those for the entity as a whole
+    pub fn citations_for<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a Citation> + 'a {
+        self.citations.iter().filter(move |citation| citation.field.as_deref().is_none_or(|f| f == field))
+    }
+    
+    /// Check whether this entity cites a source
+    pub fn cites(&self, source: &SourceId) -> bool {
+        self.citations.iter().any(|citation| &citation.source == source)
     }
     
     /// Add an attribute to this metadata
@@ -40,6 +78,33 @@ impl Metadata {
         self.attributes.insert(key.to_string(), value.to_string());
     }
     
+    /// Tag this entity with a motif or tale type
+    pub fn add_motif(&mut self, tag: MotifTag) {
+        self.motifs.push(tag);
+    }
+    
+    /// Check whether this entity is tagged with `code` or any code under it
+    pub fn has_motif(&self, code: &MotifCode) -> bool {
+        self.motifs.iter().any(|tag| code.contains(&tag.code))
+    }
+    
+    /// Set the earliest known attestation
+    pub fn set_first_attested(&mut self, date: HistoricalDate) {
+        self.first_attested = Some(date);
+    }
+    
+    /// Set the latest known attestation
+    pub fn set_last_attested(&mut self, date: HistoricalDate) {
+        self.last_attested = Some(date);
+    }
+    
+    /// The period this entity is attested in, from its first to its last attestation
+    ///
+    /// If only one attestation is known, that date alone is returned.
+    pub fn attestation(&self) -> Option<HistoricalDate> {
+        HistoricalDate::span(self.first_attested.as_ref(), self.last_attested.as_ref())
+    }
+    
     /// Update the timestamp to now
     pub fn update_timestamp(&mut self) {
         self.updated_at = Utc::now();
@@ -53,14 +118,17 @@ impl Default for Metadata {
 }
 
 /// A source of information for mythological data
+///
+/// Sources are stored once in an ontology's [`Bibliography`](crate::core::Bibliography)
+/// and cited by entities through [`Citation`]s.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Source {
     /// Title of the source
     pub title: String,
     /// Author(s) of the source
     pub author: Option<String>,
-    /// Year of publication
-    pub year: Option<i32>,
+    /// Date of composition or publication
+    pub date: Option<HistoricalDate>,
     /// Type of source (book, article, website, etc.)
     pub source_type: SourceType,
     /// URL if available
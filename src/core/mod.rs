@@ -1,13 +1,37 @@
 //! Core structures and traits for the mythological ontology
 
+mod adjacency;
+mod bibliography;
+mod claims;
+mod dating;
+mod document;
 mod id;
+mod index;
+mod labels;
 mod metadata;
 mod names;
 mod ontology;
+mod text;
+mod text_index;
 mod traits;
+mod traversal;
+mod validation;
+mod variants;
 
+pub use adjacency::*;
+pub use bibliography::*;
+pub use claims::*;
+pub use dating::*;
+pub use document::*;
 pub use id::*;
+pub use index::*;
+pub use labels::*;
 pub use metadata::*;
 pub use names::*;
 pub use ontology::*;
+pub use text::*;
+pub use text_index::*;
 pub use traits::*;
+pub use traversal::*;
+pub use validation::*;
+pub use variants::*;
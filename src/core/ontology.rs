@@ -1,9 +1,20 @@
-use std::collections::HashMap;
-use crate::core::{MythId, MythEntity};
+use std::collections::{HashMap, HashSet};
+use std::ops::{Deref, DerefMut};
+use crate::core::{Bibliography, MythId, MythEntity, CultureId, OntologyIndex};
 
 /// The main ontology that holds all mythological entities and their relationships
+///
+/// Ontologies serialize as a versioned [`OntologyDocument`](crate::core::OntologyDocument);
+/// see [`MythOntology::save`] and [`MythOntology::load`].
+///
+/// Secondary indexes by type, culture, name, and relationship endpoint are kept
+/// up to date as entities are added, removed, or modified through
+/// [`MythOntology::get_entity_mut`].
+#[derive(Debug, Clone)]
 pub struct MythOntology {
     entities: HashMap<MythId, MythEntity>,
+    index: OntologyIndex,
+    bibliography: Bibliography,
 }
 
 impl MythOntology {
@@ -11,13 +22,25 @@ impl MythOntology {
     pub fn new() -> Self {
         Self {
             entities: HashMap::new(),
+            index: OntologyIndex::new(),
+            bibliography: Bibliography::new(),
         }
     }
     
-    /// Add an entity to the ontology
+    /// Add an entity to the ontology, replacing any entity with the same ID
+    ///
+    /// Relationship entities are treated as edges: their ID is registered in the
+    /// `relationships` list of both endpoints. Adding an endpoint after its
+    /// relationships registers those relationships on it as well.
     pub fn add_entity(&mut self, entity: MythEntity) {
         let id = entity.id().clone();
-        self.entities.insert(id, entity);
+        if let Some(previous) = self.entities.remove(&id) {
+            self.index.remove(&previous);
+            self.detach(&previous);
+        }
+        self.index.insert(&entity);
+        self.entities.insert(id.clone(), entity);
+        self.attach(&id);
     }
     
     /// Get an entity by its ID
@@ -26,13 +49,107 @@ impl MythOntology {
     }
     
     /// Get a mutable reference to an entity by its ID
-    pub fn get_entity_mut(&mut self, id: &MythId) -> Option<&mut MythEntity> {
-        self.entities.get_mut(id)
+    ///
+    /// The returned guard dereferences to the entity and re-indexes it when dropped.
+    pub fn get_entity_mut(&mut self, id: &MythId) -> Option<EntityMut<'_>> {
+        let entity = self.entities.remove(id)?;
+        self.index.remove(&entity);
+        self.detach(&entity);
+        self.entities.insert(id.clone(), entity);
+        Some(EntityMut {
+            ontology: self,
+            id: id.clone(),
+        })
     }
     
     /// Remove an entity from the ontology
+    ///
+    /// Removing a relationship unregisters it from both endpoints. Removing any
+    /// other entity also removes every relationship that has it as an endpoint,
+    /// so no relationship is left pointing at a missing entity.
     pub fn remove_entity(&mut self, id: &MythId) -> Option<MythEntity> {
-        self.entities.remove(id)
+        let entity = self.entities.remove(id)?;
+        self.index.remove(&entity);
+        self.detach(&entity);
+        
+        if !entity.is_relationship() {
+            let incident: HashSet<MythId> = self.index.relationships_from(id).into_iter()
+                .chain(self.index.relationships_to(id))
+                .flatten()
+                .cloned()
+                .collect();
+            for relationship_id in incident {
+                self.remove_entity(&relationship_id);
+            }
+        }
+        
+        Some(entity)
+    }
+    
+    /// Register a stored entity's edges on the relevant endpoints
+    fn attach(&mut self, id: &MythId) {
+        let Some(entity) = self.entities.get(id) else {
+            return;
+        };
+        
+        let (edges, endpoints): (Vec<MythId>, Vec<MythId>) = match entity.relationship() {
+            Some(relationship) => (
+                vec![id.clone()],
+                vec![relationship.source_id.clone(), relationship.target_id.clone()],
+            ),
+            None => (
+                self.index.relationships_from(id).into_iter()
+                    .chain(self.index.relationships_to(id))
+                    .flatten()
+                    .cloned()
+                    .collect(),
+                vec![id.clone()],
+            ),
+        };
+        
+        for endpoint in endpoints {
+            if let Some(list) = self.entities.get_mut(&endpoint).and_then(MythEntity::relationships_mut) {
+                for edge in &edges {
+                    if !list.contains(edge) {
+                        list.push(edge.clone());
+                    }
+                }
+            }
+        }
+    }
+    
+    /// Unregister a relationship entity from its endpoints
+    fn detach(&mut self, entity: &MythEntity) {
+        let Some(relationship) = entity.relationship() else {
+            return;
+        };
+        
+        for endpoint in [&relationship.source_id, &relationship.target_id] {
+            if let Some(list) = self.entities.get_mut(endpoint).and_then(MythEntity::relationships_mut) {
+                list.retain(|edge| edge != &relationship.id);
+            }
+        }
+    }
+    
+    /// Point every relationship with `old` as an endpoint at `new` instead
+    fn redirect_relationships(&mut self, old: &MythId, new: &MythId) {
+        let incident: HashSet<MythId> = self.index.relationships_from(old).into_iter()
+            .chain(self.index.relationships_to(old))
+            .flatten()
+            .cloned()
+            .collect();
+        for id in incident {
+            if let Some(mut entity) = self.get_entity_mut(&id)
+                && let Some(relationship) = entity.relationship_mut()
+            {
+                if &relationship.source_id == old {
+                    relationship.source_id = new.clone();
+                }
+                if &relationship.target_id == old {
+                    relationship.target_id = new.clone();
+                }
+            }
+        }
     }
     
     /// Get all entities in the ontology
@@ -40,10 +157,69 @@ impl MythOntology {
         self.entities.values().collect()
     }
     
+    /// Iterate over all entities in the ontology without allocating
+    pub fn entities(&self) -> impl Iterator<Item = &MythEntity> {
+        self.entities.values()
+    }
+    
     /// Count the number of entities in the ontology
     pub fn entity_count(&self) -> usize {
         self.entities.len()
     }
+    
+    /// Check whether an entity with the given ID exists
+    pub fn contains(&self, id: &MythId) -> bool {
+        self.entities.contains_key(id)
+    }
+    
+    /// Get the secondary indexes of this ontology
+    pub fn index(&self) -> &OntologyIndex {
+        &self.index
+    }
+    
+    /// Get the shared sources cited by entities in the ontology
+    pub fn bibliography(&self) -> &Bibliography {
+        &self.bibliography
+    }
+    
+    /// Get a mutable reference to the shared sources
+    pub fn bibliography_mut(&mut self) -> &mut Bibliography {
+        &mut self.bibliography
+    }
+    
+    /// Get all entities of the given type (as returned by `entity_type()`)
+    pub fn entities_of_type(&self, entity_type: &str) -> impl Iterator<Item = &MythEntity> {
+        self.resolve(self.index.ids_of_type(entity_type))
+    }
+    
+    /// Get all entities belonging to the given culture
+    pub fn entities_in_culture(&self, culture: &CultureId) -> impl Iterator<Item = &MythEntity> {
+        self.resolve(self.index.ids_in_culture(culture))
+    }
+    
+    /// Get all entities whose name or alternative name equals `name` (case-insensitive)
+    pub fn entities_named(&self, name: &str) -> impl Iterator<Item = &MythEntity> {
+        self.resolve(self.index.ids_named(name))
+    }
+    
+    /// Get all relationship entities whose source is the given entity
+    pub fn relationships_from(&self, id: &MythId) -> impl Iterator<Item = &MythEntity> {
+        self.resolve(self.index.relationships_from(id))
+    }
+    
+    /// Get all relationship entities whose target is the given entity
+    pub fn relationships_to(&self, id: &MythId) -> impl Iterator<Item = &MythEntity> {
+        self.resolve(self.index.relationships_to(id))
+    }
+    
+    fn resolve<'a>(
+        &'a self,
+        ids: Option<&'a HashSet<MythId>>,
+    ) -> impl Iterator<Item = &'a MythEntity> {
+        ids.into_iter()
+            .flatten()
+            .filter_map(|id| self.entities.get(id))
+    }
 }
 
 impl Default for MythOntology {
@@ -51,3 +227,180 @@ impl Default for MythOntology {
         Self::new()
     }
 }
+
+/// A mutable handle to an entity inside a [`MythOntology`].
+///
+/// The entity is taken out of the secondary indexes while the handle is alive and
+/// re-indexed when it is dropped, so changes to names, culture, or relationship
+/// endpoints are reflected in later lookups. If the entity's ID is changed through
+/// the handle, the entity is moved to its new ID and every relationship with the
+/// old ID as an endpoint is rewritten to point at the new one. Other references to
+/// the old ID, such as pantheon members or event participants, are left as they are.
+pub struct EntityMut<'a> {
+    ontology: &'a mut MythOntology,
+    id: MythId,
+}
+
+impl Deref for EntityMut<'_> {
+    type Target = MythEntity;
+    
+    fn deref(&self) -> &MythEntity {
+        &self.ontology.entities[&self.id]
+    }
+}
+
+impl DerefMut for EntityMut<'_> {
+    fn deref_mut(&mut self) -> &mut MythEntity {
+        self.ontology.entities.get_mut(&self.id).expect("entity exists while borrowed")
+    }
+}
+
+impl Drop for EntityMut<'_> {
+    fn drop(&mut self) {
+        let Some(entity) = self.ontology.entities.remove(&self.id) else {
+            return;
+        };
+        let moved_to = (entity.id() != &self.id && !entity.is_relationship()).then(|| entity.id().clone());
+        self.ontology.add_entity(entity);
+        if let Some(new_id) = moved_to {
+            self.ontology.redirect_relationships(&self.id, &new_id);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Deity, Hero};
+    use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+    
+    fn names(entities: impl Iterator<Item = impl Deref<Target = MythEntity>>) -> Vec<String> {
+        let mut names: Vec<String> = entities.map(|e| e.name().to_string()).collect();
+        names.sort();
+        names
+    }
+    
+    #[test]
+    fn indexes_follow_add_and_remove() {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.add_alternative_name("Jupiter");
+        let zeus_id = zeus.id.clone();
+        let heracles = Hero::new("Heracles", "Son of Zeus", "Greek");
+        let heracles_id = heracles.id.clone();
+        let family = FamilyRelationship::new("Father", "Zeus fathers Heracles", zeus_id.clone(), heracles_id.clone(), FamilyRelationshipType::Parent);
+        let family_id = family.relationship.id.clone();
+        
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Hero(heracles));
+        ontology.add_entity(MythEntity::FamilyRelationship(family));
+        
+        assert_eq!(names(ontology.entities_of_type("Deity")), ["Zeus"]);
+        assert_eq!(names(ontology.entities_in_culture(&"Greek".into())), ["Heracles", "Zeus"]);
+        assert_eq!(names(ontology.entities_named("JUPITER")), ["Zeus"]);
+        assert_eq!(names(ontology.relationships_from(&zeus_id)), ["Father"]);
+        assert_eq!(names(ontology.relationships_to(&heracles_id)), ["Father"]);
+        
+        ontology.remove_entity(&family_id);
+        assert_eq!(ontology.relationships_from(&zeus_id).count(), 0);
+        ontology.remove_entity(&zeus_id);
+        assert_eq!(ontology.entities_of_type("Deity").count(), 0);
+        assert_eq!(ontology.entities_named("jupiter").count(), 0);
+    }
+    
+    #[test]
+    fn indexes_follow_mutation() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        let zeus_id = zeus.id.clone();
+        ontology.add_entity(MythEntity::Deity(zeus));
+        
+        if let Some(mut entity) = ontology.get_entity_mut(&zeus_id)
+            && let MythEntity::Deity(deity) = &mut *entity
+        {
+            deity.name = "Jupiter".to_string();
+            deity.culture = "Roman".into();
+        }
+        
+        assert_eq!(ontology.entities_named("zeus").count(), 0);
+        assert_eq!(names(ontology.entities_named("jupiter")), ["Jupiter"]);
+        assert_eq!(ontology.entities_in_culture(&"Greek".into()).count(), 0);
+        assert_eq!(names(ontology.entities_in_culture(&"Roman".into())), ["Jupiter"]);
+        assert!(ontology.index().text().postings("zeus").is_none());
+        assert!(ontology.index().text().postings("jupit").is_some_and(|ids| ids.contains_key(&zeus_id)));
+    }
+    
+    #[test]
+    fn relationships_register_on_endpoints() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        let zeus_id = zeus.id.clone();
+        let heracles = Hero::new("Heracles", "Son of Zeus", "Greek");
+        let heracles_id = heracles.id.clone();
+        let family = FamilyRelationship::new("Father", "Zeus fathers Heracles", zeus_id.clone(), heracles_id.clone(), FamilyRelationshipType::Parent);
+        let family_id = family.relationship.id.clone();
+        
+        // The relationship is added before one of its endpoints
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::FamilyRelationship(family));
+        ontology.add_entity(MythEntity::Hero(heracles));
+        
+        assert_eq!(ontology.get_entity(&zeus_id).unwrap().relationships(), vec![family_id.clone()]);
+        assert_eq!(ontology.get_entity(&heracles_id).unwrap().relationships(), vec![family_id.clone()]);
+        assert_eq!(ontology.outgoing_edges(&zeus_id).len(), 1);
+        assert_eq!(ontology.incoming_edges(&heracles_id).len(), 1);
+        assert_eq!(ontology.neighbors(&heracles_id), vec![zeus_id.clone()]);
+        
+        ontology.remove_entity(&family_id);
+        assert!(ontology.get_entity(&zeus_id).unwrap().relationships().is_empty());
+        assert!(ontology.get_entity(&heracles_id).unwrap().relationships().is_empty());
+    }
+    
+    #[test]
+    fn changing_an_id_moves_its_relationships() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        let zeus_id = zeus.id.clone();
+        let heracles = Hero::new("Heracles", "Son of Zeus", "Greek");
+        let heracles_id = heracles.id.clone();
+        let family = FamilyRelationship::new("Father", "Zeus fathers Heracles", zeus_id.clone(), heracles_id.clone(), FamilyRelationshipType::Parent);
+        let family_id = family.relationship.id.clone();
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Hero(heracles));
+        ontology.add_entity(MythEntity::FamilyRelationship(family));
+        
+        let new_id = MythId::new();
+        if let Some(mut entity) = ontology.get_entity_mut(&zeus_id)
+            && let MythEntity::Deity(deity) = &mut *entity
+        {
+            deity.id = new_id.clone();
+        }
+        
+        assert!(ontology.get_entity(&zeus_id).is_none());
+        assert_eq!(ontology.get_entity(&new_id).unwrap().relationships(), vec![family_id.clone()]);
+        assert_eq!(ontology.get_entity(&family_id).unwrap().relationship().unwrap().source_id, new_id);
+        assert_eq!(names(ontology.relationships_from(&new_id)), ["Father"]);
+        assert_eq!(ontology.relationships_from(&zeus_id).count(), 0);
+        assert_eq!(ontology.neighbors(&heracles_id), vec![new_id]);
+    }
+    
+    #[test]
+    fn removing_an_endpoint_removes_its_relationships() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        let zeus_id = zeus.id.clone();
+        let heracles = Hero::new("Heracles", "Son of Zeus", "Greek");
+        let heracles_id = heracles.id.clone();
+        let family = FamilyRelationship::new("Father", "Zeus fathers Heracles", zeus_id.clone(), heracles_id.clone(), FamilyRelationshipType::Parent);
+        let family_id = family.relationship.id.clone();
+        
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Hero(heracles));
+        ontology.add_entity(MythEntity::FamilyRelationship(family));
+        ontology.remove_entity(&zeus_id);
+        
+        assert!(!ontology.contains(&family_id));
+        assert!(ontology.get_entity(&heracles_id).unwrap().relationships().is_empty());
+        assert!(ontology.edges(&heracles_id).is_empty());
+    }
+}
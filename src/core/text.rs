@@ -0,0 +1,371 @@
+//! # Text Analysis
+//!
+//! Tokenization shared by full-text indexing and search. Text is split into
+//! words, folded to unaccented lowercase (so "Ragnarök" and "ragnarok" are the same
+//! word), and reduced to its English stem with the Porter algorithm, so "battles",
+//! "battled", and "battle" all match each other. Common English stop words are
+//! skipped but still counted, so phrase positions line up between indexed text
+//! and queries.
+
+use crate::core::variant_names;
+
+/// A free-text field of an entity, as indexed for full-text search
+///
+/// See [`MythEntity::text_fields`](crate::core::MythEntity::text_fields) for which
+/// fields each entity type contributes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextField {
+    Name,
+    AlternativeName,
+    Description,
+    Domain,
+    Achievement,
+    Power,
+    Ability,
+    Habitat,
+    Characteristic,
+    Accessibility,
+    Manifestation,
+    Practice,
+    FoundingMyth,
+    Significance,
+    Episode,
+}
+
+variant_names!(TextField {
+    Name, AlternativeName, Description, Domain, Achievement, Power, Ability, Habitat,
+    Characteristic, Accessibility, Manifestation, Practice, FoundingMyth, Significance, Episode,
+});
+
+impl TextField {
+    /// The default weight of a match in this field relative to a match in a description
+    pub fn default_boost(&self) -> f32 {
+        match self {
+            Self::Name => 3.0,
+            Self::AlternativeName => 2.0,
+            Self::Domain | Self::Achievement | Self::Power | Self::Ability => 1.5,
+            _ => 1.0,
+        }
+    }
+}
+
+/// A word of analysed text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// The folded, stemmed form used for matching
+    pub term: String,
+    /// Byte offset of the word in the original text
+    pub start: usize,
+    /// Byte offset just past the word in the original text
+    pub end: usize,
+    /// Word position in the text, counting skipped stop words
+    pub position: u32,
+}
+
+/// Split text into stemmed, folded tokens, skipping stop words
+///
+/// ```
+/// use mythologic::core::tokenize;
+///
+/// let terms: Vec<String> = tokenize("The Labors of Heracles").into_iter().map(|t| t.term).collect();
+/// assert_eq!(terms, ["labor", "heracl"]);
+/// ```
+pub fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut position = 0;
+    let mut word = String::new();
+    let mut start = None;
+    
+    let mut finish = |word: &mut String, start: usize, end: usize, position: &mut u32| {
+        let trimmed = word.trim_matches('\'');
+        let trimmed = trimmed.strip_suffix("'s").unwrap_or(trimmed).replace('\'', "");
+        if !trimmed.is_empty() {
+            if !is_stop_word(&trimmed) {
+                tokens.push(Token { term: stem(&trimmed), start, end, position: *position });
+            }
+            *position += 1;
+        }
+        word.clear();
+    };
+    
+    for (offset, c) in text.char_indices() {
+        if c.is_alphanumeric() || (start.is_some() && (c == '\'' || c == '’')) {
+            start.get_or_insert(offset);
+            match c {
+                '’' => word.push('\''),
+                c => match fold_char(c) {
+                    Some(folded) => word.push_str(folded),
+                    None => word.extend(c.to_lowercase()),
+                },
+            }
+        } else if let Some(word_start) = start.take() {
+            finish(&mut word, word_start, offset, &mut position);
+        }
+    }
+    if let Some(word_start) = start {
+        finish(&mut word, word_start, text.len(), &mut position);
+    }
+    tokens
+}
+
+/// Check whether a folded word is too common to be worth indexing
+pub fn is_stop_word(word: &str) -> bool {
+    matches!(
+        word,
+        "a" | "an" | "and" | "are" | "as" | "at" | "be" | "by" | "for" | "from" | "in" | "into"
+            | "is" | "it" | "its" | "of" | "on" | "or" | "that" | "the" | "their" | "to" | "was"
+            | "were" | "which" | "with"
+    )
+}
+
+/// Reduce a folded English word to its stem using the Porter algorithm
+///
+/// Words that are not plain lowercase ASCII are returned unchanged.
+///
+/// ```
+/// use mythologic::core::stem;
+///
+/// assert_eq!(stem("battles"), stem("battle"));
+/// assert_eq!(stem("transformation"), "transform");
+/// assert_eq!(stem("heroes"), "hero");
+/// ```
+pub fn stem(word: &str) -> String {
+    if word.len() <= 2 || !word.bytes().all(|b| b.is_ascii_lowercase()) {
+        return word.to_string();
+    }
+    let mut stemmer = Stemmer { word: word.as_bytes().to_vec() };
+    stemmer.step1();
+    stemmer.step2();
+    stemmer.step3();
+    stemmer.step4();
+    stemmer.step5();
+    String::from_utf8(stemmer.word).expect("stems are ASCII")
+}
+
+/// The Porter stemmer's working state: the word being reduced
+struct Stemmer {
+    word: Vec<u8>,
+}
+
+impl Stemmer {
+    fn is_consonant(&self, i: usize) -> bool {
+        match self.word[i] {
+            b'a' | b'e' | b'i' | b'o' | b'u' => false,
+            b'y' => i == 0 || !self.is_consonant(i - 1),
+            _ => true,
+        }
+    }
+    
+    /// The number of vowel-consonant sequences in the first `len` letters
+    fn measure(&self, len: usize) -> usize {
+        let mut count = 0;
+        let mut i = 0;
+        while i < len && self.is_consonant(i) {
+            i += 1;
+        }
+        loop {
+            while i < len && !self.is_consonant(i) {
+                i += 1;
+            }
+            if i >= len {
+                return count;
+            }
+            while i < len && self.is_consonant(i) {
+                i += 1;
+            }
+            count += 1;
+        }
+    }
+    
+    fn has_vowel(&self, len: usize) -> bool {
+        (0..len).any(|i| !self.is_consonant(i))
+    }
+    
+    fn ends_with_double_consonant(&self, len: usize) -> bool {
+        len >= 2 && self.word[len - 1] == self.word[len - 2] && self.is_consonant(len - 1)
+    }
+    
+    /// Whether the first `len` letters end consonant-vowel-consonant, the last
+    /// consonant not being w, x, or y
+    fn ends_cvc(&self, len: usize) -> bool {
+        len >= 3
+            && self.is_consonant(len - 3)
+            && !self.is_consonant(len - 2)
+            && self.is_consonant(len - 1)
+            && !matches!(self.word[len - 1], b'w' | b'x' | b'y')
+    }
+    
+    fn ends_with(&self, suffix: &str) -> bool {
+        self.word.ends_with(suffix.as_bytes())
+    }
+    
+    /// The length of the word without `suffix`
+    fn stem_len(&self, suffix: &str) -> usize {
+        self.word.len() - suffix.len()
+    }
+    
+    fn replace(&mut self, suffix: &str, replacement: &str) {
+        let len = self.stem_len(suffix);
+        self.word.truncate(len);
+        self.word.extend_from_slice(replacement.as_bytes());
+    }
+    
+    /// Replace the first matching suffix if the remaining stem has a measure above
+    /// `min_measure`
+    fn replace_first(&mut self, rules: &[(&str, &str)], min_measure: usize) {
+        if let Some(&(suffix, replacement)) = rules.iter().find(|(suffix, _)| self.ends_with(suffix))
+            && self.measure(self.stem_len(suffix)) > min_measure
+        {
+            self.replace(suffix, replacement);
+        }
+    }
+    
+    /// Plurals, -ed and -ing, and terminal y
+    fn step1(&mut self) {
+        if self.ends_with("sses") || self.ends_with("ies") {
+            self.word.truncate(self.word.len() - 2);
+        } else if self.ends_with("s") && !self.ends_with("ss") {
+            self.word.pop();
+        }
+        
+        if self.ends_with("eed") {
+            if self.measure(self.stem_len("eed")) > 0 {
+                self.word.pop();
+            }
+        } else if let Some(suffix) = ["ed", "ing"].into_iter().find(|suffix| self.ends_with(suffix))
+            && self.has_vowel(self.stem_len(suffix))
+        {
+            self.replace(suffix, "");
+            let len = self.word.len();
+            if self.ends_with("at") || self.ends_with("bl") || self.ends_with("iz") {
+                self.word.push(b'e');
+            } else if self.ends_with_double_consonant(len) && !matches!(self.word[len - 1], b'l' | b's' | b'z') {
+                self.word.pop();
+            } else if self.measure(len) == 1 && self.ends_cvc(len) {
+                self.word.push(b'e');
+            }
+        }
+        
+        if self.ends_with("y") && self.has_vowel(self.stem_len("y")) {
+            self.replace("y", "i");
+        }
+    }
+    
+    /// Double suffixes such as -ization and -fulness
+    fn step2(&mut self) {
+        self.replace_first(&[
+            ("ational", "ate"), ("tional", "tion"), ("enci", "ence"), ("anci", "ance"), ("izer", "ize"),
+            ("abli", "able"), ("alli", "al"), ("entli", "ent"), ("eli", "e"), ("ousli", "ous"),
+            ("ization", "ize"), ("ation", "ate"), ("ator", "ate"), ("alism", "al"), ("iveness", "ive"),
+            ("fulness", "ful"), ("ousness", "ous"), ("aliti", "al"), ("iviti", "ive"), ("biliti", "ble"),
+        ], 0);
+    }
+    
+    /// -ic-, -full, -ness and similar
+    fn step3(&mut self) {
+        self.replace_first(&[
+            ("icate", "ic"), ("ative", ""), ("alize", "al"), ("iciti", "ic"), ("ical", "ic"),
+            ("ful", ""), ("ness", ""),
+        ], 0);
+    }
+    
+    /// Remaining suffixes on long stems
+    fn step4(&mut self) {
+        const SUFFIXES: [&str; 19] = [
+            "ement", "ance", "ence", "able", "ible", "ment", "ant", "ent", "ion", "ism",
+            "ate", "iti", "ous", "ive", "ize", "al", "er", "ic", "ou",
+        ];
+        if let Some(suffix) = SUFFIXES.into_iter().find(|suffix| self.ends_with(suffix)) {
+            let len = self.stem_len(suffix);
+            let allowed = suffix != "ion" || (len > 0 && matches!(self.word[len - 1], b's' | b't'));
+            if allowed && self.measure(len) > 1 {
+                self.word.truncate(len);
+            }
+        }
+    }
+    
+    /// Final e and double l
+    fn step5(&mut self) {
+        if self.ends_with("e") {
+            let len = self.stem_len("e");
+            let measure = self.measure(len);
+            if measure > 1 || (measure == 1 && !self.ends_cvc(len)) {
+                self.word.pop();
+            }
+        }
+        let len = self.word.len();
+        if self.ends_with("ll") && self.measure(len) > 1 {
+            self.word.pop();
+        }
+    }
+}
+
+/// The unaccented, lowercase form of a Latin letter with diacritics or a ligature,
+/// or of a Greek letter with accents, breathings or a final form
+pub(crate) fn fold_char(c: char) -> Option<&'static str> {
+    Some(match c {
+        'À'..='Å' | 'à'..='å' | 'Ā'..='ą' | 'Ǎ' | 'ǎ' => "a",
+        'Æ' | 'æ' | 'Ǣ' | 'ǣ' | 'Ǽ' | 'ǽ' => "ae",
+        'Ç' | 'ç' | 'Ć'..='č' => "c",
+        'Ð' | 'ð' => "d",
+        'Ď'..='đ' => "d",
+        'È'..='Ë' | 'è'..='ë' | 'Ē'..='ě' => "e",
+        'Ĝ'..='ģ' => "g",
+        'Ĥ'..='ħ' => "h",
+        'Ì'..='Ï' | 'ì'..='ï' | 'Ĩ'..='ı' | 'Ǐ' | 'ǐ' => "i",
+        'Ĵ' | 'ĵ' => "j",
+        'Ķ' | 'ķ' => "k",
+        'Ĺ'..='ł' => "l",
+        'Ñ' | 'ñ' | 'Ń'..='ň' => "n",
+        'Ò'..='Ö' | 'Ø' | 'ò'..='ö' | 'ø' | 'Ō'..='ő' | 'Ǒ' | 'ǒ' | 'Ǫ' | 'ǫ' => "o",
+        'Œ' | 'œ' => "oe",
+        'Ŕ'..='ř' => "r",
+        'Ś'..='š' => "s",
+        'ß' => "ss",
+        'Ţ'..='ŧ' => "t",
+        'Þ' | 'þ' => "th",
+        'Ù'..='Ü' | 'ù'..='ü' | 'Ũ'..='ų' | 'Ǔ'..='ǜ' => "u",
+        'Ŵ' | 'ŵ' => "w",
+        'Ý' | 'ý' | 'ÿ' | 'Ŷ'..='Ÿ' => "y",
+        'Ź'..='ž' => "z",
+        // Monotonic accents and dialytika, then the polytonic Greek Extended block
+        '\u{386}' | '\u{3AC}' | '\u{1F00}'..='\u{1F0F}' | '\u{1F70}' | '\u{1F71}' | '\u{1F80}'..='\u{1F8F}' | '\u{1FB0}'..='\u{1FBC}' => "α",
+        '\u{388}' | '\u{3AD}' | '\u{1F10}'..='\u{1F1D}' | '\u{1F72}' | '\u{1F73}' | '\u{1FC8}' | '\u{1FC9}' => "ε",
+        '\u{389}' | '\u{3AE}' | '\u{1F20}'..='\u{1F2F}' | '\u{1F74}' | '\u{1F75}' | '\u{1F90}'..='\u{1F9F}' | '\u{1FC2}'..='\u{1FC7}' | '\u{1FCA}'..='\u{1FCC}' => "η",
+        '\u{38A}' | '\u{390}' | '\u{3AA}' | '\u{3AF}' | '\u{3CA}' | '\u{1F30}'..='\u{1F3F}' | '\u{1F76}' | '\u{1F77}' | '\u{1FD0}'..='\u{1FDB}' => "ι",
+        '\u{38C}' | '\u{3CC}' | '\u{1F40}'..='\u{1F4D}' | '\u{1F78}' | '\u{1F79}' | '\u{1FF8}' | '\u{1FF9}' => "ο",
+        '\u{38E}' | '\u{3AB}' | '\u{3B0}' | '\u{3CB}' | '\u{3CD}' | '\u{1F50}'..='\u{1F5F}' | '\u{1F7A}' | '\u{1F7B}' | '\u{1FE0}'..='\u{1FE3}' | '\u{1FE6}'..='\u{1FEB}' => "υ",
+        '\u{38F}' | '\u{3CE}' | '\u{1F60}'..='\u{1F6F}' | '\u{1F7C}' | '\u{1F7D}' | '\u{1FA0}'..='\u{1FAF}' | '\u{1FF2}'..='\u{1FF7}' | '\u{1FFA}'..='\u{1FFC}' => "ω",
+        '\u{1FE4}' | '\u{1FE5}' | '\u{1FEC}' => "ρ",
+        'ς' => "σ",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn stems_follow_porter() {
+        let cases = [
+            ("caresses", "caress"), ("ponies", "poni"), ("agreed", "agre"), ("plastered", "plaster"),
+            ("hopping", "hop"), ("filing", "file"), ("happy", "happi"), ("relational", "relat"),
+            ("conditional", "condit"), ("hopefulness", "hope"), ("adjustment", "adjust"), ("controll", "control"),
+            ("generalizations", "gener"), ("adoption", "adopt"), ("rate", "rate"),
+        ];
+        for (word, expected) in cases {
+            assert_eq!(stem(word), expected, "stem of {}", word);
+        }
+    }
+    
+    #[test]
+    fn tokens_keep_offsets_and_positions() {
+        let text = "Odin’s ravens at Ragnarök";
+        let tokens = tokenize(text);
+        let summary: Vec<(&str, &str, u32)> = tokens.iter()
+            .map(|t| (t.term.as_str(), &text[t.start..t.end], t.position))
+            .collect();
+        assert_eq!(summary, [("odin", "Odin’s", 0), ("raven", "ravens", 1), ("ragnarok", "Ragnarök", 3)]);
+    }
+}
@@ -0,0 +1,130 @@
+//! # Full-Text Index
+//!
+//! This module provides [`TextIndex`], an inverted index over the free-text fields
+//! of every entity (see [`MythEntity::text_fields`]). Like the other secondary
+//! indexes it is part of [`OntologyIndex`](crate::core::OntologyIndex) and is
+//! updated incrementally as entities are added, removed, or modified. Each term
+//! records where it occurs, by field, field value, and word position, so phrase
+//! queries can be answered from the index, and the index keeps the field length
+//! statistics needed for BM25 ranking. Ranking and snippets are provided by
+//! [`TextSearch`](crate::query::TextSearch).
+
+use std::collections::HashMap;
+use crate::core::{tokenize, MythEntity, MythId, TextField};
+
+/// One occurrence of a term in an entity's text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Occurrence {
+    /// The field the term occurs in
+    pub field: TextField,
+    /// Index of the field value among the entity's [`MythEntity::text_fields`]
+    pub value: u32,
+    /// Word position within the field value
+    pub position: u32,
+}
+
+/// Inverted index from stemmed terms to the entities containing them
+#[derive(Debug, Clone, Default)]
+pub struct TextIndex {
+    postings: HashMap<String, HashMap<MythId, Vec<Occurrence>>>,
+    field_lengths: HashMap<MythId, HashMap<TextField, u32>>,
+    field_totals: HashMap<TextField, FieldTotals>,
+}
+
+/// Totals over all entities for one field, used to average its length
+#[derive(Debug, Clone, Copy, Default)]
+struct FieldTotals {
+    words: u64,
+    entities: u32,
+}
+
+impl TextIndex {
+    /// Create an empty index
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Index the text fields of an entity
+    pub(crate) fn insert(&mut self, entity: &MythEntity) {
+        let id = entity.id();
+        let mut lengths: HashMap<TextField, u32> = HashMap::new();
+        
+        for (value, (field, text)) in entity.text_fields().into_iter().enumerate() {
+            let tokens = tokenize(text);
+            let Some(last) = tokens.last() else {
+                continue;
+            };
+            *lengths.entry(field).or_default() += last.position + 1;
+            for token in tokens {
+                self.postings.entry(token.term).or_default()
+                    .entry(id.clone()).or_default()
+                    .push(Occurrence { field, value: value as u32, position: token.position });
+            }
+        }
+        
+        for (field, length) in &lengths {
+            let totals = self.field_totals.entry(*field).or_default();
+            totals.words += u64::from(*length);
+            totals.entities += 1;
+        }
+        if !lengths.is_empty() {
+            self.field_lengths.insert(id.clone(), lengths);
+        }
+    }
+    
+    /// Remove an entity's text from the index
+    pub(crate) fn remove(&mut self, entity: &MythEntity) {
+        let id = entity.id();
+        
+        for (_, text) in entity.text_fields() {
+            for token in tokenize(text) {
+                if let Some(entities) = self.postings.get_mut(&token.term) {
+                    entities.remove(id);
+                    if entities.is_empty() {
+                        self.postings.remove(&token.term);
+                    }
+                }
+            }
+        }
+        
+        for (field, length) in self.field_lengths.remove(id).unwrap_or_default() {
+            if let Some(totals) = self.field_totals.get_mut(&field) {
+                totals.words -= u64::from(length);
+                totals.entities -= 1;
+                if totals.entities == 0 {
+                    self.field_totals.remove(&field);
+                }
+            }
+        }
+    }
+    
+    /// Get the entities containing a stemmed term, with every occurrence of it
+    pub fn postings(&self, term: &str) -> Option<&HashMap<MythId, Vec<Occurrence>>> {
+        self.postings.get(term)
+    }
+    
+    /// Count the entities with any indexed text
+    pub fn entity_count(&self) -> usize {
+        self.field_lengths.len()
+    }
+    
+    /// Count the distinct terms in the index
+    pub fn term_count(&self) -> usize {
+        self.postings.len()
+    }
+    
+    /// Get the number of words in a field of an entity, counting every value of the field
+    pub fn field_length(&self, id: &MythId, field: TextField) -> u32 {
+        self.field_lengths.get(id)
+            .and_then(|lengths| lengths.get(&field))
+            .copied()
+            .unwrap_or(0)
+    }
+    
+    /// Get the average length of a field over the entities that have it
+    pub fn average_field_length(&self, field: TextField) -> f32 {
+        self.field_totals.get(&field)
+            .map(|totals| totals.words as f32 / totals.entities as f32)
+            .unwrap_or(0.0)
+    }
+}
@@ -1,10 +1,18 @@
-use crate::core::{MythId, Metadata, CultureId};
-use crate::entities::{Deity, Hero, Creature, Artifact, Location, Concept};
-use crate::relationships::{Relationship, FamilyRelationship, AllianceRelationship, ConflictRelationship, TransformationRelationship};
+use serde::{Serialize, Deserialize};
+use crate::core::{MythId, Metadata, CultureId, TextField};
+use crate::entities::{Deity, Hero, Creature, Artifact, Location, Concept, Narrative, Event};
+use crate::relationships::{
+    Relationship, FamilyRelationship, AllianceRelationship, ConflictRelationship, TransformationRelationship,
+    SyncretismRelationship,
+};
 use crate::cultural::{Culture, Pantheon, MythologicalEra, MythologicalRegion};
 
 /// An enum representing any entity in the mythological ontology
-#[derive(Clone)]
+///
+/// Serialized form is adjacently tagged: `{"type": "Deity", "data": {...}}`,
+/// where `type` matches [`MythEntity::entity_type`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
 pub enum MythEntity {
     // Entities
     Deity(Deity),
@@ -13,6 +21,8 @@ pub enum MythEntity {
     Artifact(Artifact),
     Location(Location),
     Concept(Concept),
+    Narrative(Narrative),
+    Event(Event),
     
     // Relationships
     Relationship(Relationship),
@@ -20,6 +30,7 @@ pub enum MythEntity {
     AllianceRelationship(AllianceRelationship),
     ConflictRelationship(ConflictRelationship),
     TransformationRelationship(TransformationRelationship),
+    SyncretismRelationship(SyncretismRelationship),
     
     // Cultural contexts
     Culture(Culture),
@@ -38,11 +49,14 @@ impl MythEntity {
             Self::Artifact(e) => &e.id,
             Self::Location(e) => &e.id,
             Self::Concept(e) => &e.id,
+            Self::Narrative(e) => &e.id,
+            Self::Event(e) => &e.id,
             Self::Relationship(e) => &e.id,
             Self::FamilyRelationship(e) => &e.relationship.id,
             Self::AllianceRelationship(e) => &e.relationship.id,
             Self::ConflictRelationship(e) => &e.relationship.id,
             Self::TransformationRelationship(e) => &e.relationship.id,
+            Self::SyncretismRelationship(e) => &e.relationship.id,
             Self::Culture(e) => &e.id,
             Self::Pantheon(e) => &e.id,
             Self::MythologicalEra(e) => &e.id,
@@ -59,11 +73,14 @@ impl MythEntity {
             Self::Artifact(e) => &e.name,
             Self::Location(e) => &e.name,
             Self::Concept(e) => &e.name,
+            Self::Narrative(e) => &e.name,
+            Self::Event(e) => &e.name,
             Self::Relationship(e) => &e.name,
             Self::FamilyRelationship(e) => &e.relationship.name,
             Self::AllianceRelationship(e) => &e.relationship.name,
             Self::ConflictRelationship(e) => &e.relationship.name,
             Self::TransformationRelationship(e) => &e.relationship.name,
+            Self::SyncretismRelationship(e) => &e.relationship.name,
             Self::Culture(e) => &e.name,
             Self::Pantheon(e) => &e.name,
             Self::MythologicalEra(e) => &e.name,
@@ -71,6 +88,87 @@ impl MythEntity {
         }
     }
     
+    /// Get the alternative names of this entity: a deity's alternative names, then
+    /// the labels in every language that differ from the primary name
+    pub fn alternative_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = match self {
+            Self::Deity(e) => e.alternative_names.iter().map(|n| n.value()).collect(),
+            _ => Vec::new(),
+        };
+        for label in &self.metadata().labels {
+            if label.text != self.name() && !names.contains(&label.text.as_str()) {
+                names.push(&label.text);
+            }
+        }
+        names
+    }
+    
+    /// Get the free-text fields of this entity, as indexed for full-text search
+    ///
+    /// Each name, description, and entry of a free-text list is a separate value.
+    pub fn text_fields(&self) -> Vec<(TextField, &str)> {
+        use TextField::*;
+        
+        let mut fields = vec![(Name, self.name())];
+        fields.extend(self.alternative_names().into_iter().map(|name| (AlternativeName, name)));
+        
+        match self {
+            Self::Deity(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(sorted(e.domains.iter().map(|d| d.value())).into_iter().map(|d| (Domain, d)));
+            },
+            Self::Hero(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Achievement, &e.achievements));
+            },
+            Self::Creature(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Ability, &e.abilities));
+                fields.extend(sorted(e.habitat.iter().map(String::as_str)).into_iter().map(|h| (Habitat, h)));
+            },
+            Self::Artifact(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Power, &e.powers));
+            },
+            Self::Location(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Characteristic, &e.characteristics));
+                fields.extend(list(Accessibility, &e.accessibility));
+            },
+            Self::Concept(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Manifestation, &e.manifestations));
+            },
+            Self::Narrative(e) => {
+                fields.push((Description, &e.description));
+                for episode in &e.episodes {
+                    fields.push((Episode, &episode.title));
+                    fields.push((Episode, &episode.description));
+                }
+            },
+            Self::Event(e) => {
+                fields.push((Description, &e.description));
+            },
+            Self::Culture(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(list(Practice, &e.cultural_practices));
+            },
+            Self::Pantheon(e) => {
+                fields.push((Description, &e.description));
+                fields.extend(e.founding_myth.as_deref().map(|myth| (FoundingMyth, myth)));
+            },
+            Self::MythologicalEra(e) => fields.push((Description, &e.description)),
+            Self::MythologicalRegion(e) => {
+                fields.push((Description, &e.description));
+                fields.push((Significance, &e.significance));
+            },
+            _ => fields.extend(self.relationship().map(|r| (Description, r.description.as_str()))),
+        }
+        
+        fields.retain(|(_, value)| !value.is_empty());
+        fields
+    }
+    
     /// Get the metadata for this entity
     pub fn metadata(&self) -> &Metadata {
         match self {
@@ -80,11 +178,14 @@ impl MythEntity {
             Self::Artifact(e) => &e.metadata,
             Self::Location(e) => &e.metadata,
             Self::Concept(e) => &e.metadata,
+            Self::Narrative(e) => &e.metadata,
+            Self::Event(e) => &e.metadata,
             Self::Relationship(e) => &e.metadata,
             Self::FamilyRelationship(e) => &e.relationship.metadata,
             Self::AllianceRelationship(e) => &e.relationship.metadata,
             Self::ConflictRelationship(e) => &e.relationship.metadata,
             Self::TransformationRelationship(e) => &e.relationship.metadata,
+            Self::SyncretismRelationship(e) => &e.relationship.metadata,
             Self::Culture(e) => &e.metadata,
             Self::Pantheon(e) => &e.metadata,
             Self::MythologicalEra(e) => &e.metadata,
@@ -101,11 +202,14 @@ impl MythEntity {
             Self::Artifact(e) => &mut e.metadata,
             Self::Location(e) => &mut e.metadata,
             Self::Concept(e) => &mut e.metadata,
+            Self::Narrative(e) => &mut e.metadata,
+            Self::Event(e) => &mut e.metadata,
             Self::Relationship(e) => &mut e.metadata,
             Self::FamilyRelationship(e) => &mut e.relationship.metadata,
             Self::AllianceRelationship(e) => &mut e.relationship.metadata,
             Self::ConflictRelationship(e) => &mut e.relationship.metadata,
             Self::TransformationRelationship(e) => &mut e.relationship.metadata,
+            Self::SyncretismRelationship(e) => &mut e.relationship.metadata,
             Self::Culture(e) => &mut e.metadata,
             Self::Pantheon(e) => &mut e.metadata,
             Self::MythologicalEra(e) => &mut e.metadata,
@@ -122,11 +226,14 @@ impl MythEntity {
             Self::Artifact(_) => "Artifact",
             Self::Location(_) => "Location",
             Self::Concept(_) => "Concept",
+            Self::Narrative(_) => "Narrative",
+            Self::Event(_) => "Event",
             Self::Relationship(_) => "Relationship",
             Self::FamilyRelationship(_) => "FamilyRelationship",
             Self::AllianceRelationship(_) => "AllianceRelationship",
             Self::ConflictRelationship(_) => "ConflictRelationship",
             Self::TransformationRelationship(_) => "TransformationRelationship",
+            Self::SyncretismRelationship(_) => "SyncretismRelationship",
             Self::Culture(_) => "Culture",
             Self::Pantheon(_) => "Pantheon",
             Self::MythologicalEra(_) => "MythologicalEra",
@@ -143,6 +250,8 @@ impl MythEntity {
             Self::Artifact(e) => e.relationships.clone(),
             Self::Location(e) => e.relationships.clone(),
             Self::Concept(e) => e.relationships.clone(),
+            Self::Narrative(e) => e.relationships.clone(),
+            Self::Event(e) => e.relationships.clone(),
             Self::Culture(e) => e.relationships.clone(),
             Self::Pantheon(e) => e.relationships.clone(),
             Self::MythologicalEra(e) => e.relationships.clone(),
@@ -151,6 +260,25 @@ impl MythEntity {
         }
     }
     
+    /// Get mutable access to the relationship IDs of this entity, if it can have any
+    pub fn relationships_mut(&mut self) -> Option<&mut Vec<MythId>> {
+        match self {
+            Self::Deity(e) => Some(&mut e.relationships),
+            Self::Hero(e) => Some(&mut e.relationships),
+            Self::Creature(e) => Some(&mut e.relationships),
+            Self::Artifact(e) => Some(&mut e.relationships),
+            Self::Location(e) => Some(&mut e.relationships),
+            Self::Concept(e) => Some(&mut e.relationships),
+            Self::Narrative(e) => Some(&mut e.relationships),
+            Self::Event(e) => Some(&mut e.relationships),
+            Self::Culture(e) => Some(&mut e.relationships),
+            Self::Pantheon(e) => Some(&mut e.relationships),
+            Self::MythologicalEra(e) => Some(&mut e.relationships),
+            Self::MythologicalRegion(e) => Some(&mut e.relationships),
+            _ => None,
+        }
+    }
+    
     /// Get the culture of this entity if applicable
     pub fn culture(&self) -> Option<&CultureId> {
         match self {
@@ -160,14 +288,74 @@ impl MythEntity {
             Self::Artifact(e) => Some(&e.culture),
             Self::Location(e) => Some(&e.culture),
             Self::Concept(e) => Some(&e.culture),
+            Self::Narrative(e) => Some(&e.culture),
+            Self::Event(e) => Some(&e.culture),
             Self::Pantheon(e) => Some(&e.culture),
             Self::MythologicalEra(e) => Some(&e.culture),
             _ => None,
         }
     }
     
+    /// Get the base relationship if this entity is one of the relationship variants
+    pub fn relationship(&self) -> Option<&Relationship> {
+        match self {
+            Self::Relationship(e) => Some(e),
+            Self::FamilyRelationship(e) => Some(&e.relationship),
+            Self::AllianceRelationship(e) => Some(&e.relationship),
+            Self::ConflictRelationship(e) => Some(&e.relationship),
+            Self::TransformationRelationship(e) => Some(&e.relationship),
+            Self::SyncretismRelationship(e) => Some(&e.relationship),
+            _ => None,
+        }
+    }
+    
+    /// Get a mutable reference to the base relationship if this entity is one of the
+    /// relationship variants
+    pub fn relationship_mut(&mut self) -> Option<&mut Relationship> {
+        match self {
+            Self::Relationship(e) => Some(e),
+            Self::FamilyRelationship(e) => Some(&mut e.relationship),
+            Self::AllianceRelationship(e) => Some(&mut e.relationship),
+            Self::ConflictRelationship(e) => Some(&mut e.relationship),
+            Self::TransformationRelationship(e) => Some(&mut e.relationship),
+            Self::SyncretismRelationship(e) => Some(&mut e.relationship),
+            _ => None,
+        }
+    }
+    
+    /// Get the specific kind of a relationship entity, e.g. `Parent` for a family
+    /// relationship or `War` for a conflict
+    ///
+    /// Plain relationships report their `relationship_type`.
+    pub fn relationship_kind(&self) -> Option<String> {
+        match self {
+            Self::Relationship(e) => Some(e.relationship_type.to_string()),
+            Self::FamilyRelationship(e) => Some(e.family_type.to_string()),
+            Self::AllianceRelationship(e) => Some(e.alliance_type.to_string()),
+            Self::ConflictRelationship(e) => Some(e.conflict_type.to_string()),
+            Self::TransformationRelationship(e) => Some(e.transformation_type.to_string()),
+            Self::SyncretismRelationship(e) => Some(e.syncretism_type.to_string()),
+            _ => None,
+        }
+    }
+    
+    /// Check whether this entity is one of the relationship variants
+    pub fn is_relationship(&self) -> bool {
+        self.relationship().is_some()
+    }
+    
     /// Get the culture name as a string if applicable
     pub fn culture_name(&self) -> Option<&str> {
         self.culture().map(|c| c.value())
     }
 }
+
+fn list(field: TextField, values: &[String]) -> impl Iterator<Item = (TextField, &str)> {
+    values.iter().map(move |value| (field, value.as_str()))
+}
+
+fn sorted<'a>(values: impl Iterator<Item = &'a str>) -> Vec<&'a str> {
+    let mut values: Vec<&str> = values.collect();
+    values.sort_unstable();
+    values
+}
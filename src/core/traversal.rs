@@ -0,0 +1,617 @@
+//! # Graph Traversal
+//!
+//! Multi-hop queries over the relationship edges of a [`MythOntology`]: breadth- and
+//! depth-first traversal with depth limits, edge filters by type, direction, or an
+//! arbitrary predicate, and shortest paths by hop count or relationship strength.
+//! Every result records the [`Edge`]s that were followed, not just the entities
+//! that were reached.
+//!
+//! ```
+//! use mythologic::core::{TraversalOptions, TraversalDirection};
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::relationships::RelationshipType;
+//!
+//! let greek = create_greek_ontology();
+//! let zeus = greek.entities_named("Zeus").next().unwrap().id().clone();
+//!
+//! // Everything within two hops of Zeus along family edges, in either direction
+//! let options = TraversalOptions::new()
+//!     .max_depth(2)
+//!     .edge_types([RelationshipType::Family])
+//!     .direction(TraversalDirection::Both);
+//! let family = greek.traverse(&zeus, &options);
+//!
+//! for step in family.steps.iter().skip(1) {
+//!     let via = step.via.as_ref().unwrap();
+//!     assert!(step.depth <= 2);
+//!     assert_eq!(via.relationship_type, RelationshipType::Family);
+//! }
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt;
+use crate::core::{Edge, EdgeDirection, MythId, MythOntology};
+use crate::relationships::RelationshipType;
+
+/// Which edges a traversal may follow from each entity
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TraversalDirection {
+    /// Follow relationships from source to target (and bidirectional relationships either way)
+    #[default]
+    Outgoing,
+    /// Follow relationships from target to source (and bidirectional relationships either way)
+    Incoming,
+    /// Follow every relationship regardless of direction
+    Both,
+}
+
+/// Order in which a traversal visits entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TraversalOrder {
+    /// Visit all entities at one depth before the next
+    #[default]
+    BreadthFirst,
+    /// Follow each branch as deep as possible before backtracking
+    DepthFirst,
+}
+
+/// A condition an edge must satisfy to be followed
+pub type EdgeFilter<'a> = Box<dyn Fn(&Edge) -> bool + 'a>;
+
+/// Options controlling which edges a traversal follows and how far it goes
+#[derive(Default)]
+pub struct TraversalOptions<'a> {
+    /// Which edges may be followed
+    pub direction: TraversalDirection,
+    /// Order in which entities are visited
+    pub order: TraversalOrder,
+    /// Maximum number of hops from the start entity (`None` for unlimited)
+    pub max_depth: Option<usize>,
+    /// Relationship types that may be followed (`None` for all types)
+    pub edge_types: Option<HashSet<RelationshipType>>,
+    /// Additional condition an edge must satisfy to be followed
+    pub edge_filter: Option<EdgeFilter<'a>>,
+}
+
+impl<'a> TraversalOptions<'a> {
+    /// Create options that follow every outgoing edge breadth-first with no depth limit
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set which edges may be followed
+    pub fn direction(mut self, direction: TraversalDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+    
+    /// Set the order in which entities are visited
+    pub fn order(mut self, order: TraversalOrder) -> Self {
+        self.order = order;
+        self
+    }
+    
+    /// Limit the number of hops from the start entity
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+    
+    /// Only follow relationships of the given types
+    pub fn edge_types(mut self, edge_types: impl IntoIterator<Item = RelationshipType>) -> Self {
+        self.edge_types = Some(edge_types.into_iter().collect());
+        self
+    }
+    
+    /// Only follow edges satisfying a condition
+    pub fn edge_filter(mut self, filter: impl Fn(&Edge) -> bool + 'a) -> Self {
+        self.edge_filter = Some(Box::new(filter));
+        self
+    }
+    
+    /// Check whether an edge may be followed
+    pub fn allows(&self, edge: &Edge) -> bool {
+        let direction_ok = match self.direction {
+            TraversalDirection::Outgoing => edge.is_outgoing(),
+            TraversalDirection::Incoming => edge.is_incoming(),
+            TraversalDirection::Both => true,
+        };
+        
+        direction_ok
+            && self.edge_types.as_ref().is_none_or(|types| types.contains(&edge.relationship_type))
+            && self.edge_filter.as_ref().is_none_or(|filter| filter(edge))
+    }
+    
+    fn within_depth(&self, depth: usize) -> bool {
+        self.max_depth.is_none_or(|max| depth <= max)
+    }
+}
+
+impl fmt::Debug for TraversalOptions<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TraversalOptions")
+            .field("direction", &self.direction)
+            .field("order", &self.order)
+            .field("max_depth", &self.max_depth)
+            .field("edge_types", &self.edge_types)
+            .field("edge_filter", &self.edge_filter.as_ref().map(|_| ".."))
+            .finish()
+    }
+}
+
+/// An entity reached during a traversal
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraversalStep {
+    /// The entity reached
+    pub entity_id: MythId,
+    /// Number of hops from the start entity
+    pub depth: usize,
+    /// The edge followed to reach this entity (`None` for the start entity)
+    pub via: Option<Edge>,
+}
+
+/// The result of a traversal: every entity reached, in visiting order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Traversal {
+    /// The entity the traversal started from
+    pub start: MythId,
+    /// The entities reached, starting with the start entity
+    pub steps: Vec<TraversalStep>,
+}
+
+impl Traversal {
+    /// Get the IDs of the entities reached, in visiting order
+    pub fn entity_ids(&self) -> Vec<MythId> {
+        self.steps.iter().map(|step| step.entity_id.clone()).collect()
+    }
+    
+    /// Get the edges followed during the traversal
+    pub fn edges(&self) -> Vec<&Edge> {
+        self.steps.iter().filter_map(|step| step.via.as_ref()).collect()
+    }
+    
+    /// Check whether an entity was reached
+    pub fn contains(&self, id: &MythId) -> bool {
+        self.steps.iter().any(|step| &step.entity_id == id)
+    }
+    
+    /// Get the step at which an entity was reached
+    pub fn step(&self, id: &MythId) -> Option<&TraversalStep> {
+        self.steps.iter().find(|step| &step.entity_id == id)
+    }
+    
+    /// Get the path the traversal took from the start entity to an entity
+    ///
+    /// For a breadth-first traversal this is a path with the fewest hops.
+    pub fn path_to(&self, id: &MythId) -> Option<Path> {
+        let by_id: HashMap<&MythId, &TraversalStep> = self.steps.iter()
+            .map(|step| (&step.entity_id, step))
+            .collect();
+        
+        let mut edges = Vec::new();
+        let mut current = *by_id.get(id)?;
+        while let Some(edge) = &current.via {
+            edges.push(edge.clone());
+            current = by_id.get(edge.origin())?;
+        }
+        edges.reverse();
+        
+        Some(Path { start: self.start.clone(), edges })
+    }
+}
+
+/// A sequence of edges leading from one entity to another
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    /// The entity the path starts from
+    pub start: MythId,
+    /// The edges followed, in order; each edge's direction is relative to the entity before it
+    pub edges: Vec<Edge>,
+}
+
+impl Path {
+    /// Get the number of hops in the path
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+    
+    /// Check whether the path has no hops (starts and ends at the same entity)
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+    
+    /// Get the entity the path ends at
+    pub fn end(&self) -> &MythId {
+        self.edges.last().map(Edge::neighbor).unwrap_or(&self.start)
+    }
+    
+    /// Get the IDs of every entity on the path, including both ends
+    pub fn entity_ids(&self) -> Vec<MythId> {
+        std::iter::once(&self.start)
+            .chain(self.edges.iter().map(Edge::neighbor))
+            .cloned()
+            .collect()
+    }
+    
+    /// Get the total cost of the path as used by [`MythOntology::strongest_path`]
+    pub fn cost(&self) -> f32 {
+        self.edges.iter().map(edge_cost).sum()
+    }
+    
+    /// Describe the path using entity names, e.g. `Heracles -[Family]-> Zeus <-[Family]- Hades`
+    pub fn describe(&self, ontology: &MythOntology) -> String {
+        let name = |id: &MythId| ontology.get_entity(id)
+            .map(|entity| entity.name().to_string())
+            .unwrap_or_else(|| id.to_string());
+        
+        let mut description = name(&self.start);
+        for edge in &self.edges {
+            let (left, right) = match (edge.bidirectional, edge.direction) {
+                (true, _) => ("<-", "->"),
+                (false, EdgeDirection::Outgoing) => ("-", "->"),
+                (false, EdgeDirection::Incoming) => ("<-", "-"),
+            };
+            description.push_str(&format!(" {}[{}]{} {}", left, edge.relationship_type, right, name(edge.neighbor())));
+        }
+        description
+    }
+}
+
+/// Cost of following an edge when searching for the strongest path
+///
+/// Every hop costs at least 1, so shorter paths are still preferred, and weaker
+/// relationships cost up to 2.
+fn edge_cost(edge: &Edge) -> f32 {
+    2.0 - edge.strength.clamp(0.0, 1.0)
+}
+
+/// Frontier entry for the weighted path search, ordered by lowest cost first
+struct Candidate {
+    cost: f32,
+    id: MythId,
+    depth: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl MythOntology {
+    /// Visit every entity reachable from `start` under the given options
+    ///
+    /// Each entity is visited at most once. The result lists the start entity first,
+    /// followed by the entities reached in visiting order, each with the edge that
+    /// was followed to reach it. If `start` is not in the ontology, the result is empty.
+    pub fn traverse(&self, start: &MythId, options: &TraversalOptions<'_>) -> Traversal {
+        let mut traversal = Traversal { start: start.clone(), steps: Vec::new() };
+        if !self.contains(start) {
+            return traversal;
+        }
+        
+        let mut visited: HashSet<MythId> = HashSet::new();
+        let mut frontier: VecDeque<TraversalStep> = VecDeque::new();
+        frontier.push_back(TraversalStep { entity_id: start.clone(), depth: 0, via: None });
+        
+        let next = |frontier: &mut VecDeque<TraversalStep>| match options.order {
+            TraversalOrder::BreadthFirst => frontier.pop_front(),
+            TraversalOrder::DepthFirst => frontier.pop_back(),
+        };
+        
+        while let Some(step) = next(&mut frontier) {
+            if !visited.insert(step.entity_id.clone()) {
+                continue;
+            }
+            
+            if options.within_depth(step.depth + 1) {
+                let mut edges: Vec<Edge> = self.edges(&step.entity_id).into_iter()
+                    .filter(|edge| options.allows(edge) && !visited.contains(edge.neighbor()))
+                    .filter(|edge| self.contains(edge.neighbor()))
+                    .collect();
+                
+                // Push in reverse so a depth-first traversal explores edges in listed order
+                if options.order == TraversalOrder::DepthFirst {
+                    edges.reverse();
+                }
+                
+                for edge in edges {
+                    frontier.push_back(TraversalStep {
+                        entity_id: edge.neighbor().clone(),
+                        depth: step.depth + 1,
+                        via: Some(edge),
+                    });
+                }
+            }
+            
+            traversal.steps.push(step);
+        }
+        
+        traversal
+    }
+    
+    /// Get the IDs of every entity reachable from `start` under the given options, excluding `start`
+    pub fn reachable(&self, start: &MythId, options: &TraversalOptions<'_>) -> HashSet<MythId> {
+        self.traverse(start, options).steps.into_iter()
+            .skip(1)
+            .map(|step| step.entity_id)
+            .collect()
+    }
+    
+    /// Find a path with the fewest hops between two entities
+    ///
+    /// The traversal order in `options` is ignored; the depth limit bounds the path length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::core::{TraversalOptions, TraversalDirection};
+    /// use mythologic::examples::create_greek_ontology;
+    ///
+    /// let greek = create_greek_ontology();
+    /// let zeus = greek.entities_named("Zeus").next().unwrap().id().clone();
+    /// let hera = greek.entities_named("Hera").next().unwrap().id().clone();
+    ///
+    /// let options = TraversalOptions::new().direction(TraversalDirection::Both);
+    /// let path = greek.shortest_path(&zeus, &hera, &options).unwrap();
+    /// assert_eq!(path.len(), 1);
+    /// assert_eq!(path.end(), &hera);
+    /// ```
+    pub fn shortest_path(&self, from: &MythId, to: &MythId, options: &TraversalOptions<'_>) -> Option<Path> {
+        if !self.contains(from) || !self.contains(to) {
+            return None;
+        }
+        
+        let mut reached: HashMap<MythId, Option<Edge>> = HashMap::new();
+        reached.insert(from.clone(), None);
+        let mut queue = VecDeque::from([(from.clone(), 0)]);
+        
+        while let Some((id, depth)) = queue.pop_front() {
+            if &id == to {
+                break;
+            }
+            if !options.within_depth(depth + 1) {
+                continue;
+            }
+            
+            for edge in self.edges(&id) {
+                if options.allows(&edge) && !reached.contains_key(edge.neighbor()) && self.contains(edge.neighbor()) {
+                    queue.push_back((edge.neighbor().clone(), depth + 1));
+                    reached.insert(edge.neighbor().clone(), Some(edge));
+                }
+            }
+        }
+        
+        rebuild_path(from, to, &reached)
+    }
+    
+    /// Find the path between two entities that follows the strongest relationships
+    ///
+    /// Each edge costs `2.0 - strength`, so a path never has more hops than necessary
+    /// to beat a shorter one by a full hop, and among paths with the same number of hops
+    /// the one with the strongest relationships wins. The traversal order in `options`
+    /// is ignored; the depth limit bounds the number of hops.
+    pub fn strongest_path(&self, from: &MythId, to: &MythId, options: &TraversalOptions<'_>) -> Option<Path> {
+        if !self.contains(from) || !self.contains(to) {
+            return None;
+        }
+        
+        // With a depth limit, an entity reached cheaply over many hops may not be
+        // able to go on where a costlier route with fewer hops could, so each
+        // entity is searched once per depth it is reached at
+        let state_depth = |depth: usize| if options.max_depth.is_some() { depth } else { 0 };
+        let mut best: HashMap<(MythId, usize), (f32, Option<Edge>)> = HashMap::new();
+        let mut settled: HashSet<(MythId, usize)> = HashSet::new();
+        let mut heap = BinaryHeap::new();
+        best.insert((from.clone(), 0), (0.0, None));
+        heap.push(Candidate { cost: 0.0, id: from.clone(), depth: 0 });
+        
+        let mut found = None;
+        while let Some(Candidate { cost, id, depth }) = heap.pop() {
+            let state = (id, state_depth(depth));
+            if !settled.insert(state.clone()) {
+                continue;
+            }
+            if &state.0 == to {
+                found = Some(state);
+                break;
+            }
+            if !options.within_depth(depth + 1) {
+                continue;
+            }
+            
+            for edge in self.edges(&state.0) {
+                let next = (edge.neighbor().clone(), state_depth(depth + 1));
+                if !options.allows(&edge) || settled.contains(&next) || !self.contains(&next.0) {
+                    continue;
+                }
+                
+                let next_cost = cost + edge_cost(&edge);
+                if best.get(&next).is_none_or(|(known, _)| next_cost < *known) {
+                    heap.push(Candidate { cost: next_cost, id: next.0.clone(), depth: depth + 1 });
+                    best.insert(next, (next_cost, Some(edge)));
+                }
+            }
+        }
+        
+        let mut state = found?;
+        let mut edges = Vec::new();
+        while let Some((_, Some(edge))) = best.get(&state) {
+            edges.push(edge.clone());
+            state = (edge.origin().clone(), state_depth(state.1.saturating_sub(1)));
+        }
+        edges.reverse();
+        Some(Path { start: from.clone(), edges })
+    }
+}
+
+/// Walk back from `to` along the recorded edges to rebuild the path from `from`
+fn rebuild_path(from: &MythId, to: &MythId, reached: &HashMap<MythId, Option<Edge>>) -> Option<Path> {
+    let mut edges = Vec::new();
+    let mut current = to;
+    while current != from {
+        let edge = reached.get(current)?.as_ref()?;
+        edges.push(edge.clone());
+        current = edge.origin();
+    }
+    edges.reverse();
+    Some(Path { start: from.clone(), edges })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::MythEntity;
+    use crate::entities::Deity;
+    use crate::relationships::{AllianceRelationship, AllianceType, FamilyRelationship, FamilyRelationshipType};
+    
+    /// Kronos -> Zeus -> Athena (parent edges), Zeus <-> Poseidon (alliance), Athena -> Erichthonius (parent)
+    fn family() -> (MythOntology, Vec<MythId>) {
+        let mut ontology = MythOntology::new();
+        let ids: Vec<MythId> = ["Kronos", "Zeus", "Athena", "Poseidon", "Erichthonius"].iter()
+            .map(|name| {
+                let deity = Deity::new(name, "", "Greek");
+                let id = deity.id.clone();
+                ontology.add_entity(MythEntity::Deity(deity));
+                id
+            })
+            .collect();
+        
+        for (parent, child, strength) in [(0, 1, 0.9), (1, 2, 0.8), (2, 4, 0.2)] {
+            let mut relationship = FamilyRelationship::new(
+                "parent", "", ids[parent].clone(), ids[child].clone(), FamilyRelationshipType::Parent,
+            );
+            relationship.relationship.set_strength(strength);
+            ontology.add_entity(MythEntity::FamilyRelationship(relationship));
+        }
+        ontology.add_entity(MythEntity::AllianceRelationship(AllianceRelationship::new(
+            "brothers in arms", "", ids[1].clone(), ids[3].clone(), AllianceType::Military, "Titanomachy",
+        )));
+        
+        (ontology, ids)
+    }
+    
+    #[test]
+    fn breadth_first_respects_depth_direction_and_type() {
+        let (ontology, ids) = family();
+        
+        let descendants = ontology.reachable(&ids[0], &TraversalOptions::new().edge_types([RelationshipType::Family]));
+        assert_eq!(descendants, HashSet::from([ids[1].clone(), ids[2].clone(), ids[4].clone()]));
+        
+        let near = ontology.traverse(&ids[0], &TraversalOptions::new().max_depth(1));
+        assert_eq!(near.entity_ids(), vec![ids[0].clone(), ids[1].clone()]);
+        
+        let incoming = TraversalOptions::new().direction(TraversalDirection::Incoming);
+        let ancestors = ontology.reachable(&ids[4], &incoming.edge_types([RelationshipType::Family]));
+        assert_eq!(ancestors, HashSet::from([ids[0].clone(), ids[1].clone(), ids[2].clone()]));
+        
+        // The bidirectional alliance can be followed from either end
+        assert!(ontology.reachable(&ids[3], &TraversalOptions::new()).contains(&ids[2]));
+        
+        let traversal = ontology.traverse(&ids[0], &TraversalOptions::new());
+        let step = traversal.step(&ids[2]).unwrap();
+        assert_eq!(step.depth, 2);
+        assert_eq!(step.via.as_ref().unwrap().source_id, ids[1]);
+        assert_eq!(traversal.edges().len(), traversal.steps.len() - 1);
+    }
+    
+    #[test]
+    fn depth_first_follows_branches() {
+        let (ontology, ids) = family();
+        let options = TraversalOptions::new()
+            .order(TraversalOrder::DepthFirst)
+            .direction(TraversalDirection::Both)
+            .edge_filter(|edge| edge.relationship_type == RelationshipType::Family);
+        
+        let traversal = ontology.traverse(&ids[0], &options);
+        assert_eq!(traversal.entity_ids(), vec![ids[0].clone(), ids[1].clone(), ids[2].clone(), ids[4].clone()]);
+        assert_eq!(traversal.step(&ids[4]).unwrap().depth, 3);
+    }
+    
+    #[test]
+    fn paths_record_edges() {
+        let (ontology, ids) = family();
+        let both = TraversalOptions::new().direction(TraversalDirection::Both);
+        
+        let path = ontology.shortest_path(&ids[4], &ids[3], &both).unwrap();
+        assert_eq!(path.entity_ids(), vec![ids[4].clone(), ids[2].clone(), ids[1].clone(), ids[3].clone()]);
+        assert_eq!(path.edges[0].direction, EdgeDirection::Incoming);
+        assert_eq!(path.describe(&ontology), "Erichthonius <-[Family]- Athena <-[Family]- Zeus <-[Alliance]-> Poseidon");
+        
+        assert!(ontology.shortest_path(&ids[4], &ids[3], &TraversalOptions::new()).is_none());
+        assert!(ontology.shortest_path(&ids[0], &ids[4], &TraversalOptions::new().max_depth(2)).is_none());
+        assert_eq!(ontology.shortest_path(&ids[0], &ids[0], &both).unwrap().len(), 0);
+        
+        let traversal = ontology.traverse(&ids[0], &TraversalOptions::new());
+        assert_eq!(traversal.path_to(&ids[4]).unwrap().entity_ids(), vec![ids[0].clone(), ids[1].clone(), ids[2].clone(), ids[4].clone()]);
+    }
+    
+    #[test]
+    fn strongest_path_prefers_strong_relationships() {
+        let (mut ontology, ids) = family();
+        
+        // A second, weaker two-hop route from Kronos to Athena through Poseidon
+        for (source, target) in [(0, 3), (3, 2)] {
+            let mut relationship = FamilyRelationship::new(
+                "", "", ids[source].clone(), ids[target].clone(), FamilyRelationshipType::Ancestor,
+            );
+            relationship.relationship.set_strength(0.1);
+            ontology.add_entity(MythEntity::FamilyRelationship(relationship));
+        }
+        
+        let options = TraversalOptions::new();
+        assert_eq!(ontology.shortest_path(&ids[0], &ids[2], &options).unwrap().len(), 2);
+        
+        let path = ontology.strongest_path(&ids[0], &ids[2], &options).unwrap();
+        assert_eq!(path.entity_ids(), vec![ids[0].clone(), ids[1].clone(), ids[2].clone()]);
+        assert!((path.cost() - 2.3).abs() < 1e-6);
+        
+        let weak_only = TraversalOptions::new().edge_filter(|edge| edge.strength < 0.5);
+        let path = ontology.strongest_path(&ids[0], &ids[2], &weak_only).unwrap();
+        assert_eq!(path.entity_ids(), vec![ids[0].clone(), ids[3].clone(), ids[2].clone()]);
+        assert!(ontology.strongest_path(&ids[0], &ids[2], &TraversalOptions::new().max_depth(1)).is_none());
+    }
+    
+    #[test]
+    fn strongest_path_keeps_short_routes_within_the_depth_limit() {
+        // A cheap three-hop route and a costly two-hop route to the same entity,
+        // which is one hop from the target
+        let mut ontology = MythOntology::new();
+        let ids: Vec<MythId> = ["Start", "Cheap 1", "Cheap 2", "Costly", "Meeting", "Target"].iter()
+            .map(|name| {
+                let deity = Deity::new(name, "", "Greek");
+                let id = deity.id.clone();
+                ontology.add_entity(MythEntity::Deity(deity));
+                id
+            })
+            .collect();
+        for (source, target, strength) in [(0, 1, 1.0), (1, 2, 1.0), (2, 4, 1.0), (0, 3, 0.0), (3, 4, 0.0), (4, 5, 1.0)] {
+            let mut relationship = FamilyRelationship::new(
+                "", "", ids[source].clone(), ids[target].clone(), FamilyRelationshipType::Parent,
+            );
+            relationship.relationship.set_strength(strength);
+            ontology.add_entity(MythEntity::FamilyRelationship(relationship));
+        }
+        
+        let path = ontology.strongest_path(&ids[0], &ids[5], &TraversalOptions::new()).unwrap();
+        assert_eq!(path.len(), 4);
+        let path = ontology.strongest_path(&ids[0], &ids[5], &TraversalOptions::new().max_depth(3)).unwrap();
+        assert_eq!(path.entity_ids(), vec![ids[0].clone(), ids[3].clone(), ids[4].clone(), ids[5].clone()]);
+        assert!(ontology.strongest_path(&ids[0], &ids[5], &TraversalOptions::new().max_depth(2)).is_none());
+    }
+}
@@ -0,0 +1,565 @@
+//! # Referential Integrity Validation
+//!
+//! This module checks that the references between entities in a [`MythOntology`]
+//! are consistent. [`MythOntology::validate`] returns a [`ValidationReport`] listing
+//! every problem found, each with a [`Severity`] so callers can reject ontologies
+//! with errors while only logging warnings.
+//!
+//! The following checks are performed:
+//!
+//! - Relationship `source_id`/`target_id`, pantheon deity sets, conflict victors,
+//!   and per-entity `relationships` lists must point at entities in the ontology
+//! - Narrative episodes must refer to entities in the ontology, with locations,
+//!   artifacts, and established relationships of the matching types
+//! - Events must refer to entities in the ontology, with a `Location`, a
+//!   `MythologicalEra`, and other `Event`s as causes, consequences, and
+//!   before/after constraints; an era's `ending` must be an `Event`
+//! - Relationships should not connect an entity to itself
+//! - The same relationship should not be recorded twice, including as its mirror
+//!   image, such as Parent A→B and Child B→A
+//! - A deity's `pantheon` should name a `Pantheon` entity in the ontology
+//! - An entity's `CultureId` should name a `Culture` entity in the ontology
+//! - A syncretism relationship should connect entities of different cultures
+//! - Citations in metadata, motif tags, and claims must cite sources in the
+//!   ontology's bibliography
+
+use std::collections::HashMap;
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use crate::core::{MythId, MythEntity, MythOntology, CultureId, PantheonId, SourceId};
+use crate::relationships::Invertible;
+
+/// How serious a validation issue is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Severity {
+    /// Suspicious but usable data
+    Warning,
+    /// Broken data that should be rejected
+    Error,
+}
+
+/// The kind of problem found by validation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IssueKind {
+    /// A field refers to an entity that is not in the ontology
+    DanglingReference {
+        /// Name of the field holding the reference
+        field: String,
+        /// The missing entity ID
+        target_id: MythId,
+    },
+    /// A field refers to an entity of the wrong type
+    UnexpectedEntityType {
+        /// Name of the field holding the reference
+        field: String,
+        /// The referenced entity ID
+        target_id: MythId,
+        /// The entity type the field should refer to
+        expected: String,
+        /// The entity type actually found
+        found: String,
+    },
+    /// A relationship whose source and target are the same entity
+    SelfLoop,
+    /// A relationship identical to another one already in the ontology
+    DuplicateRelationship {
+        /// The relationship this one duplicates
+        duplicate_of: MythId,
+    },
+    /// A deity names a pantheon that is not in the ontology
+    UnknownPantheon(PantheonId),
+    /// An entity names a culture that is not in the ontology
+    UnknownCulture(CultureId),
+    /// A syncretism relationship identifies two entities of the same culture
+    SyncretismWithinCulture(CultureId),
+    /// A citation refers to a source that is not in the bibliography
+    UnknownSource {
+        /// Name of the metadata field holding the citation
+        field: String,
+        /// The missing source ID
+        source: SourceId,
+    },
+}
+
+impl IssueKind {
+    /// The default severity for this kind of issue
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::DanglingReference { .. } | Self::UnknownSource { .. } => Severity::Error,
+            Self::UnexpectedEntityType { .. }
+            | Self::SelfLoop
+            | Self::DuplicateRelationship { .. }
+            | Self::UnknownPantheon(_)
+            | Self::UnknownCulture(_)
+            | Self::SyncretismWithinCulture(_) => Severity::Warning,
+        }
+    }
+}
+
+impl fmt::Display for IssueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DanglingReference { field, target_id } => {
+                write!(f, "`{}` refers to missing entity {}", field, target_id)
+            },
+            Self::UnexpectedEntityType { field, target_id, expected, found } => {
+                write!(f, "`{}` refers to {} which is a {}, expected a {}", field, target_id, found, expected)
+            },
+            Self::SelfLoop => write!(f, "relationship connects an entity to itself"),
+            Self::DuplicateRelationship { duplicate_of } => {
+                write!(f, "relationship duplicates {}", duplicate_of)
+            },
+            Self::UnknownPantheon(pantheon) => write!(f, "pantheon \"{}\" is not in the ontology", pantheon),
+            Self::UnknownCulture(culture) => write!(f, "culture \"{}\" is not in the ontology", culture),
+            Self::SyncretismWithinCulture(culture) => {
+                write!(f, "syncretism identifies two entities of the same culture \"{}\"", culture)
+            },
+            Self::UnknownSource { field, source } => {
+                write!(f, "`{}` cites source {} which is not in the bibliography", field, source)
+            },
+        }
+    }
+}
+
+/// A single problem found by validation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationIssue {
+    /// How serious the problem is
+    pub severity: Severity,
+    /// The entity on which the problem was found
+    pub entity_id: MythId,
+    /// Name of that entity, for reporting
+    pub entity_name: String,
+    /// What the problem is
+    pub kind: IssueKind,
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let level = match self.severity {
+            Severity::Warning => "warning",
+            Severity::Error => "error",
+        };
+        write!(f, "{}: {} ({}): {}", level, self.entity_name, self.entity_id, self.kind)
+    }
+}
+
+/// The result of validating an ontology
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidationReport {
+    /// All issues found, errors first
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// Check if no errors were found (warnings are allowed)
+    pub fn is_valid(&self) -> bool {
+        !self.has_errors()
+    }
+    
+    /// Check if any errors were found
+    pub fn has_errors(&self) -> bool {
+        self.issues.iter().any(|i| i.severity == Severity::Error)
+    }
+    
+    /// Get all issues with error severity
+    pub fn errors(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.with_severity(Severity::Error)
+    }
+    
+    /// Get all issues with warning severity
+    pub fn warnings(&self) -> impl Iterator<Item = &ValidationIssue> {
+        self.with_severity(Severity::Warning)
+    }
+    
+    /// Get all issues with the given severity
+    pub fn with_severity(&self, severity: Severity) -> impl Iterator<Item = &ValidationIssue> {
+        self.issues.iter().filter(move |i| i.severity == severity)
+    }
+    
+    /// Get all issues found on a particular entity
+    pub fn for_entity<'a>(&'a self, id: &'a MythId) -> impl Iterator<Item = &'a ValidationIssue> {
+        self.issues.iter().filter(move |i| &i.entity_id == id)
+    }
+}
+
+impl MythOntology {
+    /// Check the referential integrity of this ontology
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::core::{MythOntology, MythEntity, MythId, IssueKind};
+    /// use mythologic::cultural::Pantheon;
+    ///
+    /// let mut ontology = MythOntology::new();
+    /// let mut olympians = Pantheon::new("Olympian", "The twelve Olympians", "Greek");
+    /// olympians.add_primary_deity(MythId::new());
+    /// ontology.add_entity(MythEntity::Pantheon(olympians));
+    ///
+    /// let report = ontology.validate();
+    /// assert!(report.has_errors());
+    /// assert!(report.errors().any(|i| matches!(i.kind, IssueKind::DanglingReference { .. })));
+    /// ```
+    pub fn validate(&self) -> ValidationReport {
+        let mut issues = Vec::new();
+        let mut seen_relationships: HashMap<String, MythId> = HashMap::new();
+        
+        let pantheons: Vec<_> = self.entities_of_type("Pantheon")
+            .filter_map(|e| match e {
+                MythEntity::Pantheon(p) => Some(p),
+                _ => None,
+            })
+            .collect();
+        let cultures: Vec<_> = self.entities_of_type("Culture")
+            .filter_map(|e| match e {
+                MythEntity::Culture(c) => Some(c),
+                _ => None,
+            })
+            .collect();
+        
+        let mut entities: Vec<&MythEntity> = self.entities().collect();
+        entities.sort_by_key(|e| e.id().to_string());
+        
+        for entity in entities {
+            let mut report = |kind: IssueKind| {
+                issues.push(ValidationIssue {
+                    severity: kind.severity(),
+                    entity_id: entity.id().clone(),
+                    entity_name: entity.name().to_string(),
+                    kind,
+                });
+            };
+            
+            for related_id in entity.relationships() {
+                self.check_reference(&mut report, "relationships", &related_id, None);
+            }
+            
+            if let Some(relationship) = entity.relationship() {
+                self.check_reference(&mut report, "source_id", &relationship.source_id, None);
+                self.check_reference(&mut report, "target_id", &relationship.target_id, None);
+                
+                if relationship.source_id == relationship.target_id {
+                    report(IssueKind::SelfLoop);
+                }
+                
+                let key = relationship_key(entity);
+                match seen_relationships.get(&key) {
+                    Some(original) => report(IssueKind::DuplicateRelationship { duplicate_of: original.clone() }),
+                    None => {
+                        seen_relationships.insert(key, entity.id().clone());
+                    },
+                }
+            }
+            
+            match entity {
+                MythEntity::Pantheon(pantheon) => {
+                    let mut members: Vec<_> = pantheon.primary_deities.iter().map(|id| ("primary_deities", id))
+                        .chain(pantheon.secondary_deities.iter().map(|id| ("secondary_deities", id)))
+                        .collect();
+                    members.sort_by_key(|(_, id)| id.to_string());
+                    for (field, deity_id) in members {
+                        self.check_reference(&mut report, field, deity_id, Some("Deity"));
+                    }
+                },
+                MythEntity::Narrative(narrative) => {
+                    for episode in &narrative.episodes {
+                        for participant in &episode.participants {
+                            self.check_reference(&mut report, "episodes.participants", &participant.id, None);
+                        }
+                        for location_id in &episode.locations {
+                            self.check_reference(&mut report, "episodes.locations", location_id, Some("Location"));
+                        }
+                        for artifact_id in &episode.artifacts {
+                            self.check_reference(&mut report, "episodes.artifacts", artifact_id, Some("Artifact"));
+                        }
+                        for relationship_id in &episode.establishes {
+                            self.check_reference(&mut report, "episodes.establishes", relationship_id, None);
+                            if let Some(target) = self.get_entity(relationship_id)
+                                && !target.is_relationship()
+                            {
+                                report(IssueKind::UnexpectedEntityType {
+                                    field: "episodes.establishes".to_string(),
+                                    target_id: relationship_id.clone(),
+                                    expected: "Relationship".to_string(),
+                                    found: target.entity_type().to_string(),
+                                });
+                            }
+                        }
+                    }
+                },
+                MythEntity::Event(event) => {
+                    for participant_id in &event.participants {
+                        self.check_reference(&mut report, "participants", participant_id, None);
+                    }
+                    if let Some(location_id) = &event.location {
+                        self.check_reference(&mut report, "location", location_id, Some("Location"));
+                    }
+                    if let Some(era_id) = &event.era {
+                        self.check_reference(&mut report, "era", era_id, Some("MythologicalEra"));
+                    }
+                    let linked = [("caused_by", &event.caused_by), ("leads_to", &event.leads_to), ("after", &event.after), ("before", &event.before)];
+                    for (field, event_ids) in linked {
+                        for event_id in event_ids {
+                            self.check_reference(&mut report, field, event_id, Some("Event"));
+                        }
+                    }
+                },
+                MythEntity::MythologicalEra(era) => {
+                    if let Some(event_id) = &era.ending {
+                        self.check_reference(&mut report, "ending", event_id, Some("Event"));
+                    }
+                },
+                MythEntity::SyncretismRelationship(syncretism) => {
+                    let culture = |id: &MythId| self.get_entity(id).and_then(|e| e.culture());
+                    if let (Some(source), Some(target)) = (culture(&syncretism.relationship.source_id), culture(&syncretism.relationship.target_id))
+                        && source == target
+                    {
+                        report(IssueKind::SyncretismWithinCulture(source.clone()));
+                    }
+                },
+                MythEntity::ConflictRelationship(conflict) => {
+                    if let Some(victor_id) = conflict.outcome.as_ref().and_then(|o| o.victor_id.as_ref()) {
+                        self.check_reference(&mut report, "outcome.victor_id", victor_id, None);
+                    }
+                },
+                MythEntity::Deity(deity) => {
+                    if let Some(pantheon) = &deity.pantheon
+                        && !pantheons.iter().any(|p| p.is_identified_by(pantheon))
+                    {
+                        report(IssueKind::UnknownPantheon(pantheon.clone()));
+                    }
+                },
+                _ => {},
+            }
+            
+            let metadata = entity.metadata();
+            let citations = metadata.citations.iter().map(|citation| ("citations", citation))
+                .chain(metadata.motifs.iter().filter_map(|tag| tag.citation.as_ref()).map(|citation| ("motifs", citation)))
+                .chain(metadata.claims.iter().map(|claim| ("claims", &claim.claim.citation)))
+                .chain(metadata.variant.iter().map(|variant| ("variant", &variant.claim.citation)));
+            for (field, citation) in citations {
+                if self.source(&citation.source).is_none() {
+                    report(IssueKind::UnknownSource { field: field.to_string(), source: citation.source.clone() });
+                }
+            }
+            
+            if let Some(culture) = entity.culture()
+                && !cultures.iter().any(|c| c.is_identified_by(culture))
+            {
+                report(IssueKind::UnknownCulture(culture.clone()));
+            }
+        }
+        
+        issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+        ValidationReport { issues }
+    }
+    
+    fn check_reference(
+        &self,
+        report: &mut impl FnMut(IssueKind),
+        field: &str,
+        target_id: &MythId,
+        expected_type: Option<&str>,
+    ) {
+        match self.get_entity(target_id) {
+            None => report(IssueKind::DanglingReference {
+                field: field.to_string(),
+                target_id: target_id.clone(),
+            }),
+            Some(target) => {
+                if let Some(expected) = expected_type
+                    && target.entity_type() != expected
+                {
+                    report(IssueKind::UnexpectedEntityType {
+                        field: field.to_string(),
+                        target_id: target_id.clone(),
+                        expected: expected.to_string(),
+                        found: target.entity_type().to_string(),
+                    });
+                }
+            },
+        }
+    }
+}
+
+/// A key identifying what a relationship states, ignoring its ID and description
+///
+/// A relationship and its mirror image, such as Parent A→B and Child B→A, state
+/// the same thing and share a key.
+fn relationship_key(entity: &MythEntity) -> String {
+    let relationship = entity.relationship().expect("only called for relationships");
+    let (mut first, mut second) = (relationship.source_id.to_string(), relationship.target_id.to_string());
+    if relationship.bidirectional && second < first {
+        std::mem::swap(&mut first, &mut second);
+    }
+    
+    let subtypes = match entity {
+        MythEntity::FamilyRelationship(r) => Some(mirrored(&r.family_type)),
+        MythEntity::AllianceRelationship(r) => Some(mirrored(&r.alliance_type)),
+        MythEntity::ConflictRelationship(r) => Some(mirrored(&r.conflict_type)),
+        MythEntity::TransformationRelationship(r) => Some(mirrored(&r.transformation_type)),
+        MythEntity::SyncretismRelationship(r) => Some(mirrored(&r.syncretism_type)),
+        _ => None,
+    };
+    let (subtype, first, second) = match subtypes {
+        Some((subtype, inverse)) => (subtype, first.clone(), second.clone()).min((inverse, second, first)),
+        None => (String::new(), first, second),
+    };
+    
+    format!(
+        "{}|{:?}|{}|{}|{}|{}",
+        entity.entity_type(), relationship.relationship_type, subtype, first, second, relationship.bidirectional
+    )
+}
+
+/// A relationship subtype and its inverse, as written in a relationship key
+fn mirrored<T: Invertible + fmt::Debug>(subtype: &T) -> (String, String) {
+    (format!("{:?}", subtype), format!("{:?}", subtype.inverse()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Citation, Claim, Source, SourceType};
+    use crate::entities::{Deity, Episode, Narrative, NarrativeRole};
+    use crate::motifs::MotifTag;
+    use crate::cultural::{Culture, Pantheon};
+    use crate::relationships::{FamilyRelationship, FamilyRelationshipType, SyncretismRelationship, SyncretismType};
+    
+    fn kinds(report: &ValidationReport) -> Vec<&IssueKind> {
+        report.issues.iter().map(|i| &i.kind).collect()
+    }
+    
+    #[test]
+    fn clean_ontology_has_no_issues() {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.set_pantheon("Olympian");
+        let mut pantheon = Pantheon::new("Olympian Pantheon", "The Olympians", "Greek");
+        pantheon.add_primary_deity(zeus.id.clone());
+        
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Pantheon(pantheon));
+        ontology.add_entity(MythEntity::Culture(Culture::new("Ancient Greek", "Ancient Greece")));
+        
+        let report = ontology.validate();
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+    
+    #[test]
+    fn reports_structural_problems() {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.set_pantheon("Titans");
+        let zeus_id = zeus.id.clone();
+        let hera = Deity::new("Hera", "Queen of the gods", "Greek");
+        let hera_id = hera.id.clone();
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Deity(hera));
+        
+        let marriage = |source: &MythId, target: &MythId| MythEntity::FamilyRelationship(FamilyRelationship::new(
+            "Marriage", "", source.clone(), target.clone(), FamilyRelationshipType::Spouse,
+        ));
+        ontology.add_entity(marriage(&zeus_id, &hera_id));
+        ontology.add_entity(marriage(&hera_id, &zeus_id));
+        ontology.add_entity(marriage(&zeus_id, &zeus_id));
+        let missing = MythId::new();
+        ontology.add_entity(marriage(&zeus_id, &missing));
+        ontology.add_entity(MythEntity::SyncretismRelationship(SyncretismRelationship::new(
+            "Zeus as Hera", "", zeus_id.clone(), hera_id.clone(), SyncretismType::Equivalence,
+        )));
+        
+        let report = ontology.validate();
+        let kinds = kinds(&report);
+        assert!(report.has_errors());
+        assert_eq!(report.errors().count(), 1);
+        assert!(kinds.contains(&&IssueKind::DanglingReference { field: "target_id".to_string(), target_id: missing }));
+        assert!(kinds.contains(&&IssueKind::SelfLoop));
+        assert!(kinds.iter().any(|k| matches!(k, IssueKind::DuplicateRelationship { .. })));
+        assert!(kinds.contains(&&IssueKind::UnknownPantheon("Titans".into())));
+        assert!(kinds.contains(&&IssueKind::UnknownCulture("Greek".into())));
+        assert!(kinds.contains(&&IssueKind::SyncretismWithinCulture("Greek".into())));
+        assert_eq!(report.issues[0].severity, Severity::Error);
+    }
+    
+    #[test]
+    fn detects_mirrored_relationships() {
+        let mut ontology = MythOntology::new();
+        let zeus = MythId::new();
+        let ares = MythId::new();
+        let family = |name: &str, source: &MythId, target: &MythId, family_type| {
+            MythEntity::FamilyRelationship(FamilyRelationship::new(name, "", source.clone(), target.clone(), family_type))
+        };
+        ontology.add_entity(family("Zeus parent of Ares", &zeus, &ares, FamilyRelationshipType::Parent));
+        ontology.add_entity(family("Ares child of Zeus", &ares, &zeus, FamilyRelationshipType::Child));
+        ontology.add_entity(family("Ares parent of Zeus", &ares, &zeus, FamilyRelationshipType::Parent));
+        
+        let report = ontology.validate();
+        let duplicates: Vec<&str> = report.issues.iter()
+            .filter(|issue| matches!(issue.kind, IssueKind::DuplicateRelationship { .. }))
+            .map(|issue| issue.entity_name.as_str())
+            .collect();
+        assert_eq!(duplicates.len(), 1);
+        assert!(duplicates[0] != "Ares parent of Zeus");
+    }
+    
+    #[test]
+    fn checks_narrative_references() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        let zeus_id = zeus.id.clone();
+        ontology.add_entity(MythEntity::Deity(zeus));
+        
+        let missing = MythId::new();
+        let mut episode = Episode::new("Titanomachy", "The Olympians overthrow the Titans");
+        episode.add_participant(zeus_id.clone(), NarrativeRole::Protagonist);
+        episode.add_participant(missing.clone(), NarrativeRole::Antagonist);
+        episode.add_location(zeus_id.clone());
+        let mut narrative = Narrative::new("Theogony", "The origin of the gods", "Greek");
+        narrative.add_episode(episode);
+        ontology.add_entity(MythEntity::Narrative(narrative));
+        
+        let report = ontology.validate();
+        let kinds = kinds(&report);
+        assert!(kinds.contains(&&IssueKind::DanglingReference { field: "episodes.participants".to_string(), target_id: missing }));
+        assert!(kinds.contains(&&IssueKind::UnexpectedEntityType {
+            field: "episodes.locations".to_string(),
+            target_id: zeus_id,
+            expected: "Location".to_string(),
+            found: "Deity".to_string(),
+        }));
+    }
+    
+    #[test]
+    fn reports_unknown_sources() {
+        let mut ontology = MythOntology::new();
+        let theogony = ontology.add_source(Source {
+            title: "Theogony".to_string(),
+            author: Some("Hesiod".to_string()),
+            date: None,
+            source_type: SourceType::PrimaryText,
+            url: None,
+            notes: None,
+        });
+        let missing = SourceId::new();
+        
+        let mut cerberus = Deity::new("Cerberus", "Hound of Hades", "Greek");
+        cerberus.metadata.add_citation(Citation::new(theogony.clone()));
+        cerberus.metadata.add_citation(Citation::new(missing.clone()));
+        cerberus.metadata.add_motif(MotifTag::new("B15.1.2".parse().unwrap()).citation(Citation::new(missing.clone())));
+        cerberus.metadata.add_claim("heads", "50", Claim::new(Citation::new(theogony)));
+        cerberus.metadata.add_claim("heads", "3", Claim::new(Citation::new(missing.clone())));
+        ontology.add_entity(MythEntity::Deity(cerberus));
+        
+        let report = ontology.validate();
+        let unknown: Vec<&str> = report.issues.iter()
+            .filter_map(|issue| match &issue.kind {
+                IssueKind::UnknownSource { field, source } if *source == missing => Some(field.as_str()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(unknown, ["citations", "motifs", "claims"]);
+        assert!(report.has_errors());
+    }
+}
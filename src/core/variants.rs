@@ -0,0 +1,116 @@
+//! # Variant Names
+//!
+//! Text conversion for the simple classification enums used throughout the ontology
+//! (`ArtifactType`, `HeroOrigin`, `DeityImportance`, ...). Each enum gets a `Display`
+//! implementation printing the variant name and a case-insensitive `FromStr`
+//! implementation, so the same spelling is used by queries, exports, and imports.
+//!
+//! For enums with a catch-all `Other(String)` variant, unrecognized text parses to
+//! `Other`, and `Other` displays as its inner string. Enums without a catch-all
+//! reject unrecognized text with an [`UnknownVariant`] error. Every enum also gets a
+//! `VARIANT_NAMES` constant listing its fieldless variants, and enums with a
+//! catch-all implement the crate's `CatchAll` trait for building `Other` directly.
+
+use std::fmt;
+
+/// Error returned when text does not name any variant of an enum
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownVariant {
+    /// Name of the enum being parsed
+    pub enum_name: &'static str,
+    /// The text that was not recognized
+    pub value: String,
+    /// The accepted variant names
+    pub expected: &'static [&'static str],
+}
+
+impl fmt::Display for UnknownVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} `{}`; expected one of {}", self.enum_name, self.value, self.expected.join(", "))
+    }
+}
+
+impl std::error::Error for UnknownVariant {}
+
+/// An enum with a catch-all variant holding a `String`
+pub(crate) trait CatchAll: Sized {
+    /// Wrap text in the catch-all variant, even text naming another variant
+    fn other(name: String) -> Self;
+}
+
+/// Implement `Display` and `FromStr` for a fieldless enum, optionally with a
+/// catch-all variant holding a `String`.
+///
+/// ```text
+/// variant_names!(ArtifactType { Weapon, Armor, Unknown } else Other);
+/// variant_names!(DeityImportance { Supreme, Major, Unknown });
+/// ```
+macro_rules! variant_names {
+    ($enum:ident { $($variant:ident),* $(,)? } else $other:ident) => {
+        impl std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str(stringify!($variant)),)*
+                    Self::$other(name) => f.write_str(name),
+                }
+            }
+        }
+        
+        impl std::str::FromStr for $enum {
+            type Err = std::convert::Infallible;
+            
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Ok(Self::$other(s.to_string()))
+            }
+        }
+        
+        impl $enum {
+            /// Names of the fieldless variants, in declaration order
+            pub const VARIANT_NAMES: &'static [&'static str] = &[$(stringify!($variant)),*];
+        }
+        
+        impl $crate::core::CatchAll for $enum {
+            fn other(name: String) -> Self {
+                Self::$other(name)
+            }
+        }
+    };
+    ($enum:ident { $($variant:ident),* $(,)? }) => {
+        impl std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str(stringify!($variant)),)*
+                }
+            }
+        }
+        
+        impl std::str::FromStr for $enum {
+            type Err = $crate::core::UnknownVariant;
+            
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(
+                    if s.eq_ignore_ascii_case(stringify!($variant)) {
+                        return Ok(Self::$variant);
+                    }
+                )*
+                Err($crate::core::UnknownVariant {
+                    enum_name: stringify!($enum),
+                    value: s.to_string(),
+                    expected: Self::VARIANT_NAMES,
+                })
+            }
+        }
+        
+        impl $enum {
+            /// Names of the fieldless variants, in declaration order
+            pub const VARIANT_NAMES: &'static [&'static str] = &[$(stringify!($variant)),*];
+        }
+    };
+}
+
+pub(crate) use variant_names;
@@ -0,0 +1,349 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use crate::core::{CultureId, MythEntity, MythId, MythOntology};
+use crate::cultural::MythologicalEra;
+use crate::entities::Event;
+
+/// Orders the events of an ontology relative to one another
+///
+/// One event is placed before another when:
+///
+/// - it causes the other (`caused_by` / `leads_to`)
+/// - either records the other in its `after` / `before` constraints
+/// - it belongs to an earlier era of the same culture, by
+///   [`MythologicalEra::sequence_order`]
+/// - the other is the `ending` of its era
+///
+/// Constraints involving events outside the chronology, such as events of another
+/// culture when restricted with [`Chronology::in_culture`], are ignored.
+///
+/// ```
+/// use mythologic::core::{MythEntity, MythOntology};
+/// use mythologic::cultural::Chronology;
+/// use mythologic::entities::Event;
+///
+/// let mut ontology = MythOntology::new();
+/// let mut judgement = Event::new("Judgement of Paris", "Paris chooses Aphrodite", "Greek");
+/// let mut war = Event::new("Trojan War", "The siege of Troy", "Greek");
+/// let mut return_home = Event::new("Return of Odysseus", "Odysseus reaches Ithaca", "Greek");
+/// judgement.add_consequence(war.id.clone());
+/// return_home.add_after(war.id.clone());
+/// for event in [return_home, war, judgement] {
+///     ontology.add_entity(MythEntity::Event(event));
+/// }
+///
+/// let timeline = Chronology::new(&ontology).timeline().unwrap();
+/// assert!(timeline.is_linear());
+/// let names: Vec<&str> = timeline.events().map(|id| ontology.get_entity(id).unwrap().name()).collect();
+/// assert_eq!(names, ["Judgement of Paris", "Trojan War", "Return of Odysseus"]);
+/// ```
+pub struct Chronology<'a> {
+    ontology: &'a MythOntology,
+    culture: Option<CultureId>,
+}
+
+/// The events of a chronology in order
+///
+/// Events are grouped into steps: every event comes after at least one event of
+/// the step before it, and the events within a step are not ordered relative to
+/// each other. When every step holds a single event the order is linear.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timeline {
+    steps: Vec<Vec<MythId>>,
+    later: HashMap<MythId, Vec<MythId>>,
+}
+
+/// Error when the ordering constraints between events contradict each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChronologyError {
+    /// Events on a cycle, each constrained to come before the next and the last
+    /// before the first
+    pub cycle: Vec<MythId>,
+    /// Names of the events on the cycle
+    pub names: Vec<String>,
+}
+
+impl fmt::Display for ChronologyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "events are ordered in a cycle: {}", self.names.join(" before "))?;
+        if let Some(first) = self.names.first() {
+            write!(f, " before {}", first)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ChronologyError {}
+
+impl<'a> Chronology<'a> {
+    /// Create a chronology of every event in an ontology
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        Self {
+            ontology,
+            culture: None,
+        }
+    }
+    
+    /// Only order the events of one culture
+    pub fn in_culture(mut self, culture: CultureId) -> Self {
+        self.culture = Some(culture);
+        self
+    }
+    
+    /// Get the events in the chronology, sorted by name
+    pub fn events(&self) -> Vec<&'a Event> {
+        let mut events: Vec<&Event> = self.ontology.entities_of_type("Event")
+            .filter_map(|entity| match entity {
+                MythEntity::Event(event) => Some(event),
+                _ => None,
+            })
+            .filter(|event| self.culture.as_ref().is_none_or(|culture| &event.culture == culture))
+            .collect();
+        events.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.to_string().cmp(&b.id.to_string())));
+        events
+    }
+    
+    /// Get every ordering constraint between the events, as (earlier, later) pairs
+    pub fn constraints(&self) -> Vec<(MythId, MythId)> {
+        let events = self.events();
+        let included: HashSet<&MythId> = events.iter().map(|event| &event.id).collect();
+        let mut constraints: Vec<(MythId, MythId)> = Vec::new();
+        let mut add = |earlier: &MythId, later: &MythId| {
+            let pair = (earlier.clone(), later.clone());
+            if included.contains(earlier) && included.contains(later) && !constraints.contains(&pair) {
+                constraints.push(pair);
+            }
+        };
+        
+        for event in &events {
+            for cause in event.caused_by.iter().chain(&event.after) {
+                add(cause, &event.id);
+            }
+            for consequence in event.leads_to.iter().chain(&event.before) {
+                add(&event.id, consequence);
+            }
+        }
+        
+        // Events of each era, by culture and sequence order
+        let mut eras: HashMap<&CultureId, BTreeMap<u32, Vec<&MythologicalEra>>> = HashMap::new();
+        for entity in self.ontology.entities_of_type("MythologicalEra") {
+            if let MythEntity::MythologicalEra(era) = entity
+                && let Some(order) = era.sequence_order
+            {
+                eras.entry(&era.culture).or_default().entry(order).or_default().push(era);
+            }
+        }
+        for sequence in eras.values() {
+            let steps: Vec<Vec<&MythologicalEra>> = sequence.values().cloned().collect();
+            for (index, step) in steps.iter().enumerate() {
+                for era in step {
+                    let members = era_members(&events, era);
+                    if let Some(ending) = &era.ending {
+                        for member in members.iter().filter(|member| **member != ending) {
+                            add(member, ending);
+                        }
+                    }
+                    for next in steps.get(index + 1).into_iter().flatten() {
+                        for later in era_members(&events, next) {
+                            for earlier in &members {
+                                add(earlier, later);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        constraints
+    }
+    
+    /// Order the events, or report a cycle if the constraints contradict each other
+    pub fn timeline(&self) -> Result<Timeline, ChronologyError> {
+        let events = self.events();
+        let mut later: HashMap<MythId, Vec<MythId>> = HashMap::new();
+        let mut earlier: HashMap<&MythId, Vec<MythId>> = HashMap::new();
+        let mut pending: HashMap<&MythId, usize> = events.iter().map(|event| (&event.id, 0)).collect();
+        
+        let constraints = self.constraints();
+        for (before, after) in &constraints {
+            later.entry(before.clone()).or_default().push(after.clone());
+            earlier.entry(after).or_default().push(before.clone());
+            *pending.get_mut(after).expect("constraints only involve included events") += 1;
+        }
+        
+        let mut steps: Vec<Vec<MythId>> = Vec::new();
+        let mut ready: Vec<MythId> = events.iter()
+            .filter(|event| pending[&event.id] == 0)
+            .map(|event| event.id.clone())
+            .collect();
+        while !ready.is_empty() {
+            let mut next: Vec<MythId> = Vec::new();
+            for id in &ready {
+                pending.remove(id);
+                for after in later.get(id).into_iter().flatten() {
+                    let count = pending.get_mut(after).expect("pending until all earlier events are placed");
+                    *count -= 1;
+                    if *count == 0 {
+                        next.push(after.clone());
+                    }
+                }
+            }
+            steps.push(ready);
+            ready = next;
+        }
+        
+        if pending.is_empty() {
+            let order: HashMap<&MythId, usize> = events.iter().enumerate().map(|(index, event)| (&event.id, index)).collect();
+            for step in &mut steps {
+                step.sort_by_key(|id| order[id]);
+            }
+            return Ok(Timeline { steps, later });
+        }
+        
+        // Every event left has an earlier event that is also left, so walking back
+        // through them must come round to an event already visited
+        let start = events.iter().find(|event| pending.contains_key(&event.id)).expect("pending is not empty");
+        let mut path: Vec<MythId> = vec![start.id.clone()];
+        loop {
+            let current = path.last().expect("path starts with an event");
+            let previous = earlier[current].iter()
+                .find(|id| pending.contains_key(id))
+                .expect("events left have an earlier event left")
+                .clone();
+            if let Some(position) = path.iter().position(|id| *id == previous) {
+                let mut cycle = path.split_off(position);
+                cycle.reverse();
+                let names = cycle.iter()
+                    .map(|id| self.ontology.get_entity(id).map_or_else(|| id.to_string(), |e| e.name().to_string()))
+                    .collect();
+                return Err(ChronologyError { cycle, names });
+            }
+            path.push(previous);
+        }
+    }
+}
+
+/// The events belonging to an era, including the event that ended it
+fn era_members<'e>(events: &[&'e Event], era: &'e MythologicalEra) -> Vec<&'e MythId> {
+    let mut members: Vec<&MythId> = events.iter()
+        .filter(|event| event.era.as_ref() == Some(&era.id))
+        .map(|event| &event.id)
+        .collect();
+    if let Some(ending) = &era.ending
+        && !members.contains(&ending)
+    {
+        members.push(ending);
+    }
+    members
+}
+
+impl Timeline {
+    /// Get the steps of the timeline, earliest first
+    pub fn steps(&self) -> &[Vec<MythId>] {
+        &self.steps
+    }
+    
+    /// Iterate over the events from earliest to latest, in name order within a step
+    pub fn events(&self) -> impl Iterator<Item = &MythId> {
+        self.steps.iter().flatten()
+    }
+    
+    /// Count the events
+    pub fn len(&self) -> usize {
+        self.steps.iter().map(Vec::len).sum()
+    }
+    
+    /// Check whether the timeline has no events
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+    
+    /// Check whether the events are in a single linear order
+    pub fn is_linear(&self) -> bool {
+        self.steps.iter().all(|step| step.len() == 1)
+    }
+    
+    /// Get the step an event is in
+    pub fn position(&self, id: &MythId) -> Option<usize> {
+        self.steps.iter().position(|step| step.contains(id))
+    }
+    
+    /// Check whether one event is constrained to come before another, directly or
+    /// through other events
+    pub fn precedes(&self, earlier: &MythId, later: &MythId) -> bool {
+        let mut stack: Vec<&MythId> = vec![earlier];
+        let mut seen: HashSet<&MythId> = HashSet::new();
+        while let Some(id) = stack.pop() {
+            for next in self.later.get(id).into_iter().flatten() {
+                if next == later {
+                    return true;
+                }
+                if seen.insert(next) {
+                    stack.push(next);
+                }
+            }
+        }
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn add_event(ontology: &mut MythOntology, event: Event) -> MythId {
+        let id = event.id.clone();
+        ontology.add_entity(MythEntity::Event(event));
+        id
+    }
+    
+    #[test]
+    fn orders_events_by_era() {
+        let mut ontology = MythOntology::new();
+        let mut gods = MythologicalEra::new("Age of Gods", "", "Greek");
+        gods.set_sequence_order(1);
+        let mut heroes = MythologicalEra::new("Age of Heroes", "", "Greek");
+        heroes.set_sequence_order(2);
+        
+        let mut titanomachy = Event::new("Titanomachy", "", "Greek");
+        titanomachy.set_era(gods.id.clone());
+        let flood = Event::new("Flood of Deucalion", "", "Greek");
+        let mut argonauts = Event::new("Voyage of the Argo", "", "Greek");
+        argonauts.set_era(heroes.id.clone());
+        let mut troy = Event::new("Trojan War", "", "Greek");
+        troy.set_era(heroes.id.clone());
+        gods.set_ending(&flood);
+        
+        ontology.add_entity(MythEntity::MythologicalEra(gods));
+        ontology.add_entity(MythEntity::MythologicalEra(heroes));
+        let titanomachy = add_event(&mut ontology, titanomachy);
+        let flood = add_event(&mut ontology, flood);
+        let argonauts = add_event(&mut ontology, argonauts);
+        let troy = add_event(&mut ontology, troy);
+        add_event(&mut ontology, Event::new("Ragnarök", "", "Norse"));
+        
+        let timeline = Chronology::new(&ontology).in_culture("Greek".into()).timeline().unwrap();
+        assert_eq!(timeline.steps(), [vec![titanomachy.clone()], vec![flood.clone()], vec![troy.clone(), argonauts.clone()]]);
+        assert!(!timeline.is_linear());
+        assert!(timeline.precedes(&titanomachy, &troy));
+        assert!(!timeline.precedes(&argonauts, &troy));
+    }
+    
+    #[test]
+    fn reports_cycles() {
+        let mut ontology = MythOntology::new();
+        let mut first = Event::new("First", "", "Greek");
+        let mut second = Event::new("Second", "", "Greek");
+        let mut third = Event::new("Third", "", "Greek");
+        second.add_after(first.id.clone());
+        third.add_cause(second.id.clone());
+        first.add_after(third.id.clone());
+        let unrelated = Event::new("Unrelated", "", "Greek");
+        for event in [first, second, third, unrelated] {
+            ontology.add_entity(MythEntity::Event(event));
+        }
+        
+        let error = Chronology::new(&ontology).timeline().unwrap_err();
+        assert_eq!(error.names, ["Second", "Third", "First"]);
+        assert_eq!(error.to_string(), "events are ordered in a cycle: Second before Third before First before Second");
+    }
+}
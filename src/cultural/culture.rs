@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashSet;
-use crate::core::{MythId, Metadata, CultureId, RegionId, LanguageId};
+use crate::core::{MythId, Metadata, CultureId, RegionId, LanguageId, HistoricalDate};
 
 /// Represents a cultural context for mythological entities
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +93,16 @@ impl Culture {
     pub fn cultural_practices(&self) -> &[String] {
         &self.cultural_practices
     }
+    
+    /// Check whether this culture is the one referred to by a `CultureId`
+    ///
+    /// Matches the name exactly (ignoring case) or a qualified form of it, so a
+    /// culture named "Ancient Greek" is identified by `CultureId::new("Greek")`.
+    pub fn is_identified_by(&self, culture: &CultureId) -> bool {
+        let name = self.name.to_lowercase();
+        let id = culture.value().to_lowercase();
+        name == id || name.ends_with(&format!(" {}", id))
+    }
 }
 
 // Trait implementations removed as we're using the enum approach
@@ -102,10 +112,19 @@ impl Culture {
 pub struct TimePeriod {
     /// Name of the time period
     pub name: String,
-    /// Start year (approximate, can be negative for BCE)
-    pub start_year: Option<i32>,
-    /// End year (approximate, can be negative for BCE)
-    pub end_year: Option<i32>,
+    /// When the period began
+    pub start: Option<HistoricalDate>,
+    /// When the period ended
+    pub end: Option<HistoricalDate>,
     /// Description of the time period
     pub description: Option<String>,
 }
+
+impl TimePeriod {
+    /// The dates covered by the period, from its start to its end
+    ///
+    /// If only one end of the period is known, that date alone is returned.
+    pub fn span(&self) -> Option<HistoricalDate> {
+        HistoricalDate::span(self.start.as_ref(), self.end.as_ref())
+    }
+}
@@ -1,5 +1,6 @@
 use serde::{Serialize, Deserialize};
 use crate::core::{MythId, Metadata, CultureId, CharacteristicId, EventId};
+use crate::entities::Event;
 
 /// Represents a mythological era or age
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -18,6 +19,9 @@ pub struct MythologicalEra {
     pub characteristics: Vec<CharacteristicId>,
     /// How this era ended
     pub end_event: Option<EventId>,
+    /// The [`Event`] entity that ended this era, if it is in the ontology
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ending: Option<MythId>,
     /// Relationships with other entities
     pub relationships: Vec<MythId>,
     /// Metadata
@@ -35,6 +39,7 @@ impl MythologicalEra {
             sequence_order: None,
             characteristics: Vec::new(),
             end_event: None,
+            ending: None,
             relationships: Vec::new(),
             metadata: Metadata::new(),
         }
@@ -55,6 +60,12 @@ impl MythologicalEra {
         self.end_event = Some(EventId::new(event));
     }
     
+    /// Set the end event to an event entity, recording both its name and its ID
+    pub fn set_ending(&mut self, event: &Event) {
+        self.end_event = Some(EventId::new(&event.name));
+        self.ending = Some(event.id.clone());
+    }
+    
     /// Get the culture
     pub fn culture(&self) -> &CultureId {
         &self.culture
@@ -74,6 +85,11 @@ impl MythologicalEra {
     pub fn end_event(&self) -> Option<&EventId> {
         self.end_event.as_ref()
     }
+    
+    /// Get the ID of the event entity that ended this era
+    pub fn ending(&self) -> Option<&MythId> {
+        self.ending.as_ref()
+    }
 }
 
 // Trait implementations removed as we're using the enum approach
@@ -4,8 +4,10 @@ mod culture;
 mod pantheon;
 mod era;
 mod region;
+mod chronology;
 
 pub use culture::*;
 pub use pantheon::*;
 pub use era::*;
 pub use region::*;
+pub use chronology::*;
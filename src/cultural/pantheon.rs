@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashSet;
-use crate::core::{MythId, Metadata, CultureId, CosmologyId};
+use crate::core::{MythId, Metadata, CultureId, CosmologyId, PantheonId};
 
 /// Represents a pantheon of deities
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +93,16 @@ impl Pantheon {
     pub fn founding_myth(&self) -> Option<&str> {
         self.founding_myth.as_deref()
     }
+    
+    /// Check whether this pantheon is the one referred to by a `PantheonId`
+    ///
+    /// Matches the name exactly (ignoring case), with or without a trailing
+    /// "Pantheon", so "Olympian Pantheon" is identified by `PantheonId::new("Olympian")`.
+    pub fn is_identified_by(&self, pantheon: &PantheonId) -> bool {
+        let name = self.name.to_lowercase();
+        let id = pantheon.value().to_lowercase();
+        name == id || name.strip_suffix(" pantheon") == Some(id.as_str())
+    }
 }
 
 // Trait implementations removed as we're using the enum approach
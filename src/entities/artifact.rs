@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::core::{MythId, Metadata, CultureId};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
 
 /// Represents a mythological artifact or object of power
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -106,3 +106,5 @@ pub enum ArtifactType {
     Unknown,
     Other(String),
 }
+
+variant_names!(ArtifactType { Weapon, Armor, Jewelry, Vessel, Tool, Instrument, Clothing, Book, Symbol, Unknown } else Other);
@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::core::{MythId, Metadata, CultureId};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
 
 /// Represents an abstract mythological concept
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -81,3 +81,5 @@ pub enum ConceptType {
     Unknown,
     Other(String),
 }
+
+variant_names!(ConceptType { Cosmology, Creation, Afterlife, Virtue, Vice, Fate, Time, Justice, Love, War, Unknown } else Other);
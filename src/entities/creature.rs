@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashSet;
-use crate::core::{MythId, Metadata, CultureId};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
 
 /// Represents a mythological creature or monster
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,3 +95,5 @@ pub enum CreatureType {
     Unknown,
     Other(String),
 }
+
+variant_names!(CreatureType { Dragon, Giant, Spirit, Undead, Shapeshifter, Hybrid, Guardian, Monster, Elemental, Fae, Unknown } else Other);
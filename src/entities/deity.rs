@@ -7,7 +7,7 @@
 
 use serde::{Serialize, Deserialize};
 use std::collections::HashSet;
-use crate::core::{MythId, Metadata, CultureId, PantheonId, DomainId};
+use crate::core::{MythId, Metadata, CultureId, PantheonId, DomainId, variant_names};
 
 /// A type-safe representation of a deity name or epithet.
 ///
@@ -328,6 +328,8 @@ pub enum Gender {
     Other(String),
 }
 
+variant_names!(Gender { Male, Female, NonBinary, Fluid, Androgynous, Unknown } else Other);
+
 /// Importance or hierarchical level of a deity within their pantheon.
 ///
 /// Mythological traditions often have hierarchies of deities with different levels
@@ -359,3 +361,5 @@ pub enum DeityImportance {
     /// Importance level is not specified or known
     Unknown,
 }
+
+variant_names!(DeityImportance { Supreme, Major, Minor, Demigod, Local, Household, Unknown });
@@ -0,0 +1,136 @@
+use serde::{Serialize, Deserialize};
+use crate::core::{MythId, Metadata, CultureId};
+
+/// Represents a mythological event, such as the Trojan War or Ragnarök
+///
+/// Events link the entities taking part in them, the location where they happen,
+/// and the [`MythologicalEra`](crate::cultural::MythologicalEra) they belong to, all by ID. Causal links
+/// (`caused_by` / `leads_to`) and explicit `after` / `before` constraints between
+/// events are used by [`Chronology`](crate::cultural::Chronology) to put a culture's
+/// events in order.
+///
+/// ```
+/// use mythologic::entities::Event;
+///
+/// let mut judgement = Event::new("Judgement of Paris", "Paris chooses Aphrodite as the fairest", "Greek");
+/// let mut war = Event::new("Trojan War", "The Achaeans besiege Troy for ten years", "Greek");
+/// judgement.add_consequence(war.id.clone());
+/// war.add_cause(judgement.id.clone());
+///
+/// assert_eq!(war.caused_by(), [judgement.id.clone()]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Event {
+    /// Unique identifier
+    pub id: MythId,
+    /// Name of the event
+    pub name: String,
+    /// What happened
+    pub description: String,
+    /// Cultural origin
+    pub culture: CultureId,
+    /// Entities taking part in the event
+    pub participants: Vec<MythId>,
+    /// Location where the event takes place
+    pub location: Option<MythId>,
+    /// Era the event belongs to
+    pub era: Option<MythId>,
+    /// Events that brought this one about
+    pub caused_by: Vec<MythId>,
+    /// Events this one brings about
+    pub leads_to: Vec<MythId>,
+    /// Events known to happen before this one, without causing it
+    pub after: Vec<MythId>,
+    /// Events known to happen after this one, without being caused by it
+    pub before: Vec<MythId>,
+    /// Relationships with other entities
+    pub relationships: Vec<MythId>,
+    /// Metadata
+    pub metadata: Metadata,
+}
+
+impl Event {
+    /// Create a new event
+    pub fn new(name: &str, description: &str, culture: &str) -> Self {
+        Self {
+            id: MythId::new(),
+            name: name.to_string(),
+            description: description.to_string(),
+            culture: CultureId::new(culture),
+            participants: Vec::new(),
+            location: None,
+            era: None,
+            caused_by: Vec::new(),
+            leads_to: Vec::new(),
+            after: Vec::new(),
+            before: Vec::new(),
+            relationships: Vec::new(),
+            metadata: Metadata::new(),
+        }
+    }
+    
+    /// Add a participant
+    pub fn add_participant(&mut self, id: MythId) {
+        self.participants.push(id);
+    }
+    
+    /// Set the location
+    pub fn set_location(&mut self, location_id: MythId) {
+        self.location = Some(location_id);
+    }
+    
+    /// Set the era
+    pub fn set_era(&mut self, era_id: MythId) {
+        self.era = Some(era_id);
+    }
+    
+    /// Record an event that brought this one about
+    pub fn add_cause(&mut self, event_id: MythId) {
+        self.caused_by.push(event_id);
+    }
+    
+    /// Record an event this one brings about
+    pub fn add_consequence(&mut self, event_id: MythId) {
+        self.leads_to.push(event_id);
+    }
+    
+    /// Record an event known to happen before this one
+    pub fn add_after(&mut self, event_id: MythId) {
+        self.after.push(event_id);
+    }
+    
+    /// Record an event known to happen after this one
+    pub fn add_before(&mut self, event_id: MythId) {
+        self.before.push(event_id);
+    }
+    
+    /// Get the culture
+    pub fn culture(&self) -> &CultureId {
+        &self.culture
+    }
+    
+    /// Get the participants
+    pub fn participants(&self) -> &[MythId] {
+        &self.participants
+    }
+    
+    /// Get the location
+    pub fn location(&self) -> Option<&MythId> {
+        self.location.as_ref()
+    }
+    
+    /// Get the era
+    pub fn era(&self) -> Option<&MythId> {
+        self.era.as_ref()
+    }
+    
+    /// Get the events that brought this one about
+    pub fn caused_by(&self) -> &[MythId] {
+        &self.caused_by
+    }
+    
+    /// Get the events this one brings about
+    pub fn leads_to(&self) -> &[MythId] {
+        &self.leads_to
+    }
+}
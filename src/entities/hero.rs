@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::core::{MythId, Metadata, CultureId};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
 
 /// Represents a mythological hero or protagonist
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -76,3 +76,5 @@ pub enum HeroOrigin {
     Unknown,
     Other(String),
 }
+
+variant_names!(HeroOrigin { Divine, Demigod, BlessedMortal, Mortal, Transformed, Unknown } else Other);
@@ -1,5 +1,5 @@
 use serde::{Serialize, Deserialize};
-use crate::core::{MythId, Metadata, CultureId};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
 
 /// Represents a mythological location or realm
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,3 +95,5 @@ pub enum LocationType {
     Unknown,
     Other(String),
 }
+
+variant_names!(LocationType { Afterlife, Heaven, Underworld, Mountain, Forest, Sea, Island, City, Temple, Cosmic, Liminal, Unknown } else Other);
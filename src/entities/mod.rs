@@ -13,6 +13,10 @@
 //! - [`Artifact`]: Magical or significant objects (Excalibur, etc.)
 //! - [`Location`]: Mythical places (Olympus, Valhalla, etc.)
 //! - [`Concept`]: Abstract ideas (Fate, Time, Justice, etc.)
+//! - [`Narrative`]: Myths themselves (the Twelve Labors, the Binding of Fenrir, etc.),
+//!   told as ordered episodes linking the other entities by role
+//! - [`Event`]: Mythological events (the Trojan War, Ragnarök, etc.) with their
+//!   participants, place, era, and causes
 //!
 //! Each entity type has its own attributes, classifications, and relationships
 //! with other entities in the mythological framework.
@@ -24,6 +28,8 @@ pub mod creature;
 pub mod artifact;
 pub mod location;
 pub mod concept;
+pub mod narrative;
+pub mod event;
 
 // Re-export main entity types
 pub use deity::Deity;
@@ -32,6 +38,8 @@ pub use creature::Creature;
 pub use artifact::Artifact;
 pub use location::Location;
 pub use concept::Concept;
+pub use narrative::{Narrative, Episode, Participant};
+pub use event::Event;
 
 // Re-export entity enums
 pub use deity::{Gender, DeityImportance, DeityName};
@@ -40,3 +48,4 @@ pub use creature::CreatureType;
 pub use artifact::ArtifactType;
 pub use location::LocationType;
 pub use concept::ConceptType;
+pub use narrative::NarrativeRole;
@@ -0,0 +1,179 @@
+use serde::{Serialize, Deserialize};
+use crate::core::{MythId, Metadata, CultureId, variant_names};
+
+/// Represents a myth itself: a story told as an ordered sequence of episodes
+///
+/// Each [`Episode`] links the entities taking part in it by ID, with the role
+/// they play, along with the locations where it happens, the artifacts used, and
+/// the relationships it establishes.
+///
+/// ```
+/// use mythologic::core::MythId;
+/// use mythologic::entities::{Episode, Narrative, NarrativeRole};
+///
+/// let (tyr, fenrir) = (MythId::new(), MythId::new());
+/// let mut binding = Narrative::new("The Binding of Fenrir", "The gods chain the wolf Fenrir", "Norse");
+///
+/// let mut episode = Episode::new("Gleipnir", "Fenrir is bound with a ribbon made by the dwarves");
+/// episode.add_participant(tyr.clone(), NarrativeRole::Protagonist);
+/// episode.add_participant(fenrir.clone(), NarrativeRole::Antagonist);
+/// binding.add_episode(episode);
+///
+/// assert!(binding.has_participant(&fenrir, Some(&NarrativeRole::Antagonist)));
+/// assert_eq!(binding.roles_of(&tyr), [NarrativeRole::Protagonist]);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Narrative {
+    /// Unique identifier
+    pub id: MythId,
+    /// Title of the myth
+    pub name: String,
+    /// Summary of the story
+    pub description: String,
+    /// Cultural origin
+    pub culture: CultureId,
+    /// The episodes of the story, in narrative order
+    pub episodes: Vec<Episode>,
+    /// Relationships with other entities
+    pub relationships: Vec<MythId>,
+    /// Metadata
+    pub metadata: Metadata,
+}
+
+impl Narrative {
+    /// Create a new narrative without episodes
+    pub fn new(name: &str, description: &str, culture: &str) -> Self {
+        Self {
+            id: MythId::new(),
+            name: name.to_string(),
+            description: description.to_string(),
+            culture: CultureId::new(culture),
+            episodes: Vec::new(),
+            relationships: Vec::new(),
+            metadata: Metadata::new(),
+        }
+    }
+    
+    /// Append an episode to the story
+    pub fn add_episode(&mut self, episode: Episode) {
+        self.episodes.push(episode);
+    }
+    
+    /// Get the culture
+    pub fn culture(&self) -> &CultureId {
+        &self.culture
+    }
+    
+    /// Get the episodes in narrative order
+    pub fn episodes(&self) -> &[Episode] {
+        &self.episodes
+    }
+    
+    /// Get the distinct roles an entity plays across the story, in order of first appearance
+    pub fn roles_of(&self, id: &MythId) -> Vec<NarrativeRole> {
+        let mut roles: Vec<NarrativeRole> = Vec::new();
+        for participant in self.episodes.iter().flat_map(|e| &e.participants).filter(|p| &p.id == id) {
+            if !roles.contains(&participant.role) {
+                roles.push(participant.role.clone());
+            }
+        }
+        roles
+    }
+    
+    /// Check whether an entity takes part in the story, in the given role if one is specified
+    pub fn has_participant(&self, id: &MythId, role: Option<&NarrativeRole>) -> bool {
+        self.episodes.iter()
+            .flat_map(|e| &e.participants)
+            .any(|p| &p.id == id && role.is_none_or(|role| &p.role == role))
+    }
+    
+    /// Get the IDs of every entity the story refers to: participants, locations,
+    /// artifacts, and established relationships
+    pub fn referenced_ids(&self) -> Vec<&MythId> {
+        let mut ids: Vec<&MythId> = Vec::new();
+        for episode in &self.episodes {
+            let referenced = episode.participants.iter().map(|p| &p.id)
+                .chain(&episode.locations)
+                .chain(&episode.artifacts)
+                .chain(&episode.establishes);
+            for id in referenced {
+                if !ids.contains(&id) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids
+    }
+}
+
+/// One episode of a [`Narrative`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Episode {
+    /// Short title of the episode
+    pub title: String,
+    /// What happens
+    pub description: String,
+    /// Entities taking part, with their roles
+    pub participants: Vec<Participant>,
+    /// Locations where the episode takes place
+    pub locations: Vec<MythId>,
+    /// Artifacts used in the episode
+    pub artifacts: Vec<MythId>,
+    /// Relationship entities the episode establishes, such as a marriage or a feud
+    pub establishes: Vec<MythId>,
+}
+
+impl Episode {
+    /// Create a new episode
+    pub fn new(title: &str, description: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            description: description.to_string(),
+            participants: Vec::new(),
+            locations: Vec::new(),
+            artifacts: Vec::new(),
+            establishes: Vec::new(),
+        }
+    }
+    
+    /// Add a participant in the given role
+    pub fn add_participant(&mut self, id: MythId, role: NarrativeRole) {
+        self.participants.push(Participant { id, role });
+    }
+    
+    /// Add a location where the episode takes place
+    pub fn add_location(&mut self, id: MythId) {
+        self.locations.push(id);
+    }
+    
+    /// Add an artifact used in the episode
+    pub fn add_artifact(&mut self, id: MythId) {
+        self.artifacts.push(id);
+    }
+    
+    /// Record a relationship the episode establishes
+    pub fn add_established_relationship(&mut self, id: MythId) {
+        self.establishes.push(id);
+    }
+}
+
+/// An entity taking part in an episode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Participant {
+    /// The participating entity
+    pub id: MythId,
+    /// The part it plays in the episode
+    pub role: NarrativeRole,
+}
+
+/// The part an entity plays in an episode
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NarrativeRole {
+    Protagonist,
+    Antagonist,
+    Helper,
+    Victim,
+    Other(String),
+}
+
+variant_names!(NarrativeRole { Protagonist, Antagonist, Helper, Victim } else Other);
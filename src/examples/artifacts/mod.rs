@@ -1,7 +1,8 @@
 //! Mythological artifacts from various cultures
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Artifact, ArtifactType};
+use crate::examples::motifs::add_motif_tags;
 
 /// Create an ontology focused on mythological artifacts from various cultures
 pub fn create_artifacts_ontology() -> MythOntology {
@@ -25,19 +26,22 @@ pub fn create_artifacts_ontology() -> MythOntology {
     // Add East Asian artifacts
     add_east_asian_artifacts(&mut ontology);
     
+    // Tag story motifs
+    add_motif_tags(&mut ontology);
+    
     ontology
 }
 
 /// Add Greek mythological artifacts
 fn add_greek_artifacts(ontology: &mut MythOntology) {
-    let greek_source = Source {
+    let greek_source = ontology.add_source(Source {
         title: "Greek Mythology Compendium".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Greek mythological texts".to_string()),
-    };
+    });
     
     // Zeus's Thunderbolt
     let mut thunderbolt = Artifact::new(
@@ -81,9 +85,9 @@ fn add_greek_artifacts(ontology: &mut MythOntology) {
     let mut aegis_entity = MythEntity::Artifact(aegis);
     let mut winged_sandals_entity = MythEntity::Artifact(winged_sandals);
     
-    thunderbolt_entity.metadata_mut().add_source(greek_source.clone());
-    aegis_entity.metadata_mut().add_source(greek_source.clone());
-    winged_sandals_entity.metadata_mut().add_source(greek_source);
+    thunderbolt_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    aegis_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    winged_sandals_entity.metadata_mut().add_citation(Citation::new(greek_source));
     
     ontology.add_entity(thunderbolt_entity);
     ontology.add_entity(aegis_entity);
@@ -92,14 +96,14 @@ fn add_greek_artifacts(ontology: &mut MythOntology) {
 
 /// Add Norse mythological artifacts
 fn add_norse_artifacts(ontology: &mut MythOntology) {
-    let norse_source = Source {
+    let norse_source = ontology.add_source(Source {
         title: "Prose Edda".to_string(),
         author: Some("Snorri Sturluson".to_string()),
-        year: Some(1220),
+        date: Some(HistoricalDate::Year(1220)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Old Norse work of literature written in Iceland".to_string()),
-    };
+    });
     
     // Mjölnir
     let mut mjolnir = Artifact::new(
@@ -142,9 +146,9 @@ fn add_norse_artifacts(ontology: &mut MythOntology) {
     let mut gungnir_entity = MythEntity::Artifact(gungnir);
     let mut gleipnir_entity = MythEntity::Artifact(gleipnir);
     
-    mjolnir_entity.metadata_mut().add_source(norse_source.clone());
-    gungnir_entity.metadata_mut().add_source(norse_source.clone());
-    gleipnir_entity.metadata_mut().add_source(norse_source);
+    mjolnir_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    gungnir_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    gleipnir_entity.metadata_mut().add_citation(Citation::new(norse_source));
     
     ontology.add_entity(mjolnir_entity);
     ontology.add_entity(gungnir_entity);
@@ -153,14 +157,14 @@ fn add_norse_artifacts(ontology: &mut MythOntology) {
 
 /// Add Celtic mythological artifacts
 fn add_celtic_artifacts(ontology: &mut MythOntology) {
-    let celtic_source = Source {
+    let celtic_source = ontology.add_source(Source {
         title: "Celtic Mythology Collection".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Celtic mythological tales".to_string()),
-    };
+    });
     
     // Cauldron of Dagda
     let mut cauldron = Artifact::new(
@@ -187,8 +191,8 @@ fn add_celtic_artifacts(ontology: &mut MythOntology) {
     let mut cauldron_entity = MythEntity::Artifact(cauldron);
     let mut sword_entity = MythEntity::Artifact(sword_of_light);
     
-    cauldron_entity.metadata_mut().add_source(celtic_source.clone());
-    sword_entity.metadata_mut().add_source(celtic_source);
+    cauldron_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    sword_entity.metadata_mut().add_citation(Citation::new(celtic_source));
     
     ontology.add_entity(cauldron_entity);
     ontology.add_entity(sword_entity);
@@ -196,14 +200,14 @@ fn add_celtic_artifacts(ontology: &mut MythOntology) {
 
 /// Add Arthurian mythological artifacts
 fn add_arthurian_artifacts(ontology: &mut MythOntology) {
-    let arthurian_source = Source {
+    let arthurian_source = ontology.add_source(Source {
         title: "Le Morte d'Arthur".to_string(),
         author: Some("Sir Thomas Malory".to_string()),
-        year: Some(1485),
+        date: Some(HistoricalDate::Year(1485)),
         source_type: SourceType::LiteraryText,
         url: None,
         notes: Some("Compilation of Arthurian tales".to_string()),
-    };
+    });
     
     // Excalibur
     let mut excalibur = Artifact::new(
@@ -233,8 +237,8 @@ fn add_arthurian_artifacts(ontology: &mut MythOntology) {
     let mut excalibur_entity = MythEntity::Artifact(excalibur);
     let mut grail_entity = MythEntity::Artifact(holy_grail);
     
-    excalibur_entity.metadata_mut().add_source(arthurian_source.clone());
-    grail_entity.metadata_mut().add_source(arthurian_source);
+    excalibur_entity.metadata_mut().add_citation(Citation::new(arthurian_source.clone()));
+    grail_entity.metadata_mut().add_citation(Citation::new(arthurian_source));
     
     ontology.add_entity(excalibur_entity);
     ontology.add_entity(grail_entity);
@@ -242,14 +246,14 @@ fn add_arthurian_artifacts(ontology: &mut MythOntology) {
 
 /// Add Middle-Eastern mythological artifacts
 fn add_middle_eastern_artifacts(ontology: &mut MythOntology) {
-    let arabian_source = Source {
+    let arabian_source = ontology.add_source(Source {
         title: "One Thousand and One Nights".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Middle Eastern folk tales".to_string()),
-    };
+    });
     
     // Lamp of Aladdin
     let mut aladdin_lamp = Artifact::new(
@@ -277,8 +281,8 @@ fn add_middle_eastern_artifacts(ontology: &mut MythOntology) {
     let mut lamp_entity = MythEntity::Artifact(aladdin_lamp);
     let mut ring_entity = MythEntity::Artifact(solomons_ring);
     
-    lamp_entity.metadata_mut().add_source(arabian_source.clone());
-    ring_entity.metadata_mut().add_source(arabian_source);
+    lamp_entity.metadata_mut().add_citation(Citation::new(arabian_source.clone()));
+    ring_entity.metadata_mut().add_citation(Citation::new(arabian_source));
     
     ontology.add_entity(lamp_entity);
     ontology.add_entity(ring_entity);
@@ -286,14 +290,14 @@ fn add_middle_eastern_artifacts(ontology: &mut MythOntology) {
 
 /// Add East Asian mythological artifacts
 fn add_east_asian_artifacts(ontology: &mut MythOntology) {
-    let asian_source = Source {
+    let asian_source = ontology.add_source(Source {
         title: "East Asian Mythology Collection".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of various East Asian mythological texts".to_string()),
-    };
+    });
     
     // Ruyi Jingu Bang
     let mut ruyi_jingu_bang = Artifact::new(
@@ -322,8 +326,8 @@ fn add_east_asian_artifacts(ontology: &mut MythOntology) {
     let mut ruyi_entity = MythEntity::Artifact(ruyi_jingu_bang);
     let mut kusanagi_entity = MythEntity::Artifact(kusanagi);
     
-    ruyi_entity.metadata_mut().add_source(asian_source.clone());
-    kusanagi_entity.metadata_mut().add_source(asian_source);
+    ruyi_entity.metadata_mut().add_citation(Citation::new(asian_source.clone()));
+    kusanagi_entity.metadata_mut().add_citation(Citation::new(asian_source));
     
     ontology.add_entity(ruyi_entity);
     ontology.add_entity(kusanagi_entity);
@@ -0,0 +1,46 @@
+//! Historical attestations of the example deities
+
+use crate::core::{HistoricalDate, MythId, MythOntology};
+
+/// When the example deities appear in the historical record: entity name, first
+/// attestation, and last attestation if it is known
+const ATTESTATIONS: &[(&str, &str, Option<&str>)] = &[
+    // Egyptian: royal names, the Pyramid Texts, and the last temples at Philae
+    ("Horus", "c. 3100 BCE ±100", Some("4th century CE")),
+    ("Set", "c. 2700 BCE ±100", Some("4th century CE")),
+    ("Ra", "c. 2700 BCE ±100", Some("4th century CE")),
+    ("Osiris", "25th century BCE", Some("6th century CE")),
+    ("Isis", "c. 2350 BCE", Some("6th century CE")),
+    // Greek: the Linear B tablets of Knossos and Pylos, and Homer
+    ("Zeus", "14th century BCE", Some("after 391 CE")),
+    ("Athena", "14th century BCE", Some("after 391 CE")),
+    ("Hera", "13th century BCE", Some("after 391 CE")),
+    ("Poseidon", "13th century BCE", Some("after 391 CE")),
+    ("Apollo", "c. 750 BCE", Some("after 391 CE")),
+    // Norse: runic inscriptions, skaldic poetry, and the Eddas
+    ("Odin", "6th century CE", Some("1220 CE")),
+    ("Thor", "6th century CE", Some("1220 CE")),
+    ("Loki", "c. 900 CE", Some("1220 CE")),
+    ("Freyja", "10th century CE", Some("1220 CE")),
+    ("Heimdall", "10th century CE", Some("1220 CE")),
+];
+
+/// Record when the entities of an example ontology are first and last attested
+pub fn add_attestations(ontology: &mut MythOntology) {
+    let date = |text: &str| -> HistoricalDate { text.parse().expect("example dates are valid") };
+    for (name, first, last) in ATTESTATIONS {
+        let ids: Vec<MythId> = ontology.entities_named(name)
+            .filter(|entity| entity.name() == *name)
+            .map(|entity| entity.id().clone())
+            .collect();
+        for id in ids {
+            if let Some(mut entity) = ontology.get_entity_mut(&id) {
+                let metadata = entity.metadata_mut();
+                metadata.set_first_attested(date(first));
+                if let Some(last) = last {
+                    metadata.set_last_attested(date(last));
+                }
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Celtic mythology example ontology
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Deity, Hero, Artifact, Location};
 use crate::entities::{Gender, DeityImportance, HeroOrigin, ArtifactType, LocationType};
 use crate::cultural::{Pantheon, Culture, TimePeriod};
@@ -33,14 +33,14 @@ pub fn create_celtic_ontology() -> MythOntology {
 /// Add Celtic deities to the ontology
 fn add_celtic_deities(ontology: &mut MythOntology) {
     // Primary source for Celtic deities
-    let irish_source = Source {
+    let irish_source = ontology.add_source(Source {
         title: "Lebor Gabála Érenn".to_string(),
         author: None,
-        year: Some(1100),
+        date: Some(HistoricalDate::Year(1100)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Book of the Taking of Ireland, a collection of poems and prose narratives".to_string()),
-    };
+    });
     
     // The Dagda
     let mut dagda = Deity::new(
@@ -124,11 +124,11 @@ fn add_celtic_deities(ontology: &mut MythOntology) {
     let mut cernunnos_entity = MythEntity::Deity(cernunnos);
     
     // Add source to metadata
-    dagda_entity.metadata_mut().add_source(irish_source.clone());
-    lugh_entity.metadata_mut().add_source(irish_source.clone());
-    morrigan_entity.metadata_mut().add_source(irish_source.clone());
-    brigid_entity.metadata_mut().add_source(irish_source.clone());
-    cernunnos_entity.metadata_mut().add_source(irish_source);
+    dagda_entity.metadata_mut().add_citation(Citation::new(irish_source.clone()));
+    lugh_entity.metadata_mut().add_citation(Citation::new(irish_source.clone()));
+    morrigan_entity.metadata_mut().add_citation(Citation::new(irish_source.clone()));
+    brigid_entity.metadata_mut().add_citation(Citation::new(irish_source.clone()));
+    cernunnos_entity.metadata_mut().add_citation(Citation::new(irish_source));
     
     // Add to ontology
     ontology.add_entity(dagda_entity);
@@ -140,14 +140,14 @@ fn add_celtic_deities(ontology: &mut MythOntology) {
 
 /// Add Celtic heroes to the ontology
 fn add_celtic_heroes(ontology: &mut MythOntology) {
-    let celtic_source = Source {
+    let celtic_source = ontology.add_source(Source {
         title: "Celtic Heroic Cycles".to_string(),
         author: None,
-        year: Some(800),
+        date: Some(HistoricalDate::Year(800)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Irish and Welsh mythological tales".to_string()),
-    };
+    });
     
     // Cú Chulainn
     let mut cu_chulainn = Hero::new(
@@ -175,8 +175,8 @@ fn add_celtic_heroes(ontology: &mut MythOntology) {
     let mut cu_chulainn_entity = MythEntity::Hero(cu_chulainn);
     let mut fionn_entity = MythEntity::Hero(fionn);
     
-    cu_chulainn_entity.metadata_mut().add_source(celtic_source.clone());
-    fionn_entity.metadata_mut().add_source(celtic_source);
+    cu_chulainn_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    fionn_entity.metadata_mut().add_citation(Citation::new(celtic_source));
     
     ontology.add_entity(cu_chulainn_entity);
     ontology.add_entity(fionn_entity);
@@ -292,15 +292,15 @@ fn add_celtic_culture(ontology: &mut MythOntology) {
     // Add time periods
     celtic_culture.add_time_period(TimePeriod {
         name: "La Tène Period".to_string(),
-        start_year: Some(-450),
-        end_year: Some(-50),
+        start: Some(HistoricalDate::Year(-450)),
+        end: Some(HistoricalDate::Year(-50)),
         description: Some("Major Celtic cultural period characterized by distinctive art styles".to_string()),
     });
     
     celtic_culture.add_time_period(TimePeriod {
         name: "Early Medieval Celtic Period".to_string(),
-        start_year: Some(400),
-        end_year: Some(900),
+        start: Some(HistoricalDate::Year(400)),
+        end: Some(HistoricalDate::Year(900)),
         description: Some("Period of Celtic Christianity and written preservation of oral traditions".to_string()),
     });
     
@@ -1,6 +1,6 @@
 //! Mythological concepts from various cultures
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Concept, ConceptType};
 
 /// Create an ontology focused on mythological concepts from various cultures
@@ -30,14 +30,14 @@ pub fn create_concepts_ontology() -> MythOntology {
 
 /// Add Greek mythological concepts
 fn add_greek_concepts(ontology: &mut MythOntology) {
-    let greek_source = Source {
+    let greek_source = ontology.add_source(Source {
         title: "Greek Philosophical and Mythological Concepts".to_string(),
         author: None,
-        year: Some(-400),
+        date: Some(HistoricalDate::Year(-400)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Greek mythological concepts".to_string()),
-    };
+    });
     
     // Fate (Moirai)
     let mut fate = Concept::new(
@@ -77,9 +77,9 @@ fn add_greek_concepts(ontology: &mut MythOntology) {
     let mut hubris_entity = MythEntity::Concept(hubris);
     let mut xenia_entity = MythEntity::Concept(xenia);
     
-    fate_entity.metadata_mut().add_source(greek_source.clone());
-    hubris_entity.metadata_mut().add_source(greek_source.clone());
-    xenia_entity.metadata_mut().add_source(greek_source);
+    fate_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    hubris_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    xenia_entity.metadata_mut().add_citation(Citation::new(greek_source));
     
     ontology.add_entity(fate_entity);
     ontology.add_entity(hubris_entity);
@@ -88,14 +88,14 @@ fn add_greek_concepts(ontology: &mut MythOntology) {
 
 /// Add Norse mythological concepts
 fn add_norse_concepts(ontology: &mut MythOntology) {
-    let norse_source = Source {
+    let norse_source = ontology.add_source(Source {
         title: "Norse Mythological Concepts".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Norse mythological concepts".to_string()),
-    };
+    });
     
     // Ragnarök
     let mut ragnarok = Concept::new(
@@ -136,9 +136,9 @@ fn add_norse_concepts(ontology: &mut MythOntology) {
     let mut wyrd_entity = MythEntity::Concept(wyrd);
     let mut honor_entity = MythEntity::Concept(honor);
     
-    ragnarok_entity.metadata_mut().add_source(norse_source.clone());
-    wyrd_entity.metadata_mut().add_source(norse_source.clone());
-    honor_entity.metadata_mut().add_source(norse_source);
+    ragnarok_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    wyrd_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    honor_entity.metadata_mut().add_citation(Citation::new(norse_source));
     
     ontology.add_entity(ragnarok_entity);
     ontology.add_entity(wyrd_entity);
@@ -147,14 +147,14 @@ fn add_norse_concepts(ontology: &mut MythOntology) {
 
 /// Add Eastern mythological concepts
 fn add_eastern_concepts(ontology: &mut MythOntology) {
-    let eastern_source = Source {
+    let eastern_source = ontology.add_source(Source {
         title: "Eastern Philosophical and Mythological Concepts".to_string(),
         author: None,
-        year: Some(500),
+        date: Some(HistoricalDate::Year(500)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Eastern mythological concepts".to_string()),
-    };
+    });
     
     // Karma
     let mut karma = Concept::new(
@@ -195,9 +195,9 @@ fn add_eastern_concepts(ontology: &mut MythOntology) {
     let mut yin_yang_entity = MythEntity::Concept(yin_yang);
     let mut dharma_entity = MythEntity::Concept(dharma);
     
-    karma_entity.metadata_mut().add_source(eastern_source.clone());
-    yin_yang_entity.metadata_mut().add_source(eastern_source.clone());
-    dharma_entity.metadata_mut().add_source(eastern_source);
+    karma_entity.metadata_mut().add_citation(Citation::new(eastern_source.clone()));
+    yin_yang_entity.metadata_mut().add_citation(Citation::new(eastern_source.clone()));
+    dharma_entity.metadata_mut().add_citation(Citation::new(eastern_source));
     
     ontology.add_entity(karma_entity);
     ontology.add_entity(yin_yang_entity);
@@ -206,14 +206,14 @@ fn add_eastern_concepts(ontology: &mut MythOntology) {
 
 /// Add creation concepts from various mythologies
 fn add_creation_concepts(ontology: &mut MythOntology) {
-    let creation_source = Source {
+    let creation_source = ontology.add_source(Source {
         title: "World Creation Myths".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of creation myths from various cultures".to_string()),
-    };
+    });
     
     // Ex Nihilo
     let mut ex_nihilo = Concept::new(
@@ -252,9 +252,9 @@ fn add_creation_concepts(ontology: &mut MythOntology) {
     let mut world_egg_entity = MythEntity::Concept(world_egg);
     let mut waters_entity = MythEntity::Concept(primordial_waters);
     
-    ex_nihilo_entity.metadata_mut().add_source(creation_source.clone());
-    world_egg_entity.metadata_mut().add_source(creation_source.clone());
-    waters_entity.metadata_mut().add_source(creation_source);
+    ex_nihilo_entity.metadata_mut().add_citation(Citation::new(creation_source.clone()));
+    world_egg_entity.metadata_mut().add_citation(Citation::new(creation_source.clone()));
+    waters_entity.metadata_mut().add_citation(Citation::new(creation_source));
     
     ontology.add_entity(ex_nihilo_entity);
     ontology.add_entity(world_egg_entity);
@@ -263,14 +263,14 @@ fn add_creation_concepts(ontology: &mut MythOntology) {
 
 /// Add afterlife concepts from various mythologies
 fn add_afterlife_concepts(ontology: &mut MythOntology) {
-    let afterlife_source = Source {
+    let afterlife_source = ontology.add_source(Source {
         title: "Afterlife Concepts Across Cultures".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of afterlife concepts from various mythologies".to_string()),
-    };
+    });
     
     // Paradise/Heaven
     let mut paradise = Concept::new(
@@ -311,9 +311,9 @@ fn add_afterlife_concepts(ontology: &mut MythOntology) {
     let mut reincarnation_entity = MythEntity::Concept(reincarnation);
     let mut judgment_entity = MythEntity::Concept(judgment);
     
-    paradise_entity.metadata_mut().add_source(afterlife_source.clone());
-    reincarnation_entity.metadata_mut().add_source(afterlife_source.clone());
-    judgment_entity.metadata_mut().add_source(afterlife_source);
+    paradise_entity.metadata_mut().add_citation(Citation::new(afterlife_source.clone()));
+    reincarnation_entity.metadata_mut().add_citation(Citation::new(afterlife_source.clone()));
+    judgment_entity.metadata_mut().add_citation(Citation::new(afterlife_source));
     
     ontology.add_entity(paradise_entity);
     ontology.add_entity(reincarnation_entity);
@@ -322,14 +322,14 @@ fn add_afterlife_concepts(ontology: &mut MythOntology) {
 
 /// Add virtue and vice concepts from various mythologies
 fn add_virtue_vice_concepts(ontology: &mut MythOntology) {
-    let virtue_vice_source = Source {
+    let virtue_vice_source = ontology.add_source(Source {
         title: "Virtue and Vice in World Mythology".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of virtue and vice concepts from various cultures".to_string()),
-    };
+    });
     
     // Heroic Sacrifice
     let mut sacrifice = Concept::new(
@@ -369,9 +369,9 @@ fn add_virtue_vice_concepts(ontology: &mut MythOntology) {
     let mut justice_entity = MythEntity::Concept(justice);
     let mut balance_entity = MythEntity::Concept(balance);
     
-    sacrifice_entity.metadata_mut().add_source(virtue_vice_source.clone());
-    justice_entity.metadata_mut().add_source(virtue_vice_source.clone());
-    balance_entity.metadata_mut().add_source(virtue_vice_source);
+    sacrifice_entity.metadata_mut().add_citation(Citation::new(virtue_vice_source.clone()));
+    justice_entity.metadata_mut().add_citation(Citation::new(virtue_vice_source.clone()));
+    balance_entity.metadata_mut().add_citation(Citation::new(virtue_vice_source));
     
     ontology.add_entity(sacrifice_entity);
     ontology.add_entity(justice_entity);
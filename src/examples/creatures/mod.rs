@@ -1,7 +1,8 @@
 //! Mythological creatures from various cultures
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Creature, CreatureType};
+use crate::examples::motifs::add_motif_tags;
 
 /// Create an ontology focused on mythological creatures from various cultures
 pub fn create_creatures_ontology() -> MythOntology {
@@ -25,19 +26,22 @@ pub fn create_creatures_ontology() -> MythOntology {
     // Add Slavic creatures
     add_slavic_creatures(&mut ontology);
     
+    // Tag story motifs
+    add_motif_tags(&mut ontology);
+    
     ontology
 }
 
 /// Add Greek mythological creatures
 fn add_greek_creatures(ontology: &mut MythOntology) {
-    let greek_source = Source {
+    let greek_source = ontology.add_source(Source {
         title: "Greek Mythological Bestiary".to_string(),
         author: None,
-        year: Some(-700),
+        date: Some(HistoricalDate::Year(-700)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Greek mythological creatures".to_string()),
-    };
+    });
     
     // Minotaur
     let mut minotaur = Creature::new(
@@ -102,11 +106,11 @@ fn add_greek_creatures(ontology: &mut MythOntology) {
     let mut chimera_entity = MythEntity::Creature(chimera);
     let mut siren_entity = MythEntity::Creature(siren);
     
-    minotaur_entity.metadata_mut().add_source(greek_source.clone());
-    cerberus_entity.metadata_mut().add_source(greek_source.clone());
-    medusa_entity.metadata_mut().add_source(greek_source.clone());
-    chimera_entity.metadata_mut().add_source(greek_source.clone());
-    siren_entity.metadata_mut().add_source(greek_source);
+    minotaur_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    cerberus_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    medusa_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    chimera_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    siren_entity.metadata_mut().add_citation(Citation::new(greek_source));
     
     ontology.add_entity(minotaur_entity);
     ontology.add_entity(cerberus_entity);
@@ -117,14 +121,14 @@ fn add_greek_creatures(ontology: &mut MythOntology) {
 
 /// Add Norse mythological creatures
 fn add_norse_creatures(ontology: &mut MythOntology) {
-    let norse_source = Source {
+    let norse_source = ontology.add_source(Source {
         title: "Norse Mythological Bestiary".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Norse mythological creatures".to_string()),
-    };
+    });
     
     // Jormungandr (World Serpent)
     let mut jormungandr = Creature::new(
@@ -167,9 +171,9 @@ fn add_norse_creatures(ontology: &mut MythOntology) {
     let mut fenrir_entity = MythEntity::Creature(fenrir);
     let mut draugr_entity = MythEntity::Creature(draugr);
     
-    jormungandr_entity.metadata_mut().add_source(norse_source.clone());
-    fenrir_entity.metadata_mut().add_source(norse_source.clone());
-    draugr_entity.metadata_mut().add_source(norse_source);
+    jormungandr_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    fenrir_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    draugr_entity.metadata_mut().add_citation(Citation::new(norse_source));
     
     ontology.add_entity(jormungandr_entity);
     ontology.add_entity(fenrir_entity);
@@ -178,14 +182,14 @@ fn add_norse_creatures(ontology: &mut MythOntology) {
 
 /// Add Celtic mythological creatures
 fn add_celtic_creatures(ontology: &mut MythOntology) {
-    let celtic_source = Source {
+    let celtic_source = ontology.add_source(Source {
         title: "Celtic Mythological Bestiary".to_string(),
         author: None,
-        year: Some(800),
+        date: Some(HistoricalDate::Year(800)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Celtic mythological creatures".to_string()),
-    };
+    });
     
     // Selkie
     let mut selkie = Creature::new(
@@ -228,9 +232,9 @@ fn add_celtic_creatures(ontology: &mut MythOntology) {
     let mut each_uisge_entity = MythEntity::Creature(each_uisge);
     let mut banshee_entity = MythEntity::Creature(banshee);
     
-    selkie_entity.metadata_mut().add_source(celtic_source.clone());
-    each_uisge_entity.metadata_mut().add_source(celtic_source.clone());
-    banshee_entity.metadata_mut().add_source(celtic_source);
+    selkie_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    each_uisge_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    banshee_entity.metadata_mut().add_citation(Citation::new(celtic_source));
     
     ontology.add_entity(selkie_entity);
     ontology.add_entity(each_uisge_entity);
@@ -239,14 +243,14 @@ fn add_celtic_creatures(ontology: &mut MythOntology) {
 
 /// Add Egyptian mythological creatures
 fn add_egyptian_creatures(ontology: &mut MythOntology) {
-    let egyptian_source = Source {
+    let egyptian_source = ontology.add_source(Source {
         title: "Egyptian Mythological Bestiary".to_string(),
         author: None,
-        year: Some(-2000),
+        date: Some(HistoricalDate::Year(-2000)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Egyptian mythological creatures".to_string()),
-    };
+    });
     
     // Sphinx
     let mut sphinx = Creature::new(
@@ -275,8 +279,8 @@ fn add_egyptian_creatures(ontology: &mut MythOntology) {
     let mut sphinx_entity = MythEntity::Creature(sphinx);
     let mut ammit_entity = MythEntity::Creature(ammit);
     
-    sphinx_entity.metadata_mut().add_source(egyptian_source.clone());
-    ammit_entity.metadata_mut().add_source(egyptian_source);
+    sphinx_entity.metadata_mut().add_citation(Citation::new(egyptian_source.clone()));
+    ammit_entity.metadata_mut().add_citation(Citation::new(egyptian_source));
     
     ontology.add_entity(sphinx_entity);
     ontology.add_entity(ammit_entity);
@@ -284,14 +288,14 @@ fn add_egyptian_creatures(ontology: &mut MythOntology) {
 
 /// Add East Asian mythological creatures
 fn add_east_asian_creatures(ontology: &mut MythOntology) {
-    let asian_source = Source {
+    let asian_source = ontology.add_source(Source {
         title: "East Asian Mythological Bestiary".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of East Asian mythological creatures".to_string()),
-    };
+    });
     
     // Long (Chinese Dragon)
     let mut long = Creature::new(
@@ -336,9 +340,9 @@ fn add_east_asian_creatures(ontology: &mut MythOntology) {
     let mut kitsune_entity = MythEntity::Creature(kitsune);
     let mut jiangshi_entity = MythEntity::Creature(jiangshi);
     
-    long_entity.metadata_mut().add_source(asian_source.clone());
-    kitsune_entity.metadata_mut().add_source(asian_source.clone());
-    jiangshi_entity.metadata_mut().add_source(asian_source);
+    long_entity.metadata_mut().add_citation(Citation::new(asian_source.clone()));
+    kitsune_entity.metadata_mut().add_citation(Citation::new(asian_source.clone()));
+    jiangshi_entity.metadata_mut().add_citation(Citation::new(asian_source));
     
     ontology.add_entity(long_entity);
     ontology.add_entity(kitsune_entity);
@@ -347,14 +351,14 @@ fn add_east_asian_creatures(ontology: &mut MythOntology) {
 
 /// Add Slavic mythological creatures
 fn add_slavic_creatures(ontology: &mut MythOntology) {
-    let slavic_source = Source {
+    let slavic_source = ontology.add_source(Source {
         title: "Slavic Mythological Bestiary".to_string(),
         author: None,
-        year: Some(1000),
+        date: Some(HistoricalDate::Year(1000)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Slavic mythological creatures".to_string()),
-    };
+    });
     
     // Baba Yaga
     let mut baba_yaga = Creature::new(
@@ -398,9 +402,9 @@ fn add_slavic_creatures(ontology: &mut MythOntology) {
     let mut vodyanoy_entity = MythEntity::Creature(vodyanoy);
     let mut firebird_entity = MythEntity::Creature(firebird);
     
-    baba_yaga_entity.metadata_mut().add_source(slavic_source.clone());
-    vodyanoy_entity.metadata_mut().add_source(slavic_source.clone());
-    firebird_entity.metadata_mut().add_source(slavic_source);
+    baba_yaga_entity.metadata_mut().add_citation(Citation::new(slavic_source.clone()));
+    vodyanoy_entity.metadata_mut().add_citation(Citation::new(slavic_source.clone()));
+    firebird_entity.metadata_mut().add_citation(Citation::new(slavic_source));
     
     ontology.add_entity(baba_yaga_entity);
     ontology.add_entity(vodyanoy_entity);
@@ -1,10 +1,13 @@
 //! Egyptian mythology example ontology
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Deity, Location, Concept};
 use crate::entities::{Gender, DeityImportance, LocationType, ConceptType};
 use crate::cultural::{Pantheon, Culture, TimePeriod};
 use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+use crate::examples::narratives::add_narratives;
+use crate::examples::attestations::add_attestations;
+use crate::examples::labels::add_labels;
 
 /// Create a comprehensive Egyptian mythology ontology
 pub fn create_egyptian_ontology() -> MythOntology {
@@ -28,20 +31,29 @@ pub fn create_egyptian_ontology() -> MythOntology {
     // Add family relationships
     add_family_relationships(&mut ontology);
     
+    // Add myths
+    add_narratives(&mut ontology, "Egyptian");
+    
+    // Add attestations
+    add_attestations(&mut ontology);
+    
+    // Add names in Egyptian and Greek
+    add_labels(&mut ontology);
+    
     ontology
 }
 
 /// Add Egyptian deities to the ontology
 fn add_egyptian_deities(ontology: &mut MythOntology) {
     // Primary source for Egyptian deities
-    let egyptian_source = Source {
+    let egyptian_source = ontology.add_source(Source {
         title: "Book of the Dead".to_string(),
         author: None,
-        year: Some(-1550),
+        date: Some(HistoricalDate::circa(-1550)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Egyptian funerary texts".to_string()),
-    };
+    });
     
     // Ra - Sun God
     let mut ra = Deity::new(
@@ -124,11 +136,11 @@ fn add_egyptian_deities(ontology: &mut MythOntology) {
     let mut set_entity = MythEntity::Deity(set);
     
     // Add source to metadata
-    ra_entity.metadata_mut().add_source(egyptian_source.clone());
-    osiris_entity.metadata_mut().add_source(egyptian_source.clone());
-    isis_entity.metadata_mut().add_source(egyptian_source.clone());
-    horus_entity.metadata_mut().add_source(egyptian_source.clone());
-    set_entity.metadata_mut().add_source(egyptian_source);
+    ra_entity.metadata_mut().add_citation(Citation::new(egyptian_source.clone()));
+    osiris_entity.metadata_mut().add_citation(Citation::new(egyptian_source.clone()));
+    isis_entity.metadata_mut().add_citation(Citation::new(egyptian_source.clone()));
+    horus_entity.metadata_mut().add_citation(Citation::new(egyptian_source.clone()));
+    set_entity.metadata_mut().add_citation(Citation::new(egyptian_source));
     
     // Add to ontology
     ontology.add_entity(ra_entity);
@@ -221,22 +233,22 @@ fn add_egyptian_culture(ontology: &mut MythOntology) {
     // Add time periods
     egyptian_culture.add_time_period(TimePeriod {
         name: "Old Kingdom".to_string(),
-        start_year: Some(-2686),
-        end_year: Some(-2181),
+        start: Some(HistoricalDate::circa(-2686)),
+        end: Some(HistoricalDate::circa(-2181)),
         description: Some("Period of prosperity and stability known for pyramid-building".to_string()),
     });
     
     egyptian_culture.add_time_period(TimePeriod {
         name: "Middle Kingdom".to_string(),
-        start_year: Some(-2055),
-        end_year: Some(-1650),
+        start: Some(HistoricalDate::circa(-2055)),
+        end: Some(HistoricalDate::circa(-1650)),
         description: Some("Period of reunification and cultural flourishing".to_string()),
     });
     
     egyptian_culture.add_time_period(TimePeriod {
         name: "New Kingdom".to_string(),
-        start_year: Some(-1550),
-        end_year: Some(-1069),
+        start: Some(HistoricalDate::circa(-1550)),
+        end: Some(HistoricalDate::circa(-1069)),
         description: Some("Period of Egyptian imperial expansion and increased international contacts".to_string()),
     });
     
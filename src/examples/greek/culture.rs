@@ -1,4 +1,4 @@
-use crate::core::{MythOntology, MythEntity};
+use crate::core::{MythOntology, MythEntity, HistoricalDate};
 use crate::cultural::{Culture, TimePeriod, MythologicalRegion};
 
 /// Add Greek culture and related entities to the ontology
@@ -22,36 +22,36 @@ pub fn add_greek_culture(ontology: &mut MythOntology) {
     // Add time periods
     greek_culture.add_time_period(TimePeriod {
         name: "Mycenaean Period".to_string(),
-        start_year: Some(-1600),
-        end_year: Some(-1100),
+        start: Some(HistoricalDate::circa(-1600)),
+        end: Some(HistoricalDate::circa(-1100)),
         description: Some("The period of Greek history at the end of the Bronze Age, before the Greek Dark Ages".to_string()),
     });
     
     greek_culture.add_time_period(TimePeriod {
         name: "Greek Dark Ages".to_string(),
-        start_year: Some(-1100),
-        end_year: Some(-800),
+        start: Some(HistoricalDate::circa(-1100)),
+        end: Some(HistoricalDate::Year(-800)),
         description: Some("The period of Greek history from the collapse of the Mycenaean palaces to the beginning of the Archaic period".to_string()),
     });
     
     greek_culture.add_time_period(TimePeriod {
         name: "Archaic Period".to_string(),
-        start_year: Some(-800),
-        end_year: Some(-480),
+        start: Some(HistoricalDate::Year(-800)),
+        end: Some(HistoricalDate::Year(-480)),
         description: Some("Period of ancient Greek history from the end of the Greek Dark Ages to the Persian Wars".to_string()),
     });
     
     greek_culture.add_time_period(TimePeriod {
         name: "Classical Period".to_string(),
-        start_year: Some(-480),
-        end_year: Some(-323),
+        start: Some(HistoricalDate::Year(-480)),
+        end: Some(HistoricalDate::Year(-323)),
         description: Some("Period of ancient Greek history from the Persian Wars to the death of Alexander the Great".to_string()),
     });
     
     greek_culture.add_time_period(TimePeriod {
         name: "Hellenistic Period".to_string(),
-        start_year: Some(-323),
-        end_year: Some(-31),
+        start: Some(HistoricalDate::Year(-323)),
+        end: Some(HistoricalDate::Year(-31)),
         description: Some("Period of ancient Greek history from the death of Alexander the Great to the Battle of Actium".to_string()),
     });
     
@@ -1,4 +1,4 @@
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Deity, Hero, Creature, Artifact, Location, Concept};
 use crate::entities::{Gender, DeityImportance, HeroOrigin, CreatureType, ArtifactType, LocationType, ConceptType};
 
@@ -26,14 +26,14 @@ pub fn add_greek_entities(ontology: &mut MythOntology) {
 /// Add Greek deities to the ontology
 fn add_greek_deities(ontology: &mut MythOntology) {
     // Primary source for Greek deities
-    let hesiod_source = Source {
+    let hesiod_source = ontology.add_source(Source {
         title: "Theogony".to_string(),
         author: Some("Hesiod".to_string()),
-        year: Some(-700),
+        date: Some(HistoricalDate::circa(-700)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Greek poem describing the origins of the gods".to_string()),
-    };
+    });
     
     // Zeus - King of the gods
     let mut zeus = Deity::new(
@@ -49,7 +49,6 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     zeus.set_gender(Gender::Male);
     zeus.set_importance(DeityImportance::Supreme);
     zeus.set_pantheon("Olympian");
-    zeus.add_alternative_name("Jupiter"); // Roman equivalent
     
     // Hera - Queen of the gods
     let mut hera = Deity::new(
@@ -64,7 +63,6 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     hera.set_gender(Gender::Female);
     hera.set_importance(DeityImportance::Major);
     hera.set_pantheon("Olympian");
-    hera.add_alternative_name("Juno"); // Roman equivalent
     
     // Athena - Goddess of wisdom
     let mut athena = Deity::new(
@@ -79,7 +77,6 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     athena.set_gender(Gender::Female);
     athena.set_importance(DeityImportance::Major);
     athena.set_pantheon("Olympian");
-    athena.add_alternative_name("Minerva"); // Roman equivalent
     
     // Poseidon - God of the sea
     let mut poseidon = Deity::new(
@@ -94,7 +91,6 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     poseidon.set_gender(Gender::Male);
     poseidon.set_importance(DeityImportance::Major);
     poseidon.set_pantheon("Olympian");
-    poseidon.add_alternative_name("Neptune"); // Roman equivalent
     
     // Apollo - God of music, arts, and prophecy
     let mut apollo = Deity::new(
@@ -111,19 +107,58 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     apollo.set_importance(DeityImportance::Major);
     apollo.set_pantheon("Olympian");
     
+    // Aphrodite - Goddess of love, whose parentage Homer and Hesiod tell differently
+    let mut aphrodite = Deity::new(
+        "Aphrodite",
+        "Goddess of love, beauty, desire, and procreation.",
+        "Greek"
+    );
+    aphrodite.add_domain("Love");
+    aphrodite.add_domain("Beauty");
+    aphrodite.add_domain("Desire");
+    aphrodite.set_gender(Gender::Female);
+    aphrodite.set_importance(DeityImportance::Major);
+    aphrodite.set_pantheon("Olympian");
+    
+    // Dione - Titaness, mother of Aphrodite in Homer
+    let mut dione = Deity::new(
+        "Dione",
+        "Titaness and oracular goddess worshipped with Zeus at Dodona.",
+        "Greek"
+    );
+    dione.add_domain("Prophecy");
+    dione.set_gender(Gender::Female);
+    dione.set_importance(DeityImportance::Minor);
+    
+    // Ouranos - Primordial sky
+    let mut ouranos = Deity::new(
+        "Ouranos",
+        "Primordial god of the sky, overthrown and castrated by his son Cronus.",
+        "Greek"
+    );
+    ouranos.add_domain("Sky");
+    ouranos.set_gender(Gender::Male);
+    ouranos.set_importance(DeityImportance::Major);
+    
     // Convert to MythEntity enum and add source metadata
     let mut zeus_entity = MythEntity::Deity(zeus);
     let mut hera_entity = MythEntity::Deity(hera);
     let mut athena_entity = MythEntity::Deity(athena);
     let mut poseidon_entity = MythEntity::Deity(poseidon);
     let mut apollo_entity = MythEntity::Deity(apollo);
+    let mut aphrodite_entity = MythEntity::Deity(aphrodite);
+    let mut dione_entity = MythEntity::Deity(dione);
+    let mut ouranos_entity = MythEntity::Deity(ouranos);
     
     // Add source to metadata
-    zeus_entity.metadata_mut().add_source(hesiod_source.clone());
-    hera_entity.metadata_mut().add_source(hesiod_source.clone());
-    athena_entity.metadata_mut().add_source(hesiod_source.clone());
-    poseidon_entity.metadata_mut().add_source(hesiod_source.clone());
-    apollo_entity.metadata_mut().add_source(hesiod_source);
+    zeus_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    hera_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    athena_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    poseidon_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    apollo_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    aphrodite_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    dione_entity.metadata_mut().add_citation(Citation::new(hesiod_source.clone()));
+    ouranos_entity.metadata_mut().add_citation(Citation::new(hesiod_source));
     
     // Add to ontology
     ontology.add_entity(zeus_entity);
@@ -131,18 +166,21 @@ fn add_greek_deities(ontology: &mut MythOntology) {
     ontology.add_entity(athena_entity);
     ontology.add_entity(poseidon_entity);
     ontology.add_entity(apollo_entity);
+    ontology.add_entity(aphrodite_entity);
+    ontology.add_entity(dione_entity);
+    ontology.add_entity(ouranos_entity);
 }
 
 /// Add Greek heroes to the ontology
 fn add_greek_heroes(ontology: &mut MythOntology) {
-    let homer_source = Source {
+    let homer_source = ontology.add_source(Source {
         title: "Iliad & Odyssey".to_string(),
         author: Some("Homer".to_string()),
-        year: Some(-750),
+        date: Some(HistoricalDate::circa(-750)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Greek epic poems depicting the Trojan War and its aftermath".to_string()),
-    };
+    });
     
     // Heracles (Hercules)
     let mut heracles = Hero::new(
@@ -186,9 +224,9 @@ fn add_greek_heroes(ontology: &mut MythOntology) {
     let mut odysseus_entity = MythEntity::Hero(odysseus);
     
     // Add source to metadata
-    heracles_entity.metadata_mut().add_source(homer_source.clone());
-    perseus_entity.metadata_mut().add_source(homer_source.clone());
-    odysseus_entity.metadata_mut().add_source(homer_source);
+    heracles_entity.metadata_mut().add_citation(Citation::new(homer_source.clone()));
+    perseus_entity.metadata_mut().add_citation(Citation::new(homer_source.clone()));
+    odysseus_entity.metadata_mut().add_citation(Citation::new(homer_source));
     
     // Add to ontology
     ontology.add_entity(heracles_entity);
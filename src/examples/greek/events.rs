@@ -0,0 +1,102 @@
+use crate::core::{MythOntology, MythEntity};
+use crate::cultural::MythologicalEra;
+use crate::entities::Event;
+
+/// Add Greek eras and the events of the mythic chronology to the ontology
+pub fn add_greek_events(ontology: &mut MythOntology) {
+    let entity_id = |name: &str| {
+        ontology.entities_named(name)
+            .find(|entity| entity.name() == name)
+            .map(|entity| entity.id().clone())
+    };
+    
+    // Eras of the Greek mythic past
+    let mut age_of_titans = MythologicalEra::new(
+        "Age of the Titans",
+        "The rule of Cronus and the Titans, before the Olympians took power.",
+        "Greek"
+    );
+    age_of_titans.set_sequence_order(1);
+    age_of_titans.add_characteristic("Rule of Cronus");
+    
+    let mut age_of_heroes = MythologicalEra::new(
+        "Age of Heroes",
+        "The age of the demigods and great heroes, from Perseus to the return from Troy.",
+        "Greek"
+    );
+    age_of_heroes.set_sequence_order(2);
+    age_of_heroes.add_characteristic("Gods mingle with mortals");
+    
+    // The war of the gods
+    let mut titanomachy = Event::new(
+        "Titanomachy",
+        "Ten years of war in which Zeus and his siblings overthrow Cronus and the Titans.",
+        "Greek"
+    );
+    for name in ["Zeus", "Poseidon", "Hera"] {
+        if let Some(id) = entity_id(name) {
+            titanomachy.add_participant(id);
+        }
+    }
+    if let Some(olympus_id) = entity_id("Mount Olympus") {
+        titanomachy.set_location(olympus_id);
+    }
+    age_of_titans.set_ending(&titanomachy);
+    
+    // Heroic deeds
+    let mut quest_of_perseus = Event::new(
+        "Quest of Perseus",
+        "Perseus beheads the Gorgon Medusa and rescues Andromeda on his way home.",
+        "Greek"
+    );
+    quest_of_perseus.set_era(age_of_heroes.id.clone());
+    for name in ["Perseus", "Medusa", "Athena"] {
+        if let Some(id) = entity_id(name) {
+            quest_of_perseus.add_participant(id);
+        }
+    }
+    
+    let mut voyage_of_argo = Event::new(
+        "Voyage of the Argo",
+        "Jason and the Argonauts sail to Colchis to fetch the Golden Fleece.",
+        "Greek"
+    );
+    voyage_of_argo.set_era(age_of_heroes.id.clone());
+    voyage_of_argo.add_after(quest_of_perseus.id.clone());
+    if let Some(heracles_id) = entity_id("Heracles") {
+        voyage_of_argo.add_participant(heracles_id);
+    }
+    
+    let mut trojan_war = Event::new(
+        "Trojan War",
+        "The Achaeans besiege Troy for ten years to recover Helen, until the city falls to the wooden horse.",
+        "Greek"
+    );
+    trojan_war.set_era(age_of_heroes.id.clone());
+    trojan_war.add_after(voyage_of_argo.id.clone());
+    for name in ["Odysseus", "Athena", "Apollo", "Poseidon", "Hera"] {
+        if let Some(id) = entity_id(name) {
+            trojan_war.add_participant(id);
+        }
+    }
+    
+    let mut return_of_odysseus = Event::new(
+        "Return of Odysseus",
+        "Having blinded Poseidon's son, Odysseus wanders for ten years before reaching Ithaca.",
+        "Greek"
+    );
+    return_of_odysseus.set_era(age_of_heroes.id.clone());
+    return_of_odysseus.add_cause(trojan_war.id.clone());
+    trojan_war.add_consequence(return_of_odysseus.id.clone());
+    for name in ["Odysseus", "Poseidon", "Athena"] {
+        if let Some(id) = entity_id(name) {
+            return_of_odysseus.add_participant(id);
+        }
+    }
+    
+    ontology.add_entity(MythEntity::MythologicalEra(age_of_titans));
+    ontology.add_entity(MythEntity::MythologicalEra(age_of_heroes));
+    for event in [titanomachy, quest_of_perseus, voyage_of_argo, trojan_war, return_of_odysseus] {
+        ontology.add_entity(MythEntity::Event(event));
+    }
+}
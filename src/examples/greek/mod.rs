@@ -4,19 +4,35 @@ mod entities;
 mod relationships;
 mod pantheons;
 mod culture;
+mod events;
+mod roman;
+mod variants;
 
 use crate::core::MythOntology;
+use crate::examples::motifs::add_motif_tags;
+use crate::examples::narratives::add_narratives;
+use crate::examples::attestations::add_attestations;
+use crate::examples::labels::add_labels;
 
 /// Create a comprehensive Greek mythology ontology including deities, heroes, locations,
-/// artifacts, creatures, and concepts, with relationships between them.
+/// artifacts, creatures, and concepts, with relationships between them, the Roman
+/// deities identified with the Olympians, and the competing accounts of Aphrodite's
+/// parentage and Cerberus's heads.
 pub fn create_greek_ontology() -> MythOntology {
     let mut ontology = MythOntology::new();
     
-    // Add all entities, relationships, and cultural context
+    // Add all entities, relationships, cultural context, and events
     entities::add_greek_entities(&mut ontology);
     relationships::add_greek_relationships(&mut ontology);
     pantheons::add_greek_pantheons(&mut ontology);
     culture::add_greek_culture(&mut ontology);
+    events::add_greek_events(&mut ontology);
+    roman::add_roman_equivalents(&mut ontology);
+    variants::add_greek_variants(&mut ontology);
+    add_attestations(&mut ontology);
+    add_labels(&mut ontology);
+    add_narratives(&mut ontology, "Greek");
+    add_motif_tags(&mut ontology);
     
     ontology
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
+use crate::entities::{Deity, Gender, DeityImportance};
+use crate::relationships::{SyncretismRelationship, SyncretismType};
+
+/// Add the Roman deities identified with the Olympians, linked to their Greek
+/// counterparts by interpretatio romana
+pub fn add_roman_equivalents(ontology: &mut MythOntology) {
+    let cicero_source = ontology.add_source(Source {
+        title: "De Natura Deorum".to_string(),
+        author: Some("Cicero".to_string()),
+        date: Some(HistoricalDate::Year(-45)),
+        source_type: SourceType::PrimaryText,
+        url: None,
+        notes: Some("Roman dialogue on the nature of the gods, discussing them under both Greek and Latin names".to_string()),
+    });
+    
+    let roman_deities = [
+        ("Jupiter", "Zeus", "King of the Roman gods, god of the sky and thunder, and protector of the Roman state.", Gender::Male, DeityImportance::Supreme, ["Sky", "Thunder"]),
+        ("Juno", "Hera", "Queen of the Roman gods and protector of women, marriage, and the state.", Gender::Female, DeityImportance::Major, ["Marriage", "Women"]),
+        ("Minerva", "Athena", "Roman goddess of wisdom, crafts, and strategic warfare.", Gender::Female, DeityImportance::Major, ["Wisdom", "Crafts"]),
+        ("Neptune", "Poseidon", "Roman god of fresh water and the sea.", Gender::Male, DeityImportance::Major, ["Sea", "Horses"]),
+    ];
+    
+    for (name, greek_name, description, gender, importance, domains) in roman_deities {
+        let Some(greek_id) = ontology.entities_named(greek_name)
+            .find(|entity| entity.name() == greek_name)
+            .map(|entity| entity.id().clone())
+        else {
+            continue;
+        };
+        
+        let mut deity = Deity::new(name, description, "Roman");
+        for domain in domains {
+            deity.add_domain(domain);
+        }
+        deity.set_gender(gender);
+        deity.set_importance(importance);
+        let roman_id = deity.id.clone();
+        ontology.add_entity(MythEntity::Deity(deity));
+        
+        let equivalence = SyncretismRelationship::builder(
+            &format!("{} as {}", name, greek_name),
+            &format!("The Romans identified their {} with the Greek {}.", name, greek_name),
+            roman_id,
+            greek_id,
+            SyncretismType::Equivalence
+        )
+        .strength(0.9)
+        .attestation(Citation::new(cicero_source.clone()).book("2"))
+        .build();
+        
+        ontology.add_entity(MythEntity::SyncretismRelationship(equivalence));
+    }
+}
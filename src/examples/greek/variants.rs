@@ -0,0 +1,78 @@
+use crate::core::{Citation, Claim, HistoricalDate, MythEntity, MythOntology, Source, SourceId, SourceType};
+use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+
+/// Add the Greek myths that different sources tell differently, as competing claims
+pub fn add_greek_variants(ontology: &mut MythOntology) {
+    let homer = find_source(ontology, "Iliad & Odyssey");
+    let hesiod = find_source(ontology, "Theogony");
+    let bibliotheca = ontology.add_source(Source {
+        title: "Bibliotheca".to_string(),
+        author: Some("Pseudo-Apollodorus".to_string()),
+        date: Some(HistoricalDate::Century(2)),
+        source_type: SourceType::PrimaryText,
+        url: None,
+        notes: Some("Compendium of Greek myths and heroic legends".to_string()),
+    });
+    let horace = ontology.add_source(Source {
+        title: "Odes".to_string(),
+        author: Some("Horace".to_string()),
+        date: Some(HistoricalDate::circa(-23)),
+        source_type: SourceType::LiteraryText,
+        url: None,
+        notes: None,
+    });
+    
+    // Homer makes Aphrodite the daughter of Zeus and Dione; in Hesiod she is born
+    // from the sea foam around the severed genitals of Ouranos
+    if let (Some(homer), Some(hesiod)) = (homer, hesiod) {
+        let homeric = Claim::new(Citation::new(homer).book("Iliad 5").line("370-417")).tradition("Homeric");
+        let hesiodic = Claim::new(Citation::new(hesiod.clone()).line("188-206")).tradition("Hesiodic");
+        for (parent, claim) in [("Zeus", &homeric), ("Dione", &homeric), ("Ouranos", &hesiodic)] {
+            add_parentage_of_aphrodite(ontology, parent, claim.clone());
+        }
+        
+        let cerberus_id = ontology.entities_named("Cerberus")
+            .find(|entity| entity.name() == "Cerberus")
+            .map(|entity| entity.id().clone());
+        if let Some(cerberus_id) = cerberus_id
+            && let Some(mut cerberus) = ontology.get_entity_mut(&cerberus_id)
+        {
+            let metadata = cerberus.metadata_mut();
+            metadata.add_claim("heads", "50", Claim::new(Citation::new(hesiod).line("310-312")).tradition("Hesiodic"));
+            metadata.add_claim("heads", "3", Claim::new(Citation::new(bibliotheca).book("2").chapter("5.12")));
+            metadata.add_claim(
+                "heads",
+                "100",
+                Claim::new(Citation::new(horace).book("2").chapter("13")).note("A hundred serpent heads"),
+            );
+        }
+    }
+}
+
+fn add_parentage_of_aphrodite(ontology: &mut MythOntology, parent: &str, claim: Claim) {
+    let entity_id = |name: &str| {
+        ontology.entities_named(name)
+            .find(|entity| entity.name() == name)
+            .map(|entity| entity.id().clone())
+    };
+    let (Some(parent_id), Some(aphrodite_id)) = (entity_id(parent), entity_id("Aphrodite")) else {
+        return;
+    };
+    let relationship = FamilyRelationship::new(
+        &format!("{} parent of Aphrodite", parent),
+        &format!("{} is a parent of Aphrodite.", parent),
+        parent_id,
+        aphrodite_id,
+        FamilyRelationshipType::Parent,
+    );
+    let mut entity = MythEntity::FamilyRelationship(relationship);
+    entity.metadata_mut().set_variant("parentage of Aphrodite", claim);
+    ontology.add_entity(entity);
+}
+
+/// Find a source the ontology already cites by its exact title
+fn find_source(ontology: &MythOntology, title: &str) -> Option<SourceId> {
+    ontology.bibliography().find_by_title(title).into_iter()
+        .find(|(_, source)| source.title == title)
+        .map(|(id, _)| id.clone())
+}
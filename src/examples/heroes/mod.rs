@@ -1,7 +1,8 @@
 //! Mythological heroes from various cultures
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Hero, HeroOrigin};
+use crate::examples::motifs::add_motif_tags;
 
 /// Create an ontology focused on mythological heroes from various cultures
 pub fn create_heroes_ontology() -> MythOntology {
@@ -25,19 +26,22 @@ pub fn create_heroes_ontology() -> MythOntology {
     // Add Hindu heroes
     add_hindu_heroes(&mut ontology);
     
+    // Tag story motifs
+    add_motif_tags(&mut ontology);
+    
     ontology
 }
 
 /// Add Greek mythological heroes
 fn add_greek_heroes(ontology: &mut MythOntology) {
-    let greek_source = Source {
+    let greek_source = ontology.add_source(Source {
         title: "Greek Heroic Epics".to_string(),
         author: None,
-        year: Some(-700),
+        date: Some(HistoricalDate::Year(-700)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of ancient Greek heroic myths".to_string()),
-    };
+    });
     
     // Heracles (Hercules)
     let mut heracles = Hero::new(
@@ -92,10 +96,10 @@ fn add_greek_heroes(ontology: &mut MythOntology) {
     let mut odysseus_entity = MythEntity::Hero(odysseus);
     let mut theseus_entity = MythEntity::Hero(theseus);
     
-    heracles_entity.metadata_mut().add_source(greek_source.clone());
-    achilles_entity.metadata_mut().add_source(greek_source.clone());
-    odysseus_entity.metadata_mut().add_source(greek_source.clone());
-    theseus_entity.metadata_mut().add_source(greek_source);
+    heracles_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    achilles_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    odysseus_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    theseus_entity.metadata_mut().add_citation(Citation::new(greek_source));
     
     ontology.add_entity(heracles_entity);
     ontology.add_entity(achilles_entity);
@@ -105,14 +109,14 @@ fn add_greek_heroes(ontology: &mut MythOntology) {
 
 /// Add Norse mythological heroes
 fn add_norse_heroes(ontology: &mut MythOntology) {
-    let norse_source = Source {
+    let norse_source = ontology.add_source(Source {
         title: "Norse Sagas".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Norse heroic sagas".to_string()),
-    };
+    });
     
     // Sigurd/Siegfried
     let mut sigurd = Hero::new(
@@ -152,9 +156,9 @@ fn add_norse_heroes(ontology: &mut MythOntology) {
     let mut beowulf_entity = MythEntity::Hero(beowulf);
     let mut ragnar_entity = MythEntity::Hero(ragnar);
     
-    sigurd_entity.metadata_mut().add_source(norse_source.clone());
-    beowulf_entity.metadata_mut().add_source(norse_source.clone());
-    ragnar_entity.metadata_mut().add_source(norse_source);
+    sigurd_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    beowulf_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    ragnar_entity.metadata_mut().add_citation(Citation::new(norse_source));
     
     ontology.add_entity(sigurd_entity);
     ontology.add_entity(beowulf_entity);
@@ -163,14 +167,14 @@ fn add_norse_heroes(ontology: &mut MythOntology) {
 
 /// Add Celtic mythological heroes
 fn add_celtic_heroes(ontology: &mut MythOntology) {
-    let celtic_source = Source {
+    let celtic_source = ontology.add_source(Source {
         title: "Celtic Mythological Cycles".to_string(),
         author: None,
-        year: Some(800),
+        date: Some(HistoricalDate::Year(800)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Irish and Welsh mythological tales".to_string()),
-    };
+    });
     
     // Cú Chulainn
     let mut cu_chulainn = Hero::new(
@@ -198,8 +202,8 @@ fn add_celtic_heroes(ontology: &mut MythOntology) {
     let mut cu_chulainn_entity = MythEntity::Hero(cu_chulainn);
     let mut fionn_entity = MythEntity::Hero(fionn);
     
-    cu_chulainn_entity.metadata_mut().add_source(celtic_source.clone());
-    fionn_entity.metadata_mut().add_source(celtic_source);
+    cu_chulainn_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    fionn_entity.metadata_mut().add_citation(Citation::new(celtic_source));
     
     ontology.add_entity(cu_chulainn_entity);
     ontology.add_entity(fionn_entity);
@@ -207,14 +211,14 @@ fn add_celtic_heroes(ontology: &mut MythOntology) {
 
 /// Add Mesopotamian mythological heroes
 fn add_mesopotamian_heroes(ontology: &mut MythOntology) {
-    let mesopotamian_source = Source {
+    let mesopotamian_source = ontology.add_source(Source {
         title: "Epic of Gilgamesh".to_string(),
         author: None,
-        year: Some(-2100),
+        date: Some(HistoricalDate::Year(-2100)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Mesopotamian epic poem".to_string()),
-    };
+    });
     
     // Gilgamesh
     let mut gilgamesh = Hero::new(
@@ -243,8 +247,8 @@ fn add_mesopotamian_heroes(ontology: &mut MythOntology) {
     let mut gilgamesh_entity = MythEntity::Hero(gilgamesh);
     let mut enkidu_entity = MythEntity::Hero(enkidu);
     
-    gilgamesh_entity.metadata_mut().add_source(mesopotamian_source.clone());
-    enkidu_entity.metadata_mut().add_source(mesopotamian_source);
+    gilgamesh_entity.metadata_mut().add_citation(Citation::new(mesopotamian_source.clone()));
+    enkidu_entity.metadata_mut().add_citation(Citation::new(mesopotamian_source));
     
     ontology.add_entity(gilgamesh_entity);
     ontology.add_entity(enkidu_entity);
@@ -252,23 +256,23 @@ fn add_mesopotamian_heroes(ontology: &mut MythOntology) {
 
 /// Add East Asian mythological heroes
 fn add_east_asian_heroes(ontology: &mut MythOntology) {
-    let chinese_source = Source {
+    let chinese_source = ontology.add_source(Source {
         title: "Journey to the West".to_string(),
         author: Some("Wu Cheng'en".to_string()),
-        year: Some(1592),
+        date: Some(HistoricalDate::Year(1592)),
         source_type: SourceType::LiteraryText,
         url: None,
         notes: Some("Chinese novel, one of the Four Great Classical Novels".to_string()),
-    };
+    });
     
-    let japanese_source = Source {
+    let japanese_source = ontology.add_source(Source {
         title: "Japanese Legendary Cycles".to_string(),
         author: None,
-        year: None,
+        date: None,
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Japanese historical and mythological tales".to_string()),
-    };
+    });
     
     // Sun Wukong (Monkey King)
     let mut sun_wukong = Hero::new(
@@ -297,8 +301,8 @@ fn add_east_asian_heroes(ontology: &mut MythOntology) {
     let mut sun_wukong_entity = MythEntity::Hero(sun_wukong);
     let mut yamato_takeru_entity = MythEntity::Hero(yamato_takeru);
     
-    sun_wukong_entity.metadata_mut().add_source(chinese_source);
-    yamato_takeru_entity.metadata_mut().add_source(japanese_source);
+    sun_wukong_entity.metadata_mut().add_citation(Citation::new(chinese_source));
+    yamato_takeru_entity.metadata_mut().add_citation(Citation::new(japanese_source));
     
     ontology.add_entity(sun_wukong_entity);
     ontology.add_entity(yamato_takeru_entity);
@@ -306,14 +310,14 @@ fn add_east_asian_heroes(ontology: &mut MythOntology) {
 
 /// Add Hindu mythological heroes
 fn add_hindu_heroes(ontology: &mut MythOntology) {
-    let hindu_source = Source {
+    let hindu_source = ontology.add_source(Source {
         title: "Mahabharata and Ramayana".to_string(),
         author: None,
-        year: Some(-400),
+        date: Some(HistoricalDate::Year(-400)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Sanskrit epics of India".to_string()),
-    };
+    });
     
     // Arjuna
     let mut arjuna = Hero::new(
@@ -355,9 +359,9 @@ fn add_hindu_heroes(ontology: &mut MythOntology) {
     let mut rama_entity = MythEntity::Hero(rama);
     let mut hanuman_entity = MythEntity::Hero(hanuman);
     
-    arjuna_entity.metadata_mut().add_source(hindu_source.clone());
-    rama_entity.metadata_mut().add_source(hindu_source.clone());
-    hanuman_entity.metadata_mut().add_source(hindu_source);
+    arjuna_entity.metadata_mut().add_citation(Citation::new(hindu_source.clone()));
+    rama_entity.metadata_mut().add_citation(Citation::new(hindu_source.clone()));
+    hanuman_entity.metadata_mut().add_citation(Citation::new(hindu_source));
     
     ontology.add_entity(arjuna_entity);
     ontology.add_entity(rama_entity);
@@ -1,6 +1,6 @@
 //! Hindu mythology example ontology
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Deity, Hero, Location, Concept};
 use crate::entities::{Gender, DeityImportance, HeroOrigin, LocationType, ConceptType};
 use crate::cultural::{Pantheon, Culture, TimePeriod};
@@ -33,23 +33,23 @@ pub fn create_hindu_ontology() -> MythOntology {
 /// Add Hindu deities to the ontology
 fn add_hindu_deities(ontology: &mut MythOntology) {
     // Primary source for Hindu deities
-    let vedic_source = Source {
+    let vedic_source = ontology.add_source(Source {
         title: "Vedas".to_string(),
         author: None,
-        year: Some(-1500),
+        date: Some(HistoricalDate::Year(-1500)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Sanskrit texts of Hinduism".to_string()),
-    };
+    });
     
-    let puranic_source = Source {
+    let puranic_source = ontology.add_source(Source {
         title: "Puranas".to_string(),
         author: None,
-        year: Some(500),
+        date: Some(HistoricalDate::Year(500)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient and medieval texts of Hinduism focusing on deity narratives".to_string()),
-    };
+    });
     
     // Brahma
     let mut brahma = Deity::new(
@@ -145,15 +145,15 @@ fn add_hindu_deities(ontology: &mut MythOntology) {
     let mut krishna_entity = MythEntity::Deity(krishna);
     
     // Add source to metadata
-    brahma_entity.metadata_mut().add_source(vedic_source.clone());
-    brahma_entity.metadata_mut().add_source(puranic_source.clone());
-    vishnu_entity.metadata_mut().add_source(vedic_source.clone());
-    vishnu_entity.metadata_mut().add_source(puranic_source.clone());
-    shiva_entity.metadata_mut().add_source(vedic_source.clone());
-    shiva_entity.metadata_mut().add_source(puranic_source.clone());
-    devi_entity.metadata_mut().add_source(puranic_source.clone());
-    ganesha_entity.metadata_mut().add_source(puranic_source.clone());
-    krishna_entity.metadata_mut().add_source(puranic_source.clone());
+    brahma_entity.metadata_mut().add_citation(Citation::new(vedic_source.clone()));
+    brahma_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
+    vishnu_entity.metadata_mut().add_citation(Citation::new(vedic_source.clone()));
+    vishnu_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
+    shiva_entity.metadata_mut().add_citation(Citation::new(vedic_source.clone()));
+    shiva_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
+    devi_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
+    ganesha_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
+    krishna_entity.metadata_mut().add_citation(Citation::new(puranic_source.clone()));
     
     // Add to ontology
     ontology.add_entity(brahma_entity);
@@ -166,14 +166,14 @@ fn add_hindu_deities(ontology: &mut MythOntology) {
 
 /// Add Hindu heroes to the ontology
 fn add_hindu_heroes(ontology: &mut MythOntology) {
-    let epic_source = Source {
+    let epic_source = ontology.add_source(Source {
         title: "Mahabharata and Ramayana".to_string(),
         author: None,
-        year: Some(-400),
+        date: Some(HistoricalDate::Year(-400)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Sanskrit epics of India".to_string()),
-    };
+    });
     
     // Arjuna
     let mut arjuna = Hero::new(
@@ -215,9 +215,9 @@ fn add_hindu_heroes(ontology: &mut MythOntology) {
     let mut rama_entity = MythEntity::Hero(rama);
     let mut hanuman_entity = MythEntity::Hero(hanuman);
     
-    arjuna_entity.metadata_mut().add_source(epic_source.clone());
-    rama_entity.metadata_mut().add_source(epic_source.clone());
-    hanuman_entity.metadata_mut().add_source(epic_source);
+    arjuna_entity.metadata_mut().add_citation(Citation::new(epic_source.clone()));
+    rama_entity.metadata_mut().add_citation(Citation::new(epic_source.clone()));
+    hanuman_entity.metadata_mut().add_citation(Citation::new(epic_source));
     
     ontology.add_entity(arjuna_entity);
     ontology.add_entity(rama_entity);
@@ -330,22 +330,22 @@ fn add_hindu_culture(ontology: &mut MythOntology) {
     // Add time periods
     hindu_culture.add_time_period(TimePeriod {
         name: "Vedic Period".to_string(),
-        start_year: Some(-1500),
-        end_year: Some(-500),
+        start: Some(HistoricalDate::Year(-1500)),
+        end: Some(HistoricalDate::Year(-500)),
         description: Some("Period when the Vedas were composed and early Hindu traditions developed".to_string()),
     });
     
     hindu_culture.add_time_period(TimePeriod {
         name: "Epic Period".to_string(),
-        start_year: Some(-500),
-        end_year: Some(500),
+        start: Some(HistoricalDate::Year(-500)),
+        end: Some(HistoricalDate::Year(500)),
         description: Some("Period of the composition of the Mahabharata, Ramayana, and early Puranas".to_string()),
     });
     
     hindu_culture.add_time_period(TimePeriod {
         name: "Puranic Period".to_string(),
-        start_year: Some(500),
-        end_year: Some(1500),
+        start: Some(HistoricalDate::Year(500)),
+        end: Some(HistoricalDate::Year(1500)),
         description: Some("Period of compilation of the Puranas and development of devotional traditions".to_string()),
     });
     
@@ -0,0 +1,61 @@
+//! Names of the example entities in their own languages and scripts
+
+use crate::core::{Label, MythId, MythOntology, NameKind};
+
+/// Labels of the example entities: entity name, label, language, script, and kind
+const LABELS: &[(&str, &str, &str, &str, NameKind)] = &[
+    // Greek, with the scholarly transliteration of the Greek
+    ("Zeus", "Ζεύς", "Ancient Greek", "Grek", NameKind::Original),
+    ("Zeus", "Zeús", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Zeus", "Κρονίδης", "Ancient Greek", "Grek", NameKind::Epithet),
+    ("Athena", "Ἀθηνᾶ", "Ancient Greek", "Grek", NameKind::Original),
+    ("Athena", "Athēnâ", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Athena", "Παλλάς", "Ancient Greek", "Grek", NameKind::Epithet),
+    ("Hera", "Ἥρα", "Ancient Greek", "Grek", NameKind::Original),
+    ("Hera", "Hḗrā", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Heracles", "Ἡρακλῆς", "Ancient Greek", "Grek", NameKind::Original),
+    ("Heracles", "Hēraklês", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Heracles", "Hercules", "Latin", "Latn", NameKind::Exonym),
+    ("Cerberus", "Κέρβερος", "Ancient Greek", "Grek", NameKind::Original),
+    ("Cerberus", "Kérberos", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Aegis", "αἰγίς", "Ancient Greek", "Grek", NameKind::Original),
+    ("Mount Olympus", "Ὄλυμπος", "Ancient Greek", "Grek", NameKind::Original),
+    ("Mount Olympus", "Ólympos", "Ancient Greek", "Latn", NameKind::Transliteration),
+    ("Xenia", "ξενία", "Ancient Greek", "Grek", NameKind::Original),
+    // Norse, in normalized Old Norse spelling and as the Old English cognates
+    ("Odin", "Óðinn", "Old Norse", "Latn", NameKind::Original),
+    ("Odin", "Wōden", "Old English", "Latn", NameKind::Exonym),
+    ("Odin", "Alfǫðr", "Old Norse", "Latn", NameKind::Epithet),
+    ("Thor", "Þórr", "Old Norse", "Latn", NameKind::Original),
+    ("Thor", "Þunor", "Old English", "Latn", NameKind::Exonym),
+    ("Thor", "Hlórriði", "Old Norse", "Latn", NameKind::Epithet),
+    ("Loki", "Loptr", "Old Norse", "Latn", NameKind::Epithet),
+    ("Mjölnir", "Mjǫllnir", "Old Norse", "Latn", NameKind::Original),
+    ("Asgard", "Ásgarðr", "Old Norse", "Latn", NameKind::Original),
+    ("Ragnarök", "Ragnarǫk", "Old Norse", "Latn", NameKind::Original),
+    // Egyptian, in hieroglyphs, Egyptological transliteration and Greek
+    ("Osiris", "𓊨𓁹", "Ancient Egyptian", "Egyp", NameKind::Original),
+    ("Osiris", "wsjr", "Ancient Egyptian", "Latn", NameKind::Transliteration),
+    ("Osiris", "Ὄσιρις", "Ancient Greek", "Grek", NameKind::Exonym),
+    ("Isis", "ꜣst", "Ancient Egyptian", "Latn", NameKind::Transliteration),
+    ("Isis", "Ἶσις", "Ancient Greek", "Grek", NameKind::Exonym),
+    ("Horus", "ḥr", "Ancient Egyptian", "Latn", NameKind::Transliteration),
+    ("Horus", "Ὧρος", "Ancient Greek", "Grek", NameKind::Exonym),
+    ("Ra", "rꜥ", "Ancient Egyptian", "Latn", NameKind::Transliteration),
+    ("Ma'at", "mꜣꜥt", "Ancient Egyptian", "Latn", NameKind::Transliteration),
+];
+
+/// Add the names the entities of an example ontology have in other languages
+pub fn add_labels(ontology: &mut MythOntology) {
+    for (name, text, language, script, kind) in LABELS {
+        let ids: Vec<MythId> = ontology.entities_named(name)
+            .filter(|entity| entity.name() == *name)
+            .map(|entity| entity.id().clone())
+            .collect();
+        for id in ids {
+            if let Some(mut entity) = ontology.get_entity_mut(&id) {
+                entity.metadata_mut().add_label(Label::new(text, language, *kind).script(script));
+            }
+        }
+    }
+}
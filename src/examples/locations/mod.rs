@@ -1,7 +1,8 @@
 //! Mythological locations from various cultures
 
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Location, LocationType};
+use crate::examples::motifs::add_motif_tags;
 
 /// Create an ontology focused on mythological locations from various cultures
 pub fn create_locations_ontology() -> MythOntology {
@@ -25,19 +26,22 @@ pub fn create_locations_ontology() -> MythOntology {
     // Add Hindu locations
     add_hindu_locations(&mut ontology);
     
+    // Tag story motifs
+    add_motif_tags(&mut ontology);
+    
     ontology
 }
 
 /// Add Greek mythological locations
 fn add_greek_locations(ontology: &mut MythOntology) {
-    let greek_source = Source {
+    let greek_source = ontology.add_source(Source {
         title: "Greek Mythological Geography".to_string(),
         author: None,
-        year: Some(-700),
+        date: Some(HistoricalDate::Year(-700)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Greek mythological locations".to_string()),
-    };
+    });
     
     // Mount Olympus
     let mut olympus = Location::new(
@@ -97,10 +101,10 @@ fn add_greek_locations(ontology: &mut MythOntology) {
     let mut elysium_entity = MythEntity::Location(elysium);
     let mut tartarus_entity = MythEntity::Location(tartarus);
     
-    olympus_entity.metadata_mut().add_source(greek_source.clone());
-    underworld_entity.metadata_mut().add_source(greek_source.clone());
-    elysium_entity.metadata_mut().add_source(greek_source.clone());
-    tartarus_entity.metadata_mut().add_source(greek_source);
+    olympus_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    underworld_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    elysium_entity.metadata_mut().add_citation(Citation::new(greek_source.clone()));
+    tartarus_entity.metadata_mut().add_citation(Citation::new(greek_source));
     
     ontology.add_entity(olympus_entity);
     ontology.add_entity(underworld_entity);
@@ -110,14 +114,14 @@ fn add_greek_locations(ontology: &mut MythOntology) {
 
 /// Add Norse mythological locations
 fn add_norse_locations(ontology: &mut MythOntology) {
-    let norse_source = Source {
+    let norse_source = ontology.add_source(Source {
         title: "Norse Mythological Cosmography".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Norse mythological locations".to_string()),
-    };
+    });
     
     // Asgard
     let mut asgard = Location::new(
@@ -175,10 +179,10 @@ fn add_norse_locations(ontology: &mut MythOntology) {
     let mut valhalla_entity = MythEntity::Location(valhalla);
     let mut yggdrasil_entity = MythEntity::Location(yggdrasil);
     
-    asgard_entity.metadata_mut().add_source(norse_source.clone());
-    midgard_entity.metadata_mut().add_source(norse_source.clone());
-    valhalla_entity.metadata_mut().add_source(norse_source.clone());
-    yggdrasil_entity.metadata_mut().add_source(norse_source);
+    asgard_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    midgard_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    valhalla_entity.metadata_mut().add_citation(Citation::new(norse_source.clone()));
+    yggdrasil_entity.metadata_mut().add_citation(Citation::new(norse_source));
     
     ontology.add_entity(asgard_entity);
     ontology.add_entity(midgard_entity);
@@ -188,14 +192,14 @@ fn add_norse_locations(ontology: &mut MythOntology) {
 
 /// Add Celtic mythological locations
 fn add_celtic_locations(ontology: &mut MythOntology) {
-    let celtic_source = Source {
+    let celtic_source = ontology.add_source(Source {
         title: "Celtic Mythological Geography".to_string(),
         author: None,
-        year: Some(800),
+        date: Some(HistoricalDate::Year(800)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Celtic mythological locations".to_string()),
-    };
+    });
     
     // Tír na nÓg
     let mut tir_na_nog = Location::new(
@@ -227,8 +231,8 @@ fn add_celtic_locations(ontology: &mut MythOntology) {
     let mut tir_na_nog_entity = MythEntity::Location(tir_na_nog);
     let mut annwn_entity = MythEntity::Location(annwn);
     
-    tir_na_nog_entity.metadata_mut().add_source(celtic_source.clone());
-    annwn_entity.metadata_mut().add_source(celtic_source);
+    tir_na_nog_entity.metadata_mut().add_citation(Citation::new(celtic_source.clone()));
+    annwn_entity.metadata_mut().add_citation(Citation::new(celtic_source));
     
     ontology.add_entity(tir_na_nog_entity);
     ontology.add_entity(annwn_entity);
@@ -236,14 +240,14 @@ fn add_celtic_locations(ontology: &mut MythOntology) {
 
 /// Add Middle Eastern mythological locations
 fn add_middle_eastern_locations(ontology: &mut MythOntology) {
-    let mesopotamian_source = Source {
+    let mesopotamian_source = ontology.add_source(Source {
         title: "Mesopotamian Mythological Geography".to_string(),
         author: None,
-        year: Some(-2000),
+        date: Some(HistoricalDate::Year(-2000)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Mesopotamian mythological locations".to_string()),
-    };
+    });
     
     // Garden of Eden
     let mut eden = Location::new(
@@ -274,8 +278,8 @@ fn add_middle_eastern_locations(ontology: &mut MythOntology) {
     let mut eden_entity = MythEntity::Location(eden);
     let mut dilmun_entity = MythEntity::Location(dilmun);
     
-    eden_entity.metadata_mut().add_source(mesopotamian_source.clone());
-    dilmun_entity.metadata_mut().add_source(mesopotamian_source);
+    eden_entity.metadata_mut().add_citation(Citation::new(mesopotamian_source.clone()));
+    dilmun_entity.metadata_mut().add_citation(Citation::new(mesopotamian_source));
     
     ontology.add_entity(eden_entity);
     ontology.add_entity(dilmun_entity);
@@ -283,14 +287,14 @@ fn add_middle_eastern_locations(ontology: &mut MythOntology) {
 
 /// Add East Asian mythological locations
 fn add_east_asian_locations(ontology: &mut MythOntology) {
-    let chinese_source = Source {
+    let chinese_source = ontology.add_source(Source {
         title: "Chinese Mythological Geography".to_string(),
         author: None,
-        year: Some(300),
+        date: Some(HistoricalDate::Year(300)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Chinese mythological locations".to_string()),
-    };
+    });
     
     // Mount Kunlun
     let mut kunlun = Location::new(
@@ -321,8 +325,8 @@ fn add_east_asian_locations(ontology: &mut MythOntology) {
     let mut kunlun_entity = MythEntity::Location(kunlun);
     let mut diyu_entity = MythEntity::Location(diyu);
     
-    kunlun_entity.metadata_mut().add_source(chinese_source.clone());
-    diyu_entity.metadata_mut().add_source(chinese_source);
+    kunlun_entity.metadata_mut().add_citation(Citation::new(chinese_source.clone()));
+    diyu_entity.metadata_mut().add_citation(Citation::new(chinese_source));
     
     ontology.add_entity(kunlun_entity);
     ontology.add_entity(diyu_entity);
@@ -330,14 +334,14 @@ fn add_east_asian_locations(ontology: &mut MythOntology) {
 
 /// Add Hindu mythological locations
 fn add_hindu_locations(ontology: &mut MythOntology) {
-    let hindu_source = Source {
+    let hindu_source = ontology.add_source(Source {
         title: "Hindu Mythological Cosmography".to_string(),
         author: None,
-        year: Some(-500),
+        date: Some(HistoricalDate::Year(-500)),
         source_type: SourceType::CompilationText,
         url: None,
         notes: Some("Collection of Hindu mythological locations".to_string()),
-    };
+    });
     
     // Mount Meru
     let mut meru = Location::new(
@@ -380,9 +384,9 @@ fn add_hindu_locations(ontology: &mut MythOntology) {
     let mut svarga_entity = MythEntity::Location(svarga);
     let mut naraka_entity = MythEntity::Location(naraka);
     
-    meru_entity.metadata_mut().add_source(hindu_source.clone());
-    svarga_entity.metadata_mut().add_source(hindu_source.clone());
-    naraka_entity.metadata_mut().add_source(hindu_source);
+    meru_entity.metadata_mut().add_citation(Citation::new(hindu_source.clone()));
+    svarga_entity.metadata_mut().add_citation(Citation::new(hindu_source.clone()));
+    naraka_entity.metadata_mut().add_citation(Citation::new(hindu_source));
     
     ontology.add_entity(meru_entity);
     ontology.add_entity(svarga_entity);
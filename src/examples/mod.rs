@@ -22,6 +22,15 @@
 //! - [`locations`]: Mythical places and realms
 //! - [`concepts`]: Abstract mythological concepts
 //!
+//! ## Classification
+//!
+//! - [`motifs`]: Thompson motifs and ATU tale types, with the tags applied to the
+//!   example entities
+//! - [`narratives`]: Myths told as ordered episodes, linked to the example entities
+//! - [`attestations`]: When the example deities first and last appear in the
+//!   historical record
+//! - [`labels`]: Names of the example entities in their own languages and scripts
+//!
 
 // Cultural examples
 pub mod greek;
@@ -37,6 +46,12 @@ pub mod creatures;
 pub mod locations;
 pub mod concepts;
 
+// Classification
+pub mod motifs;
+pub mod narratives;
+pub mod attestations;
+pub mod labels;
+
 
 // Re-export main creation functions
 pub use greek::create_greek_ontology;
@@ -51,6 +66,7 @@ pub use heroes::create_heroes_ontology;
 pub use creatures::create_creatures_ontology;
 pub use locations::create_locations_ontology;
 pub use concepts::create_concepts_ontology;
+pub use motifs::create_motif_catalogue;
 
 #[cfg(test)]
 mod tests {
@@ -67,6 +83,10 @@ mod tests {
         
         let has_olympus = entities.iter().any(|e| e.name() == "Mount Olympus");
         assert!(has_olympus, "Greek ontology should have Mount Olympus");
+        
+        let zeus_id = entities.iter().find(|e| e.name() == "Zeus").unwrap().id().clone();
+        let related = crate::query::QueryEngine::new(&ontology).find_related(&zeus_id);
+        assert!(related.results.iter().any(|r| r.name == "Hera"), "Zeus should be related to Hera");
     }
     
     #[test]
@@ -111,4 +131,83 @@ mod tests {
         let has_heracles = entities.iter().any(|e| e.name() == "Heracles");
         assert!(has_heracles, "Heroes ontology should have Heracles");
     }
+    
+    #[test]
+    fn test_motif_tags() {
+        let ontology = create_heroes_ontology();
+        let catalogue = create_motif_catalogue();
+        for entity in ontology.entities() {
+            for tag in &entity.metadata().motifs {
+                assert!(catalogue.get(&tag.code).is_some(), "{} is not in the example catalogue", tag.code);
+            }
+        }
+        
+        let strong = crate::query::QueryEngine::new(&ontology).find_by_motif(&"F600-F699".parse().unwrap());
+        let names: Vec<&str> = strong.results.iter().map(|r| r.name.as_str()).collect();
+        assert!(names.contains(&"Heracles") && names.contains(&"Beowulf"));
+    }
+    
+    #[test]
+    fn test_narratives() {
+        for ontology in [create_greek_ontology(), create_norse_ontology(), create_egyptian_ontology()] {
+            let report = ontology.validate();
+            for narrative in ontology.entities_of_type("Narrative") {
+                assert_eq!(report.for_entity(narrative.id()).count(), 0, "{} has invalid references", narrative.name());
+                assert_eq!(ontology.entities_named(narrative.name()).count(), 1);
+            }
+        }
+        
+        let egyptian = create_egyptian_ontology();
+        let set = egyptian.entities_named("Set").next().unwrap().id().clone();
+        let myths = crate::query::QueryEngine::new(&egyptian).find_narratives(&set, Some(crate::entities::NarrativeRole::Antagonist));
+        assert_eq!(myths.first().map(|r| r.name.as_str()), Some("The Death and Resurrection of Osiris"));
+    }
+    
+    #[test]
+    fn test_timelines() {
+        let norse = create_norse_ontology();
+        assert!(norse.validate().errors().next().is_none());
+        let timeline = crate::cultural::Chronology::new(&norse).timeline().unwrap();
+        let names: Vec<&str> = timeline.events().map(|id| norse.get_entity(id).unwrap().name()).collect();
+        assert_eq!(names, ["Shaping of the World", "Death of Baldr", "Binding of Loki", "Battle of Vigrid", "Rebirth of the World"]);
+        
+        let greek = create_greek_ontology();
+        let timeline = crate::cultural::Chronology::new(&greek).timeline().unwrap();
+        assert!(timeline.is_linear());
+    }
+    
+    #[test]
+    fn test_attestations() {
+        use crate::query::{QueryEngine, QueryFilter};
+        
+        let names = |ontology: &crate::core::MythOntology, query: &str| {
+            let mut results = QueryEngine::new(ontology).query(&[query.parse::<QueryFilter>().unwrap()]);
+            results.sort_by_name();
+            results.results.into_iter().map(|r| r.name).collect::<Vec<_>>()
+        };
+        
+        let greek = create_greek_ontology();
+        assert_eq!(names(&greek, "attested:\"before 1000 BCE\""), ["Athena", "Hera", "Poseidon", "Zeus"]);
+        
+        // Odin and Thor may or may not be attested before 550 CE, depending on
+        // where in the 6th century their first attestation falls
+        let norse = create_norse_ontology();
+        assert_eq!(names(&norse, "attested:\"before 550 CE\""), ["Odin", "Thor"]);
+        assert!(names(&norse, "attested_certain:\"before 550 CE\"").is_empty());
+    }
+    
+    #[test]
+    fn test_shared_bibliography() {
+        let norse = create_norse_ontology();
+        let edda = norse.bibliography().find_by_title("Poetic Edda");
+        assert_eq!(edda.len(), 1, "narratives should cite the existing Poetic Edda");
+        
+        let citations = norse.cited_by(edda[0].0);
+        let (narrative, citation) = citations.iter()
+            .find(|(entity, _)| entity.entity_type() == "Narrative")
+            .unwrap();
+        assert_eq!(narrative.name(), "The Doom of the Gods");
+        assert_eq!(citation.locator.chapter.as_deref(), Some("Völuspá"));
+        assert!(citations.iter().any(|(entity, _)| entity.name() == "Odin"));
+    }
 }
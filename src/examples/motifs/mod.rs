@@ -0,0 +1,108 @@
+//! Folklore motifs and tale types for the example ontologies
+
+use crate::core::{Citation, MythId, MythOntology, Source, SourceType, HistoricalDate};
+use crate::motifs::{Motif, MotifCatalogue, MotifCode, MotifSystem, MotifTag};
+
+/// Motif tags for the example entities: entity name, code, and how the motif appears
+const MOTIF_TAGS: &[(&str, &str, &str)] = &[
+    ("Underworld", "E481", "The realm of Hades beneath the earth"),
+    ("Valhalla", "E481", "Odin's hall for warriors slain in battle"),
+    ("Annwn", "E481", "The Welsh Otherworld ruled by Arawn"),
+    ("Diyu", "E481", "Courts where the dead are judged and punished"),
+    ("Naraka", "E481", "Realm of punishment under Yama"),
+    ("Cerberus", "A673", "Three-headed hound guarding the gates of the underworld"),
+    ("Jormungandr", "B91", "The World Serpent encircling Midgard"),
+    ("Long", "B11", "Dragon ruling over rain and waters"),
+    ("Medusa", "D581", "Turns those who meet her gaze to stone"),
+    ("Heracles", "F610", "Strangles the Nemean lion bare-handed"),
+    ("Heracles", "F81", "Descends to Hades to fetch Cerberus"),
+    ("Odysseus", "F81", "Consults the shade of Tiresias"),
+    ("Beowulf", "F610", "Tears the arm from Grendel"),
+    ("Beowulf", "B11", "Fights a dragon in his old age"),
+    ("Sigurd", "B11", "Slays Fafnir"),
+    ("Perseus", "ATU 300", "Rescues Andromeda from the sea monster"),
+    ("Gilgamesh", "A1021", "Utnapishtim's account of the flood"),
+    ("Winged Sandals", "F821", "Sandals that let Hermes and Perseus fly"),
+];
+
+/// Create an excerpt of the Thompson motif index and the ATU tale type index
+/// covering the motifs used in the example ontologies
+pub fn create_motif_catalogue() -> MotifCatalogue {
+    let entries = [
+        ("A", "Mythological motifs", None),
+        ("A600-A899", "Cosmogony and cosmology", None),
+        ("A673", "Hound of hell", Some("A dog guards the entrance to the world of the dead")),
+        ("A1000-A1099", "World calamities", None),
+        ("A1010", "Deluge", Some("A flood destroys the world or all of humankind")),
+        ("A1021", "Deluge: escape in boat (ark)", None),
+        ("B", "Animals", None),
+        ("B0-B99", "Mythical animals", None),
+        ("B11", "Dragon", None),
+        ("B91", "Mythical serpent", None),
+        ("D", "Magic", None),
+        ("D500-D599", "Means of transformation", None),
+        ("D581", "Petrification by glance", None),
+        ("E", "The dead", None),
+        ("E400-E599", "Ghosts and other revenants", None),
+        ("E481", "Land of the dead", Some("An abode of the dead, whether beneath the earth, across the sea, or in the sky")),
+        ("F", "Marvels", None),
+        ("F0-F199", "Otherworld journeys", None),
+        ("F81", "Descent to lower world of dead", None),
+        ("F500-F599", "Remarkable persons", None),
+        ("F531", "Giant", None),
+        ("F600-F699", "Persons with extraordinary powers", None),
+        ("F610", "Remarkably strong man", None),
+        ("F800-F899", "Extraordinary places and things", None),
+        ("F821", "Extraordinary clothing", None),
+        ("ATU 1-299", "Animal tales", None),
+        ("ATU 300-749", "Tales of magic", None),
+        ("ATU 300", "The Dragon-Slayer", Some("A hero kills a dragon and rescues the princess offered to it")),
+    ];
+    
+    entries.into_iter()
+        .map(|(code, name, description)| {
+            let motif = Motif::new(code.parse().expect("example motif codes are valid"), name);
+            match description {
+                Some(description) => motif.description(description),
+                None => motif,
+            }
+        })
+        .collect()
+}
+
+/// Tag the entities of an example ontology with the motifs of their stories
+pub fn add_motif_tags(ontology: &mut MythOntology) {
+    for (name, code, note) in MOTIF_TAGS {
+        let code: MotifCode = code.parse().expect("example motif codes are valid");
+        let ids: Vec<MythId> = ontology.entities_named(name)
+            .filter(|entity| entity.name() == *name)
+            .map(|entity| entity.id().clone())
+            .collect();
+        if ids.is_empty() {
+            continue;
+        }
+        let source = ontology.add_source(index_source(code.system()));
+        for id in ids {
+            if let Some(mut entity) = ontology.get_entity_mut(&id) {
+                let tag = MotifTag::new(code.clone()).citation(Citation::new(source.clone())).note(note);
+                entity.metadata_mut().add_motif(tag);
+            }
+        }
+    }
+}
+
+/// The published index a code comes from
+fn index_source(system: MotifSystem) -> Source {
+    let (title, author, year) = match system {
+        MotifSystem::Thompson => ("Motif-Index of Folk-Literature", "Stith Thompson", 1955),
+        MotifSystem::Atu => ("The Types of International Folktales", "Hans-Jörg Uther", 2004),
+    };
+    Source {
+        title: title.to_string(),
+        author: Some(author.to_string()),
+        date: Some(HistoricalDate::Year(year)),
+        source_type: SourceType::Book,
+        url: None,
+        notes: None,
+    }
+}
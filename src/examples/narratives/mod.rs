@@ -0,0 +1,267 @@
+//! Myths told as narratives for the example ontologies
+
+use crate::core::{Citation, MythEntity, MythId, MythOntology, Source, SourceId, SourceType};
+use crate::entities::{Episode, Narrative, NarrativeRole};
+
+/// A myth in the example ontologies, referring to entities by name
+struct NarrativeData {
+    name: &'static str,
+    description: &'static str,
+    culture: &'static str,
+    /// Title and author of the text the myth is told in
+    source: (&'static str, Option<&'static str>),
+    /// Book and chapter of the text where the myth is told
+    locator: (Option<&'static str>, Option<&'static str>),
+    episodes: &'static [EpisodeData],
+}
+
+/// An episode of an example myth
+struct EpisodeData {
+    title: &'static str,
+    description: &'static str,
+    /// Participant name and role
+    participants: &'static [(&'static str, &'static str)],
+    locations: &'static [&'static str],
+    artifacts: &'static [&'static str],
+    /// Names of the relationships the episode establishes
+    establishes: &'static [&'static str],
+}
+
+const NARRATIVES: &[NarrativeData] = &[
+    NarrativeData {
+        name: "The Labors of Heracles",
+        description: "Driven mad by Hera, Heracles kills his family and serves King Eurystheus for twelve years in penance, after which he is made a god.",
+        culture: "Greek",
+        source: ("Bibliotheca", Some("Pseudo-Apollodorus")),
+        locator: (Some("2"), Some("5")),
+        episodes: &[
+            EpisodeData {
+                title: "The Madness",
+                description: "Hera sends a fit of madness upon Heracles, in which he kills his wife Megara and their children.",
+                participants: &[("Hera", "Antagonist"), ("Heracles", "Protagonist")],
+                locations: &[],
+                artifacts: &[],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "The Oracle",
+                description: "Seeking purification, Heracles consults Apollo's oracle, which sends him to serve Eurystheus.",
+                participants: &[("Heracles", "Protagonist"), ("Apollo", "Helper")],
+                locations: &["Delphi"],
+                artifacts: &[],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "The Capture of Cerberus",
+                description: "For the last labor Heracles descends to the underworld and carries off its three-headed guardian.",
+                participants: &[("Heracles", "Protagonist"), ("Cerberus", "Antagonist"), ("Athena", "Helper")],
+                locations: &["Underworld"],
+                artifacts: &[],
+                establishes: &["Twelve Labors of Heracles"],
+            },
+            EpisodeData {
+                title: "The Apotheosis",
+                description: "Burned on his pyre, Heracles is taken up to Olympus, where Zeus makes him immortal.",
+                participants: &[("Heracles", "Protagonist"), ("Zeus", "Helper")],
+                locations: &["Mount Olympus"],
+                artifacts: &[],
+                establishes: &[],
+            },
+        ],
+    },
+    NarrativeData {
+        name: "Perseus and Medusa",
+        description: "Sent to fetch the head of the Gorgon Medusa, Perseus beheads her with the help of the gods.",
+        culture: "Greek",
+        source: ("Bibliotheca", Some("Pseudo-Apollodorus")),
+        locator: (Some("2"), Some("4")),
+        episodes: &[
+            EpisodeData {
+                title: "The Gifts of the Gods",
+                description: "Athena guides Perseus and gives him a polished shield to look upon the Gorgon without meeting her gaze.",
+                participants: &[("Perseus", "Protagonist"), ("Athena", "Helper")],
+                locations: &[],
+                artifacts: &["Aegis"],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "The Beheading",
+                description: "Watching her reflection, Perseus cuts off the head of the sleeping Medusa.",
+                participants: &[("Perseus", "Protagonist"), ("Medusa", "Victim"), ("Athena", "Helper")],
+                locations: &[],
+                artifacts: &[],
+                establishes: &["Perseus slays Medusa"],
+            },
+        ],
+    },
+    NarrativeData {
+        name: "The Binding of Fenrir",
+        description: "Warned that Loki's children will bring ruin, the gods cast them out and bind the wolf Fenrir until Ragnarök.",
+        culture: "Norse",
+        source: ("Prose Edda", Some("Snorri Sturluson")),
+        locator: (Some("Gylfaginning"), Some("34")),
+        episodes: &[
+            EpisodeData {
+                title: "Loki's Children",
+                description: "Odin has Loki's monstrous children brought to him and throws the serpent into the sea surrounding Midgard.",
+                participants: &[("Odin", "Protagonist"), ("Loki", "Antagonist"), ("Jormungandr", "Antagonist")],
+                locations: &["Midgard"],
+                artifacts: &[],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "Gleipnir",
+                description: "Having broken two chains, Fenrir is bound with a ribbon made by the dwarves, biting off the hand of Tyr.",
+                participants: &[("Odin", "Protagonist"), ("Fenrir", "Antagonist")],
+                locations: &["Asgard"],
+                artifacts: &[],
+                establishes: &[],
+            },
+        ],
+    },
+    NarrativeData {
+        name: "The Theft of Brisingamen",
+        description: "Loki steals Freyja's necklace and Heimdall wins it back from him.",
+        culture: "Norse",
+        source: ("Prose Edda", Some("Snorri Sturluson")),
+        locator: (Some("Skáldskaparmál"), None),
+        episodes: &[
+            EpisodeData {
+                title: "The Theft",
+                description: "Taking the form of a fly, Loki slips into Freyja's bower and takes the necklace while she sleeps.",
+                participants: &[("Loki", "Antagonist"), ("Freyja", "Victim")],
+                locations: &[],
+                artifacts: &["Brisingamen"],
+                establishes: &["Loki's shapeshifting"],
+            },
+            EpisodeData {
+                title: "The Fight at Singasteinn",
+                description: "Heimdall and Loki fight in the shape of seals, and Heimdall returns the necklace to Freyja.",
+                participants: &[("Heimdall", "Protagonist"), ("Loki", "Antagonist"), ("Freyja", "Beneficiary")],
+                locations: &[],
+                artifacts: &["Brisingamen"],
+                establishes: &[],
+            },
+        ],
+    },
+    NarrativeData {
+        name: "The Doom of the Gods",
+        description: "The prophecy of the last battle, in which the gods and their enemies destroy each other and the world is reborn.",
+        culture: "Norse",
+        source: ("Poetic Edda", None),
+        locator: (None, Some("Völuspá")),
+        episodes: &[
+            EpisodeData {
+                title: "The Monsters Break Free",
+                description: "Loki and Fenrir break their bonds and the World Serpent rises from the sea.",
+                participants: &[("Loki", "Antagonist"), ("Fenrir", "Antagonist"), ("Jormungandr", "Antagonist")],
+                locations: &["Midgard"],
+                artifacts: &[],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "The Last Battle",
+                description: "Odin is devoured by Fenrir, and Thor kills the serpent with his hammer but dies of its venom.",
+                participants: &[("Odin", "Victim"), ("Fenrir", "Antagonist"), ("Thor", "Protagonist"), ("Jormungandr", "Antagonist")],
+                locations: &[],
+                artifacts: &["Mjölnir", "Gungnir"],
+                establishes: &["Odin battles Fenrir at Ragnarök", "Thor battles Jormungandr at Ragnarök"],
+            },
+        ],
+    },
+    NarrativeData {
+        name: "The Death and Resurrection of Osiris",
+        description: "Osiris is murdered by his brother Set, restored by Isis long enough to conceive Horus, and becomes ruler of the dead.",
+        culture: "Egyptian",
+        source: ("De Iside et Osiride", Some("Plutarch")),
+        locator: (None, Some("13-19")),
+        episodes: &[
+            EpisodeData {
+                title: "The Murder",
+                description: "Set tricks Osiris into a chest made to his measure, seals it, and casts it into the Nile.",
+                participants: &[("Set", "Antagonist"), ("Osiris", "Victim")],
+                locations: &[],
+                artifacts: &[],
+                establishes: &[],
+            },
+            EpisodeData {
+                title: "The Search",
+                description: "Isis recovers the body of Osiris, and with her magic revives him long enough to conceive Horus.",
+                participants: &[("Isis", "Protagonist"), ("Osiris", "Victim")],
+                locations: &[],
+                artifacts: &[],
+                establishes: &["Osiris fathers Horus", "Isis mothers Horus"],
+            },
+            EpisodeData {
+                title: "The Ruler of the Dead",
+                description: "Osiris descends to the Duat to judge the dead, while Horus grows to avenge him on Set.",
+                participants: &[("Osiris", "Protagonist"), ("Horus", "Protagonist"), ("Set", "Antagonist")],
+                locations: &["Duat"],
+                artifacts: &[],
+                establishes: &[],
+            },
+        ],
+    },
+];
+
+/// Add the example myths of a culture to an ontology, linking their episodes to
+/// the entities the ontology already has
+pub fn add_narratives(ontology: &mut MythOntology, culture: &str) {
+    for data in NARRATIVES.iter().filter(|data| data.culture == culture) {
+        let mut narrative = Narrative::new(data.name, data.description, data.culture);
+        for episode_data in data.episodes {
+            let mut episode = Episode::new(episode_data.title, episode_data.description);
+            for (name, role) in episode_data.participants {
+                if let Some(id) = find_entity_id(ontology, name) {
+                    let role: NarrativeRole = role.parse().expect("example roles are valid");
+                    episode.add_participant(id, role);
+                }
+            }
+            for id in episode_data.locations.iter().filter_map(|name| find_entity_id(ontology, name)) {
+                episode.add_location(id);
+            }
+            for id in episode_data.artifacts.iter().filter_map(|name| find_entity_id(ontology, name)) {
+                episode.add_artifact(id);
+            }
+            for id in episode_data.establishes.iter().filter_map(|name| find_entity_id(ontology, name)) {
+                episode.add_established_relationship(id);
+            }
+            narrative.add_episode(episode);
+        }
+        
+        let source = cite_text(ontology, data.source);
+        let (book, chapter) = data.locator;
+        let mut citation = Citation::new(source);
+        if let Some(book) = book {
+            citation = citation.book(book);
+        }
+        if let Some(chapter) = chapter {
+            citation = citation.chapter(chapter);
+        }
+        narrative.metadata.add_citation(citation);
+        ontology.add_entity(MythEntity::Narrative(narrative));
+    }
+}
+
+/// Find the text a myth is told in, adding it to the bibliography if the ontology
+/// does not already cite it
+fn cite_text(ontology: &mut MythOntology, (title, author): (&str, Option<&str>)) -> SourceId {
+    let existing = ontology.bibliography().find_by_title(title).into_iter()
+        .find(|(_, source)| source.title == title && source.author.as_deref() == author)
+        .map(|(id, _)| id.clone());
+    existing.unwrap_or_else(|| ontology.add_source(Source {
+        title: title.to_string(),
+        author: author.map(str::to_string),
+        date: None,
+        source_type: SourceType::PrimaryText,
+        url: None,
+        notes: None,
+    }))
+}
+
+/// Helper function to find an entity ID by name
+fn find_entity_id(ontology: &MythOntology, name: &str) -> Option<MythId> {
+    ontology.entities_named(name)
+        .find(|entity| entity.name() == name)
+        .map(|entity| entity.id().clone())
+}
@@ -1,4 +1,4 @@
-use crate::core::{MythOntology, MythEntity};
+use crate::core::{MythOntology, MythEntity, HistoricalDate};
 use crate::cultural::{Culture, TimePeriod, MythologicalRegion};
 
 /// Add Norse culture and related entities to the ontology
@@ -22,22 +22,22 @@ pub fn add_norse_culture(ontology: &mut MythOntology) {
     // Add time periods
     norse_culture.add_time_period(TimePeriod {
         name: "Germanic Iron Age".to_string(),
-        start_year: Some(400),
-        end_year: Some(800),
+        start: Some(HistoricalDate::Year(400)),
+        end: Some(HistoricalDate::Year(800)),
         description: Some("Period before the Viking Age with early development of Norse culture".to_string()),
     });
     
     norse_culture.add_time_period(TimePeriod {
         name: "Viking Age".to_string(),
-        start_year: Some(793),
-        end_year: Some(1066),
+        start: Some(HistoricalDate::Year(793)),
+        end: Some(HistoricalDate::Year(1066)),
         description: Some("Period in European history when Scandinavian Norsemen explored, raided, and settled throughout Europe".to_string()),
     });
     
     norse_culture.add_time_period(TimePeriod {
         name: "Christianization".to_string(),
-        start_year: Some(1000),
-        end_year: Some(1300),
+        start: Some(HistoricalDate::Year(1000)),
+        end: Some(HistoricalDate::Year(1300)),
         description: Some("Period when Norse regions converted to Christianity and traditional belief systems declined".to_string()),
     });
     
@@ -1,4 +1,4 @@
-use crate::core::{MythOntology, MythEntity, Source, SourceType};
+use crate::core::{MythOntology, MythEntity, Source, SourceType, HistoricalDate, Citation};
 use crate::entities::{Deity, Hero, Creature, Artifact, Location, Concept};
 use crate::entities::{Gender, DeityImportance, HeroOrigin, CreatureType, ArtifactType, LocationType, ConceptType};
 
@@ -26,23 +26,23 @@ pub fn add_norse_entities(ontology: &mut MythOntology) {
 /// Add Norse deities to the ontology
 fn add_norse_deities(ontology: &mut MythOntology) {
     // Primary source for Norse deities
-    let edda_source = Source {
+    let edda_source = ontology.add_source(Source {
         title: "Poetic Edda".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Collection of Old Norse poems from the Icelandic medieval manuscript Codex Regius".to_string()),
-    };
+    });
     
-    let prose_edda_source = Source {
+    let prose_edda_source = ontology.add_source(Source {
         title: "Prose Edda".to_string(),
         author: Some("Snorri Sturluson".to_string()),
-        year: Some(1220),
+        date: Some(HistoricalDate::Year(1220)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Old Norse work of literature written in Iceland in the early 13th century".to_string()),
-    };
+    });
     
     // Odin
     let mut odin = Deity::new(
@@ -126,16 +126,16 @@ fn add_norse_deities(ontology: &mut MythOntology) {
     let mut heimdall_entity = MythEntity::Deity(heimdall);
     
     // Add source to metadata
-    odin_entity.metadata_mut().add_source(edda_source.clone());
-    odin_entity.metadata_mut().add_source(prose_edda_source.clone());
-    thor_entity.metadata_mut().add_source(edda_source.clone());
-    thor_entity.metadata_mut().add_source(prose_edda_source.clone());
-    freyja_entity.metadata_mut().add_source(edda_source.clone());
-    freyja_entity.metadata_mut().add_source(prose_edda_source.clone());
-    loki_entity.metadata_mut().add_source(edda_source.clone());
-    loki_entity.metadata_mut().add_source(prose_edda_source.clone());
-    heimdall_entity.metadata_mut().add_source(edda_source.clone());
-    heimdall_entity.metadata_mut().add_source(prose_edda_source.clone());
+    odin_entity.metadata_mut().add_citation(Citation::new(edda_source.clone()));
+    odin_entity.metadata_mut().add_citation(Citation::new(prose_edda_source.clone()));
+    thor_entity.metadata_mut().add_citation(Citation::new(edda_source.clone()));
+    thor_entity.metadata_mut().add_citation(Citation::new(prose_edda_source.clone()));
+    freyja_entity.metadata_mut().add_citation(Citation::new(edda_source.clone()));
+    freyja_entity.metadata_mut().add_citation(Citation::new(prose_edda_source.clone()));
+    loki_entity.metadata_mut().add_citation(Citation::new(edda_source.clone()));
+    loki_entity.metadata_mut().add_citation(Citation::new(prose_edda_source.clone()));
+    heimdall_entity.metadata_mut().add_citation(Citation::new(edda_source.clone()));
+    heimdall_entity.metadata_mut().add_citation(Citation::new(prose_edda_source.clone()));
     
     // Add to ontology
     ontology.add_entity(odin_entity);
@@ -147,14 +147,14 @@ fn add_norse_deities(ontology: &mut MythOntology) {
 
 /// Add Norse heroes to the ontology
 fn add_norse_heroes(ontology: &mut MythOntology) {
-    let sagas_source = Source {
+    let sagas_source = ontology.add_source(Source {
         title: "Icelandic Sagas".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: SourceType::PrimaryText,
         url: None,
         notes: Some("Norse/Icelandic sagas recording historical events and mythology".to_string()),
-    };
+    });
     
     // Sigurd/Siegfried
     let mut sigurd = Hero::new(
@@ -183,8 +183,8 @@ fn add_norse_heroes(ontology: &mut MythOntology) {
     let mut beowulf_entity = MythEntity::Hero(beowulf);
     
     // Add source to metadata
-    sigurd_entity.metadata_mut().add_source(sagas_source.clone());
-    beowulf_entity.metadata_mut().add_source(sagas_source);
+    sigurd_entity.metadata_mut().add_citation(Citation::new(sagas_source.clone()));
+    beowulf_entity.metadata_mut().add_citation(Citation::new(sagas_source));
     
     // Add to ontology
     ontology.add_entity(sigurd_entity);
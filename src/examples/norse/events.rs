@@ -0,0 +1,98 @@
+use crate::core::{MythOntology, MythEntity};
+use crate::cultural::MythologicalEra;
+use crate::entities::Event;
+
+/// Add Norse eras and the events of the mythic chronology to the ontology
+pub fn add_norse_events(ontology: &mut MythOntology) {
+    let entity_id = |name: &str| {
+        ontology.entities_named(name)
+            .find(|entity| entity.name() == name)
+            .map(|entity| entity.id().clone())
+    };
+    
+    // Eras of the Norse cosmos
+    let mut present_age = MythologicalEra::new(
+        "Age of the Gods",
+        "The present age of the cosmos, from the shaping of the world out of Ymir's body to the last battle.",
+        "Norse"
+    );
+    present_age.set_sequence_order(1);
+    present_age.add_characteristic("Rule of the Aesir");
+    
+    let mut renewed_world = MythologicalEra::new(
+        "Renewed World",
+        "The green world that rises from the sea after Ragnarök, ruled by the surviving gods and Baldr.",
+        "Norse"
+    );
+    renewed_world.set_sequence_order(2);
+    
+    // Creation of the world
+    let mut creation = Event::new(
+        "Shaping of the World",
+        "Odin and his brothers slay the giant Ymir and build the world from his body, fencing Midgard with his eyebrows.",
+        "Norse"
+    );
+    creation.set_era(present_age.id.clone());
+    if let Some(odin_id) = entity_id("Odin") {
+        creation.add_participant(odin_id);
+    }
+    if let Some(midgard_id) = entity_id("Midgard") {
+        creation.set_location(midgard_id);
+    }
+    
+    // Death of Baldr
+    let mut death_of_baldr = Event::new(
+        "Death of Baldr",
+        "Loki guides the blind god Hodr to kill Baldr with a dart of mistletoe, the first of the great sorrows.",
+        "Norse"
+    );
+    death_of_baldr.set_era(present_age.id.clone());
+    death_of_baldr.add_after(creation.id.clone());
+    for name in ["Loki", "Odin"] {
+        if let Some(id) = entity_id(name) {
+            death_of_baldr.add_participant(id);
+        }
+    }
+    
+    // Punishment of Loki
+    let mut binding_of_loki = Event::new(
+        "Binding of Loki",
+        "The gods bind Loki beneath a serpent dripping venom, where he lies until Ragnarök.",
+        "Norse"
+    );
+    binding_of_loki.set_era(present_age.id.clone());
+    binding_of_loki.add_cause(death_of_baldr.id.clone());
+    death_of_baldr.add_consequence(binding_of_loki.id.clone());
+    if let Some(loki_id) = entity_id("Loki") {
+        binding_of_loki.add_participant(loki_id);
+    }
+    
+    // The last battle
+    let mut last_battle = Event::new(
+        "Battle of Vigrid",
+        "The last battle of Ragnarök on the plain of Vigrid, where the gods and their enemies destroy one another.",
+        "Norse"
+    );
+    last_battle.add_cause(binding_of_loki.id.clone());
+    binding_of_loki.add_consequence(last_battle.id.clone());
+    for name in ["Odin", "Thor", "Heimdall", "Loki", "Fenrir", "Jormungandr"] {
+        if let Some(id) = entity_id(name) {
+            last_battle.add_participant(id);
+        }
+    }
+    present_age.set_ending(&last_battle);
+    
+    // Rebirth of the world
+    let mut rebirth = Event::new(
+        "Rebirth of the World",
+        "The earth rises again from the sea, and the surviving gods meet on the plain of Idavoll.",
+        "Norse"
+    );
+    rebirth.set_era(renewed_world.id.clone());
+    
+    ontology.add_entity(MythEntity::MythologicalEra(present_age));
+    ontology.add_entity(MythEntity::MythologicalEra(renewed_world));
+    for event in [creation, death_of_baldr, binding_of_loki, last_battle, rebirth] {
+        ontology.add_entity(MythEntity::Event(event));
+    }
+}
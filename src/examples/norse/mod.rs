@@ -4,19 +4,29 @@ mod entities;
 mod relationships;
 mod pantheons;
 mod culture;
+mod events;
 
 use crate::core::MythOntology;
+use crate::examples::motifs::add_motif_tags;
+use crate::examples::narratives::add_narratives;
+use crate::examples::attestations::add_attestations;
+use crate::examples::labels::add_labels;
 
 /// Create a comprehensive Norse mythology ontology including deities, heroes, locations,
 /// artifacts, creatures, and concepts, with relationships between them.
 pub fn create_norse_ontology() -> MythOntology {
     let mut ontology = MythOntology::new();
     
-    // Add all entities, relationships, and cultural context
+    // Add all entities, relationships, cultural context, and events
     entities::add_norse_entities(&mut ontology);
     relationships::add_norse_relationships(&mut ontology);
     pantheons::add_norse_pantheons(&mut ontology);
     culture::add_norse_culture(&mut ontology);
+    events::add_norse_events(&mut ontology);
+    add_attestations(&mut ontology);
+    add_labels(&mut ontology);
+    add_narratives(&mut ontology, "Norse");
+    add_motif_tags(&mut ontology);
     
     ontology
 }
\ No newline at end of file
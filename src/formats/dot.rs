@@ -0,0 +1,498 @@
+//! # Graphviz DOT Export
+//!
+//! Renders an ontology, or any subset of its entities such as a query result, as a
+//! Graphviz `digraph`. Entities become nodes styled by their `entity_type()`, and
+//! relationship entities become edges labeled with their kind and styled by variant:
+//! bold for family, dashed for conflicts and cross-cultural identifications, dotted
+//! for transformations. Bidirectional relationships are drawn with arrows at both
+//! ends. Nodes can optionally be grouped into clusters by culture or pantheon.
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::formats::{ontology_to_dot, DotClustering, DotOptions};
+//!
+//! let greek = create_greek_ontology();
+//! let dot = ontology_to_dot(&greek, &DotOptions::new().clustering(DotClustering::Pantheon));
+//! assert!(dot.starts_with("digraph"));
+//! assert!(dot.contains("label=\"Zeus\""));
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use crate::core::{MythEntity, MythId, MythOntology, ResolutionPolicy};
+
+/// How nodes are grouped into clusters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DotClustering {
+    /// No clusters
+    #[default]
+    None,
+    /// One cluster per culture
+    Culture,
+    /// One cluster per pantheon, containing its deities and the pantheon itself
+    Pantheon,
+}
+
+/// Options for DOT export
+#[derive(Debug, Clone)]
+pub struct DotOptions {
+    /// Name of the generated graph
+    pub graph_name: String,
+    /// How nodes are grouped into clusters
+    pub clustering: DotClustering,
+    /// Whether to label edges with the relationship kind
+    pub edge_labels: bool,
+    /// Which variant claims to draw
+    pub resolution: ResolutionPolicy,
+}
+
+impl Default for DotOptions {
+    fn default() -> Self {
+        Self {
+            graph_name: "mythology".to_string(),
+            clustering: DotClustering::None,
+            edge_labels: true,
+            resolution: ResolutionPolicy::ShowAll,
+        }
+    }
+}
+
+impl DotOptions {
+    /// Create the default options: no clustering, labeled edges
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the name of the generated graph
+    pub fn graph_name(mut self, name: &str) -> Self {
+        self.graph_name = name.to_string();
+        self
+    }
+    
+    /// Set how nodes are grouped into clusters
+    pub fn clustering(mut self, clustering: DotClustering) -> Self {
+        self.clustering = clustering;
+        self
+    }
+    
+    /// Set whether to label edges with the relationship kind
+    pub fn edge_labels(mut self, edge_labels: bool) -> Self {
+        self.edge_labels = edge_labels;
+        self
+    }
+    
+    /// Set how competing variant claims are resolved
+    pub fn resolution(mut self, resolution: ResolutionPolicy) -> Self {
+        self.resolution = resolution;
+        self
+    }
+}
+
+/// Render every entity and relationship of an ontology as DOT
+pub fn ontology_to_dot(ontology: &MythOntology, options: &DotOptions) -> String {
+    let ids: Vec<MythId> = ontology.entities().map(|entity| entity.id().clone()).collect();
+    subgraph_to_dot(ontology, &ids, options)
+}
+
+/// Render a subset of an ontology as DOT
+///
+/// The graph contains the listed entities plus the endpoints of any listed
+/// relationship entities, and every relationship whose endpoints are both in the
+/// graph. Pass `QueryResultSet::entity_ids()` to draw a query result. Relationships
+/// whose variant is not preferred by [`DotOptions::resolution`] are left out.
+pub fn subgraph_to_dot(ontology: &MythOntology, ids: &[MythId], options: &DotOptions) -> String {
+    if options.resolution != ResolutionPolicy::ShowAll {
+        let resolved = ontology.resolved(options.resolution);
+        return subgraph_to_dot(&resolved, ids, &options.clone().resolution(ResolutionPolicy::ShowAll));
+    }
+    
+    let nodes = subgraph_nodes(ontology, ids);
+    let node_ids: HashSet<&MythId> = nodes.iter().map(|node| node.id()).collect();
+    
+    let mut output = String::new();
+    let _ = writeln!(output, "digraph {} {{", quote(&options.graph_name));
+    output.push_str("    graph [rankdir=LR, fontname=\"Helvetica\"];\n");
+    output.push_str("    node [style=filled, fontname=\"Helvetica\"];\n");
+    output.push_str("    edge [fontname=\"Helvetica\", fontsize=10];\n");
+    
+    // Nodes, grouped into clusters where requested
+    let clusters = assign_clusters(ontology, &nodes, options.clustering);
+    let mut clustered: BTreeMap<&str, Vec<&MythEntity>> = BTreeMap::new();
+    let mut unclustered = Vec::new();
+    for node in &nodes {
+        match clusters.get(node.id()) {
+            Some(cluster) => clustered.entry(cluster.as_str()).or_default().push(node),
+            None => unclustered.push(node),
+        }
+    }
+    
+    for (i, (label, members)) in clustered.iter().enumerate() {
+        output.push('\n');
+        let _ = writeln!(output, "    subgraph {} {{", quote(&format!("cluster_{}", i)));
+        let _ = writeln!(output, "        label={};", quote(label));
+        output.push_str("        style=rounded;\n");
+        for node in members {
+            write_node(&mut output, node, "        ");
+        }
+        output.push_str("    }\n");
+    }
+    
+    if !unclustered.is_empty() {
+        output.push('\n');
+    }
+    for node in unclustered {
+        write_node(&mut output, node, "    ");
+    }
+    
+    // Edges between nodes in the graph
+    let edges = subgraph_edges(ontology, &nodes);
+    if !edges.is_empty() {
+        output.push('\n');
+    }
+    for edge in edges {
+        write_edge(&mut output, edge, options);
+    }
+    
+    // Direct links recorded in entities' own relationship lists
+    let mut links: Vec<(&MythEntity, &MythEntity)> = Vec::new();
+    let mut seen: HashSet<(&MythId, &MythId)> = HashSet::new();
+    for node in &nodes {
+        for related in node.relationships().iter().filter_map(|id| ontology.get_entity(id)) {
+            if related.is_relationship() || !node_ids.contains(related.id()) {
+                continue;
+            }
+            if seen.insert((node.id(), related.id())) && !seen.contains(&(related.id(), node.id())) {
+                links.push((node, related));
+            }
+        }
+    }
+    links.sort_by(|a, b| (a.0.name(), a.1.name()).cmp(&(b.0.name(), b.1.name())));
+    for (from, to) in links {
+        let _ = writeln!(
+            output,
+            "    {} -> {} [style=dotted, color=\"#999999\", arrowhead=none];",
+            quote(&from.id().to_string()),
+            quote(&to.id().to_string()),
+        );
+    }
+    
+    output.push_str("}\n");
+    output
+}
+
+/// Write an ontology as DOT to a file
+pub fn write_dot(ontology: &MythOntology, path: &Path, options: &DotOptions) -> std::io::Result<()> {
+    fs::write(path, ontology_to_dot(ontology, options))
+}
+
+/// The nodes drawn for a subset of an ontology: the listed entities plus the
+/// endpoints of any listed relationship entities, ordered by type and name
+pub(super) fn subgraph_nodes<'a>(ontology: &'a MythOntology, ids: &[MythId]) -> Vec<&'a MythEntity> {
+    let mut node_ids: HashSet<&MythId> = HashSet::new();
+    for entity in ids.iter().filter_map(|id| ontology.get_entity(id)) {
+        match entity.relationship() {
+            Some(relationship) => {
+                node_ids.extend([&relationship.source_id, &relationship.target_id]);
+            },
+            None => {
+                node_ids.insert(entity.id());
+            },
+        }
+    }
+    
+    let mut nodes: Vec<&MythEntity> = node_ids.iter()
+        .filter_map(|id| ontology.get_entity(id))
+        .filter(|entity| !entity.is_relationship())
+        .collect();
+    nodes.sort_by(|a, b| (a.entity_type(), a.name()).cmp(&(b.entity_type(), b.name())));
+    nodes
+}
+
+/// The relationship entities whose endpoints are both among the nodes, in a
+/// stable order
+pub(super) fn subgraph_edges<'a>(ontology: &'a MythOntology, nodes: &[&MythEntity]) -> Vec<&'a MythEntity> {
+    let node_ids: HashSet<&MythId> = nodes.iter().map(|node| node.id()).collect();
+    let mut edges: Vec<(String, &MythEntity)> = Vec::new();
+    for node in nodes {
+        for edge in ontology.relationships_from(node.id()) {
+            let relationship = edge.relationship().expect("indexed as a relationship");
+            if node_ids.contains(&relationship.target_id) {
+                edges.push((sort_key(ontology, edge), edge));
+            }
+        }
+    }
+    edges.sort_by(|a, b| a.0.cmp(&b.0));
+    edges.into_iter().map(|(_, edge)| edge).collect()
+}
+
+/// Shape and fill color for each entity type
+fn node_style(entity_type: &str) -> (&'static str, &'static str) {
+    match entity_type {
+        "Deity" => ("ellipse", "#ffd966"),
+        "Hero" => ("box", "#9fc5e8"),
+        "Creature" => ("octagon", "#ea9999"),
+        "Artifact" => ("diamond", "#d5a6bd"),
+        "Location" => ("house", "#b6d7a8"),
+        "Concept" => ("note", "#b4a7d6"),
+        "Narrative" => ("signature", "#ead1dc"),
+        "Event" => ("cds", "#fce5cd"),
+        "Culture" => ("tab", "#cccccc"),
+        "Pantheon" => ("folder", "#f9cb9c"),
+        "MythologicalEra" => ("cds", "#ffe599"),
+        "MythologicalRegion" => ("component", "#a2c4c9"),
+        _ => ("ellipse", "#ffffff"),
+    }
+}
+
+/// Line style and color for each relationship variant
+fn edge_style(entity: &MythEntity) -> (&'static str, &'static str) {
+    match entity {
+        MythEntity::FamilyRelationship(_) => ("bold", "#8b4513"),
+        MythEntity::AllianceRelationship(_) => ("solid", "#1f77b4"),
+        MythEntity::ConflictRelationship(_) => ("dashed", "#d62728"),
+        MythEntity::TransformationRelationship(_) => ("dotted", "#9467bd"),
+        MythEntity::SyncretismRelationship(_) => ("dashed", "#17becf"),
+        _ => ("solid", "#555555"),
+    }
+}
+
+fn write_node(output: &mut String, entity: &MythEntity, indent: &str) {
+    let (shape, color) = node_style(entity.entity_type());
+    let _ = writeln!(
+        output,
+        "{}{} [label={}, shape={}, fillcolor=\"{}\", tooltip={}];",
+        indent,
+        quote(&entity.id().to_string()),
+        quote(entity.name()),
+        shape,
+        color,
+        quote(entity.entity_type()),
+    );
+}
+
+fn write_edge(output: &mut String, entity: &MythEntity, options: &DotOptions) {
+    let relationship = entity.relationship().expect("edges are relationship entities");
+    let (style, color) = edge_style(entity);
+    
+    let mut attributes = vec![format!("style={}", style), format!("color=\"{}\"", color)];
+    if options.edge_labels {
+        attributes.push(format!("label={}", quote(&entity.relationship_kind().unwrap_or_default())));
+    }
+    if !relationship.name.is_empty() {
+        attributes.push(format!("tooltip={}", quote(&relationship.name)));
+    }
+    if relationship.bidirectional {
+        attributes.push("dir=both".to_string());
+    }
+    
+    let _ = writeln!(
+        output,
+        "    {} -> {} [{}];",
+        quote(&relationship.source_id.to_string()),
+        quote(&relationship.target_id.to_string()),
+        attributes.join(", "),
+    );
+}
+
+/// Order edges by endpoint names, then kind, so output is stable between runs
+fn sort_key(ontology: &MythOntology, entity: &MythEntity) -> String {
+    let relationship = entity.relationship().expect("edges are relationship entities");
+    let name = |id: &MythId| ontology.get_entity(id).map(|e| e.name().to_string()).unwrap_or_default();
+    format!(
+        "{}\u{0}{}\u{0}{}\u{0}{}",
+        name(&relationship.source_id),
+        name(&relationship.target_id),
+        entity.relationship_kind().unwrap_or_default(),
+        relationship.id,
+    )
+}
+
+/// Choose the cluster label for each node, if any
+fn assign_clusters(ontology: &MythOntology, nodes: &[&MythEntity], clustering: DotClustering) -> HashMap<MythId, String> {
+    let mut clusters = HashMap::new();
+    match clustering {
+        DotClustering::None => {},
+        DotClustering::Culture => {
+            let culture_ids: HashSet<_> = nodes.iter().filter_map(|node| node.culture()).collect();
+            for node in nodes {
+                let culture = match node {
+                    // Place a culture entity alongside the entities that reference it
+                    MythEntity::Culture(culture) => Some(culture_ids.iter()
+                        .find(|id| culture.is_identified_by(id))
+                        .map(|id| id.value().to_string())
+                        .unwrap_or_else(|| culture.name.clone())),
+                    _ => node.culture().map(|culture| culture.value().to_string()),
+                };
+                if let Some(culture) = culture {
+                    clusters.insert(node.id().clone(), culture);
+                }
+            }
+        },
+        DotClustering::Pantheon => {
+            let mut pantheons: Vec<_> = ontology.entities_of_type("Pantheon")
+                .filter_map(|entity| match entity {
+                    MythEntity::Pantheon(pantheon) => Some(pantheon),
+                    _ => None,
+                })
+                .collect();
+            // A deity in several pantheons goes to the first, by name, that lists
+            // it as primary, or else the first that lists it at all
+            pantheons.sort_by(|a, b| a.name.cmp(&b.name).then_with(|| a.id.to_string().cmp(&b.id.to_string())));
+            
+            for node in nodes {
+                let cluster = match node {
+                    MythEntity::Pantheon(pantheon) => Some(pantheon.name.clone()),
+                    MythEntity::Deity(deity) => pantheons.iter()
+                        .find(|p| p.primary_deities.contains(&deity.id))
+                        .or_else(|| pantheons.iter().find(|p| p.secondary_deities.contains(&deity.id)))
+                        .map(|p| p.name.clone())
+                        .or_else(|| deity.pantheon.as_ref().map(|id| {
+                            pantheons.iter()
+                                .find(|p| p.is_identified_by(id))
+                                .map(|p| p.name.clone())
+                                .unwrap_or_else(|| id.value().to_string())
+                        })),
+                    _ => None,
+                };
+                if let Some(cluster) = cluster {
+                    clusters.insert(node.id().clone(), cluster);
+                }
+            }
+        },
+    }
+    clusters
+}
+
+/// Quote a string as a DOT identifier
+fn quote(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cultural::Pantheon;
+    use crate::entities::Deity;
+    use crate::examples::create_greek_ontology;
+    use crate::query::QueryEngine;
+    use crate::relationships::{ConflictRelationship, ConflictType, FamilyRelationship, FamilyRelationshipType};
+    
+    fn line_containing<'a>(dot: &'a str, needle: &str) -> &'a str {
+        dot.lines().find(|line| line.contains(needle)).unwrap_or_else(|| panic!("no line with {}", needle))
+    }
+    
+    #[test]
+    fn styles_nodes_and_edges() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "", "Greek");
+        let hera = Deity::new("Hera \"Queen\"", "", "Greek");
+        let cronus = Deity::new("Cronus", "", "Greek");
+        let (zeus_id, hera_id, cronus_id) = (zeus.id.clone(), hera.id.clone(), cronus.id.clone());
+        for deity in [zeus, hera, cronus] {
+            ontology.add_entity(MythEntity::Deity(deity));
+        }
+        ontology.add_entity(MythEntity::FamilyRelationship(FamilyRelationship::new(
+            "Marriage", "", zeus_id.clone(), hera_id.clone(), FamilyRelationshipType::Spouse,
+        )));
+        ontology.add_entity(MythEntity::ConflictRelationship(ConflictRelationship::new(
+            "Titanomachy", "", zeus_id.clone(), cronus_id.clone(), ConflictType::War,
+        )));
+        ontology.add_entity(MythEntity::FamilyRelationship(FamilyRelationship::new(
+            "Cronus fathers Zeus", "", cronus_id.clone(), zeus_id.clone(), FamilyRelationshipType::Parent,
+        )));
+        
+        let dot = ontology_to_dot(&ontology, &DotOptions::new());
+        assert!(dot.starts_with("digraph \"mythology\" {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(line_containing(&dot, "Queen").contains("label=\"Hera \\\"Queen\\\"\""));
+        assert!(line_containing(&dot, "label=\"Zeus\"").contains("shape=ellipse"));
+        
+        let marriage = line_containing(&dot, "label=\"Spouse\"");
+        assert!(marriage.contains(&format!("\"{}\" -> \"{}\"", zeus_id, hera_id)));
+        assert!(marriage.contains("style=bold") && marriage.contains("dir=both"));
+        
+        let war = line_containing(&dot, "label=\"War\"");
+        assert!(war.contains("style=dashed"));
+        
+        let parent = line_containing(&dot, "label=\"Parent\"");
+        assert!(parent.contains(&format!("\"{}\" -> \"{}\"", cronus_id, zeus_id)));
+        assert!(!parent.contains("dir=both"));
+        
+        let unlabeled = ontology_to_dot(&ontology, &DotOptions::new().edge_labels(false));
+        assert!(!unlabeled.contains("label=\"Spouse\""));
+    }
+    
+    #[test]
+    fn clusters_by_culture_and_pantheon() {
+        let greek = create_greek_ontology();
+        
+        let dot = ontology_to_dot(&greek, &DotOptions::new().clustering(DotClustering::Culture));
+        assert_eq!(dot.matches("subgraph").count(), 2);
+        assert!(dot.contains("label=\"Greek\";"));
+        assert!(dot.contains("label=\"Roman\";"));
+        
+        let dot = ontology_to_dot(&greek, &DotOptions::new().clustering(DotClustering::Pantheon));
+        let cluster_start = dot.find("subgraph").unwrap();
+        let cluster = &dot[cluster_start..cluster_start + dot[cluster_start..].find("    }\n").unwrap()];
+        assert!(cluster.contains("label=\"Zeus\""));
+        assert!(!cluster.contains("label=\"Heracles\""));
+    }
+    
+    #[test]
+    fn clusters_deities_in_several_pantheons_by_primary_then_name() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "", "Greek");
+        let hestia = Deity::new("Hestia", "", "Greek");
+        let (zeus_id, hestia_id) = (zeus.id.clone(), hestia.id.clone());
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Deity(hestia));
+        for (name, primary, secondary) in [("Olympians", &zeus_id, &hestia_id), ("Dodecatheon", &hestia_id, &zeus_id), ("Cretan", &zeus_id, &hestia_id)] {
+            let mut pantheon = Pantheon::new(name, "", "Greek");
+            pantheon.add_primary_deity(primary.clone());
+            pantheon.add_secondary_deity(secondary.clone());
+            ontology.add_entity(MythEntity::Pantheon(pantheon));
+        }
+        
+        let nodes: Vec<&MythEntity> = ontology.entities().collect();
+        let clusters = assign_clusters(&ontology, &nodes, DotClustering::Pantheon);
+        assert_eq!(clusters[&zeus_id], "Cretan");
+        assert_eq!(clusters[&hestia_id], "Dodecatheon");
+    }
+    
+    #[test]
+    fn renders_query_subgraphs() {
+        let greek = create_greek_ontology();
+        let results = QueryEngine::new(&greek).query_str("type:Deity AND (name~zeus OR name~athena)").unwrap();
+        let dot = subgraph_to_dot(&greek, &results.entity_ids(), &DotOptions::new().graph_name("zeus"));
+        
+        assert_eq!(dot.matches("fillcolor").count(), 2);
+        assert_eq!(dot.matches("label=\"Parent\"").count(), 1);
+        assert!(!dot.contains("label=\"Spouse\""));
+    }
+    
+    #[test]
+    fn draws_variants_by_resolution() {
+        let greek = create_greek_ontology();
+        let id = |name: &str| greek.entities_named(name).find(|e| e.name() == name).unwrap().id().clone();
+        let ouranos = format!("\"{}\" -> \"{}\"", id("Ouranos"), id("Aphrodite"));
+        let zeus = format!("\"{}\" -> \"{}\"", id("Zeus"), id("Aphrodite"));
+        
+        let all = ontology_to_dot(&greek, &DotOptions::new());
+        assert!(all.contains(&ouranos) && all.contains(&zeus));
+        let earliest = ontology_to_dot(&greek, &DotOptions::new().resolution(ResolutionPolicy::PreferEarliest));
+        assert!(!earliest.contains(&ouranos) && earliest.contains(&zeus));
+    }
+}
@@ -0,0 +1,726 @@
+//! # JSON-LD Export
+//!
+//! Serializes an ontology, or a single entity, as standalone JSON-LD for partners
+//! that consume CIDOC-CRM. Every node is typed with its `myth:` class plus the
+//! closest CIDOC-CRM and schema.org classes:
+//!
+//! | Entity                         | CIDOC-CRM                    | schema.org      |
+//! |--------------------------------|------------------------------|-----------------|
+//! | `Deity`, `Hero`                | `E28_Conceptual_Object`      | `Person`        |
+//! | `Creature`, `Artifact`         | `E28_Conceptual_Object`      | `Thing`         |
+//! | `Concept`                      | `E89_Propositional_Object`   | `DefinedTerm`   |
+//! | `Narrative`                    | `E89_Propositional_Object`   | `CreativeWork`  |
+//! | `Event`                        | `E5_Event`                   | `Event`         |
+//! | narrative episodes             | `E5_Event`                   | —               |
+//! | `Location`, `MythologicalRegion` | `E53_Place`                | `Place`         |
+//! | `Culture`, `Pantheon`          | `E74_Group`                  | `Organization`  |
+//! | `MythologicalEra`              | `E4_Period`                  | —               |
+//! | `TimePeriod`                   | `E52_Time-Span`              | —               |
+//! | `Source`                       | `E31_Document`               | `CreativeWork`  |
+//! | relationships                  | `E13_Attribute_Assignment`   | —               |
+//!
+//! The term mapping is available on its own from [`jsonld_context`] so it can be
+//! published once and referenced by URL with [`JsonLdOptions::context_url`].
+//! Entity IRIs are built the same way as in the RDF export, so both describe the
+//! same resources. Variant claims are exported as `claims`, limited to the preferred
+//! ones when [`JsonLdOptions::resolution`] is set to a policy other than `ShowAll`.
+//! Multilingual labels are exported as SKOS-XL labels, the preferred one of each
+//! language as `prefLabel`, and [`JsonLdOptions::locale`] picks the language of
+//! each node's `name`.
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::formats::{entity_to_jsonld, JsonLdOptions};
+//!
+//! let greek = create_greek_ontology();
+//! let zeus = greek.entities_named("Zeus").next().unwrap();
+//! let node = entity_to_jsonld(&greek, zeus, &JsonLdOptions::new());
+//!
+//! assert!(node["@context"].is_object());
+//! assert_eq!(node["name"], "Zeus");
+//! assert_eq!(node["type"][1], "crm:E28_Conceptual_Object");
+//! ```
+
+use std::fs;
+use std::path::Path;
+use serde_json::{json, Map, Value};
+use crate::core::{Claim, HistoricalDate, Label, LanguageId, Metadata, MythEntity, MythId, MythOntology, ResolutionPolicy, Source, SourceId, SourceType};
+use crate::cultural::TimePeriod;
+use crate::formats::rdf::{normalize_base_iri, xsd_year, DEFAULT_BASE_IRI};
+
+/// Options for JSON-LD export
+#[derive(Debug, Clone)]
+pub struct JsonLdOptions {
+    /// Base IRI; entities are `<base>entity/<id>` and the vocabulary is `<base>ontology#`
+    pub base_iri: String,
+    /// URL of a published context to reference instead of embedding it
+    pub context_url: Option<String>,
+    /// Which variant claims to export
+    pub resolution: ResolutionPolicy,
+    /// Language of the names to export, or `None` for the primary names
+    pub locale: Option<LanguageId>,
+}
+
+impl Default for JsonLdOptions {
+    fn default() -> Self {
+        Self {
+            base_iri: DEFAULT_BASE_IRI.to_string(),
+            context_url: None,
+            resolution: ResolutionPolicy::ShowAll,
+            locale: None,
+        }
+    }
+}
+
+impl JsonLdOptions {
+    /// Create options with the default base IRI and an embedded context
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the base IRI; any fragment is dropped, and a `/` is appended unless it
+    /// already ends in `/` or `:`
+    pub fn base_iri(mut self, base_iri: &str) -> Self {
+        self.base_iri = normalize_base_iri(base_iri);
+        self
+    }
+    
+    /// Reference a published context by URL instead of embedding it
+    pub fn context_url(mut self, url: &str) -> Self {
+        self.context_url = Some(url.to_string());
+        self
+    }
+    
+    /// Set how competing variant claims are resolved
+    pub fn resolution(mut self, resolution: ResolutionPolicy) -> Self {
+        self.resolution = resolution;
+        self
+    }
+    
+    /// Name entities by their preferred label in a language, where they have one
+    pub fn locale(mut self, language: &str) -> Self {
+        self.locale = Some(LanguageId::new(language));
+        self
+    }
+    
+    /// The IRI of an entity
+    pub fn entity_iri(&self, id: &MythId) -> String {
+        format!("{}entity/{}", self.base_iri, id)
+    }
+    
+    /// The IRI of a source in the bibliography
+    pub fn source_iri(&self, id: &SourceId) -> String {
+        format!("{}source/{}", self.base_iri, id)
+    }
+    
+    fn context(&self) -> Value {
+        match &self.context_url {
+            Some(url) => Value::String(url.clone()),
+            None => jsonld_context(self)["@context"].clone(),
+        }
+    }
+}
+
+/// The JSON-LD context document mapping the export's terms to CIDOC-CRM,
+/// schema.org and the `myth:` vocabulary
+pub fn jsonld_context(options: &JsonLdOptions) -> Value {
+    let id = |iri: &str| json!({ "@id": iri, "@type": "@id" });
+    let set = |iri: &str| json!({ "@id": iri, "@container": "@set" });
+    let id_set = |iri: &str| json!({ "@id": iri, "@type": "@id", "@container": "@set" });
+    let typed = |iri: &str, datatype: &str| json!({ "@id": iri, "@type": datatype });
+    let index = |iri: &str| json!({ "@id": iri, "@container": "@index" });
+    let list = |iri: &str| json!({ "@id": iri, "@container": "@list" });
+    
+    let terms = [
+        ("@version", json!(1.1)),
+        ("crm", json!("http://www.cidoc-crm.org/cidoc-crm/")),
+        ("schema", json!("https://schema.org/")),
+        ("rdf", json!("http://www.w3.org/1999/02/22-rdf-syntax-ns#")),
+        ("rdfs", json!("http://www.w3.org/2000/01/rdf-schema#")),
+        ("skosxl", json!("http://www.w3.org/2008/05/skos-xl#")),
+        ("xsd", json!("http://www.w3.org/2001/XMLSchema#")),
+        ("myth", json!(format!("{}ontology#", options.base_iri))),
+        ("id", json!("@id")),
+        ("type", json!("@type")),
+        ("graph", json!("@graph")),
+        ("name", json!("rdfs:label")),
+        ("description", json!("schema:description")),
+        ("alternateName", set("schema:alternateName")),
+        ("culture", json!("myth:culture")),
+        ("relatedTo", id_set("myth:relatedTo")),
+        ("documentedIn", id_set("crm:P70i_is_documented_in")),
+        ("dateCreated", typed("schema:dateCreated", "xsd:dateTime")),
+        ("dateModified", typed("schema:dateModified", "xsd:dateTime")),
+        ("confidence", typed("myth:confidence", "xsd:decimal")),
+        ("attributes", index("myth:attribute")),
+        ("domains", set("myth:domain")),
+        ("pantheon", json!("myth:pantheon")),
+        ("gender", json!("schema:gender")),
+        ("importance", json!("myth:importance")),
+        ("origin", json!("myth:origin")),
+        ("achievements", set("myth:achievement")),
+        ("creatureType", json!("myth:creatureType")),
+        ("habitat", set("myth:habitat")),
+        ("abilities", set("myth:ability")),
+        ("artifactType", json!("myth:artifactType")),
+        ("powers", set("myth:power")),
+        ("creator", json!("schema:creator")),
+        ("owner", json!("myth:owner")),
+        ("locationType", json!("myth:locationType")),
+        ("characteristics", set("myth:characteristic")),
+        ("accessibility", set("myth:accessibility")),
+        ("conceptType", json!("myth:conceptType")),
+        ("manifestations", set("myth:manifestation")),
+        ("episodes", list("myth:episode")),
+        ("participants", set("myth:participant")),
+        ("entity", id("crm:P11_had_participant")),
+        ("role", json!("crm:P14.1_in_the_role_of")),
+        ("locations", id_set("crm:P7_took_place_at")),
+        ("artifacts", id_set("crm:P16_used_specific_object")),
+        ("establishes", id_set("myth:establishes")),
+        ("hadParticipant", id_set("crm:P11_had_participant")),
+        ("era", id("crm:P10_falls_within")),
+        ("causedBy", id_set("myth:causedBy")),
+        ("leadsTo", id_set("myth:leadsTo")),
+        ("after", id_set("crm:P183i_starts_after_the_end_of")),
+        ("before", id_set("crm:P183_ends_before_the_start_of")),
+        ("regions", set("myth:region")),
+        ("influences", set("myth:influence")),
+        ("languages", set("schema:knowsLanguage")),
+        ("culturalPractices", set("myth:culturalPractice")),
+        ("timePeriods", set("myth:timePeriod")),
+        ("begin", typed("crm:P82a_begin_of_the_begin", "xsd:gYear")),
+        ("end", typed("crm:P82b_end_of_the_end", "xsd:gYear")),
+        ("dating", json!("myth:dating")),
+        ("firstAttested", json!("myth:firstAttested")),
+        ("lastAttested", json!("myth:lastAttested")),
+        ("citations", set("myth:citation")),
+        ("citedSource", id("myth:citedSource")),
+        ("locator", json!("myth:locator")),
+        ("citedField", json!("myth:citedField")),
+        ("variantOf", json!("myth:variantOf")),
+        ("claims", set("myth:claim")),
+        ("claimedField", json!("myth:claimedField")),
+        ("value", json!("rdf:value")),
+        ("tradition", json!("myth:tradition")),
+        ("prefLabel", set("skosxl:prefLabel")),
+        ("altLabel", set("skosxl:altLabel")),
+        ("literalForm", json!("skosxl:literalForm")),
+        ("language", json!("myth:language")),
+        ("script", json!("myth:script")),
+        ("nameKind", json!("myth:nameKind")),
+        ("primaryDeities", id_set("myth:primaryDeity")),
+        ("secondaryDeities", id_set("myth:secondaryDeity")),
+        ("cosmology", json!("myth:cosmology")),
+        ("foundingMyth", json!("myth:foundingMyth")),
+        ("sequenceOrder", typed("myth:sequenceOrder", "xsd:integer")),
+        ("endEvent", json!("myth:endEvent")),
+        ("endedBy", id("myth:endedBy")),
+        ("cultures", set("myth:culture")),
+        ("features", set("myth:feature")),
+        ("modernLocations", set("myth:modernLocation")),
+        ("significance", json!("myth:significance")),
+        ("assignedTo", id("crm:P140_assigned_attribute_to")),
+        ("assigned", id("crm:P141_assigned")),
+        ("relationshipType", json!("myth:relationshipType")),
+        ("relationshipKind", json!("crm:P177_assigned_property_type")),
+        ("strength", typed("myth:strength", "xsd:decimal")),
+        ("bidirectional", typed("myth:bidirectional", "xsd:boolean")),
+        ("purpose", json!("myth:purpose")),
+        ("duration", json!("myth:duration")),
+        ("cause", json!("myth:cause")),
+        ("permanent", typed("myth:permanent", "xsd:boolean")),
+        ("reversible", typed("myth:reversible", "xsd:boolean")),
+        ("outcome", json!("myth:outcome")),
+        ("victor", id("myth:victor")),
+        ("consequences", set("myth:consequence")),
+        ("properties", index("myth:property")),
+        ("author", json!("schema:author")),
+        ("datePublished", typed("schema:datePublished", "xsd:gYear")),
+        ("sourceType", json!("myth:sourceType")),
+        ("url", id("schema:url")),
+        ("notes", json!("rdfs:comment")),
+    ];
+    
+    let context: Map<String, Value> = terms.into_iter().map(|(term, value)| (term.to_string(), value)).collect();
+    json!({ "@context": context })
+}
+
+/// Render a whole ontology as a JSON-LD document with a `@graph` of entities
+/// followed by the sources of the bibliography
+pub fn ontology_to_jsonld(ontology: &MythOntology, options: &JsonLdOptions) -> Value {
+    if options.resolution != ResolutionPolicy::ShowAll {
+        let resolved = ontology.resolved(options.resolution);
+        return ontology_to_jsonld(&resolved, &options.clone().resolution(ResolutionPolicy::ShowAll));
+    }
+    
+    let mut entities: Vec<&MythEntity> = ontology.entities().collect();
+    entities.sort_by_cached_key(|e| (e.entity_type(), e.name().to_string(), e.id().to_string()));
+    let mut sources: Vec<_> = ontology.bibliography().iter().collect();
+    sources.sort_by(|a, b| a.1.title.cmp(&b.1.title).then_with(|| a.0.cmp(b.0)));
+    
+    let mut graph: Vec<Value> = entities.into_iter().map(|entity| entity_node(ontology, entity, options)).collect();
+    graph.extend(sources.into_iter().map(|(id, source)| source_node(source, &options.source_iri(id))));
+    json!({ "@context": options.context(), "graph": graph })
+}
+
+/// Render one entity, with its sources, as a standalone JSON-LD document
+///
+/// Linked entities are referenced by IRI only.
+pub fn entity_to_jsonld(ontology: &MythOntology, entity: &MythEntity, options: &JsonLdOptions) -> Value {
+    if options.resolution != ResolutionPolicy::ShowAll {
+        let resolved = ontology.resolved(options.resolution);
+        let entity = resolved.get_entity(entity.id()).unwrap_or(entity);
+        return entity_to_jsonld(&resolved, entity, &options.clone().resolution(ResolutionPolicy::ShowAll));
+    }
+    
+    let mut node = Map::new();
+    node.insert("@context".to_string(), options.context());
+    if let Value::Object(fields) = entity_node(ontology, entity, options) {
+        node.extend(fields);
+    }
+    let sources = source_nodes(ontology, entity, options);
+    if !sources.is_empty() {
+        node.insert("documentedIn".to_string(), Value::Array(sources));
+    }
+    Value::Object(node)
+}
+
+/// Write an ontology as pretty-printed JSON-LD to a file
+pub fn write_jsonld(ontology: &MythOntology, path: &Path, options: &JsonLdOptions) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(&ontology_to_jsonld(ontology, options))?;
+    fs::write(path, json)
+}
+
+/// Builds a node, leaving out empty values
+#[derive(Default)]
+struct Node(Map<String, Value>);
+
+impl Node {
+    fn put(&mut self, key: &str, value: impl Into<Value>) {
+        let value = value.into();
+        let empty = match &value {
+            Value::Null => true,
+            Value::String(s) => s.is_empty(),
+            Value::Array(a) => a.is_empty(),
+            Value::Object(o) => o.is_empty(),
+            _ => false,
+        };
+        if !empty {
+            self.0.insert(key.to_string(), value);
+        }
+    }
+    
+    /// Put a list of values, sorted when they come from an unordered set
+    fn put_list<'a>(&mut self, key: &str, values: impl Iterator<Item = &'a str>, sorted: bool) {
+        let mut values: Vec<&str> = values.collect();
+        if sorted {
+            values.sort_unstable();
+        }
+        self.put(key, values);
+    }
+}
+
+fn entity_node(ontology: &MythOntology, entity: &MythEntity, options: &JsonLdOptions) -> Value {
+    let iri = |id: &MythId| options.entity_iri(id);
+    let iris = |ids: &mut dyn Iterator<Item = &MythId>| {
+        let mut iris: Vec<String> = ids.filter(|id| ontology.contains(id)).map(&iri).collect();
+        iris.sort();
+        iris
+    };
+    
+    let mut node = Node::default();
+    node.put("id", iri(entity.id()));
+    node.put("type", classes(entity).iter().map(|c| c.to_string()).collect::<Vec<_>>());
+    node.put("name", match &options.locale {
+        Some(locale) => entity.name_in(locale),
+        None => entity.name(),
+    });
+    
+    match entity {
+        MythEntity::Deity(e) => {
+            node.put("description", e.description.as_str());
+            node.put_list("alternateName", e.alternative_names.iter().map(|n| n.value()), false);
+            node.put("culture", e.culture.value());
+            node.put("pantheon", e.pantheon.as_ref().map(|p| p.value()));
+            node.put("gender", e.gender.to_string());
+            node.put("importance", e.importance.to_string());
+            node.put_list("domains", e.domains.iter().map(|d| d.value()), true);
+        },
+        MythEntity::Hero(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("origin", e.origin.to_string());
+            node.put_list("achievements", e.achievements.iter().map(String::as_str), false);
+        },
+        MythEntity::Creature(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("creatureType", e.creature_type.to_string());
+            node.put_list("habitat", e.habitat.iter().map(String::as_str), true);
+            node.put_list("abilities", e.abilities.iter().map(String::as_str), false);
+        },
+        MythEntity::Artifact(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("artifactType", e.artifact_type.to_string());
+            node.put_list("powers", e.powers.iter().map(String::as_str), false);
+            node.put("creator", e.creator.as_deref());
+            node.put("owner", e.owner.as_deref());
+        },
+        MythEntity::Location(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("locationType", e.location_type.to_string());
+            node.put_list("characteristics", e.characteristics.iter().map(String::as_str), false);
+            node.put_list("accessibility", e.accessibility.iter().map(String::as_str), false);
+        },
+        MythEntity::Concept(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("conceptType", e.concept_type.to_string());
+            node.put_list("manifestations", e.manifestations.iter().map(String::as_str), false);
+        },
+        MythEntity::Narrative(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            let episodes: Vec<Value> = e.episodes.iter()
+                .map(|episode| {
+                    let participants: Vec<Value> = episode.participants.iter()
+                        .filter(|p| ontology.contains(&p.id))
+                        .map(|p| json!({ "entity": iri(&p.id), "role": p.role.to_string() }))
+                        .collect();
+                    let mut episode_node = Node::default();
+                    episode_node.put("type", "crm:E5_Event");
+                    episode_node.put("name", episode.title.as_str());
+                    episode_node.put("description", episode.description.as_str());
+                    episode_node.put("participants", participants);
+                    episode_node.put("locations", iris(&mut episode.locations.iter()));
+                    episode_node.put("artifacts", iris(&mut episode.artifacts.iter()));
+                    episode_node.put("establishes", iris(&mut episode.establishes.iter()));
+                    Value::Object(episode_node.0)
+                })
+                .collect();
+            node.put("episodes", episodes);
+        },
+        MythEntity::Event(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("hadParticipant", iris(&mut e.participants.iter()));
+            node.put("locations", iris(&mut e.location.iter()));
+            node.put("era", e.era.as_ref().filter(|id| ontology.contains(id)).map(iri));
+            node.put("causedBy", iris(&mut e.caused_by.iter()));
+            node.put("leadsTo", iris(&mut e.leads_to.iter()));
+            node.put("after", iris(&mut e.after.iter()));
+            node.put("before", iris(&mut e.before.iter()));
+        },
+        MythEntity::Culture(e) => {
+            node.put("description", e.description.as_str());
+            node.put_list("regions", e.regions.iter().map(|r| r.value()), true);
+            node.put_list("influences", e.influences.iter().map(|c| c.value()), false);
+            node.put_list("languages", e.languages.iter().map(|l| l.value()), true);
+            node.put_list("culturalPractices", e.cultural_practices.iter().map(String::as_str), false);
+            node.put("timePeriods", e.time_periods.iter().map(time_span).collect::<Vec<_>>());
+        },
+        MythEntity::Pantheon(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("primaryDeities", iris(&mut e.primary_deities.iter()));
+            node.put("secondaryDeities", iris(&mut e.secondary_deities.iter()));
+            node.put("cosmology", e.cosmology.as_ref().map(|c| c.value()));
+            node.put("foundingMyth", e.founding_myth.as_deref());
+        },
+        MythEntity::MythologicalEra(e) => {
+            node.put("description", e.description.as_str());
+            node.put("culture", e.culture.value());
+            node.put("sequenceOrder", e.sequence_order);
+            node.put_list("characteristics", e.characteristics.iter().map(|c| c.value()), false);
+            node.put("endEvent", e.end_event.as_ref().map(|event| event.value()));
+            node.put("endedBy", e.ending.as_ref().filter(|id| ontology.contains(id)).map(iri));
+        },
+        MythEntity::MythologicalRegion(e) => {
+            node.put("description", e.description.as_str());
+            node.put_list("cultures", e.cultures.iter().map(|c| c.value()), true);
+            node.put_list("features", e.features.iter().map(|f| f.value()), false);
+            node.put_list("modernLocations", e.modern_locations.iter().map(|l| l.value()), true);
+            node.put("significance", e.significance.as_str());
+        },
+        _ => {
+            if let Some(relationship) = entity.relationship() {
+                node.put("description", relationship.description.as_str());
+                node.put("assignedTo", iri(&relationship.source_id));
+                node.put("assigned", iri(&relationship.target_id));
+                node.put("relationshipType", relationship.relationship_type.to_string());
+                node.put("relationshipKind", entity.relationship_kind());
+                node.put("strength", relationship.strength);
+                node.put("bidirectional", relationship.bidirectional);
+            }
+            match entity {
+                MythEntity::AllianceRelationship(e) => {
+                    node.put("purpose", e.purpose.as_str());
+                    node.put("duration", e.duration.as_deref());
+                    node.put("properties", json!(e.properties));
+                },
+                MythEntity::ConflictRelationship(e) => {
+                    if let Some(outcome) = &e.outcome {
+                        let mut outcome_node = Node::default();
+                        outcome_node.put("description", outcome.description.as_str());
+                        outcome_node.put("victor", outcome.victor_id.as_ref().map(iri));
+                        outcome_node.put_list("consequences", outcome.consequences.iter().map(String::as_str), false);
+                        node.put("outcome", Value::Object(outcome_node.0));
+                    }
+                    node.put("properties", json!(e.properties));
+                },
+                MythEntity::TransformationRelationship(e) => {
+                    node.put("cause", e.cause.as_str());
+                    node.put("permanent", e.permanent);
+                    node.put("reversible", e.reversible);
+                    node.put("properties", json!(e.properties));
+                },
+                MythEntity::FamilyRelationship(e) => node.put("properties", json!(e.properties)),
+                MythEntity::SyncretismRelationship(e) => node.put("properties", json!(e.properties)),
+                _ => {},
+            }
+        },
+    }
+    
+    node.put("relatedTo", iris(&mut entity.relationships().iter()));
+    put_metadata(&mut node, entity.metadata(), options);
+    Value::Object(node.0)
+}
+
+/// The `myth:`, CIDOC-CRM and schema.org classes of an entity
+fn classes(entity: &MythEntity) -> Vec<String> {
+    let (crm, schema) = match entity {
+        MythEntity::Deity(_) | MythEntity::Hero(_) => ("E28_Conceptual_Object", Some("Person")),
+        MythEntity::Creature(_) | MythEntity::Artifact(_) => ("E28_Conceptual_Object", Some("Thing")),
+        MythEntity::Concept(_) => ("E89_Propositional_Object", Some("DefinedTerm")),
+        MythEntity::Narrative(_) => ("E89_Propositional_Object", Some("CreativeWork")),
+        MythEntity::Event(_) => ("E5_Event", Some("Event")),
+        MythEntity::Location(_) | MythEntity::MythologicalRegion(_) => ("E53_Place", Some("Place")),
+        MythEntity::Culture(_) | MythEntity::Pantheon(_) => ("E74_Group", Some("Organization")),
+        MythEntity::MythologicalEra(_) => ("E4_Period", None),
+        _ => ("E13_Attribute_Assignment", None),
+    };
+    let mut classes = vec![format!("myth:{}", entity.entity_type()), format!("crm:{}", crm)];
+    classes.extend(schema.map(|s| format!("schema:{}", s)));
+    classes
+}
+
+fn time_span(period: &TimePeriod) -> Value {
+    let mut node = Node::default();
+    node.put("type", "crm:E52_Time-Span");
+    node.put("name", period.name.as_str());
+    node.put("begin", period.start.as_ref().and_then(|date| date.extent().earliest).map(xsd_year));
+    node.put("end", period.end.as_ref().and_then(|date| date.extent().latest).map(xsd_year));
+    node.put("dating", period.span().map(|date| date.to_string()));
+    node.put("description", period.description.as_deref());
+    Value::Object(node.0)
+}
+
+fn put_metadata(node: &mut Node, metadata: &Metadata, options: &JsonLdOptions) {
+    node.put("documentedIn", cited_sources(metadata).iter().map(|id| options.source_iri(id)).collect::<Vec<_>>());
+    let citations: Vec<Value> = metadata.citations.iter()
+        .filter(|citation| !citation.locator.is_empty() || citation.field.is_some() || citation.note.is_some())
+        .map(|citation| {
+            let mut node = Node::default();
+            node.put("citedSource", options.source_iri(&citation.source));
+            node.put("locator", citation.locator.to_string());
+            node.put("citedField", citation.field.as_deref());
+            node.put("notes", citation.note.as_deref());
+            Value::Object(node.0)
+        })
+        .collect();
+    node.put("citations", citations);
+    node.put("variantOf", metadata.variant.as_ref().map(|variant| variant.question.as_str()));
+    let claims: Vec<Value> = metadata.variant.iter().map(|variant| claim_node(&variant.claim, None, options))
+        .chain(metadata.claims.iter().map(|claim| claim_node(&claim.claim, Some((&claim.field, &claim.value)), options)))
+        .collect();
+    node.put("claims", claims);
+    let (preferred, alternative): (Vec<_>, Vec<_>) = metadata.labels.iter().partition(|label| metadata.is_preferred_label(label));
+    let label_node = |label: &Label| {
+        let mut node = Node::default();
+        node.put("type", "skosxl:Label");
+        node.put("literalForm", label.text.as_str());
+        node.put("language", label.language.value());
+        node.put("script", label.script.as_deref());
+        node.put("nameKind", label.kind.to_string());
+        Value::Object(node.0)
+    };
+    node.put("prefLabel", preferred.into_iter().map(label_node).collect::<Vec<_>>());
+    node.put("altLabel", alternative.into_iter().map(label_node).collect::<Vec<_>>());
+    node.put("confidence", metadata.confidence);
+    node.put("firstAttested", metadata.first_attested.as_ref().map(ToString::to_string));
+    node.put("lastAttested", metadata.last_attested.as_ref().map(ToString::to_string));
+    node.put("attributes", json!(metadata.attributes));
+    node.put("dateCreated", metadata.created_at.to_rfc3339());
+    node.put("dateModified", metadata.updated_at.to_rfc3339());
+}
+
+fn claim_node(claim: &Claim, field_value: Option<(&str, &str)>, options: &JsonLdOptions) -> Value {
+    let mut node = Node::default();
+    if let Some((field, value)) = field_value {
+        node.put("claimedField", field);
+        node.put("value", value);
+    }
+    node.put("tradition", claim.tradition.as_deref());
+    node.put("citedSource", options.source_iri(&claim.citation.source));
+    node.put("locator", claim.citation.locator.to_string());
+    node.put("notes", claim.note.as_deref());
+    Value::Object(node.0)
+}
+
+/// The distinct sources cited in an entity's metadata, claims included, in citation order
+fn cited_sources(metadata: &Metadata) -> Vec<&SourceId> {
+    let mut sources = Vec::new();
+    for citation in metadata.citations.iter().chain(metadata.all_claims().map(|claim| &claim.citation)) {
+        if !sources.contains(&&citation.source) {
+            sources.push(&citation.source);
+        }
+    }
+    sources
+}
+
+fn source_nodes(ontology: &MythOntology, entity: &MythEntity, options: &JsonLdOptions) -> Vec<Value> {
+    cited_sources(entity.metadata()).into_iter()
+        .filter_map(|id| ontology.source(id).map(|source| source_node(source, &options.source_iri(id))))
+        .collect()
+}
+
+fn source_node(source: &Source, iri: &str) -> Value {
+    let source_type = match &source.source_type {
+        SourceType::Other(name) => name.clone(),
+        known => serde_json::to_value(known).ok().and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default(),
+    };
+    let mut node = Node::default();
+    node.put("id", iri);
+    node.put("type", json!(["crm:E31_Document", "schema:CreativeWork"]));
+    node.put("name", source.title.as_str());
+    node.put("author", source.author.as_deref());
+    node.put("datePublished", match source.date {
+        Some(HistoricalDate::Year(year)) => Some(xsd_year(year)),
+        _ => None,
+    });
+    node.put("dating", source.date.as_ref().map(ToString::to_string));
+    node.put("sourceType", source_type);
+    node.put("url", source.url.as_deref());
+    node.put("notes", source.notes.as_deref());
+    Value::Object(node.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::NameKind;
+    use crate::entities::Deity;
+    use crate::examples::{create_greek_ontology, create_norse_ontology};
+    
+    #[test]
+    fn maps_entities_to_crm_and_schema_classes() {
+        let greek = create_greek_ontology();
+        let options = JsonLdOptions::new().base_iri("https://myths.example.org");
+        let document = ontology_to_jsonld(&greek, &options);
+        let graph = document["graph"].as_array().unwrap();
+        
+        let node_named = |name: &str| graph.iter().find(|n| n["name"] == name).unwrap();
+        let zeus = node_named("Zeus");
+        assert_eq!(zeus["type"], json!(["myth:Deity", "crm:E28_Conceptual_Object", "schema:Person"]));
+        assert!(zeus["id"].as_str().unwrap().starts_with("https://myths.example.org/entity/"));
+        assert!(zeus["domains"].as_array().unwrap().contains(&json!("Sky")));
+        assert_eq!(zeus["prefLabel"][0]["literalForm"], "Ζεύς");
+        assert_eq!(zeus["altLabel"][1], json!({
+            "type": "skosxl:Label",
+            "literalForm": "Κρονίδης",
+            "language": "Ancient Greek",
+            "script": "Grek",
+            "nameKind": "Epithet",
+        }));
+        let zeus_id = zeus["id"].clone();
+        let localized = ontology_to_jsonld(&greek, &options.clone().locale("Ancient Greek"));
+        assert!(localized["graph"].as_array().unwrap().iter().any(|n| n["id"] == zeus_id && n["name"] == "Ζεύς"));
+        
+        let source = graph.iter().find(|n| n["id"] == zeus["documentedIn"][0]).unwrap();
+        assert_eq!(source["type"][0], "crm:E31_Document");
+        
+        let olympus = node_named("Mount Olympus");
+        assert_eq!(olympus["type"][1], "crm:E53_Place");
+        
+        let family = graph.iter().find(|n| n["type"][0] == "myth:FamilyRelationship").unwrap();
+        assert_eq!(family["type"][1], "crm:E13_Attribute_Assignment");
+        assert!(graph.iter().any(|n| n["id"] == family["assignedTo"]));
+        assert_eq!(document["@context"]["myth"], "https://myths.example.org/ontology#");
+        
+        let fragment = ontology_to_jsonld(&greek, &JsonLdOptions::new().base_iri("https://myths.example.org/data#"));
+        assert_eq!(fragment["@context"]["myth"], "https://myths.example.org/data/ontology#");
+    }
+    
+    #[test]
+    fn emits_standalone_entities_and_time_spans() {
+        let norse = create_norse_ontology();
+        let culture = norse.entities_of_type("Culture").next().unwrap();
+        let options = JsonLdOptions::new().context_url("https://myths.example.org/context.jsonld");
+        let node = entity_to_jsonld(&norse, culture, &options);
+        
+        assert_eq!(node["@context"], "https://myths.example.org/context.jsonld");
+        assert_eq!(node["id"], options.entity_iri(culture.id()));
+        let period = &node["timePeriods"][0];
+        assert_eq!(period["type"], "crm:E52_Time-Span");
+        assert!(period["begin"].is_string());
+        
+        let context = jsonld_context(&options);
+        assert_eq!(context["@context"]["begin"]["@id"], "crm:P82a_begin_of_the_begin");
+    }
+    
+    #[test]
+    fn writes_labels_by_locale() {
+        let mut ontology = MythOntology::new();
+        let mut osiris = Deity::new("Osiris", "God of the dead", "Egyptian");
+        osiris.metadata.add_label(Label::new("wsjr", "Ancient Egyptian", NameKind::Transliteration).script("Latn"));
+        osiris.metadata.add_label(Label::new("Ὄσιρις", "Ancient Greek", NameKind::Exonym).script("Grek").preferred());
+        osiris.metadata.add_label(Label::new("Ὀσῖρις", "Ancient Greek", NameKind::Exonym).script("Grek"));
+        ontology.add_entity(MythEntity::Deity(osiris));
+        ontology.add_entity(MythEntity::Deity(Deity::new("Set", "God of storms", "Egyptian")));
+        
+        let document = ontology_to_jsonld(&ontology, &JsonLdOptions::new());
+        assert_eq!(document["@context"]["skosxl"], "http://www.w3.org/2008/05/skos-xl#");
+        let graph = document["graph"].as_array().unwrap();
+        let osiris = graph.iter().find(|n| n["name"] == "Osiris").unwrap();
+        let forms = |labels: &Value| labels.as_array().unwrap().iter().map(|l| l["literalForm"].clone()).collect::<Vec<_>>();
+        assert_eq!(forms(&osiris["prefLabel"]), [json!("wsjr"), json!("Ὄσιρις")]);
+        assert_eq!(forms(&osiris["altLabel"]), [json!("Ὀσῖρις")]);
+        assert_eq!(osiris["prefLabel"][0]["nameKind"], "Transliteration");
+        let set = graph.iter().find(|n| n["name"] == "Set").unwrap();
+        assert!(set.get("prefLabel").is_none() && set.get("altLabel").is_none());
+        
+        let localized = ontology_to_jsonld(&ontology, &JsonLdOptions::new().locale("Ancient Greek"));
+        let names: Vec<&str> = localized["graph"].as_array().unwrap().iter()
+            .filter_map(|n| n["name"].as_str())
+            .collect();
+        assert!(names.contains(&"Ὄσιρις") && names.contains(&"Set"));
+    }
+    
+    #[test]
+    fn writes_variants_by_resolution() {
+        let greek = create_greek_ontology();
+        let options = JsonLdOptions::new();
+        let all = ontology_to_jsonld(&greek, &options);
+        let graph = all["graph"].as_array().unwrap();
+        let ouranos = graph.iter().find(|n| n["name"] == "Ouranos parent of Aphrodite").unwrap();
+        assert_eq!(ouranos["variantOf"], "parentage of Aphrodite");
+        assert_eq!(ouranos["claims"][0]["tradition"], "Hesiodic");
+        let cerberus = graph.iter().find(|n| n["name"] == "Cerberus").unwrap();
+        assert_eq!(cerberus["claims"].as_array().unwrap().len(), 3);
+        
+        let earliest = ontology_to_jsonld(&greek, &options.resolution(ResolutionPolicy::PreferEarliest));
+        let graph = earliest["graph"].as_array().unwrap();
+        assert!(graph.iter().all(|n| n["name"] != "Ouranos parent of Aphrodite"));
+        let cerberus = graph.iter().find(|n| n["name"] == "Cerberus").unwrap();
+        assert_eq!(cerberus["claims"], json!([{
+            "claimedField": "heads",
+            "value": "50",
+            "tradition": "Hesiodic",
+            "citedSource": cerberus["claims"][0]["citedSource"],
+            "locator": "line 310-312",
+        }]));
+    }
+}
@@ -0,0 +1,16 @@
+//! Export formats for mythological ontologies
+//!
+//! This module renders ontologies, or subsets of them such as query results, in
+//! formats understood by external tools, and imports ontologies from spreadsheets.
+
+mod dot;
+mod jsonld;
+mod network;
+mod rdf;
+mod tabular;
+
+pub use dot::*;
+pub use jsonld::*;
+pub use network::*;
+pub use rdf::*;
+pub use tabular::*;
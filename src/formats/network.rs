@@ -0,0 +1,426 @@
+//! # GraphML and GEXF Export
+//!
+//! Writes the relationship graph in the two XML formats read by network analysis
+//! tools: GraphML for Cytoscape, yEd and NetworkX, and GEXF for Gephi. Entities
+//! become nodes carrying their type, culture, domains, importance and origin, and
+//! relationship entities become edges carrying their strength, direction and the
+//! fields of their variant, such as the family relationship type or the outcome of
+//! a conflict. Bidirectional relationships are written as undirected edges.
+//!
+//! List-valued attributes are joined with `"; "`, since neither format has a list
+//! type that every tool understands.
+//!
+//! Every variant claim is written. To export only the claims preferred by a
+//! [`ResolutionPolicy`](crate::core::ResolutionPolicy), export the view returned by
+//! [`MythOntology::resolved`].
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::formats::{ontology_to_gexf, ontology_to_graphml};
+//!
+//! let greek = create_greek_ontology();
+//! assert!(ontology_to_graphml(&greek).contains("<data key=\"label\">Zeus</data>"));
+//! assert!(ontology_to_gexf(&greek).contains("label=\"Zeus\""));
+//! ```
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use crate::core::{MythEntity, MythId, MythOntology};
+use crate::formats::dot::{subgraph_edges, subgraph_nodes};
+
+/// Value type of an attribute column
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AttributeType {
+    String,
+    Double,
+    Boolean,
+}
+
+impl AttributeType {
+    fn name(self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::Double => "double",
+            Self::Boolean => "boolean",
+        }
+    }
+}
+
+/// Attribute columns of nodes, in output order
+const NODE_ATTRIBUTES: &[(&str, AttributeType)] = &[
+    ("label", AttributeType::String),
+    ("type", AttributeType::String),
+    ("culture", AttributeType::String),
+    ("description", AttributeType::String),
+    ("domains", AttributeType::String),
+    ("importance", AttributeType::String),
+    ("origin", AttributeType::String),
+];
+
+/// Attribute columns of edges, in output order
+const EDGE_ATTRIBUTES: &[(&str, AttributeType)] = &[
+    ("label", AttributeType::String),
+    ("type", AttributeType::String),
+    ("kind", AttributeType::String),
+    ("relationship_type", AttributeType::String),
+    ("strength", AttributeType::Double),
+    ("bidirectional", AttributeType::Boolean),
+    ("family_type", AttributeType::String),
+    ("alliance_type", AttributeType::String),
+    ("purpose", AttributeType::String),
+    ("duration", AttributeType::String),
+    ("conflict_type", AttributeType::String),
+    ("outcome", AttributeType::String),
+    ("victor", AttributeType::String),
+    ("consequences", AttributeType::String),
+    ("transformation_type", AttributeType::String),
+    ("cause", AttributeType::String),
+    ("permanent", AttributeType::Boolean),
+    ("reversible", AttributeType::Boolean),
+    ("syncretism_type", AttributeType::String),
+];
+
+/// A node or edge with its attribute values
+struct Element<'a> {
+    entity: &'a MythEntity,
+    attributes: Vec<(&'static str, String)>,
+}
+
+/// Render every entity and relationship of an ontology as GraphML
+pub fn ontology_to_graphml(ontology: &MythOntology) -> String {
+    subgraph_to_graphml(ontology, &all_ids(ontology))
+}
+
+/// Render a subset of an ontology as GraphML
+///
+/// The subset is chosen as in [`subgraph_to_dot`](crate::formats::subgraph_to_dot).
+pub fn subgraph_to_graphml(ontology: &MythOntology, ids: &[MythId]) -> String {
+    let (nodes, edges) = elements(ontology, ids);
+    
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\" ");
+    output.push_str("xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\" ");
+    output.push_str("xsi:schemaLocation=\"http://graphml.graphdrawing.org/xmlns ");
+    output.push_str("http://graphml.graphdrawing.org/xmlns/1.0/graphml.xsd\">\n");
+    
+    for (domain, attributes) in [("node", NODE_ATTRIBUTES), ("edge", EDGE_ATTRIBUTES)] {
+        for (name, attribute_type) in attributes {
+            let _ = writeln!(
+                output,
+                "  <key id=\"{}\" for=\"{}\" attr.name=\"{}\" attr.type=\"{}\"/>",
+                graphml_key(domain, name),
+                domain,
+                name,
+                attribute_type.name(),
+            );
+        }
+    }
+    
+    output.push_str("  <graph id=\"mythology\" edgedefault=\"directed\">\n");
+    for node in &nodes {
+        let _ = writeln!(output, "    <node id=\"{}\">", node.entity.id());
+        write_graphml_data(&mut output, "node", &node.attributes);
+        output.push_str("    </node>\n");
+    }
+    for edge in &edges {
+        let relationship = edge.entity.relationship().expect("edges are relationship entities");
+        let _ = writeln!(
+            output,
+            "    <edge id=\"{}\" source=\"{}\" target=\"{}\" directed=\"{}\">",
+            relationship.id,
+            relationship.source_id,
+            relationship.target_id,
+            !relationship.bidirectional,
+        );
+        write_graphml_data(&mut output, "edge", &edge.attributes);
+        output.push_str("    </edge>\n");
+    }
+    output.push_str("  </graph>\n");
+    output.push_str("</graphml>\n");
+    output
+}
+
+/// Render every entity and relationship of an ontology as GEXF
+pub fn ontology_to_gexf(ontology: &MythOntology) -> String {
+    subgraph_to_gexf(ontology, &all_ids(ontology))
+}
+
+/// Render a subset of an ontology as GEXF
+///
+/// The subset is chosen as in [`subgraph_to_dot`](crate::formats::subgraph_to_dot).
+pub fn subgraph_to_gexf(ontology: &MythOntology, ids: &[MythId]) -> String {
+    let (nodes, edges) = elements(ontology, ids);
+    
+    let mut output = String::new();
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<gexf xmlns=\"http://gexf.net/1.3\" version=\"1.3\">\n");
+    output.push_str("  <meta>\n    <creator>mythologic</creator>\n  </meta>\n");
+    output.push_str("  <graph mode=\"static\" defaultedgetype=\"directed\">\n");
+    
+    // Labels are written as the element's own `label`, not as attributes
+    for (class, attributes) in [("node", NODE_ATTRIBUTES), ("edge", EDGE_ATTRIBUTES)] {
+        let _ = writeln!(output, "    <attributes class=\"{}\">", class);
+        for (name, attribute_type) in attributes.iter().filter(|(name, _)| *name != "label") {
+            let _ = writeln!(
+                output,
+                "      <attribute id=\"{}\" title=\"{}\" type=\"{}\"/>",
+                name,
+                name,
+                attribute_type.name(),
+            );
+        }
+        output.push_str("    </attributes>\n");
+    }
+    
+    output.push_str("    <nodes>\n");
+    for node in &nodes {
+        let _ = writeln!(
+            output,
+            "      <node id=\"{}\" label=\"{}\">",
+            node.entity.id(),
+            escape_xml(node.entity.name()),
+        );
+        write_gexf_values(&mut output, &node.attributes);
+        output.push_str("      </node>\n");
+    }
+    output.push_str("    </nodes>\n");
+    
+    output.push_str("    <edges>\n");
+    for edge in &edges {
+        let relationship = edge.entity.relationship().expect("edges are relationship entities");
+        let _ = writeln!(
+            output,
+            "      <edge id=\"{}\" source=\"{}\" target=\"{}\" type=\"{}\" label=\"{}\" weight=\"{}\">",
+            relationship.id,
+            relationship.source_id,
+            relationship.target_id,
+            if relationship.bidirectional { "undirected" } else { "directed" },
+            escape_xml(&relationship.name),
+            relationship.strength,
+        );
+        write_gexf_values(&mut output, &edge.attributes);
+        output.push_str("      </edge>\n");
+    }
+    output.push_str("    </edges>\n");
+    
+    output.push_str("  </graph>\n");
+    output.push_str("</gexf>\n");
+    output
+}
+
+/// Write an ontology as GraphML to a file
+pub fn write_graphml(ontology: &MythOntology, path: &Path) -> std::io::Result<()> {
+    fs::write(path, ontology_to_graphml(ontology))
+}
+
+/// Write an ontology as GEXF to a file
+pub fn write_gexf(ontology: &MythOntology, path: &Path) -> std::io::Result<()> {
+    fs::write(path, ontology_to_gexf(ontology))
+}
+
+fn all_ids(ontology: &MythOntology) -> Vec<MythId> {
+    ontology.entities().map(|entity| entity.id().clone()).collect()
+}
+
+/// The nodes and edges of a subset, with their attribute values
+fn elements<'a>(ontology: &'a MythOntology, ids: &[MythId]) -> (Vec<Element<'a>>, Vec<Element<'a>>) {
+    let nodes = subgraph_nodes(ontology, ids);
+    let edges = subgraph_edges(ontology, &nodes);
+    (
+        nodes.into_iter().map(|entity| Element { entity, attributes: node_attributes(entity) }).collect(),
+        edges.into_iter().map(|entity| Element { entity, attributes: edge_attributes(entity) }).collect(),
+    )
+}
+
+fn node_attributes(entity: &MythEntity) -> Vec<(&'static str, String)> {
+    let mut attributes = vec![
+        ("label", entity.name().to_string()),
+        ("type", entity.entity_type().to_string()),
+        ("culture", entity.culture_name().unwrap_or_default().to_string()),
+    ];
+    match entity {
+        MythEntity::Deity(deity) => {
+            attributes.push(("description", deity.description.clone()));
+            let mut domains: Vec<&str> = deity.domains.iter().map(|d| d.value()).collect();
+            domains.sort_unstable();
+            attributes.push(("domains", domains.join("; ")));
+            attributes.push(("importance", deity.importance.to_string()));
+        },
+        MythEntity::Hero(hero) => {
+            attributes.push(("description", hero.description.clone()));
+            attributes.push(("origin", hero.origin.to_string()));
+        },
+        MythEntity::Creature(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Artifact(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Location(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Concept(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Narrative(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Event(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Culture(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::Pantheon(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::MythologicalEra(e) => attributes.push(("description", e.description.clone())),
+        MythEntity::MythologicalRegion(e) => attributes.push(("description", e.description.clone())),
+        _ => {},
+    }
+    attributes
+}
+
+fn edge_attributes(entity: &MythEntity) -> Vec<(&'static str, String)> {
+    let relationship = entity.relationship().expect("edges are relationship entities");
+    let mut attributes = vec![
+        ("label", relationship.name.clone()),
+        ("type", entity.entity_type().to_string()),
+        ("kind", entity.relationship_kind().unwrap_or_default()),
+        ("relationship_type", relationship.relationship_type.to_string()),
+        ("strength", relationship.strength.to_string()),
+        ("bidirectional", relationship.bidirectional.to_string()),
+    ];
+    match entity {
+        MythEntity::FamilyRelationship(e) => {
+            attributes.push(("family_type", e.family_type.to_string()));
+        },
+        MythEntity::AllianceRelationship(e) => {
+            attributes.push(("alliance_type", e.alliance_type.to_string()));
+            attributes.push(("purpose", e.purpose.clone()));
+            attributes.push(("duration", e.duration.clone().unwrap_or_default()));
+        },
+        MythEntity::ConflictRelationship(e) => {
+            attributes.push(("conflict_type", e.conflict_type.to_string()));
+            if let Some(outcome) = &e.outcome {
+                attributes.push(("outcome", outcome.description.clone()));
+                attributes.push(("victor", outcome.victor_id.as_ref().map(MythId::to_string).unwrap_or_default()));
+                attributes.push(("consequences", outcome.consequences.join("; ")));
+            }
+        },
+        MythEntity::TransformationRelationship(e) => {
+            attributes.push(("transformation_type", e.transformation_type.to_string()));
+            attributes.push(("cause", e.cause.clone()));
+            attributes.push(("permanent", e.permanent.to_string()));
+            attributes.push(("reversible", e.reversible.to_string()));
+        },
+        MythEntity::SyncretismRelationship(e) => {
+            attributes.push(("syncretism_type", e.syncretism_type.to_string()));
+        },
+        _ => {},
+    }
+    attributes.retain(|(_, value)| !value.is_empty());
+    attributes
+}
+
+/// GraphML keys are shared between nodes and edges, so prefix them by domain
+fn graphml_key(domain: &str, name: &str) -> String {
+    match (domain, name) {
+        // Cytoscape and Gephi look for a node key named `label`
+        ("node", "label") => "label".to_string(),
+        _ => format!("{}_{}", &domain[..1], name),
+    }
+}
+
+fn write_graphml_data(output: &mut String, domain: &str, attributes: &[(&str, String)]) {
+    for (name, value) in attributes.iter().filter(|(_, value)| !value.is_empty()) {
+        let _ = writeln!(
+            output,
+            "      <data key=\"{}\">{}</data>",
+            graphml_key(domain, name),
+            escape_xml(value),
+        );
+    }
+}
+
+fn write_gexf_values(output: &mut String, attributes: &[(&str, String)]) {
+    let values: Vec<_> = attributes.iter()
+        .filter(|(name, value)| *name != "label" && !value.is_empty())
+        .collect();
+    if values.is_empty() {
+        return;
+    }
+    output.push_str("        <attvalues>\n");
+    for (name, value) in values {
+        let _ = writeln!(output, "          <attvalue for=\"{}\" value=\"{}\"/>", name, escape_xml(value));
+    }
+    output.push_str("        </attvalues>\n");
+}
+
+/// Escape text for use in XML content and attribute values
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            '\n' => escaped.push_str("&#10;"),
+            // Control characters other than tab and carriage return are not allowed in XML 1.0
+            c if c.is_control() && c != '\t' && c != '\r' => {},
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Deity;
+    use crate::relationships::{
+        ConflictOutcome, ConflictRelationship, ConflictType, FamilyRelationship, FamilyRelationshipType,
+    };
+    
+    fn sample() -> (MythOntology, MythId, MythId) {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.add_domain("Sky");
+        zeus.add_domain("Thunder");
+        let cronus = Deity::new("Cronus & Rhea's husband", "", "Greek");
+        let (zeus_id, cronus_id) = (zeus.id.clone(), cronus.id.clone());
+        ontology.add_entity(MythEntity::Deity(zeus));
+        ontology.add_entity(MythEntity::Deity(cronus));
+        
+        ontology.add_entity(MythEntity::FamilyRelationship(FamilyRelationship::new(
+            "Cronus fathers Zeus", "", cronus_id.clone(), zeus_id.clone(), FamilyRelationshipType::Parent,
+        )));
+        let mut war = ConflictRelationship::new("Titanomachy", "", zeus_id.clone(), cronus_id.clone(), ConflictType::War);
+        war.outcome = Some(ConflictOutcome {
+            description: "The Titans are imprisoned".to_string(),
+            victor_id: Some(zeus_id.clone()),
+            consequences: vec!["Zeus rules".to_string()],
+        });
+        ontology.add_entity(MythEntity::ConflictRelationship(war));
+        (ontology, zeus_id, cronus_id)
+    }
+    
+    #[test]
+    fn writes_graphml_with_typed_attributes() {
+        let (ontology, zeus_id, cronus_id) = sample();
+        let graphml = ontology_to_graphml(&ontology);
+        
+        assert!(graphml.contains("<key id=\"e_strength\" for=\"edge\" attr.name=\"strength\" attr.type=\"double\"/>"));
+        assert!(graphml.contains("<data key=\"n_domains\">Sky; Thunder</data>"));
+        assert!(graphml.contains("<data key=\"label\">Cronus &amp; Rhea&apos;s husband</data>"));
+        assert!(graphml.contains(&format!("source=\"{}\" target=\"{}\" directed=\"true\"", cronus_id, zeus_id)));
+        assert!(graphml.contains(&format!("source=\"{}\" target=\"{}\" directed=\"false\"", zeus_id, cronus_id)));
+        assert!(graphml.contains("<data key=\"e_family_type\">Parent</data>"));
+        assert!(graphml.contains(&format!("<data key=\"e_victor\">{}</data>", zeus_id)));
+        assert_eq!(graphml.matches("<edge ").count(), 2);
+    }
+    
+    #[test]
+    fn writes_gexf_with_weights_and_edge_types() {
+        let (ontology, zeus_id, _) = sample();
+        let gexf = ontology_to_gexf(&ontology);
+        
+        assert!(gexf.contains("<attribute id=\"conflict_type\" title=\"conflict_type\" type=\"string\"/>"));
+        assert!(gexf.contains(&format!("<node id=\"{}\" label=\"Zeus\">", zeus_id)));
+        assert!(gexf.contains("type=\"undirected\" label=\"Titanomachy\""));
+        assert!(gexf.contains("<attvalue for=\"outcome\" value=\"The Titans are imprisoned\"/>"));
+        assert!(gexf.contains("<attvalue for=\"importance\""));
+        
+        let only_zeus = subgraph_to_gexf(&ontology, &[zeus_id]);
+        assert_eq!(only_zeus.matches("<node ").count(), 1);
+        assert!(!only_zeus.contains("<edge "));
+    }
+}
@@ -0,0 +1,733 @@
+//! # RDF/Turtle and OWL Export
+//!
+//! Publishes an ontology as linked data in Turtle. The output has two parts:
+//!
+//! - An OWL schema: a class for every entity type, grouped under `MythEntity`,
+//!   `CulturalContext` and `Relationship`, and an object property for every
+//!   `RelationshipType` with the family, alliance, conflict, transformation and
+//!   syncretism subtypes as sub-properties (`myth:family` ⊃ `myth:familyParent`).
+//! - One description per entity. IRIs are built from the base IRI and the entity's
+//!   `MythId`, so they stay stable across exports. Structured fields become
+//!   `myth:` datatype properties, and fields holding entity IDs link to those
+//!   entities.
+//!
+//! Each relationship entity is written both as a direct edge between its endpoints
+//! (`<zeus> myth:familyParent <athena>`) and as a reified `rdf:Statement` about that
+//! edge carrying its strength and metadata. `Metadata::citations` become
+//! `prov:wasDerivedFrom` links to the descriptions of the bibliography's sources,
+//! with a `myth:citation` node for any locator or cited field, and `Metadata::confidence` is
+//! attached to a reified statement of the entity's type, or directly to the
+//! relationship statement for relationships.
+//!
+//! Variant claims become `myth:claim` nodes naming the claimed field and value, the
+//! tradition and the cited source, and competing relationships carry the question
+//! they answer as `myth:variantOf`. With a [`ResolutionPolicy`] other than
+//! `ShowAll`, only the preferred claims are exported.
+//!
+//! Multilingual labels become SKOS-XL labels with their language, script and kind,
+//! linked by `skosxl:prefLabel` for the preferred label of each language and
+//! `skosxl:altLabel` for the others. `rdfs:label` is the primary name, or the
+//! preferred label in the language set with [`RdfOptions::locale`].
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::formats::{ontology_to_turtle, RdfOptions};
+//!
+//! let greek = create_greek_ontology();
+//! let turtle = ontology_to_turtle(&greek, &RdfOptions::new().base_iri("https://myths.example.org/"));
+//! assert!(turtle.contains("@prefix myth: <https://myths.example.org/ontology#> ."));
+//! assert!(turtle.contains("myth:Deity a owl:Class"));
+//! assert!(turtle.contains("rdfs:label \"Zeus\""));
+//! ```
+
+use std::collections::BTreeSet;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use serde_json::Value;
+use crate::core::{Claim, HistoricalDate, LanguageId, Metadata, MythEntity, MythId, MythOntology, ResolutionPolicy, Source, SourceId};
+use crate::relationships::{
+    AllianceType, ConflictType, FamilyRelationshipType, RelationshipType, SyncretismType, TransformationType,
+};
+
+/// Base IRI used when none is configured
+pub(crate) const DEFAULT_BASE_IRI: &str = "https://mythologic.example.org/";
+
+/// Options for RDF export
+#[derive(Debug, Clone)]
+pub struct RdfOptions {
+    /// Base IRI; entities are `<base>entity/<id>` and the vocabulary is `<base>ontology#`
+    pub base_iri: String,
+    /// Whether to write the OWL class and property declarations
+    pub include_schema: bool,
+    /// Which variant claims to export
+    pub resolution: ResolutionPolicy,
+    /// Language of the `rdfs:label`s, or `None` for the primary names
+    pub locale: Option<LanguageId>,
+}
+
+impl Default for RdfOptions {
+    fn default() -> Self {
+        Self {
+            base_iri: DEFAULT_BASE_IRI.to_string(),
+            include_schema: true,
+            resolution: ResolutionPolicy::ShowAll,
+            locale: None,
+        }
+    }
+}
+
+impl RdfOptions {
+    /// Create options with the default base IRI, including the schema
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Set the base IRI; any fragment is dropped, and a `/` is appended unless it
+    /// already ends in `/` or `:`
+    pub fn base_iri(mut self, base_iri: &str) -> Self {
+        self.base_iri = normalize_base_iri(base_iri);
+        self
+    }
+    
+    /// Set whether to write the OWL schema
+    pub fn include_schema(mut self, include_schema: bool) -> Self {
+        self.include_schema = include_schema;
+        self
+    }
+    
+    /// Set how competing variant claims are resolved
+    pub fn resolution(mut self, resolution: ResolutionPolicy) -> Self {
+        self.resolution = resolution;
+        self
+    }
+    
+    /// Label entities by their preferred label in a language, where they have one
+    pub fn locale(mut self, language: &str) -> Self {
+        self.locale = Some(LanguageId::new(language));
+        self
+    }
+    
+    /// The IRI of an entity
+    pub fn entity_iri(&self, id: &MythId) -> String {
+        format!("{}entity/{}", self.base_iri, id)
+    }
+    
+    /// The IRI of a source in the bibliography
+    pub fn source_iri(&self, id: &SourceId) -> String {
+        format!("{}source/{}", self.base_iri, id)
+    }
+}
+
+/// Drop the fragment of a base IRI, since entity and vocabulary IRIs are built by
+/// appending paths and a fragment of their own, then append a `/` unless it
+/// already ends in `/` or `:`
+pub(crate) fn normalize_base_iri(base_iri: &str) -> String {
+    let mut base_iri = base_iri.split('#').next().unwrap_or_default().to_string();
+    if !base_iri.ends_with(['/', ':']) {
+        base_iri.push('/');
+    }
+    base_iri
+}
+
+/// Format a year as an `xsd:gYear`, e.g. `-0700` for 700 BCE
+pub(crate) fn xsd_year(year: i32) -> String {
+    if year < 0 { format!("-{:04}", -year) } else { format!("{:04}", year) }
+}
+
+/// Subclasses of each abstract class in the schema
+const CLASS_HIERARCHY: &[(&str, &[&str])] = &[
+    ("MythEntity", &["Deity", "Hero", "Creature", "Artifact", "Location", "Concept", "Narrative", "Event", "CulturalContext", "Relationship"]),
+    ("CulturalContext", &["Culture", "Pantheon", "MythologicalEra", "MythologicalRegion"]),
+    ("Relationship", &["FamilyRelationship", "AllianceRelationship", "ConflictRelationship", "TransformationRelationship", "SyncretismRelationship"]),
+];
+
+/// Family subtypes that hold in both directions
+const SYMMETRIC_FAMILY_TYPES: &[&str] = &["Sibling", "Spouse", "Twin", "Cousin"];
+
+/// Syncretism subtypes that hold in both directions
+const SYMMETRIC_SYNCRETISM_TYPES: &[&str] = &["Equivalence", "PartialIdentification"];
+
+/// Render an ontology as Turtle
+pub fn ontology_to_turtle(ontology: &MythOntology, options: &RdfOptions) -> String {
+    if options.resolution != ResolutionPolicy::ShowAll {
+        let resolved = ontology.resolved(options.resolution);
+        return ontology_to_turtle(&resolved, &options.clone().resolution(ResolutionPolicy::ShowAll));
+    }
+    
+    let mut entities: Vec<&MythEntity> = ontology.entities().collect();
+    entities.sort_by_cached_key(|e| (e.entity_type(), e.name().to_string(), e.id().to_string()));
+    
+    let mut writer = TurtleWriter {
+        ontology,
+        options,
+        output: String::new(),
+        datatype_properties: BTreeSet::new(),
+        object_properties: BTreeSet::new(),
+    };
+    for entity in entities {
+        writer.write_entity(entity);
+    }
+    let mut sources: Vec<_> = ontology.bibliography().iter().collect();
+    sources.sort_by(|a, b| a.1.title.cmp(&b.1.title).then_with(|| a.0.cmp(b.0)));
+    for (id, source) in sources {
+        writer.write_source(id, source);
+    }
+    
+    let mut output = String::new();
+    for (prefix, iri) in [
+        ("rdf", "http://www.w3.org/1999/02/22-rdf-syntax-ns#"),
+        ("rdfs", "http://www.w3.org/2000/01/rdf-schema#"),
+        ("owl", "http://www.w3.org/2002/07/owl#"),
+        ("xsd", "http://www.w3.org/2001/XMLSchema#"),
+        ("prov", "http://www.w3.org/ns/prov#"),
+        ("skosxl", "http://www.w3.org/2008/05/skos-xl#"),
+        ("dcterms", "http://purl.org/dc/terms/"),
+    ] {
+        let _ = writeln!(output, "@prefix {}: <{}> .", prefix, iri);
+    }
+    let _ = writeln!(output, "@prefix myth: <{}ontology#> .", options.base_iri);
+    output.push('\n');
+    if options.include_schema {
+        write_schema(&mut output, &writer.datatype_properties, &writer.object_properties);
+    }
+    output.push_str(&writer.output);
+    output
+}
+
+/// Write an ontology as Turtle to a file
+pub fn write_turtle(ontology: &MythOntology, path: &Path, options: &RdfOptions) -> std::io::Result<()> {
+    fs::write(path, ontology_to_turtle(ontology, options))
+}
+
+/// The `myth:` object property for a relationship entity's edge
+///
+/// Known subtypes map to sub-properties such as `familyParent`; `Other` subtypes
+/// fall back to the property of their relationship type.
+fn edge_property(entity: &MythEntity) -> Option<String> {
+    let subtype = |base: &str, kind: String, known: &[&str]| {
+        if known.contains(&kind.as_str()) { format!("{}{}", base, kind) } else { base.to_string() }
+    };
+    Some(match entity {
+        MythEntity::FamilyRelationship(e) => subtype("family", e.family_type.to_string(), FamilyRelationshipType::VARIANT_NAMES),
+        MythEntity::AllianceRelationship(e) => subtype("alliance", e.alliance_type.to_string(), AllianceType::VARIANT_NAMES),
+        MythEntity::ConflictRelationship(e) => subtype("conflict", e.conflict_type.to_string(), ConflictType::VARIANT_NAMES),
+        MythEntity::TransformationRelationship(e) => subtype("transformation", e.transformation_type.to_string(), TransformationType::VARIANT_NAMES),
+        MythEntity::SyncretismRelationship(e) => subtype("syncretism", e.syncretism_type.to_string(), SyncretismType::VARIANT_NAMES),
+        MythEntity::Relationship(e) => type_property(&e.relationship_type),
+        _ => return None,
+    })
+}
+
+/// The `myth:` object property for a relationship type
+fn type_property(relationship_type: &RelationshipType) -> String {
+    match relationship_type {
+        RelationshipType::Unknown | RelationshipType::Other(_) => "relatedTo".to_string(),
+        known => lower_first(&known.to_string()),
+    }
+}
+
+fn lower_first(name: &str) -> String {
+    let mut chars = name.chars();
+    chars.next().map(|c| c.to_lowercase().chain(chars).collect()).unwrap_or_default()
+}
+
+/// Convert a snake_case field name to a camelCase property name
+fn camel_case(field: &str) -> String {
+    let mut output = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        match c {
+            '_' => upper = true,
+            _ if upper => {
+                output.extend(c.to_uppercase());
+                upper = false;
+            },
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+fn write_schema(output: &mut String, datatype_properties: &BTreeSet<String>, object_properties: &BTreeSet<String>) {
+    output.push_str("# Schema\n\nmyth: a owl:Ontology ;\n    rdfs:label \"Mythologic ontology\" .\n\n");
+    
+    output.push_str("myth:MythEntity a owl:Class .\n");
+    for (parent, children) in CLASS_HIERARCHY {
+        for child in *children {
+            let _ = writeln!(output, "myth:{} a owl:Class ;\n    rdfs:subClassOf myth:{} .", child, parent);
+        }
+    }
+    output.push_str("myth:Relationship rdfs:subClassOf rdf:Statement .\n");
+    output.push_str("myth:Source a owl:Class ;\n    rdfs:subClassOf prov:Entity .\n\n");
+    
+    output.push_str("myth:relatedTo a owl:ObjectProperty .\n");
+    for name in RelationshipType::VARIANT_NAMES.iter().filter(|n| **n != "Unknown") {
+        let _ = writeln!(output, "myth:{} a owl:ObjectProperty ;\n    rdfs:subPropertyOf myth:relatedTo .", lower_first(name));
+    }
+    let subtypes: [(&str, &[&str]); 5] = [
+        ("family", FamilyRelationshipType::VARIANT_NAMES),
+        ("alliance", AllianceType::VARIANT_NAMES),
+        ("conflict", ConflictType::VARIANT_NAMES),
+        ("transformation", TransformationType::VARIANT_NAMES),
+        ("syncretism", SyncretismType::VARIANT_NAMES),
+    ];
+    for (base, names) in subtypes {
+        for name in names {
+            let symmetric = (base == "family" && SYMMETRIC_FAMILY_TYPES.contains(name))
+                || (base == "syncretism" && SYMMETRIC_SYNCRETISM_TYPES.contains(name));
+            let _ = writeln!(
+                output,
+                "myth:{}{} a owl:ObjectProperty{} ;\n    rdfs:subPropertyOf myth:{} .",
+                base, name, if symmetric { ", owl:SymmetricProperty" } else { "" }, base
+            );
+        }
+    }
+    output.push_str("myth:familyParent owl:inverseOf myth:familyChild .\n");
+    output.push_str("myth:familyAncestor a owl:TransitiveProperty ;\n    owl:inverseOf myth:familyDescendant .\n");
+    output.push_str("myth:syncretismEquivalence a owl:TransitiveProperty .\n\n");
+    
+    let annotations = [
+        "strength", "confidence", "bidirectional", "relationshipKind", "attributeName", "sourceType", "dating",
+        "firstAttested", "lastAttested", "locator", "citedField", "variantOf", "tradition", "claimedField",
+        "language", "script", "nameKind",
+    ];
+    for name in annotations.iter().map(|n| n.to_string()).chain(datatype_properties.iter().cloned()).collect::<BTreeSet<_>>() {
+        let _ = writeln!(output, "myth:{} a owl:DatatypeProperty .", name);
+    }
+    for name in object_properties.iter().map(String::as_str).chain(["attribute", "citation", "citedSource", "claim"]).collect::<BTreeSet<_>>() {
+        let _ = writeln!(output, "myth:{} a owl:ObjectProperty .", name);
+    }
+    output.push_str("\n# Instances\n\n");
+}
+
+/// Accumulates instance descriptions, recording the field properties used so the
+/// schema can declare them
+struct TurtleWriter<'a> {
+    ontology: &'a MythOntology,
+    options: &'a RdfOptions,
+    output: String,
+    datatype_properties: BTreeSet<String>,
+    object_properties: BTreeSet<String>,
+}
+
+/// Predicate-object pairs about one subject, grouped by predicate on output
+#[derive(Default)]
+struct Description {
+    statements: Vec<(String, Vec<String>)>,
+}
+
+impl Description {
+    fn add(&mut self, predicate: &str, object: String) {
+        match self.statements.iter_mut().find(|(p, _)| p == predicate) {
+            Some((_, objects)) => objects.push(object),
+            None => self.statements.push((predicate.to_string(), vec![object])),
+        }
+    }
+    
+    /// Write as a block about `subject`, or as a `[ ... ]` blank node when `None`
+    fn render(mut self, subject: Option<&str>) -> String {
+        for (_, objects) in &mut self.statements {
+            if objects.len() > 1 {
+                objects.sort();
+                objects.dedup();
+            }
+        }
+        let body = self.statements.iter()
+            .map(|(predicate, objects)| format!("{} {}", predicate, objects.join(", ")))
+            .collect::<Vec<_>>();
+        match subject {
+            Some(subject) => format!("{} {} .\n\n", subject, body.join(" ;\n    ")),
+            None => format!("[ {} ]", body.join(" ; ")),
+        }
+    }
+}
+
+impl TurtleWriter<'_> {
+    fn iri(&self, id: &MythId) -> String {
+        format!("<{}>", self.options.entity_iri(id))
+    }
+    
+    fn write_entity(&mut self, entity: &MythEntity) {
+        let subject = self.iri(entity.id());
+        let mut description = Description::default();
+        description.add("a", format!("myth:{}", entity.entity_type()));
+        let name = match &self.options.locale {
+            Some(locale) => entity.name_in(locale),
+            None => entity.name(),
+        };
+        description.add("rdfs:label", literal(name));
+        
+        let mut value = serde_json::to_value(entity).map(|v| v["data"].clone()).unwrap_or(Value::Null);
+        if let Value::Object(fields) = &mut value
+            && let Some(Value::Object(base)) = fields.remove("relationship")
+        {
+            fields.extend(base);
+        }
+        if let Some(text) = value.get("description").and_then(Value::as_str)
+            && !text.is_empty()
+        {
+            description.add("rdfs:comment", literal(text));
+        }
+        
+        let relationship = entity.relationship();
+        if let Some(relationship) = relationship
+            && let Some(property) = edge_property(entity)
+        {
+            let (source, target) = (self.iri(&relationship.source_id), self.iri(&relationship.target_id));
+            description.add("rdf:subject", source.clone());
+            description.add("rdf:predicate", format!("myth:{}", property));
+            description.add("rdf:object", target.clone());
+            description.add("myth:relationshipKind", literal(&entity.relationship_kind().unwrap_or_default()));
+            description.add("myth:strength", decimal(relationship.strength.to_string()));
+            description.add("myth:bidirectional", relationship.bidirectional.to_string());
+            
+            let _ = writeln!(self.output, "{} myth:{} {} .", source, property, target);
+            if relationship.bidirectional {
+                let _ = writeln!(self.output, "{} myth:{} {} .", target, property, source);
+            }
+        }
+        
+        if let Value::Object(fields) = &value {
+            for (field, field_value) in fields {
+                if matches!(field.as_str(), "id" | "name" | "description" | "metadata" | "source_id" | "target_id" | "relationship_type" | "strength" | "bidirectional")
+                    || field.ends_with("_type") && relationship.is_some()
+                {
+                    continue;
+                }
+                if field == "relationships" {
+                    for related in field_value.as_array().into_iter().flatten() {
+                        description.add("myth:relatedTo", self.object(related));
+                    }
+                    continue;
+                }
+                self.add_field(&mut description, field, field_value);
+            }
+        }
+        
+        let metadata = entity.metadata();
+        self.add_metadata(&mut description, &subject, entity, metadata);
+        self.output.push_str(&description.render(Some(&subject)));
+    }
+    
+    /// Add a structured field as `myth:<field>` triples, one per array element
+    fn add_field(&mut self, description: &mut Description, field: &str, value: &Value) {
+        let predicate = camel_case(field);
+        let objects: Vec<String> = match value {
+            Value::Null => Vec::new(),
+            Value::Array(items) => items.iter().map(|item| self.object(item)).collect(),
+            // Free-form maps such as `properties` have arbitrary keys
+            Value::Object(map) if field == "properties" => map.iter()
+                .map(|(key, value)| format!("[ myth:attributeName {} ; rdf:value {} ]", literal(key), self.object(value)))
+                .collect(),
+            _ => vec![self.object(value)],
+        };
+        if objects.iter().any(|o| o.starts_with(['<', '['])) {
+            self.object_properties.insert(predicate.clone());
+        } else if !objects.is_empty() {
+            self.datatype_properties.insert(predicate.clone());
+        }
+        for object in objects {
+            description.add(&format!("myth:{}", predicate), object);
+        }
+    }
+    
+    /// Render a JSON value as a Turtle object: entity IDs become IRIs, enum
+    /// `Other` values their inner string, and nested structs blank nodes
+    fn object(&mut self, value: &Value) -> String {
+        match value {
+            Value::String(text) => match MythId::from_str(text) {
+                Ok(id) if self.ontology.contains(&id) => self.iri(&id),
+                _ => literal(text),
+            },
+            Value::Bool(b) => b.to_string(),
+            Value::Number(n) if n.is_f64() => decimal(n.to_string()),
+            Value::Number(n) => n.to_string(),
+            Value::Object(map) if map.len() == 1 && map.contains_key("Other") => self.object(&map["Other"]),
+            Value::Object(map) => {
+                let mut nested = Description::default();
+                for (field, value) in map {
+                    self.add_field(&mut nested, field, value);
+                }
+                nested.render(None)
+            },
+            Value::Array(items) => format!("( {} )", items.iter().map(|item| self.object(item)).collect::<Vec<_>>().join(" ")),
+            Value::Null => "rdf:nil".to_string(),
+        }
+    }
+    
+    fn add_metadata(&mut self, description: &mut Description, subject: &str, entity: &MythEntity, metadata: &Metadata) {
+        description.add("dcterms:created", typed(&metadata.created_at.to_rfc3339(), "xsd:dateTime"));
+        description.add("dcterms:modified", typed(&metadata.updated_at.to_rfc3339(), "xsd:dateTime"));
+        let mut attributes: Vec<_> = metadata.attributes.iter().collect();
+        attributes.sort();
+        for (key, value) in attributes {
+            description.add("myth:attribute", format!("[ myth:attributeName {} ; rdf:value {} ]", literal(key), literal(value)));
+        }
+        for citation in &metadata.citations {
+            let source = format!("<{}>", self.options.source_iri(&citation.source));
+            description.add("prov:wasDerivedFrom", source.clone());
+            if !citation.locator.is_empty() || citation.field.is_some() || citation.note.is_some() {
+                let mut details = Description::default();
+                details.add("myth:citedSource", source);
+                if !citation.locator.is_empty() {
+                    details.add("myth:locator", literal(&citation.locator.to_string()));
+                }
+                if let Some(field) = &citation.field {
+                    details.add("myth:citedField", literal(field));
+                }
+                if let Some(note) = &citation.note {
+                    details.add("rdfs:comment", literal(note));
+                }
+                description.add("myth:citation", details.render(None));
+            }
+        }
+        if let Some(variant) = &metadata.variant {
+            description.add("myth:variantOf", literal(&variant.question));
+            let node = self.claim_node(description, &variant.claim, None);
+            description.add("myth:claim", node);
+        }
+        for claim in &metadata.claims {
+            let node = self.claim_node(description, &claim.claim, Some((&claim.field, &claim.value)));
+            description.add("myth:claim", node);
+        }
+        for label in &metadata.labels {
+            let mut node = Description::default();
+            node.add("a", "skosxl:Label".to_string());
+            node.add("skosxl:literalForm", literal(&label.text));
+            node.add("myth:language", literal(label.language.value()));
+            if let Some(script) = &label.script {
+                node.add("myth:script", literal(script));
+            }
+            node.add("myth:nameKind", literal(&label.kind.to_string()));
+            let predicate = if metadata.is_preferred_label(label) { "skosxl:prefLabel" } else { "skosxl:altLabel" };
+            description.add(predicate, node.render(None));
+        }
+        if let Some(date) = &metadata.first_attested {
+            description.add("myth:firstAttested", literal(&date.to_string()));
+        }
+        if let Some(date) = &metadata.last_attested {
+            description.add("myth:lastAttested", literal(&date.to_string()));
+        }
+        
+        if let Some(confidence) = metadata.confidence {
+            let confidence = decimal(confidence.to_string());
+            if entity.is_relationship() {
+                description.add("myth:confidence", confidence);
+            } else {
+                let _ = writeln!(
+                    self.output,
+                    "[] a rdf:Statement ;\n    rdf:subject {} ;\n    rdf:predicate rdf:type ;\n    rdf:object myth:{} ;\n    myth:confidence {} .\n",
+                    subject, entity.entity_type(), confidence
+                );
+            }
+        }
+    }
+    
+    /// Render a claim as a blank node, deriving the described resource from its source
+    fn claim_node(&self, description: &mut Description, claim: &Claim, field_value: Option<(&str, &str)>) -> String {
+        let source = format!("<{}>", self.options.source_iri(&claim.citation.source));
+        description.add("prov:wasDerivedFrom", source.clone());
+        let mut node = Description::default();
+        if let Some((field, value)) = field_value {
+            node.add("myth:claimedField", literal(field));
+            node.add("rdf:value", literal(value));
+        }
+        if let Some(tradition) = &claim.tradition {
+            node.add("myth:tradition", literal(tradition));
+        }
+        node.add("myth:citedSource", source);
+        if !claim.citation.locator.is_empty() {
+            node.add("myth:locator", literal(&claim.citation.locator.to_string()));
+        }
+        if let Some(note) = &claim.note {
+            node.add("rdfs:comment", literal(note));
+        }
+        node.render(None)
+    }
+    
+    fn write_source(&mut self, id: &SourceId, source: &Source) {
+        let mut description = Description::default();
+        description.add("a", "myth:Source".to_string());
+        description.add("dcterms:title", literal(&source.title));
+        if let Some(author) = &source.author {
+            description.add("dcterms:creator", literal(author));
+        }
+        match &source.date {
+            Some(HistoricalDate::Year(year)) => description.add("dcterms:date", typed(&xsd_year(*year), "xsd:gYear")),
+            Some(date) => description.add("myth:dating", literal(&date.to_string())),
+            None => {},
+        }
+        let source_type = serde_json::to_value(&source.source_type).unwrap_or(Value::Null);
+        description.add("myth:sourceType", self.object(&source_type));
+        if let Some(url) = &source.url {
+            let is_iri = url.contains(':') && !url.contains(|c: char| c.is_whitespace() || "<>\"{}|^`\\".contains(c));
+            description.add("rdfs:seeAlso", if is_iri { format!("<{}>", url) } else { literal(url) });
+        }
+        if let Some(notes) = &source.notes {
+            description.add("rdfs:comment", literal(notes));
+        }
+        let subject = format!("<{}>", self.options.source_iri(id));
+        self.output.push_str(&description.render(Some(&subject)));
+    }
+}
+
+/// Quote a string as a Turtle literal
+fn literal(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04X}", c as u32);
+            },
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+fn typed(text: &str, datatype: &str) -> String {
+    format!("{}^^{}", literal(text), datatype)
+}
+
+/// Write a number as an `xsd:decimal`, falling back to `xsd:double` for exponents
+fn decimal(number: String) -> String {
+    if number.contains(['e', 'E']) || number.contains("inf") || number.contains("NaN") {
+        typed(&number, "xsd:double")
+    } else if number.contains('.') {
+        number
+    } else {
+        format!("{}.0", number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Citation, SourceType};
+    use crate::examples::create_greek_ontology;
+    
+    fn find<'a>(ontology: &'a MythOntology, name: &str) -> &'a MythEntity {
+        ontology.entities_named(name).next().unwrap()
+    }
+    
+    #[test]
+    fn writes_schema_and_edges() {
+        let greek = create_greek_ontology();
+        let options = RdfOptions::new().base_iri("http://example.org/myth");
+        let turtle = ontology_to_turtle(&greek, &options);
+        
+        assert!(turtle.contains("myth:familyParent a owl:ObjectProperty ;\n    rdfs:subPropertyOf myth:family ."));
+        assert!(turtle.contains("myth:familySibling a owl:ObjectProperty, owl:SymmetricProperty"));
+        assert!(turtle.contains("myth:Pantheon a owl:Class ;\n    rdfs:subClassOf myth:CulturalContext ."));
+        assert!(turtle.contains("myth:domains a owl:DatatypeProperty ."));
+        
+        let zeus = options.entity_iri(find(&greek, "Zeus").id());
+        let athena = options.entity_iri(find(&greek, "Athena").id());
+        assert!(zeus.starts_with("http://example.org/myth/entity/"));
+        assert!(turtle.contains(&format!("<{}> myth:familyParent <{}> .", zeus, athena)));
+        assert!(turtle.contains("rdf:predicate myth:familyParent"));
+        
+        let without_schema = ontology_to_turtle(&greek, &options.clone().include_schema(false));
+        assert!(!without_schema.contains("owl:Class"));
+        assert!(without_schema.contains("@prefix myth:"));
+    }
+    
+    #[test]
+    fn drops_the_fragment_of_the_base_iri() {
+        let greek = create_greek_ontology();
+        for base in ["https://x.org/data#", "https://x.org/data#section"] {
+            let options = RdfOptions::new().base_iri(base);
+            assert_eq!(options.base_iri, "https://x.org/data/");
+            let turtle = ontology_to_turtle(&greek, &options);
+            assert!(turtle.contains("@prefix myth: <https://x.org/data/ontology#> ."));
+            assert!(options.entity_iri(find(&greek, "Zeus").id()).starts_with("https://x.org/data/entity/"));
+        }
+        assert_eq!(RdfOptions::new().base_iri("urn:myth:").base_iri, "urn:myth:");
+    }
+    
+    #[test]
+    fn writes_provenance_and_confidence() {
+        let mut greek = create_greek_ontology();
+        let id = find(&greek, "Zeus").id().clone();
+        let source = greek.add_source(Source {
+            title: "Theogony \"of\" Hesiod".to_string(),
+            author: Some("Hesiod".to_string()),
+            date: Some(HistoricalDate::Year(-700)),
+            source_type: SourceType::PrimaryText,
+            url: Some("https://example.org/theogony".to_string()),
+            notes: None,
+        });
+        {
+            let mut zeus = greek.get_entity_mut(&id).unwrap();
+            let metadata = zeus.metadata_mut();
+            metadata.confidence = Some(0.75);
+            metadata.add_citation(Citation::new(source.clone()).line("453-506").field("family"));
+        }
+        
+        let options = RdfOptions::new();
+        let turtle = ontology_to_turtle(&greek, &options);
+        let zeus = format!("<{}>", options.entity_iri(&id));
+        let source = format!("<{}>", options.source_iri(&source));
+        assert_eq!(turtle.matches(&format!("{} a myth:Source", source)).count(), 1);
+        assert!(turtle.contains(&format!("{} a myth:Source ;\n    dcterms:title \"Theogony \\\"of\\\" Hesiod\"", source)));
+        assert!(turtle.contains(&format!("myth:citation [ myth:citedSource {} ; myth:locator \"line 453-506\" ; myth:citedField \"family\" ]", source)));
+        assert!(turtle.contains("dcterms:date \"-0700\"^^xsd:gYear"));
+        assert!(turtle.contains(&format!("rdf:subject {} ;\n    rdf:predicate rdf:type ;\n    rdf:object myth:Deity ;\n    myth:confidence 0.75 .", zeus)));
+    }
+    
+    #[test]
+    fn writes_claims_by_resolution() {
+        let mut ontology = MythOntology::new();
+        let source = |title: &str, source_type| Source {
+            title: title.to_string(),
+            author: None,
+            date: None,
+            source_type,
+            url: None,
+            notes: None,
+        };
+        let theogony = ontology.add_source(source("Theogony", SourceType::PrimaryText));
+        let odes = ontology.add_source(source("Odes", SourceType::LiteraryText));
+        let mut cerberus = crate::entities::Creature::new("Cerberus", "Hound of Hades", "Greek");
+        cerberus.metadata.add_claim("heads", "50", Claim::new(Citation::new(theogony.clone()).line("312")).tradition("Hesiodic"));
+        cerberus.metadata.add_claim("heads", "100", Claim::new(Citation::new(odes.clone())));
+        ontology.add_entity(MythEntity::Creature(cerberus));
+        
+        let options = RdfOptions::new().include_schema(false);
+        let all = ontology_to_turtle(&ontology, &options);
+        let theogony = options.source_iri(&theogony);
+        assert!(all.contains(&format!(
+            "[ myth:claimedField \"heads\" ; rdf:value \"50\" ; myth:tradition \"Hesiodic\" ; myth:citedSource <{}> ; myth:locator \"line 312\" ]",
+            theogony
+        )));
+        assert!(all.contains("rdf:value \"100\""));
+        
+        let primary = ontology_to_turtle(&ontology, &options.resolution(ResolutionPolicy::PreferPrimaryText));
+        assert!(primary.contains("rdf:value \"50\""));
+        assert!(!primary.contains("rdf:value \"100\""));
+    }
+    
+    #[test]
+    fn writes_labels_by_locale() {
+        let greek = create_greek_ontology();
+        let options = RdfOptions::new().include_schema(false);
+        let turtle = ontology_to_turtle(&greek, &options);
+        assert!(turtle.contains(
+            "skosxl:prefLabel [ a skosxl:Label ; skosxl:literalForm \"Ζεύς\" ; myth:language \"Ancient Greek\" ; myth:script \"Grek\" ; myth:nameKind \"Original\" ]"
+        ));
+        assert!(turtle.contains("skosxl:literalForm \"Κρονίδης\""));
+        assert!(turtle.contains("rdfs:label \"Zeus\""));
+        
+        let greek_names = ontology_to_turtle(&greek, &options.locale("Ancient Greek"));
+        assert!(greek_names.contains("rdfs:label \"Ζεύς\""));
+        assert!(greek_names.contains("rdfs:label \"Poseidon\""));
+    }
+}
@@ -0,0 +1,816 @@
+//! # Tabular Import and Export
+//!
+//! Reads and writes ontologies as spreadsheets: one CSV or TSV sheet per entity
+//! kind (`Deity`, `Hero`, `Creature`, `Artifact`, `Location`, `Concept`, `Culture`,
+//! `Pantheon`) plus a `Relationship` sheet holding the relationship variants as
+//! edges.
+//!
+//! - The first row of each sheet is a header. Columns are matched by name, ignoring
+//!   case, and may appear in any order; missing columns are left at their defaults.
+//! - Multi-valued fields such as `domains`, `alternative_names`, `habitat` and
+//!   `powers` are written as a single cell with values separated by `|`.
+//! - The `id` column is optional on import; rows without one get a fresh ID.
+//! - Relationship endpoints and pantheon members are written as names and resolved
+//!   on import by name (or alternative name) plus culture.
+//!
+//! Problems are reported per row as [`RowError`]s; a row with an error is skipped
+//! and the rest of the sheet is still imported.
+//!
+//! Every variant relationship is exported. To export only the variants preferred
+//! by a [`ResolutionPolicy`](crate::core::ResolutionPolicy), export the view
+//! returned by [`MythOntology::resolved`].
+//!
+//! ```
+//! use mythologic::core::MythOntology;
+//! use mythologic::formats::{import_sheet, Sheet, TabularFormat};
+//!
+//! let mut ontology = MythOntology::new();
+//! let deities = "name,culture,domains,importance\n\
+//!                Zeus,Greek,Sky|Thunder,Supreme\n\
+//!                Hera,Greek,Marriage,Chief\n";
+//! let errors = import_sheet(&mut ontology, Sheet::Deity, deities, TabularFormat::Csv);
+//!
+//! assert_eq!(ontology.entity_count(), 1);
+//! assert_eq!(errors.len(), 1);
+//! assert_eq!(errors[0].line, 3);
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use crate::core::{variant_names, CultureId, MythEntity, MythId, MythOntology};
+use crate::cultural::{Culture, Pantheon};
+use crate::entities::{Artifact, Concept, Creature, Deity, Hero, Location};
+use crate::relationships::{
+    AllianceRelationship, ConflictRelationship, FamilyRelationship, Relationship,
+    RelationshipType, SyncretismRelationship, TransformationRelationship,
+};
+
+/// Separator between values in a multi-valued cell
+const LIST_SEPARATOR: char = '|';
+
+/// Delimited text format of a sheet
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabularFormat {
+    /// Comma-separated values
+    #[default]
+    Csv,
+    /// Tab-separated values
+    Tsv,
+}
+
+impl TabularFormat {
+    /// The cell delimiter
+    pub fn delimiter(self) -> char {
+        match self {
+            Self::Csv => ',',
+            Self::Tsv => '\t',
+        }
+    }
+    
+    /// The file extension, without a dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+        }
+    }
+}
+
+/// A sheet in a tabular ontology, one per entity kind plus one for relationships
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Sheet {
+    Deity,
+    Hero,
+    Creature,
+    Artifact,
+    Location,
+    Concept,
+    Culture,
+    Pantheon,
+    Relationship,
+}
+
+variant_names!(Sheet { Deity, Hero, Creature, Artifact, Location, Concept, Culture, Pantheon, Relationship });
+
+impl Sheet {
+    /// Every sheet, in import order: pantheons after the deities they list, and
+    /// relationships after every entity they may connect
+    pub const ALL: [Sheet; 9] = [
+        Sheet::Deity,
+        Sheet::Hero,
+        Sheet::Creature,
+        Sheet::Artifact,
+        Sheet::Location,
+        Sheet::Concept,
+        Sheet::Culture,
+        Sheet::Pantheon,
+        Sheet::Relationship,
+    ];
+    
+    /// The columns written on export
+    pub fn columns(self) -> &'static [&'static str] {
+        match self {
+            Self::Deity => &["id", "name", "alternative_names", "description", "culture", "pantheon", "gender", "importance", "domains"],
+            Self::Hero => &["id", "name", "description", "culture", "origin", "achievements"],
+            Self::Creature => &["id", "name", "description", "culture", "creature_type", "habitat", "abilities"],
+            Self::Artifact => &["id", "name", "description", "culture", "artifact_type", "powers", "creator", "owner"],
+            Self::Location => &["id", "name", "description", "culture", "location_type", "characteristics", "accessibility"],
+            Self::Concept => &["id", "name", "description", "culture", "concept_type", "manifestations"],
+            Self::Culture => &["id", "name", "description", "regions", "influences", "languages", "cultural_practices"],
+            Self::Pantheon => &["id", "name", "description", "culture", "cosmology", "founding_myth", "primary_deities", "secondary_deities"],
+            Self::Relationship => &[
+                "id", "kind", "type", "name", "description", "source", "source_culture", "target",
+                "target_culture", "bidirectional", "strength", "purpose", "cause",
+            ],
+        }
+    }
+    
+    /// The file name of this sheet in a directory export, without extension
+    pub fn file_stem(self) -> &'static str {
+        match self {
+            Self::Deity => "deities",
+            Self::Hero => "heroes",
+            Self::Creature => "creatures",
+            Self::Artifact => "artifacts",
+            Self::Location => "locations",
+            Self::Concept => "concepts",
+            Self::Culture => "cultures",
+            Self::Pantheon => "pantheons",
+            Self::Relationship => "relationships",
+        }
+    }
+    
+    /// The sheet an entity is written to, if any
+    pub fn of(entity: &MythEntity) -> Option<Sheet> {
+        match entity {
+            MythEntity::Deity(_) => Some(Self::Deity),
+            MythEntity::Hero(_) => Some(Self::Hero),
+            MythEntity::Creature(_) => Some(Self::Creature),
+            MythEntity::Artifact(_) => Some(Self::Artifact),
+            MythEntity::Location(_) => Some(Self::Location),
+            MythEntity::Concept(_) => Some(Self::Concept),
+            MythEntity::Culture(_) => Some(Self::Culture),
+            MythEntity::Pantheon(_) => Some(Self::Pantheon),
+            _ if entity.is_relationship() => Some(Self::Relationship),
+            _ => None,
+        }
+    }
+}
+
+/// A problem with one row of a sheet; the row was not imported
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// The sheet containing the row
+    pub sheet: Sheet,
+    /// 1-based line number of the row, counting the header
+    pub line: usize,
+    /// The offending column, if the problem is with a single cell
+    pub column: Option<&'static str>,
+    /// What went wrong
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} sheet, line {}", self.sheet, self.line)?;
+        if let Some(column) = self.column {
+            write!(f, ", column `{}`", column)?;
+        }
+        write!(f, ": {}", self.message)
+    }
+}
+
+impl std::error::Error for RowError {}
+
+/// Render one sheet of an ontology, header included
+///
+/// Rows are sorted by culture and name. Relationships whose endpoints are missing
+/// from the ontology cannot be resolved by name and are left out.
+pub fn export_sheet(ontology: &MythOntology, sheet: Sheet, format: TabularFormat) -> String {
+    let mut entities: Vec<&MythEntity> = ontology.entities()
+        .filter(|entity| Sheet::of(entity) == Some(sheet))
+        .collect();
+    entities.sort_by_cached_key(|e| (e.culture_name().map(str::to_string), e.name().to_string(), e.id().to_string()));
+    let rows: Vec<Vec<String>> = entities.into_iter()
+        .filter_map(|entity| export_row(ontology, entity))
+        .collect();
+    
+    let mut output = String::new();
+    let header: Vec<String> = sheet.columns().iter().map(|c| c.to_string()).collect();
+    write_record(&mut output, &header, format.delimiter());
+    for row in &rows {
+        write_record(&mut output, row, format.delimiter());
+    }
+    output
+}
+
+/// Write every sheet of an ontology into a directory, e.g. `deities.csv`
+pub fn export_tables(ontology: &MythOntology, dir: &Path, format: TabularFormat) -> std::io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for sheet in Sheet::ALL {
+        let path = dir.join(format!("{}.{}", sheet.file_stem(), format.extension()));
+        fs::write(path, export_sheet(ontology, sheet, format))?;
+    }
+    Ok(())
+}
+
+/// Import the rows of one sheet into an ontology
+///
+/// Relationship endpoints and pantheon members are resolved against everything
+/// already in the ontology, so import those sheets last. Returns the errors for
+/// rows that were skipped.
+pub fn import_sheet(ontology: &mut MythOntology, sheet: Sheet, text: &str, format: TabularFormat) -> Vec<RowError> {
+    let (records, mut errors) = read_records(text, format.delimiter(), sheet);
+    let mut records = records.into_iter();
+    let Some((_, header)) = records.next() else {
+        return errors;
+    };
+    
+    let columns: HashMap<String, usize> = header.iter()
+        .enumerate()
+        .map(|(i, name)| (name.trim().to_lowercase(), i))
+        .collect();
+    if !columns.contains_key("name") {
+        errors.insert(0, RowError { sheet, line: 1, column: None, message: "header has no `name` column".to_string() });
+        return errors;
+    }
+    
+    for (line, cells) in records {
+        let row = Row { sheet, line, columns: &columns, cells: &cells };
+        match row.to_entity(ontology) {
+            Ok(entity) => ontology.add_entity(entity),
+            Err(error) => errors.push(error),
+        }
+    }
+    errors.sort_by_key(|e| e.line);
+    errors
+}
+
+/// Import every sheet found in a directory written by [`export_tables`]
+///
+/// Missing sheet files are skipped. Returns the new ontology and every row error.
+pub fn import_tables(dir: &Path, format: TabularFormat) -> std::io::Result<(MythOntology, Vec<RowError>)> {
+    let mut ontology = MythOntology::new();
+    let mut errors = Vec::new();
+    for sheet in Sheet::ALL {
+        let path = dir.join(format!("{}.{}", sheet.file_stem(), format.extension()));
+        if !path.exists() {
+            continue;
+        }
+        let text = fs::read_to_string(path)?;
+        errors.extend(import_sheet(&mut ontology, sheet, &text, format));
+    }
+    Ok((ontology, errors))
+}
+
+/// Cells of an entity in [`Sheet::columns`] order
+fn export_row(ontology: &MythOntology, entity: &MythEntity) -> Option<Vec<String>> {
+    let id = entity.id().to_string();
+    let row = match entity {
+        MythEntity::Deity(e) => vec![
+            id,
+            e.name.clone(),
+            join(e.alternative_names.iter().map(|n| n.value())),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.pantheon.as_ref().map(|p| p.value().to_string()).unwrap_or_default(),
+            e.gender.to_string(),
+            e.importance.to_string(),
+            join_sorted(e.domains.iter().map(|d| d.value())),
+        ],
+        MythEntity::Hero(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.origin.to_string(),
+            join(e.achievements.iter().map(String::as_str)),
+        ],
+        MythEntity::Creature(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.creature_type.to_string(),
+            join_sorted(e.habitat.iter().map(String::as_str)),
+            join(e.abilities.iter().map(String::as_str)),
+        ],
+        MythEntity::Artifact(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.artifact_type.to_string(),
+            join(e.powers.iter().map(String::as_str)),
+            e.creator.clone().unwrap_or_default(),
+            e.owner.clone().unwrap_or_default(),
+        ],
+        MythEntity::Location(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.location_type.to_string(),
+            join(e.characteristics.iter().map(String::as_str)),
+            join(e.accessibility.iter().map(String::as_str)),
+        ],
+        MythEntity::Concept(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            e.culture.value().to_string(),
+            e.concept_type.to_string(),
+            join(e.manifestations.iter().map(String::as_str)),
+        ],
+        MythEntity::Culture(e) => vec![
+            id,
+            e.name.clone(),
+            e.description.clone(),
+            join_sorted(e.regions.iter().map(|r| r.value())),
+            join(e.influences.iter().map(|c| c.value())),
+            join_sorted(e.languages.iter().map(|l| l.value())),
+            join(e.cultural_practices.iter().map(String::as_str)),
+        ],
+        MythEntity::Pantheon(e) => {
+            let names = |ids: &std::collections::HashSet<MythId>| {
+                join_sorted(ids.iter().filter_map(|id| ontology.get_entity(id)).map(|d| d.name()))
+            };
+            vec![
+                id,
+                e.name.clone(),
+                e.description.clone(),
+                e.culture.value().to_string(),
+                e.cosmology.as_ref().map(|c| c.value().to_string()).unwrap_or_default(),
+                e.founding_myth.clone().unwrap_or_default(),
+                names(&e.primary_deities),
+                names(&e.secondary_deities),
+            ]
+        },
+        _ => {
+            let relationship = entity.relationship()?;
+            let source = ontology.get_entity(&relationship.source_id)?;
+            let target = ontology.get_entity(&relationship.target_id)?;
+            let (kind, purpose, cause) = match entity {
+                MythEntity::FamilyRelationship(_) => ("Family", "", ""),
+                MythEntity::AllianceRelationship(e) => ("Alliance", e.purpose.as_str(), ""),
+                MythEntity::ConflictRelationship(_) => ("Conflict", "", ""),
+                MythEntity::TransformationRelationship(e) => ("Transformation", "", e.cause.as_str()),
+                MythEntity::SyncretismRelationship(_) => ("Syncretism", "", ""),
+                _ => ("Relationship", "", ""),
+            };
+            vec![
+                id,
+                kind.to_string(),
+                entity.relationship_kind().unwrap_or_default(),
+                relationship.name.clone(),
+                relationship.description.clone(),
+                source.name().to_string(),
+                source.culture_name().unwrap_or_default().to_string(),
+                target.name().to_string(),
+                target.culture_name().unwrap_or_default().to_string(),
+                relationship.bidirectional.to_string(),
+                relationship.strength.to_string(),
+                purpose.to_string(),
+                cause.to_string(),
+            ]
+        },
+    };
+    Some(row)
+}
+
+fn join<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    values.collect::<Vec<_>>().join(&format!(" {} ", LIST_SEPARATOR))
+}
+
+/// Join the values of an unordered set, sorted so output is stable between runs
+fn join_sorted<'a>(values: impl Iterator<Item = &'a str>) -> String {
+    let mut values: Vec<&str> = values.collect();
+    values.sort_unstable();
+    join(values.into_iter())
+}
+
+/// One data row of a sheet being imported
+struct Row<'a> {
+    sheet: Sheet,
+    line: usize,
+    columns: &'a HashMap<String, usize>,
+    cells: &'a [String],
+}
+
+impl Row<'_> {
+    /// The trimmed cell in a column, or `""` if the column or cell is missing
+    fn get(&self, column: &str) -> &str {
+        self.columns.get(column)
+            .and_then(|&i| self.cells.get(i))
+            .map_or("", |cell| cell.trim())
+    }
+    
+    fn list(&self, column: &str) -> impl Iterator<Item = &str> {
+        self.get(column).split(LIST_SEPARATOR).map(str::trim).filter(|v| !v.is_empty())
+    }
+    
+    fn error(&self, column: Option<&'static str>, message: String) -> RowError {
+        RowError { sheet: self.sheet, line: self.line, column, message }
+    }
+    
+    fn required(&self, column: &'static str) -> Result<&str, RowError> {
+        match self.get(column) {
+            "" => Err(self.error(Some(column), "value is required".to_string())),
+            value => Ok(value),
+        }
+    }
+    
+    /// Parse a cell, treating an empty cell as absent
+    fn parse<T>(&self, column: &'static str) -> Result<Option<T>, RowError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        match self.get(column) {
+            "" => Ok(None),
+            value => value.parse::<T>().map(Some).map_err(|e| self.error(Some(column), e.to_string())),
+        }
+    }
+    
+    fn required_parse<T>(&self, column: &'static str) -> Result<T, RowError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        self.required(column)?;
+        Ok(self.parse(column)?.expect("required cell is not empty"))
+    }
+    
+    fn parse_bool(&self, column: &'static str) -> Result<Option<bool>, RowError> {
+        match self.get(column).to_lowercase().as_str() {
+            "" => Ok(None),
+            "true" | "yes" | "y" | "1" => Ok(Some(true)),
+            "false" | "no" | "n" | "0" => Ok(Some(false)),
+            other => Err(self.error(Some(column), format!("expected true or false, found `{}`", other))),
+        }
+    }
+    
+    /// The row's ID, or a fresh one; an ID already in the ontology is an error
+    fn id(&self, ontology: &MythOntology) -> Result<MythId, RowError> {
+        match self.get("id") {
+            "" => Ok(MythId::new()),
+            value => {
+                let id = MythId::from_str(value)
+                    .map_err(|e| self.error(Some("id"), format!("invalid ID `{}`: {}", value, e)))?;
+                if ontology.contains(&id) {
+                    return Err(self.error(Some("id"), format!("ID {} is already in the ontology", id)));
+                }
+                Ok(id)
+            },
+        }
+    }
+    
+    /// Resolve an entity by name and culture, blaming `column` if that fails
+    fn resolve(
+        &self,
+        ontology: &MythOntology,
+        column: &'static str,
+        name: &str,
+        culture: &str,
+        entity_type: Option<&str>,
+    ) -> Result<MythId, RowError> {
+        resolve(ontology, name, culture, entity_type).map_err(|message| self.error(Some(column), message))
+    }
+    
+    fn to_entity(&self, ontology: &MythOntology) -> Result<MythEntity, RowError> {
+        let id = self.id(ontology)?;
+        let name = self.required("name")?;
+        let description = self.get("description");
+        let culture = self.get("culture");
+        
+        let mut entity = match self.sheet {
+            Sheet::Deity => {
+                let mut deity = Deity::new(name, description, self.required("culture")?);
+                self.list("alternative_names").for_each(|n| deity.add_alternative_name(n));
+                self.list("domains").for_each(|d| deity.add_domain(d));
+                if !self.get("pantheon").is_empty() {
+                    deity.set_pantheon(self.get("pantheon"));
+                }
+                if let Some(gender) = self.parse("gender")? {
+                    deity.set_gender(gender);
+                }
+                if let Some(importance) = self.parse("importance")? {
+                    deity.set_importance(importance);
+                }
+                MythEntity::Deity(deity)
+            },
+            Sheet::Hero => {
+                let mut hero = Hero::new(name, description, self.required("culture")?);
+                self.list("achievements").for_each(|a| hero.add_achievement(a));
+                if let Some(origin) = self.parse("origin")? {
+                    hero.set_origin(origin);
+                }
+                MythEntity::Hero(hero)
+            },
+            Sheet::Creature => {
+                let mut creature = Creature::new(name, description, self.required("culture")?);
+                self.list("habitat").for_each(|h| creature.add_habitat(h));
+                self.list("abilities").for_each(|a| creature.add_ability(a));
+                if let Some(creature_type) = self.parse("creature_type")? {
+                    creature.set_creature_type(creature_type);
+                }
+                MythEntity::Creature(creature)
+            },
+            Sheet::Artifact => {
+                let mut artifact = Artifact::new(name, description, self.required("culture")?);
+                self.list("powers").for_each(|p| artifact.add_power(p));
+                if let Some(artifact_type) = self.parse("artifact_type")? {
+                    artifact.set_artifact_type(artifact_type);
+                }
+                if !self.get("creator").is_empty() {
+                    artifact.set_creator(self.get("creator"));
+                }
+                if !self.get("owner").is_empty() {
+                    artifact.set_owner(self.get("owner"));
+                }
+                MythEntity::Artifact(artifact)
+            },
+            Sheet::Location => {
+                let mut location = Location::new(name, description, self.required("culture")?);
+                self.list("characteristics").for_each(|c| location.add_characteristic(c));
+                self.list("accessibility").for_each(|a| location.add_accessibility(a));
+                if let Some(location_type) = self.parse("location_type")? {
+                    location.set_location_type(location_type);
+                }
+                MythEntity::Location(location)
+            },
+            Sheet::Concept => {
+                let mut concept = Concept::new(name, description, self.required("culture")?);
+                self.list("manifestations").for_each(|m| concept.add_manifestation(m));
+                if let Some(concept_type) = self.parse("concept_type")? {
+                    concept.set_concept_type(concept_type);
+                }
+                MythEntity::Concept(concept)
+            },
+            Sheet::Culture => {
+                let mut entry = Culture::new(name, description);
+                self.list("regions").for_each(|r| entry.add_region(r));
+                self.list("influences").for_each(|i| entry.add_influence(i));
+                self.list("languages").for_each(|l| entry.add_language(l));
+                self.list("cultural_practices").for_each(|p| entry.add_cultural_practice(p));
+                MythEntity::Culture(entry)
+            },
+            Sheet::Pantheon => {
+                let mut pantheon = Pantheon::new(name, description, self.required("culture")?);
+                if !self.get("cosmology").is_empty() {
+                    pantheon.set_cosmology(self.get("cosmology"));
+                }
+                if !self.get("founding_myth").is_empty() {
+                    pantheon.set_founding_myth(self.get("founding_myth"));
+                }
+                for deity in self.list("primary_deities") {
+                    pantheon.add_primary_deity(self.resolve(ontology, "primary_deities", deity, culture, Some("Deity"))?);
+                }
+                for deity in self.list("secondary_deities") {
+                    pantheon.add_secondary_deity(self.resolve(ontology, "secondary_deities", deity, culture, Some("Deity"))?);
+                }
+                MythEntity::Pantheon(pantheon)
+            },
+            Sheet::Relationship => self.to_relationship(ontology, name, description)?,
+        };
+        
+        match &mut entity {
+            MythEntity::Deity(e) => e.id = id,
+            MythEntity::Hero(e) => e.id = id,
+            MythEntity::Creature(e) => e.id = id,
+            MythEntity::Artifact(e) => e.id = id,
+            MythEntity::Location(e) => e.id = id,
+            MythEntity::Concept(e) => e.id = id,
+            MythEntity::Culture(e) => e.id = id,
+            MythEntity::Pantheon(e) => e.id = id,
+            MythEntity::Relationship(e) => e.id = id,
+            MythEntity::FamilyRelationship(e) => e.relationship.id = id,
+            MythEntity::AllianceRelationship(e) => e.relationship.id = id,
+            MythEntity::ConflictRelationship(e) => e.relationship.id = id,
+            MythEntity::TransformationRelationship(e) => e.relationship.id = id,
+            MythEntity::SyncretismRelationship(e) => e.relationship.id = id,
+            MythEntity::Narrative(_) | MythEntity::Event(_) | MythEntity::MythologicalEra(_) | MythEntity::MythologicalRegion(_) => {},
+        }
+        Ok(entity)
+    }
+    
+    fn to_relationship(&self, ontology: &MythOntology, name: &str, description: &str) -> Result<MythEntity, RowError> {
+        let source = self.resolve(ontology, "source", self.required("source")?, self.get("source_culture"), None)?;
+        let target = self.resolve(ontology, "target", self.required("target")?, self.get("target_culture"), None)?;
+        let kind = self.required("kind")?;
+        let kind = kind.strip_suffix("Relationship").filter(|k| !k.is_empty()).unwrap_or(kind);
+        
+        let mut entity = match kind.to_lowercase().as_str() {
+            "family" => MythEntity::FamilyRelationship(FamilyRelationship::new(
+                name, description, source, target, self.required_parse("type")?,
+            )),
+            "alliance" => MythEntity::AllianceRelationship(AllianceRelationship::new(
+                name, description, source, target, self.required_parse("type")?, self.get("purpose"),
+            )),
+            "conflict" => MythEntity::ConflictRelationship(ConflictRelationship::new(
+                name, description, source, target, self.required_parse("type")?,
+            )),
+            "transformation" => MythEntity::TransformationRelationship(TransformationRelationship::new(
+                name, description, source, target, self.required_parse("type")?, self.get("cause"),
+            )),
+            "syncretism" => MythEntity::SyncretismRelationship(SyncretismRelationship::new(
+                name, description, source, target, self.required_parse("type")?,
+            )),
+            "relationship" => MythEntity::Relationship(Relationship::new(
+                name, description, source, target, self.parse("type")?.unwrap_or(RelationshipType::Unknown),
+            )),
+            _ => return Err(self.error(Some("kind"), format!(
+                "unknown relationship kind `{}`; expected one of Family, Alliance, Conflict, Transformation, Syncretism, Relationship", kind
+            ))),
+        };
+        
+        let bidirectional = self.parse_bool("bidirectional")?;
+        let strength = self.parse::<f32>("strength")?;
+        if let Some(strength) = strength
+            && !(0.0..=1.0).contains(&strength)
+        {
+            return Err(self.error(Some("strength"), format!("strength {} is outside 0.0..=1.0", strength)));
+        }
+        let relationship = match &mut entity {
+            MythEntity::Relationship(r) => r,
+            MythEntity::FamilyRelationship(r) => &mut r.relationship,
+            MythEntity::AllianceRelationship(r) => &mut r.relationship,
+            MythEntity::ConflictRelationship(r) => &mut r.relationship,
+            MythEntity::TransformationRelationship(r) => &mut r.relationship,
+            MythEntity::SyncretismRelationship(r) => &mut r.relationship,
+            _ => unreachable!("only relationship variants are built above"),
+        };
+        if let Some(bidirectional) = bidirectional {
+            relationship.bidirectional = bidirectional;
+        }
+        if let Some(strength) = strength {
+            relationship.strength = strength;
+        }
+        Ok(entity)
+    }
+}
+
+/// Find the single non-relationship entity with a name (or alternative name) in a
+/// culture; an empty culture matches any
+fn resolve(ontology: &MythOntology, name: &str, culture: &str, entity_type: Option<&str>) -> Result<MythId, String> {
+    let culture_id = CultureId::new(culture);
+    let candidates: Vec<&MythEntity> = ontology.entities_named(name)
+        .filter(|e| !e.is_relationship() && entity_type.is_none_or(|t| e.entity_type() == t))
+        .filter(|e| culture.is_empty() || match e {
+            MythEntity::Culture(c) => c.is_identified_by(&culture_id),
+            _ => e.culture_name().is_some_and(|c| c.eq_ignore_ascii_case(culture)),
+        })
+        .collect();
+    
+    // Prefer primary names over alternative names when both match
+    let exact: Vec<&MythEntity> = candidates.iter().copied().filter(|e| e.name().eq_ignore_ascii_case(name)).collect();
+    match (candidates.as_slice(), exact.as_slice()) {
+        ([only], _) | (_, [only]) => Ok(only.id().clone()),
+        ([], _) if culture.is_empty() => Err(format!("no entity named `{}`", name)),
+        ([], _) => Err(format!("no entity named `{}` in culture `{}`", name, culture)),
+        (many, _) => Err(format!("`{}` matches {} entities; add a culture to tell them apart", name, many.len())),
+    }
+}
+
+/// Write one record, quoting cells that contain the delimiter, quotes or newlines
+fn write_record(output: &mut String, cells: &[String], delimiter: char) {
+    for (i, cell) in cells.iter().enumerate() {
+        if i > 0 {
+            output.push(delimiter);
+        }
+        if cell.contains([delimiter, '"', '\n', '\r']) {
+            output.push('"');
+            output.push_str(&cell.replace('"', "\"\""));
+            output.push('"');
+        } else {
+            output.push_str(cell);
+        }
+    }
+    output.push('\n');
+}
+
+/// Split text into records of cells, each with the line number it starts on
+///
+/// Quoted cells may contain delimiters, newlines and doubled quotes. Blank lines
+/// are skipped. An unterminated quote is reported and ends the sheet.
+fn read_records(text: &str, delimiter: char, sheet: Sheet) -> (Vec<(usize, Vec<String>)>, Vec<RowError>) {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut cell = String::new();
+    let mut in_quotes = false;
+    let mut line = 1;
+    let mut start_line = 1;
+    let mut chars = text.chars().peekable();
+    
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes => {
+                if chars.peek() == Some(&'"') {
+                    chars.next();
+                    cell.push('"');
+                } else {
+                    in_quotes = false;
+                }
+            },
+            '"' if cell.is_empty() => in_quotes = true,
+            '\n' if in_quotes => {
+                line += 1;
+                cell.push(c);
+            },
+            '\r' if !in_quotes && chars.peek() == Some(&'\n') => {},
+            '\n' => {
+                record.push(std::mem::take(&mut cell));
+                if !(record.len() == 1 && record[0].trim().is_empty()) {
+                    records.push((start_line, std::mem::take(&mut record)));
+                }
+                record.clear();
+                line += 1;
+                start_line = line;
+            },
+            _ if c == delimiter && !in_quotes => record.push(std::mem::take(&mut cell)),
+            _ => cell.push(c),
+        }
+    }
+    
+    if in_quotes {
+        let error = RowError { sheet, line: start_line, column: None, message: "unterminated quoted cell".to_string() };
+        return (records, vec![error]);
+    }
+    record.push(cell);
+    if !(record.len() == 1 && record[0].trim().is_empty()) {
+        records.push((start_line, record));
+    }
+    (records, Vec::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::create_greek_ontology;
+    
+    #[test]
+    fn round_trips_every_sheet() {
+        let greek = create_greek_ontology();
+        for format in [TabularFormat::Csv, TabularFormat::Tsv] {
+            let mut imported = MythOntology::new();
+            for sheet in Sheet::ALL {
+                let text = export_sheet(&greek, sheet, format);
+                let errors = import_sheet(&mut imported, sheet, &text, format);
+                assert!(errors.is_empty(), "{:?}", errors);
+            }
+            
+            let exported = greek.entities().filter(|e| Sheet::of(e).is_some()).count();
+            assert_eq!(imported.entity_count(), exported);
+            for sheet in Sheet::ALL {
+                assert_eq!(export_sheet(&imported, sheet, format), export_sheet(&greek, sheet, format));
+            }
+        }
+    }
+    
+    #[test]
+    fn reads_quoted_cells() {
+        let text = "name,culture,description,powers\n\
+                    \"Aegis, the\",Greek,\"Said \"\"unbreakable\"\"\nby some\",Protection | Terror\r\n\n";
+        let mut ontology = MythOntology::new();
+        assert!(import_sheet(&mut ontology, Sheet::Artifact, text, TabularFormat::Csv).is_empty());
+        
+        let Some(MythEntity::Artifact(aegis)) = ontology.entities_named("Aegis, the").next() else {
+            panic!("artifact not imported");
+        };
+        assert_eq!(aegis.description, "Said \"unbreakable\"\nby some");
+        assert_eq!(aegis.powers, vec!["Protection", "Terror"]);
+    }
+    
+    #[test]
+    fn reports_row_errors() {
+        let mut ontology = MythOntology::new();
+        let deities = "name\tculture\n\
+                       Zeus\tGreek\n\
+                       Zeus\tRoman\n\
+                       Odin\t\n";
+        let errors = import_sheet(&mut ontology, Sheet::Deity, deities, TabularFormat::Tsv);
+        assert_eq!(errors.len(), 1);
+        assert_eq!((errors[0].line, errors[0].column), (4, Some("culture")));
+        
+        let relationships = "kind,type,name,source,source_culture,target,target_culture,strength\n\
+                             Family,Parent,Ambiguous,Zeus,,Zeus,Greek,\n\
+                             Family,Parent,Missing,Zeus,Greek,Athena,Greek,\n\
+                             Conflict,War,Too strong,Zeus,Greek,Zeus,Roman,2\n\
+                             Friendship,,Unknown kind,Zeus,Greek,Zeus,Roman,\n\
+                             Alliance,Pact,Fine,Zeus,greek,Zeus,roman,0.5\n";
+        let errors = import_sheet(&mut ontology, Sheet::Relationship, relationships, TabularFormat::Csv);
+        let located: Vec<_> = errors.iter().map(|e| (e.line, e.column)).collect();
+        assert_eq!(located, vec![
+            (2, Some("source")),
+            (3, Some("target")),
+            (4, Some("strength")),
+            (5, Some("kind")),
+        ]);
+        assert_eq!(ontology.entities_of_type("AllianceRelationship").count(), 1);
+        assert!(errors[1].to_string().contains("no entity named `Athena` in culture `Greek`"));
+    }
+}
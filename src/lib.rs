@@ -23,6 +23,8 @@
 //! - [`cultural`]: Cultural contexts such as pantheons, regions, and historical eras
 //! - [`relationships`]: Relationships between mythological entities
 //! - [`query`]: Query engine for filtering and retrieving mythological data
+//! - [`motifs`]: Thompson motif and ATU tale type classification
+//! - [`formats`]: Import and export formats such as Graphviz DOT, GraphML, GEXF, RDF/Turtle, JSON-LD and CSV/TSV sheets
 //! - [`utils`]: Utility functions and helpers
 //! - [`examples`]: Comprehensive mythological examples from various cultures
 //!
@@ -81,6 +83,8 @@ pub mod entities;
 pub mod relationships;
 pub mod cultural;
 pub mod query;
+pub mod motifs;
+pub mod formats;
 pub mod utils;
 pub mod examples;
 
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+use crate::core::{CultureId, MythEntity, MythId, MythOntology};
+use crate::motifs::{MotifCatalogue, MotifCode, MotifTag};
+
+/// An entity sharing motifs with the one compared
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedMotifs {
+    /// The entity sharing motifs
+    pub id: MythId,
+    /// Its name
+    pub name: String,
+    /// Its entity type
+    pub entity_type: String,
+    /// The motifs in common, in code order; where the two entities are tagged with
+    /// a motif and one of its subdivisions, the more general code
+    pub shared: Vec<MotifCode>,
+}
+
+/// Answers questions about the motifs entities are tagged with
+///
+/// Without a catalogue a code matches itself and the codes under it, as read from
+/// the codes (see [`MotifCode::contains`]). With a catalogue, explicit parents in
+/// the catalogue are followed as well.
+///
+/// ```
+/// use mythologic::examples::create_locations_ontology;
+/// use mythologic::motifs::{MotifCode, Motifs};
+///
+/// let ontology = create_locations_ontology();
+/// let land_of_the_dead = MotifCode::new("E481").unwrap();
+///
+/// let cultures = Motifs::new(&ontology).cultures_with(&land_of_the_dead);
+/// let names: Vec<&str> = cultures.iter().map(|c| c.value()).collect();
+/// assert!(names.contains(&"Greek") && names.contains(&"Chinese"));
+/// ```
+pub struct Motifs<'a> {
+    ontology: &'a MythOntology,
+    catalogue: Option<&'a MotifCatalogue>,
+}
+
+impl<'a> Motifs<'a> {
+    /// Create an analysis over the tags in an ontology
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        Self {
+            ontology,
+            catalogue: None,
+        }
+    }
+    
+    /// Follow the hierarchy of a catalogue when matching codes
+    pub fn with_catalogue(mut self, catalogue: &'a MotifCatalogue) -> Self {
+        self.catalogue = Some(catalogue);
+        self
+    }
+    
+    /// Check whether `code` is `ancestor` or falls under it
+    pub fn is_within(&self, code: &MotifCode, ancestor: &MotifCode) -> bool {
+        match self.catalogue {
+            Some(catalogue) => catalogue.is_within(code, ancestor),
+            None => ancestor.contains(code),
+        }
+    }
+    
+    /// Get every entity or relationship tagged with `code` or a code under it,
+    /// with the matching tag, sorted by name
+    pub fn tagged_with(&self, code: &MotifCode) -> Vec<(&'a MythEntity, &'a MotifTag)> {
+        let mut tagged: Vec<(&MythEntity, &MotifTag)> = self.ontology.entities()
+            .flat_map(|entity| {
+                entity.metadata().motifs.iter()
+                    .filter(|tag| self.is_within(&tag.code, code))
+                    .map(move |tag| (entity, tag))
+            })
+            .collect();
+        tagged.sort_by_cached_key(|(entity, tag)| (entity.name().to_string(), entity.id().to_string(), tag.code.clone()));
+        tagged
+    }
+    
+    /// Get the cultures with an entity tagged with `code` or a code under it, sorted
+    ///
+    /// A tagged relationship counts for the cultures of its endpoints.
+    pub fn cultures_with(&self, code: &MotifCode) -> Vec<CultureId> {
+        let mut cultures: Vec<CultureId> = Vec::new();
+        for (entity, _) in self.tagged_with(code) {
+            for culture in self.cultures_of(entity) {
+                if !cultures.contains(&culture) {
+                    cultures.push(culture);
+                }
+            }
+        }
+        cultures.sort_by(|a, b| a.value().cmp(b.value()));
+        cultures
+    }
+    
+    /// Get the entities sharing at least one motif with the given entity, most
+    /// motifs in common first
+    pub fn sharing_with(&self, id: &MythId) -> Vec<SharedMotifs> {
+        let Some(entity) = self.ontology.get_entity(id) else {
+            return Vec::new();
+        };
+        let codes: Vec<&MotifCode> = entity.metadata().motifs.iter().map(|tag| &tag.code).collect();
+        if codes.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut shared: HashMap<&MythId, (&MythEntity, HashSet<MotifCode>)> = HashMap::new();
+        for other in self.ontology.entities().filter(|other| other.id() != id) {
+            for tag in &other.metadata().motifs {
+                for code in &codes {
+                    let common = if self.is_within(&tag.code, code) {
+                        (*code).clone()
+                    } else if self.is_within(code, &tag.code) {
+                        tag.code.clone()
+                    } else {
+                        continue;
+                    };
+                    shared.entry(other.id()).or_insert_with(|| (other, HashSet::new())).1.insert(common);
+                }
+            }
+        }
+        
+        let mut results: Vec<SharedMotifs> = shared.into_values()
+            .map(|(other, codes)| {
+                let mut shared: Vec<MotifCode> = codes.into_iter().collect();
+                shared.sort();
+                SharedMotifs {
+                    id: other.id().clone(),
+                    name: other.name().to_string(),
+                    entity_type: other.entity_type().to_string(),
+                    shared,
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.shared.len().cmp(&a.shared.len())
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+        results
+    }
+    
+    /// The cultures an entity belongs to, taking relationships from their endpoints
+    fn cultures_of(&self, entity: &MythEntity) -> Vec<CultureId> {
+        if let Some(culture) = entity.culture() {
+            return vec![culture.clone()];
+        }
+        let Some(relationship) = entity.relationship() else {
+            return Vec::new();
+        };
+        [&relationship.source_id, &relationship.target_id].into_iter()
+            .filter_map(|id| self.ontology.get_entity(id))
+            .filter_map(|endpoint| endpoint.culture().cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Creature, Hero};
+    use crate::motifs::Motif;
+    
+    fn tagged(mut entity: MythEntity, codes: &[&str]) -> MythEntity {
+        for code in codes {
+            entity.metadata_mut().add_motif(MotifTag::new(code.parse().unwrap()));
+        }
+        entity
+    }
+    
+    #[test]
+    fn finds_shared_motifs() {
+        let mut ontology = MythOntology::new();
+        let heracles = tagged(MythEntity::Hero(Hero::new("Heracles", "Son of Zeus", "Greek")), &["F610", "B11"]);
+        let heracles_id = heracles.id().clone();
+        ontology.add_entity(heracles);
+        ontology.add_entity(tagged(MythEntity::Hero(Hero::new("Samson", "Judge of Israel", "Hebrew")), &["F610.1"]));
+        ontology.add_entity(tagged(MythEntity::Hero(Hero::new("Sigurd", "Slayer of Fafnir", "Norse")), &["B11.11", "F610"]));
+        ontology.add_entity(tagged(MythEntity::Creature(Creature::new("Fafnir", "A dwarf turned dragon", "Norse")), &["D199"]));
+        
+        let motifs = Motifs::new(&ontology);
+        let sharing = motifs.sharing_with(&heracles_id);
+        let sharing: Vec<(&str, usize)> = sharing.iter().map(|s| (s.name.as_str(), s.shared.len())).collect();
+        assert_eq!(sharing, [("Sigurd", 2), ("Samson", 1)]);
+        
+        let strong = motifs.cultures_with(&"F600-F699".parse().unwrap());
+        let strong: Vec<&str> = strong.iter().map(|c| c.value()).collect();
+        assert_eq!(strong, ["Greek", "Hebrew", "Norse"]);
+        assert!(motifs.tagged_with(&"B11".parse().unwrap()).iter().all(|(entity, _)| entity.name() != "Fafnir"));
+        
+        let mut catalogue = MotifCatalogue::new();
+        catalogue.add(Motif::new("D199".parse().unwrap(), "Transformation: man to dragon").parent("B11".parse().unwrap()));
+        let dragons = Motifs::new(&ontology).with_catalogue(&catalogue).tagged_with(&"B11".parse().unwrap());
+        let names: Vec<&str> = dragons.iter().map(|(entity, _)| entity.name()).collect();
+        assert_eq!(names, ["Fafnir", "Heracles", "Sigurd"]);
+    }
+}
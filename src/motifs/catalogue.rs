@@ -0,0 +1,292 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use serde::{Serialize, Deserialize};
+use crate::motifs::MotifCode;
+
+/// An entry in a motif or tale type catalogue
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Motif {
+    /// The motif's code
+    pub code: MotifCode,
+    /// Short title, e.g. "Deluge"
+    pub name: String,
+    /// Longer description of the motif
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Parent entry, when it cannot be worked out from the codes alone
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<MotifCode>,
+}
+
+impl Motif {
+    /// Create a catalogue entry
+    pub fn new(code: MotifCode, name: &str) -> Self {
+        Self {
+            code,
+            name: name.to_string(),
+            description: None,
+            parent: None,
+        }
+    }
+    
+    /// Set the description
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_string());
+        self
+    }
+    
+    /// Set an explicit parent entry
+    pub fn parent(mut self, parent: MotifCode) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+}
+
+/// A catalogue of motifs and tale types, such as an excerpt of the Thompson index
+///
+/// Entries are kept in code order. The hierarchy comes from the codes themselves
+/// (see [`MotifCode::contains`]): the parent of an entry is its explicit
+/// [`Motif::parent`] if set, and otherwise the narrowest catalogue entry whose code
+/// contains it.
+///
+/// Catalogues are loaded from and saved to JSON (an array of [`Motif`]s) or a
+/// tab-separated file with `code`, `name`, `description`, and `parent` columns, where
+/// the last two columns are optional and lines starting with `#` are comments.
+///
+/// ```
+/// use mythologic::motifs::{MotifCatalogue, MotifCode};
+///
+/// let catalogue = MotifCatalogue::from_tsv(
+///     "# code\tname\n\
+///      A\tMythological motifs\n\
+///      A1000-A1099\tWorld calamities\n\
+///      A1010\tDeluge\n",
+/// ).unwrap();
+///
+/// let deluge = MotifCode::new("A1010").unwrap();
+/// let ancestors: Vec<&str> = catalogue.ancestors(&deluge).iter().map(|m| m.name.as_str()).collect();
+/// assert_eq!(ancestors, ["World calamities", "Mythological motifs"]);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MotifCatalogue {
+    motifs: BTreeMap<MotifCode, Motif>,
+}
+
+/// Error in a tab-separated motif catalogue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogueError {
+    /// 1-based line number
+    pub line: usize,
+    /// What went wrong
+    pub message: String,
+}
+
+impl fmt::Display for CatalogueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CatalogueError {}
+
+impl MotifCatalogue {
+    /// Create an empty catalogue
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add an entry, replacing any entry with the same code
+    pub fn add(&mut self, motif: Motif) {
+        self.motifs.insert(motif.code.clone(), motif);
+    }
+    
+    /// Get the entry for a code
+    pub fn get(&self, code: &MotifCode) -> Option<&Motif> {
+        self.motifs.get(code)
+    }
+    
+    /// Count the entries
+    pub fn len(&self) -> usize {
+        self.motifs.len()
+    }
+    
+    /// Check whether the catalogue has no entries
+    pub fn is_empty(&self) -> bool {
+        self.motifs.is_empty()
+    }
+    
+    /// Iterate over the entries in code order
+    pub fn iter(&self) -> impl Iterator<Item = &Motif> {
+        self.motifs.values()
+    }
+    
+    /// Get the parent entry of a code, which need not be in the catalogue itself
+    pub fn parent(&self, code: &MotifCode) -> Option<&Motif> {
+        if let Some(parent) = self.get(code).and_then(|motif| motif.parent.as_ref()) {
+            return self.get(parent);
+        }
+        // Codes that contain one another sort outermost first, so the last is the narrowest
+        self.motifs.values().rfind(|motif| motif.code != *code && motif.code.contains(code))
+    }
+    
+    /// Get the ancestors of a code, nearest first
+    pub fn ancestors(&self, code: &MotifCode) -> Vec<&Motif> {
+        let mut ancestors: Vec<&Motif> = Vec::new();
+        let mut current = code;
+        while let Some(parent) = self.parent(current) {
+            if ancestors.iter().any(|a| a.code == parent.code) {
+                break;
+            }
+            ancestors.push(parent);
+            current = &parent.code;
+        }
+        ancestors
+    }
+    
+    /// Get the entries whose parent is `code`
+    pub fn children(&self, code: &MotifCode) -> Vec<&Motif> {
+        self.motifs.values()
+            .filter(|motif| self.parent(&motif.code).is_some_and(|parent| parent.code == *code))
+            .collect()
+    }
+    
+    /// Check whether `code` is `ancestor` or falls under it, by code or by explicit parent
+    ///
+    /// Explicit parents are followed even when they are not themselves catalogued.
+    pub fn is_within(&self, code: &MotifCode, ancestor: &MotifCode) -> bool {
+        let mut current = code.clone();
+        for _ in 0..=self.len() {
+            if ancestor.contains(&current) {
+                return true;
+            }
+            let explicit = self.get(&current).and_then(|motif| motif.parent.clone());
+            match explicit.or_else(|| self.parent(&current).map(|motif| motif.code.clone())) {
+                Some(parent) => current = parent,
+                None => return false,
+            }
+        }
+        false
+    }
+    
+    /// Get a display label for a code, e.g. `A1010 Deluge`
+    pub fn label(&self, code: &MotifCode) -> String {
+        match self.get(code) {
+            Some(motif) => format!("{} {}", code, motif.name),
+            None => code.to_string(),
+        }
+    }
+    
+    /// Parse a tab-separated catalogue
+    pub fn from_tsv(text: &str) -> Result<Self, CatalogueError> {
+        let mut catalogue = Self::new();
+        for (index, line) in text.lines().enumerate() {
+            let error = |message: String| CatalogueError { line: index + 1, message };
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+            
+            let cells: Vec<&str> = line.split('\t').map(str::trim).collect();
+            let code = MotifCode::new(cells[0]).map_err(|e| error(e.to_string()))?;
+            let name = cells.get(1).copied().filter(|name| !name.is_empty())
+                .ok_or_else(|| error(format!("motif {} has no name", code)))?;
+            let mut motif = Motif::new(code, name);
+            if let Some(description) = cells.get(2).filter(|d| !d.is_empty()) {
+                motif = motif.description(description);
+            }
+            if let Some(parent) = cells.get(3).filter(|p| !p.is_empty()) {
+                motif = motif.parent(MotifCode::new(parent).map_err(|e| error(e.to_string()))?);
+            }
+            if cells.len() > 4 {
+                return Err(error(format!("expected at most 4 columns, found {}", cells.len())));
+            }
+            catalogue.add(motif);
+        }
+        Ok(catalogue)
+    }
+    
+    /// Render the catalogue as tab-separated text
+    pub fn to_tsv(&self) -> String {
+        let mut output = String::from("# code\tname\tdescription\tparent\n");
+        for motif in self.iter() {
+            let mut cells = vec![motif.code.to_string(), motif.name.clone()];
+            if motif.description.is_some() || motif.parent.is_some() {
+                cells.push(motif.description.clone().unwrap_or_default());
+            }
+            if let Some(parent) = &motif.parent {
+                cells.push(parent.to_string());
+            }
+            output.push_str(&cells.join("\t"));
+            output.push('\n');
+        }
+        output
+    }
+    
+    /// Load a catalogue from a `.json` file, or a tab-separated file otherwise
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let text = fs::read_to_string(path)?;
+        if is_json(path) {
+            let motifs: Vec<Motif> = serde_json::from_str(&text)?;
+            Ok(motifs.into_iter().collect())
+        } else {
+            Self::from_tsv(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        }
+    }
+    
+    /// Save the catalogue as a `.json` file, or a tab-separated file otherwise
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let text = if is_json(path) {
+            serde_json::to_string_pretty(&self.iter().collect::<Vec<_>>())?
+        } else {
+            self.to_tsv()
+        };
+        fs::write(path, text)
+    }
+}
+
+impl FromIterator<Motif> for MotifCatalogue {
+    fn from_iter<I: IntoIterator<Item = Motif>>(iter: I) -> Self {
+        let mut catalogue = Self::new();
+        for motif in iter {
+            catalogue.add(motif);
+        }
+        catalogue
+    }
+}
+
+fn is_json(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| extension.eq_ignore_ascii_case("json"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn code(text: &str) -> MotifCode {
+        text.parse().unwrap()
+    }
+    
+    #[test]
+    fn saves_and_loads_both_formats() {
+        let catalogue: MotifCatalogue = [
+            Motif::new(code("ATU 300-749"), "Tales of Magic"),
+            Motif::new(code("ATU 300"), "The Dragon-Slayer").description("A hero kills a dragon and rescues a princess"),
+            Motif::new(code("B11"), "Dragon").parent(code("ATU 300")),
+        ].into_iter().collect();
+        assert_eq!(catalogue.children(&code("ATU 300")).len(), 1);
+        assert!(catalogue.is_within(&code("B11"), &code("ATU 300-749")));
+        
+        for extension in ["json", "tsv"] {
+            let path = std::env::temp_dir().join(format!("mythologic-motifs-{}.{}", crate::core::MythId::new(), extension));
+            catalogue.save(&path).unwrap();
+            let loaded = MotifCatalogue::load(&path).unwrap();
+            fs::remove_file(&path).unwrap();
+            assert_eq!(loaded, catalogue);
+        }
+        
+        let error = MotifCatalogue::from_tsv("A\tMythological motifs\nA10x\tBroken\n").unwrap_err();
+        assert_eq!(error.line, 2);
+    }
+}
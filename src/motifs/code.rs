@@ -0,0 +1,345 @@
+use std::cmp::{Ordering, Reverse};
+use std::fmt;
+use std::str::FromStr;
+use serde::{Serialize, Deserialize};
+use crate::core::Citation;
+
+/// The classification system a [`MotifCode`] belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MotifSystem {
+    /// Thompson's *Motif-Index of Folk-Literature*: a chapter letter followed by a
+    /// number and optional subdivisions, e.g. `A1010` or `F821.1.5`
+    Thompson,
+    /// The Aarne–Thompson–Uther tale type index, e.g. `ATU 300` or `ATU 510A`
+    Atu,
+}
+
+/// A code in the Thompson motif index or the ATU tale type index
+///
+/// Codes are normalized on parsing (`"atu510a"` becomes `"ATU 510A"`) and form a
+/// hierarchy that can be read from the code alone: chapter `A` contains the range
+/// `A1000-A1099`, which contains `A1010`, which contains `A1010.1`. Likewise
+/// `ATU 300-749` contains `ATU 510`, which contains `ATU 510A`.
+///
+/// ```
+/// use mythologic::motifs::MotifCode;
+///
+/// let deluge: MotifCode = "A1010".parse().unwrap();
+/// let calamities: MotifCode = "A1000-A1099".parse().unwrap();
+/// assert!(calamities.contains(&deluge));
+/// assert!(deluge.contains(&"A1010.2".parse().unwrap()));
+/// assert_eq!("atu510a".parse::<MotifCode>().unwrap().to_string(), "ATU 510A");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct MotifCode(String);
+
+/// Error returned when text is not a valid motif or tale type code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MotifCodeError {
+    /// The text that was rejected
+    pub code: String,
+}
+
+impl fmt::Display for MotifCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid motif code `{}`; expected a Thompson code like A1010 or A1000-A1099, or a tale type like ATU 510A",
+            self.code
+        )
+    }
+}
+
+impl std::error::Error for MotifCodeError {}
+
+/// The parsed structure of a code
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Shape {
+    Chapter(char),
+    MotifRange { letter: char, from: u32, to: u32 },
+    Motif { letter: char, number: u32, subdivisions: Vec<u32> },
+    TaleTypeRange { from: u32, to: u32 },
+    TaleType { number: u32, suffix: String },
+}
+
+impl MotifCode {
+    /// Parse and normalize a code
+    pub fn new(code: &str) -> Result<Self, MotifCodeError> {
+        code.parse()
+    }
+    
+    /// The normalized code
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+    
+    /// The index this code belongs to
+    pub fn system(&self) -> MotifSystem {
+        match self.shape() {
+            Shape::TaleType { .. } | Shape::TaleTypeRange { .. } => MotifSystem::Atu,
+            _ => MotifSystem::Thompson,
+        }
+    }
+    
+    /// Check whether this code is a chapter or a range of codes rather than a
+    /// single motif or tale type
+    pub fn is_group(&self) -> bool {
+        matches!(self.shape(), Shape::Chapter(_) | Shape::MotifRange { .. } | Shape::TaleTypeRange { .. })
+    }
+    
+    /// Check whether `other` is this code or falls under it
+    pub fn contains(&self, other: &MotifCode) -> bool {
+        match (self.shape(), other.shape()) {
+            (Shape::Chapter(a), Shape::Chapter(b)) => a == b,
+            (Shape::Chapter(a), Shape::MotifRange { letter, .. } | Shape::Motif { letter, .. }) => a == letter,
+            (Shape::MotifRange { letter, from, to }, Shape::MotifRange { letter: l, from: f, to: t }) => {
+                letter == l && from <= f && t <= to
+            },
+            (Shape::MotifRange { letter, from, to }, Shape::Motif { letter: l, number, .. }) => {
+                letter == l && (from..=to).contains(&number)
+            },
+            (Shape::Motif { letter, number, subdivisions }, Shape::Motif { letter: l, number: n, subdivisions: s }) => {
+                letter == l && number == n && s.starts_with(&subdivisions)
+            },
+            (Shape::TaleTypeRange { from, to }, Shape::TaleTypeRange { from: f, to: t }) => from <= f && t <= to,
+            (Shape::TaleTypeRange { from, to }, Shape::TaleType { number, .. }) => (from..=to).contains(&number),
+            (Shape::TaleType { number, suffix }, Shape::TaleType { number: n, suffix: s }) => {
+                number == n && s.starts_with(&suffix)
+            },
+            _ => false,
+        }
+    }
+    
+    /// The code one level up that can be read from this code, e.g. `A1010` for
+    /// `A1010.1` or `ATU 510` for `ATU 510A`
+    ///
+    /// Top-level motif numbers have no syntactic parent; the chapter ranges they
+    /// belong to come from a [`MotifCatalogue`](crate::motifs::MotifCatalogue).
+    pub fn parent(&self) -> Option<MotifCode> {
+        match self.shape() {
+            Shape::Motif { letter, number, mut subdivisions } if !subdivisions.is_empty() => {
+                subdivisions.pop();
+                Some(Self::format_motif(letter, number, &subdivisions))
+            },
+            Shape::TaleType { number, mut suffix } if !suffix.is_empty() => {
+                suffix.pop();
+                Some(MotifCode(format!("ATU {}{}", number, suffix)))
+            },
+            _ => None,
+        }
+    }
+    
+    fn format_motif(letter: char, number: u32, subdivisions: &[u32]) -> MotifCode {
+        let mut code = format!("{}{}", letter, number);
+        for subdivision in subdivisions {
+            code.push_str(&format!(".{}", subdivision));
+        }
+        MotifCode(code)
+    }
+    
+    fn shape(&self) -> Shape {
+        parse_shape(&self.0).expect("motif codes are validated on construction")
+    }
+    
+    /// Key ordering codes by system, chapter, and number, with groups before
+    /// their members and wider ranges before narrower ones
+    fn sort_key(&self) -> (MotifSystem, char, u32, u8, Reverse<u32>, Vec<u32>, String) {
+        let system = self.system();
+        match self.shape() {
+            Shape::Chapter(letter) => (system, letter, 0, 0, Reverse(u32::MAX), Vec::new(), String::new()),
+            Shape::MotifRange { letter, from, to } => (system, letter, from, 1, Reverse(to), Vec::new(), String::new()),
+            Shape::Motif { letter, number, subdivisions } => (system, letter, number, 2, Reverse(number), subdivisions, String::new()),
+            Shape::TaleTypeRange { from, to } => (system, ' ', from, 1, Reverse(to), Vec::new(), String::new()),
+            Shape::TaleType { number, suffix } => (system, ' ', number, 2, Reverse(number), Vec::new(), suffix),
+        }
+    }
+}
+
+/// Normalize a code: uppercase, no spaces in Thompson codes, and `ATU n` for tale types
+fn normalize(code: &str) -> Result<String, MotifCodeError> {
+    let error = || MotifCodeError { code: code.to_string() };
+    let compact: String = code.chars().filter(|c| !c.is_whitespace()).collect::<String>().to_uppercase();
+    
+    let normalized = if let Some(rest) = compact.strip_prefix("ATU").or_else(|| compact.strip_prefix("AT")) {
+        match rest.split_once('-') {
+            Some((from, to)) => format!("ATU {}-{}", from, to),
+            None => format!("ATU {}", rest),
+        }
+    } else {
+        match compact.split_once('-') {
+            // `A1000-1099` is shorthand for `A1000-A1099`
+            Some((from, to)) if to.starts_with(|c: char| c.is_ascii_digit()) => {
+                format!("{}-{}{}", from, &from[..from.len().min(1)], to)
+            },
+            _ => compact,
+        }
+    };
+    parse_shape(&normalized).ok_or_else(error)?;
+    Ok(normalized)
+}
+
+/// Parse a normalized code
+fn parse_shape(code: &str) -> Option<Shape> {
+    let number = |text: &str| -> Option<u32> {
+        (!text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())).then(|| text.parse().ok()).flatten()
+    };
+    
+    if let Some(rest) = code.strip_prefix("ATU ") {
+        if let Some((from, to)) = rest.split_once('-') {
+            let (from, to) = (number(from)?, number(to)?);
+            return (from <= to).then_some(Shape::TaleTypeRange { from, to });
+        }
+        let digits = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+        let suffix = &rest[digits..];
+        if !suffix.chars().all(|c| c.is_ascii_uppercase() || c == '*') {
+            return None;
+        }
+        return Some(Shape::TaleType { number: number(&rest[..digits])?, suffix: suffix.to_string() });
+    }
+    
+    let mut chars = code.chars();
+    let letter = chars.next().filter(char::is_ascii_uppercase)?;
+    let rest = chars.as_str();
+    if rest.is_empty() {
+        return Some(Shape::Chapter(letter));
+    }
+    if let Some((from, to)) = rest.split_once('-') {
+        let to = to.strip_prefix(letter)?;
+        let (from, to) = (number(from)?, number(to)?);
+        return (from <= to).then_some(Shape::MotifRange { letter, from, to });
+    }
+    let mut parts = rest.split('.');
+    let number_part = number(parts.next()?)?;
+    let subdivisions = parts.map(number).collect::<Option<Vec<u32>>>()?;
+    Some(Shape::Motif { letter, number: number_part, subdivisions })
+}
+
+impl FromStr for MotifCode {
+    type Err = MotifCodeError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        normalize(s).map(MotifCode)
+    }
+}
+
+impl TryFrom<String> for MotifCode {
+    type Error = MotifCodeError;
+    
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<MotifCode> for String {
+    fn from(code: MotifCode) -> Self {
+        code.0
+    }
+}
+
+impl fmt::Display for MotifCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Ord for MotifCode {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.sort_key().cmp(&other.sort_key()).then_with(|| self.0.cmp(&other.0))
+    }
+}
+
+impl PartialOrd for MotifCode {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The classification of an entity or relationship under a motif or tale type
+///
+/// Tags are stored in [`Metadata::motifs`](crate::core::Metadata::motifs).
+///
+/// ```
+/// use mythologic::core::Metadata;
+/// use mythologic::motifs::{MotifCode, MotifTag};
+///
+/// let mut metadata = Metadata::new();
+/// metadata.add_motif(MotifTag::new("A1021".parse().unwrap()).note("Deucalion's chest"));
+/// assert!(metadata.has_motif(&MotifCode::new("A1000-A1099").unwrap()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MotifTag {
+    /// The motif or tale type
+    pub code: MotifCode,
+    /// Where the classification comes from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub citation: Option<Citation>,
+    /// How the motif appears in this entity's story
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+impl MotifTag {
+    /// Create a tag without a citation or note
+    pub fn new(code: MotifCode) -> Self {
+        Self {
+            code,
+            citation: None,
+            note: None,
+        }
+    }
+    
+    /// Cite the source of the classification
+    pub fn citation(mut self, citation: Citation) -> Self {
+        self.citation = Some(citation);
+        self
+    }
+    
+    /// Set a note on how the motif appears
+    pub fn note(mut self, note: &str) -> Self {
+        self.note = Some(note.to_string());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn code(text: &str) -> MotifCode {
+        text.parse().unwrap()
+    }
+    
+    #[test]
+    fn parses_and_orders_codes() {
+        assert_eq!(code(" f 821.1.5 ").to_string(), "F821.1.5");
+        assert_eq!(code("A1000-1099").to_string(), "A1000-A1099");
+        assert_eq!(code("at 300-749").to_string(), "ATU 300-749");
+        assert_eq!(code("ATU 510A*").system(), MotifSystem::Atu);
+        for invalid in ["", "1010", "A10.x", "A1099-A1000", "A1000-B1099", "ATU", "ATU 510a-"] {
+            assert!(invalid.parse::<MotifCode>().is_err(), "{} should be rejected", invalid);
+        }
+        
+        let mut codes: Vec<MotifCode> = ["A1010.1", "ATU 300", "A1010", "A", "A1000-A1099", "A102", "A1000-A1049"]
+            .into_iter()
+            .map(code)
+            .collect();
+        codes.sort();
+        let sorted: Vec<String> = codes.iter().map(ToString::to_string).collect();
+        assert_eq!(sorted, ["A", "A102", "A1000-A1099", "A1000-A1049", "A1010", "A1010.1", "ATU 300"]);
+    }
+    
+    #[test]
+    fn hierarchy_follows_codes() {
+        assert!(code("A").contains(&code("A1010.1")));
+        assert!(code("A1000-A1099").contains(&code("A1000-A1049")));
+        assert!(!code("A1000-A1049").contains(&code("A1050")));
+        assert!(!code("A1010").contains(&code("A10101")));
+        assert!(code("ATU 510").contains(&code("ATU 510B")));
+        assert!(!code("ATU 510A").contains(&code("ATU 510B")));
+        assert!(!code("A").contains(&code("ATU 300")));
+        assert_eq!(code("A1010.1.2").parent(), Some(code("A1010.1")));
+        assert_eq!(code("ATU 510A").parent(), Some(code("ATU 510")));
+        assert_eq!(code("A1010").parent(), None);
+    }
+}
@@ -0,0 +1,26 @@
+//! # Motifs and Tale Types
+//!
+//! This module classifies stories by folklore motif and tale type. A
+//! [`MotifCode`] is a code in Stith Thompson's *Motif-Index of Folk-Literature*
+//! (e.g. `A1010` "Deluge", `F821` "Extraordinary clothing") or in the
+//! Aarne–Thompson–Uther tale type index (e.g. `ATU 300` "The Dragon-Slayer").
+//! Codes are hierarchical, so a chapter or range such as `A1000-A1099` stands for
+//! every motif under it.
+//!
+//! - Any entity or relationship can be tagged with motifs through
+//!   [`Metadata::motifs`](crate::core::Metadata::motifs); each [`MotifTag`] may
+//!   name the source of the classification.
+//! - A [`MotifCatalogue`] holds motif titles and descriptions and can be loaded
+//!   from JSON or tab-separated files.
+//! - [`Motifs`] answers questions such as "which cultures have a flood motif" or
+//!   "which entities share motifs with Heracles". The `motif:` query filter (see
+//!   [`QueryFilter::Motif`](crate::query::QueryFilter::Motif)) selects tagged
+//!   entities in ordinary queries.
+
+mod analysis;
+mod catalogue;
+mod code;
+
+pub use analysis::*;
+pub use catalogue::*;
+pub use code::*;
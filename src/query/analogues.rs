@@ -0,0 +1,481 @@
+//! # Comparative Analogues
+//!
+//! [`AnalogueFinder`] answers the comparative question "which figures in other
+//! cultures resemble this one?". It compares an entity with every entity of the
+//! same type in a different culture, scoring each pair on several features:
+//!
+//! | Feature                               | Compared                                           |
+//! |---------------------------------------|----------------------------------------------------|
+//! | [`Domains`](AnalogueFeature::Domains) | overlap of deity domains                           |
+//! | [`Gender`](AnalogueFeature::Gender)   | deity gender                                       |
+//! | [`Importance`](AnalogueFeature::Importance) | deity importance, with partial credit for neighbouring ranks |
+//! | [`Kind`](AnalogueFeature::Kind)       | creature, artifact, location, or concept type, or hero origin |
+//! | [`Relationships`](AnalogueFeature::Relationships) | relationship kinds, directions, and the kind of entity at the other end, so two children of sky gods match |
+//! | [`Description`](AnalogueFeature::Description) | TF-IDF weighted overlap of description words |
+//!
+//! A feature only counts when both entities have something to compare, and the
+//! overall score is the weighted average of the features that count. Every
+//! [`Analogue`] explains each feature's contribution.
+//!
+//! ```
+//! use mythologic::examples::{create_greek_ontology, create_norse_ontology};
+//! use mythologic::query::AnalogueFinder;
+//!
+//! let mut ontology = create_greek_ontology();
+//! for entity in create_norse_ontology().entities() {
+//!     ontology.add_entity(entity.clone());
+//! }
+//! let thor = ontology.entities_named("Thor").next().unwrap().id().clone();
+//!
+//! let analogues = AnalogueFinder::new(&ontology).limit(2).find(&thor);
+//! let names: Vec<&str> = analogues.iter().map(|a| a.name.as_str()).collect();
+//! assert!(names.contains(&"Zeus") && names.contains(&"Jupiter"));
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use crate::core::{stem, tokenize, CultureId, MythEntity, MythId, MythOntology};
+use crate::entities::DeityImportance;
+use crate::query::fold_name;
+
+/// A feature on which two entities are compared
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AnalogueFeature {
+    Domains,
+    Gender,
+    Importance,
+    Kind,
+    Relationships,
+    Description,
+}
+
+impl AnalogueFeature {
+    /// Every feature, in order of default weight
+    pub const ALL: [AnalogueFeature; 6] = [
+        Self::Domains, Self::Relationships, Self::Description, Self::Kind, Self::Importance, Self::Gender,
+    ];
+    
+    /// The weight of this feature unless overridden with [`AnalogueFinder::weight`]
+    pub fn default_weight(&self) -> f32 {
+        match self {
+            Self::Domains => 3.0,
+            Self::Relationships => 2.0,
+            Self::Description => 2.0,
+            Self::Kind => 1.5,
+            Self::Importance => 0.5,
+            Self::Gender => 0.25,
+        }
+    }
+}
+
+impl fmt::Display for AnalogueFeature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Domains => "domains",
+            Self::Gender => "gender",
+            Self::Importance => "importance",
+            Self::Kind => "kind",
+            Self::Relationships => "relationships",
+            Self::Description => "description",
+        };
+        f.write_str(name)
+    }
+}
+
+/// How one feature contributed to an analogue's score
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeatureScore {
+    /// The feature compared
+    pub feature: AnalogueFeature,
+    /// Similarity on this feature between 0.0 and 1.0
+    pub score: f32,
+    /// The weight of the feature in the overall score
+    pub weight: f32,
+    /// Why the feature scored as it did, e.g. "both rule over Thunder"
+    pub explanation: String,
+}
+
+/// An entity from another culture resembling the one compared
+#[derive(Debug, Clone, PartialEq)]
+pub struct Analogue {
+    /// The resembling entity
+    pub id: MythId,
+    /// Its name
+    pub name: String,
+    /// Its entity type
+    pub entity_type: String,
+    /// Its culture
+    pub culture: CultureId,
+    /// Weighted average of the feature scores, between 0.0 and 1.0
+    pub score: f32,
+    /// The features compared, largest contribution first
+    pub features: Vec<FeatureScore>,
+}
+
+/// Finds entities in other cultures that resemble a given entity
+pub struct AnalogueFinder<'a> {
+    ontology: &'a MythOntology,
+    weights: HashMap<AnalogueFeature, f32>,
+    min_score: f32,
+    limit: Option<usize>,
+    culture: Option<CultureId>,
+}
+
+impl<'a> AnalogueFinder<'a> {
+    /// Create a finder with the default feature weights, returning analogues
+    /// scoring at least 0.2 from any other culture
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        Self {
+            ontology,
+            weights: HashMap::new(),
+            min_score: 0.2,
+            limit: None,
+            culture: None,
+        }
+    }
+    
+    /// Set the weight of a feature; a weight of zero ignores it
+    pub fn weight(mut self, feature: AnalogueFeature, weight: f32) -> Self {
+        self.weights.insert(feature, weight);
+        self
+    }
+    
+    /// Set the lowest score an analogue may have
+    pub fn min_score(mut self, min_score: f32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+    
+    /// Return at most this many analogues
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    
+    /// Only look for analogues in the given culture
+    pub fn in_culture(mut self, culture: CultureId) -> Self {
+        self.culture = Some(culture);
+        self
+    }
+    
+    /// Find analogues of an entity in other cultures, best first
+    pub fn find(&self, id: &MythId) -> Vec<Analogue> {
+        let Some(entity) = self.ontology.get_entity(id) else {
+            return Vec::new();
+        };
+        let Some(culture) = entity.culture() else {
+            return Vec::new();
+        };
+        
+        let mut analogues: Vec<Analogue> = self.ontology.entities_of_type(entity.entity_type())
+            .filter(|candidate| candidate.culture().is_some_and(|c| {
+                c != culture && self.culture.as_ref().is_none_or(|wanted| c == wanted)
+            }))
+            .filter_map(|candidate| self.compare_entities(entity, candidate))
+            .filter(|analogue| analogue.score >= self.min_score)
+            .collect();
+        analogues.sort_by(|a, b| {
+            b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+        if let Some(limit) = self.limit {
+            analogues.truncate(limit);
+        }
+        analogues
+    }
+    
+    /// Compare two entities of the same type, regardless of culture
+    ///
+    /// Returns `None` if either entity is missing, they have different types, or
+    /// no feature applies to them.
+    pub fn compare(&self, a: &MythId, b: &MythId) -> Option<Analogue> {
+        self.compare_entities(self.ontology.get_entity(a)?, self.ontology.get_entity(b)?)
+    }
+    
+    fn compare_entities(&self, entity: &MythEntity, candidate: &MythEntity) -> Option<Analogue> {
+        if entity.entity_type() != candidate.entity_type() || entity.id() == candidate.id() {
+            return None;
+        }
+        
+        let mut features: Vec<FeatureScore> = AnalogueFeature::ALL.into_iter()
+            .filter_map(|feature| {
+                let weight = self.weights.get(&feature).copied().unwrap_or_else(|| feature.default_weight());
+                if weight <= 0.0 {
+                    return None;
+                }
+                let (score, explanation) = match feature {
+                    AnalogueFeature::Domains => compare_domains(entity, candidate),
+                    AnalogueFeature::Gender => compare_gender(entity, candidate),
+                    AnalogueFeature::Importance => compare_importance(entity, candidate),
+                    AnalogueFeature::Kind => compare_kind(entity, candidate),
+                    AnalogueFeature::Relationships => self.compare_relationships(entity, candidate),
+                    AnalogueFeature::Description => self.compare_descriptions(entity, candidate),
+                }?;
+                Some(FeatureScore { feature, score, weight, explanation })
+            })
+            .collect();
+        
+        let total_weight: f32 = features.iter().map(|f| f.weight).sum();
+        if total_weight == 0.0 {
+            return None;
+        }
+        let score = features.iter().map(|f| f.score * f.weight).sum::<f32>() / total_weight;
+        features.sort_by(|a, b| {
+            (b.score * b.weight).partial_cmp(&(a.score * a.weight)).unwrap_or(Ordering::Equal)
+        });
+        
+        Some(Analogue {
+            id: candidate.id().clone(),
+            name: candidate.name().to_string(),
+            entity_type: candidate.entity_type().to_string(),
+            culture: candidate.culture()?.clone(),
+            score,
+            features,
+        })
+    }
+    
+    /// Jaccard similarity of relationship signatures
+    fn compare_relationships(&self, entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+        let ours = self.relationship_signature(entity);
+        let theirs = self.relationship_signature(candidate);
+        if ours.is_empty() || theirs.is_empty() {
+            return None;
+        }
+        
+        let mut shared: Vec<&String> = ours.intersection(&theirs).collect();
+        shared.sort();
+        let score = shared.len() as f32 / ours.union(&theirs).count() as f32;
+        let explanation = if shared.is_empty() {
+            "no relationships in common".to_string()
+        } else {
+            format!("both have {}", shared.into_iter().take(3).cloned().collect::<Vec<_>>().join("; "))
+        };
+        Some((score, explanation))
+    }
+    
+    /// Describe each relationship of an entity by its kind, direction, and the
+    /// type and domains of the entity at the other end
+    fn relationship_signature(&self, entity: &MythEntity) -> HashSet<String> {
+        let id = entity.id();
+        let outgoing = self.ontology.relationships_from(id).map(|r| (r, true));
+        let incoming = self.ontology.relationships_to(id).map(|r| (r, false));
+        
+        let mut signature = HashSet::new();
+        for (edge, is_source) in outgoing.chain(incoming) {
+            // Identifications are what analogues are compared for, not evidence of them
+            if matches!(edge, MythEntity::SyncretismRelationship(_)) {
+                continue;
+            }
+            let (Some(relationship), Some(kind)) = (edge.relationship(), edge.relationship_kind()) else {
+                continue;
+            };
+            let other_id = if is_source { &relationship.target_id } else { &relationship.source_id };
+            let Some(other) = self.ontology.get_entity(other_id).filter(|other| other.id() != id) else {
+                continue;
+            };
+            
+            let direction = match (relationship.bidirectional, is_source) {
+                (true, _) => "with",
+                (false, true) => "to",
+                (false, false) => "from",
+            };
+            let other_type = other.entity_type().to_lowercase();
+            signature.insert(format!("a {} relationship {} a {}", kind, direction, other_type));
+            if let MythEntity::Deity(deity) = other {
+                for domain in &deity.domains {
+                    signature.insert(format!("a {} relationship {} a {} {}", kind, direction, domain.value(), other_type));
+                }
+            }
+        }
+        signature
+    }
+    
+    /// Cosine similarity of TF-IDF weighted description words
+    fn compare_descriptions(&self, entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+        let ours = self.description_vector(entity)?;
+        let theirs = self.description_vector(candidate)?;
+        
+        let norm = |vector: &HashMap<String, (f32, String)>| vector.values().map(|(w, _)| w * w).sum::<f32>().sqrt();
+        let mut shared: Vec<(f32, &str)> = ours.iter()
+            .filter_map(|(term, (weight, word))| theirs.get(term).map(|(other, _)| (weight * other, word.as_str())))
+            .collect();
+        let dot: f32 = shared.iter().map(|(product, _)| product).sum();
+        let score = dot / (norm(&ours) * norm(&theirs));
+        
+        shared.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(Ordering::Equal).then_with(|| a.1.cmp(b.1)));
+        let explanation = if shared.is_empty() {
+            "no description words in common".to_string()
+        } else {
+            format!("descriptions share {}", shared.iter().take(5).map(|(_, word)| *word).collect::<Vec<_>>().join(", "))
+        };
+        Some((score, explanation))
+    }
+    
+    /// Description terms weighted by frequency and rarity across the ontology,
+    /// each with the first word it was found as
+    fn description_vector(&self, entity: &MythEntity) -> Option<HashMap<String, (f32, String)>> {
+        let description = entity.text_fields().into_iter()
+            .find(|(field, _)| *field == crate::core::TextField::Description)?
+            .1;
+        let index = self.ontology.index().text();
+        let entities = index.entity_count().max(1) as f32;
+        
+        let mut vector: HashMap<String, (f32, String)> = HashMap::new();
+        for token in tokenize(description) {
+            let frequency = index.postings(&token.term).map_or(1, |postings| postings.len()) as f32;
+            let idf = (1.0 + entities / frequency).ln();
+            let word = description[token.start..token.end].to_lowercase();
+            vector.entry(token.term).or_insert((0.0, word)).0 += idf;
+        }
+        (!vector.is_empty()).then_some(vector)
+    }
+}
+
+/// Jaccard similarity of deity domains, matching stems so "Storm" and "Storms" agree
+fn compare_domains(entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+    let (MythEntity::Deity(a), MythEntity::Deity(b)) = (entity, candidate) else {
+        return None;
+    };
+    if a.domains.is_empty() || b.domains.is_empty() {
+        return None;
+    }
+    
+    let key = |domain: &str| stem(&fold_name(domain));
+    let ours: HashSet<String> = a.domains.iter().map(|d| key(d.value())).collect();
+    let theirs: HashSet<String> = b.domains.iter().map(|d| key(d.value())).collect();
+    let mut shared: Vec<&str> = ours.intersection(&theirs)
+        .filter_map(|common| a.domains.iter().map(|d| d.value()).filter(|d| key(d) == *common).min())
+        .collect();
+    shared.sort_unstable();
+    
+    let score = shared.len() as f32 / ours.union(&theirs).count() as f32;
+    let explanation = if shared.is_empty() {
+        "no domains in common".to_string()
+    } else {
+        format!("both rule over {}", shared.join(", "))
+    };
+    Some((score, explanation))
+}
+
+fn compare_gender(entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+    let (MythEntity::Deity(a), MythEntity::Deity(b)) = (entity, candidate) else {
+        return None;
+    };
+    compare_labels(&a.gender.to_string(), &b.gender.to_string())
+}
+
+/// Exact importance scores 1.0, neighbouring ranks 0.5
+fn compare_importance(entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+    let (MythEntity::Deity(a), MythEntity::Deity(b)) = (entity, candidate) else {
+        return None;
+    };
+    if a.importance == DeityImportance::Unknown || b.importance == DeityImportance::Unknown {
+        return None;
+    }
+    
+    let rank = |importance: &DeityImportance| DeityImportance::VARIANT_NAMES.iter()
+        .position(|name| *name == importance.to_string())
+        .unwrap_or(0);
+    let score = match rank(&a.importance).abs_diff(rank(&b.importance)) {
+        0 => 1.0,
+        1 => 0.5,
+        _ => 0.0,
+    };
+    let explanation = if score == 1.0 {
+        format!("both {}", a.importance)
+    } else {
+        format!("{} vs {}", a.importance, b.importance)
+    };
+    Some((score, explanation))
+}
+
+/// Compare the type classification of non-deity entities
+fn compare_kind(entity: &MythEntity, candidate: &MythEntity) -> Option<(f32, String)> {
+    let kind = |entity: &MythEntity| match entity {
+        MythEntity::Hero(e) => Some(e.origin.to_string()),
+        MythEntity::Creature(e) => Some(e.creature_type.to_string()),
+        MythEntity::Artifact(e) => Some(e.artifact_type.to_string()),
+        MythEntity::Location(e) => Some(e.location_type.to_string()),
+        MythEntity::Concept(e) => Some(e.concept_type.to_string()),
+        _ => None,
+    };
+    compare_labels(&kind(entity)?, &kind(candidate)?)
+}
+
+/// Score two classification labels 1.0 if equal, ignoring unknown values
+fn compare_labels(a: &str, b: &str) -> Option<(f32, String)> {
+    if a == "Unknown" || b == "Unknown" {
+        return None;
+    }
+    if a == b {
+        Some((1.0, format!("both {}", a)))
+    } else {
+        Some((0.0, format!("{} vs {}", a, b)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Deity, Gender};
+    use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+    
+    fn deity(ontology: &mut MythOntology, name: &str, culture: &str, domains: &[&str]) -> MythId {
+        let mut deity = Deity::new(name, &format!("{} is a god of {}", name, domains.join(" and ")), culture);
+        for domain in domains {
+            deity.add_domain(domain);
+        }
+        deity.set_gender(Gender::Male);
+        let id = deity.id.clone();
+        ontology.add_entity(MythEntity::Deity(deity));
+        id
+    }
+    
+    fn parent(ontology: &mut MythOntology, parent: &MythId, child: &MythId) {
+        let family = FamilyRelationship::new("Parent", "", parent.clone(), child.clone(), FamilyRelationshipType::Parent);
+        ontology.add_entity(MythEntity::FamilyRelationship(family));
+    }
+    
+    #[test]
+    fn scores_and_explains_features() {
+        let mut ontology = MythOntology::new();
+        let odin = deity(&mut ontology, "Odin", "Norse", &["Sky", "Wisdom"]);
+        let thor = deity(&mut ontology, "Thor", "Norse", &["Thunder", "Storms"]);
+        let zeus = deity(&mut ontology, "Zeus", "Greek", &["Sky", "Thunder"]);
+        let ares = deity(&mut ontology, "Ares", "Greek", &["War"]);
+        let indra = deity(&mut ontology, "Indra", "Hindu", &["Storm", "Thunder", "Rain"]);
+        let dyaus = deity(&mut ontology, "Dyaus", "Hindu", &["Sky"]);
+        parent(&mut ontology, &odin, &thor);
+        parent(&mut ontology, &zeus, &ares);
+        parent(&mut ontology, &dyaus, &indra);
+        
+        let analogues = AnalogueFinder::new(&ontology).find(&thor);
+        let names: Vec<&str> = analogues.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names[0], "Indra");
+        assert!(!names.contains(&"Odin"), "analogues come from other cultures");
+        
+        let explain = |feature| analogues[0].features.iter().find(|f| f.feature == feature).unwrap().explanation.clone();
+        assert_eq!(explain(AnalogueFeature::Domains), "both rule over Storms, Thunder");
+        assert!(explain(AnalogueFeature::Relationships).contains("a Parent relationship from a Sky deity"));
+        assert_eq!(explain(AnalogueFeature::Gender), "both Male");
+        
+        let greek_only = AnalogueFinder::new(&ontology).in_culture("Greek".into()).find(&thor);
+        assert_eq!(greek_only[0].name, "Zeus");
+        assert!(AnalogueFinder::new(&ontology).compare(&thor, &odin).is_some());
+    }
+    
+    #[test]
+    fn counts_domains_with_the_same_stem_once() {
+        let mut ontology = MythOntology::new();
+        let thor = deity(&mut ontology, "Thor", "Norse", &["Storm", "Storms", "Thunder"]);
+        let indra = deity(&mut ontology, "Indra", "Hindu", &["Storm", "Rain"]);
+        
+        let thor = ontology.get_entity(&thor).unwrap();
+        let indra = ontology.get_entity(&indra).unwrap();
+        let (score, explanation) = compare_domains(thor, indra).unwrap();
+        assert_eq!(score, 1.0 / 3.0);
+        assert_eq!(explanation, "both rule over Storm");
+    }
+}
@@ -8,8 +8,19 @@
 //! - Logical operations (AND, OR, NOT)
 //! - Type-safe culture-based filtering using `CultureId`
 //! - Case-insensitive partial name matching
+//! - Typed filters over the structured fields of each entity type
+//! - Index-backed candidate selection via [`QueryFilter::candidates`]
 
-use crate::core::{MythEntity, CultureId};
+use std::collections::HashSet;
+use crate::core::{
+    MythEntity, MythId, MythOntology, CultureId, DomainId, PantheonId, CosmologyId, EventId,
+    HistoricalDate, DateMatch,
+};
+use crate::motifs::MotifCode;
+use crate::entities::{
+    ArtifactType, ConceptType, CreatureType, DeityImportance, Gender, HeroOrigin, LocationType,
+    NarrativeRole,
+};
 
 /// A filter for querying mythological entities.
 ///
@@ -35,6 +46,9 @@ use crate::core::{MythEntity, CultureId};
 /// // Usage with a collection of entities
 /// // entities.iter().filter(|e| filter.matches(e)).collect::<Vec<_>>();
 /// ```
+///
+/// Filters can also be written in a text syntax; see [`QueryFilter::parse`].
+#[derive(Debug, Clone, PartialEq)]
 pub enum QueryFilter {
     /// Filter by entity type (e.g., "Deity", "Hero", "Artifact").
     ///
@@ -51,6 +65,11 @@ pub enum QueryFilter {
     /// Matches entities that belong to the specified culture.
     Culture(CultureId),
     
+    /// Filter by deity domain.
+    ///
+    /// Matches deities that have the specified domain of influence.
+    Domain(DomainId),
+    
     /// Filter by the presence of a metadata attribute.
     ///
     /// Matches entities that have the specified attribute key in their metadata.
@@ -61,6 +80,166 @@ pub enum QueryFilter {
     /// Matches entities that have the specified attribute key with exactly the specified value.
     AttributeEquals(String, String),
     
+    /// Filter by folklore motif or tale type.
+    ///
+    /// Matches entities and relationships tagged with the specified code or any code under it.
+    Motif(MotifCode),
+    
+    /// Filter by when an entity is attested in the historical record.
+    ///
+    /// Matches entities whose attestation, from first to last, overlaps the years
+    /// the given date allows. With `certain`, the overlap must hold however the
+    /// uncertainty in the attestation is resolved; otherwise it only needs to be
+    /// possible. Entities without an attestation never match.
+    Attested {
+        /// The date or period to compare against
+        during: HistoricalDate,
+        /// Whether the overlap must be certain rather than possible
+        certain: bool,
+    },
+    
+    /// Filter by the tradition of a variant claim.
+    ///
+    /// Matches relationships whose variant, and entities with a field claim, belong
+    /// to the specified tradition (case-insensitive).
+    Tradition(String),
+    
+    /// Filter by a contested field value.
+    ///
+    /// Matches entities with a claim about the specified field, and with exactly the
+    /// specified value if one is given.
+    Claim {
+        /// The field claimed about
+        field: String,
+        /// The value claimed, or `None` for any value
+        value: Option<String>,
+    },
+    
+    /// Filter by contested question.
+    ///
+    /// Matches relationships that are a variant answer to exactly the specified question.
+    Variant(String),
+    
+    /// Filter by several deity domains.
+    ///
+    /// Matches deities that have any of the specified domains.
+    DomainIn(Vec<DomainId>),
+    
+    /// Filter by deity importance.
+    Importance(DeityImportance),
+    
+    /// Filter by several deity importance levels.
+    ///
+    /// Matches deities whose importance is any of the specified levels.
+    ImportanceIn(Vec<DeityImportance>),
+    
+    /// Filter by deity gender.
+    Gender(Gender),
+    
+    /// Filter by several deity genders.
+    GenderIn(Vec<Gender>),
+    
+    /// Filter by the pantheon a deity belongs to.
+    Pantheon(PantheonId),
+    
+    /// Filter by hero origin.
+    HeroOrigin(HeroOrigin),
+    
+    /// Filter by several hero origins.
+    HeroOriginIn(Vec<HeroOrigin>),
+    
+    /// Filter by partial case-insensitive match on any of a hero's achievements.
+    AchievementContains(String),
+    
+    /// Filter by creature type.
+    CreatureType(CreatureType),
+    
+    /// Filter by several creature types.
+    CreatureTypeIn(Vec<CreatureType>),
+    
+    /// Filter by partial case-insensitive match on any of a creature's habitats.
+    HabitatContains(String),
+    
+    /// Filter by partial case-insensitive match on any of a creature's abilities.
+    AbilityContains(String),
+    
+    /// Filter by artifact type.
+    ArtifactType(ArtifactType),
+    
+    /// Filter by several artifact types.
+    ArtifactTypeIn(Vec<ArtifactType>),
+    
+    /// Filter by partial case-insensitive match on any of an artifact's powers.
+    PowerContains(String),
+    
+    /// Filter by partial case-insensitive match on an artifact's creator.
+    CreatorContains(String),
+    
+    /// Filter by partial case-insensitive match on an artifact's owner.
+    OwnerContains(String),
+    
+    /// Filter by location type.
+    LocationType(LocationType),
+    
+    /// Filter by several location types.
+    LocationTypeIn(Vec<LocationType>),
+    
+    /// Filter by partial case-insensitive match on any characteristic.
+    ///
+    /// Matches locations and mythological eras.
+    CharacteristicContains(String),
+    
+    /// Filter by concept type.
+    ConceptType(ConceptType),
+    
+    /// Filter by several concept types.
+    ConceptTypeIn(Vec<ConceptType>),
+    
+    /// Filter by partial case-insensitive match on any of a concept's manifestations.
+    ManifestationContains(String),
+    
+    /// Filter pantheons by member deity.
+    ///
+    /// Matches pantheons listing the deity as a primary or secondary member.
+    PantheonMember(MythId),
+    
+    /// Filter pantheons by cosmology.
+    Cosmology(CosmologyId),
+    
+    /// Filter by partial case-insensitive match on a pantheon's founding myth.
+    FoundingMythContains(String),
+    
+    /// Filter mythological eras by position in their culture's sequence.
+    ///
+    /// Matches eras with a sequence order between `min` and `max` (inclusive);
+    /// a missing bound is unbounded. Eras without a sequence order never match.
+    EraSequence {
+        /// Lowest sequence order to match
+        min: Option<u32>,
+        /// Highest sequence order to match
+        max: Option<u32>,
+    },
+    
+    /// Filter mythological eras by the event that ended them.
+    EndEvent(EventId),
+    
+    /// Filter narratives by participant.
+    ///
+    /// Matches narratives in which the entity takes part in any episode, in the
+    /// given role if one is specified.
+    Participant {
+        /// The participating entity
+        id: MythId,
+        /// The role it must play, or `None` for any role
+        role: Option<NarrativeRole>,
+    },
+    
+    /// Filter by partial case-insensitive match on any alternative name.
+    AlternativeNameContains(String),
+    
+    /// Filter by partial case-insensitive match on the name or any alternative name.
+    AnyNameContains(String),
+    
     /// Logical AND of two filters.
     ///
     /// Matches entities that match both the left and right filters.
@@ -125,6 +304,11 @@ impl QueryFilter {
                 }
             },
             
+            // Match by deity domain
+            Self::Domain(domain) => {
+                matches!(entity, MythEntity::Deity(deity) if deity.domains.contains(domain))
+            },
+            
             // Match by attribute existence
             Self::HasAttribute(key) => {
                 entity.metadata().attributes.contains_key(key)
@@ -132,7 +316,181 @@ impl QueryFilter {
             
             // Match by attribute value (exact match)
             Self::AttributeEquals(key, value) => {
-                entity.metadata().attributes.get(key).map_or(false, |v| v == value)
+                entity.metadata().attributes.get(key) == Some(value)
+            },
+            
+            // Match by motif, including the motifs under a chapter or range
+            Self::Motif(code) => {
+                entity.metadata().has_motif(code)
+            },
+            
+            // Match by attestation, respecting the uncertainty of both dates
+            Self::Attested { during, certain } => {
+                entity.metadata().attestation().is_some_and(|attested| match attested.falls_within(during) {
+                    DateMatch::Certain => true,
+                    DateMatch::Possible => !certain,
+                    DateMatch::Impossible => false,
+                })
+            },
+            
+            // Match by variant claims
+            Self::Tradition(tradition) => {
+                entity.metadata().all_claims()
+                    .any(|claim| claim.tradition.as_ref().is_some_and(|t| t.eq_ignore_ascii_case(tradition)))
+            },
+            
+            Self::Claim { field, value } => {
+                entity.metadata().claims_for(field).any(|claim| value.as_ref().is_none_or(|v| &claim.value == v))
+            },
+            
+            Self::Variant(question) => {
+                entity.metadata().variant.as_ref().is_some_and(|variant| &variant.question == question)
+            },
+            
+            // Deity fields
+            Self::DomainIn(domains) => {
+                matches!(entity, MythEntity::Deity(deity) if domains.iter().any(|d| deity.domains.contains(d)))
+            },
+            
+            Self::Importance(importance) => {
+                matches!(entity, MythEntity::Deity(deity) if &deity.importance == importance)
+            },
+            
+            Self::ImportanceIn(levels) => {
+                matches!(entity, MythEntity::Deity(deity) if levels.contains(&deity.importance))
+            },
+            
+            Self::Gender(gender) => {
+                matches!(entity, MythEntity::Deity(deity) if &deity.gender == gender)
+            },
+            
+            Self::GenderIn(genders) => {
+                matches!(entity, MythEntity::Deity(deity) if genders.contains(&deity.gender))
+            },
+            
+            Self::Pantheon(pantheon) => {
+                matches!(entity, MythEntity::Deity(deity) if deity.pantheon.as_ref() == Some(pantheon))
+            },
+            
+            // Hero fields
+            Self::HeroOrigin(origin) => {
+                matches!(entity, MythEntity::Hero(hero) if &hero.origin == origin)
+            },
+            
+            Self::HeroOriginIn(origins) => {
+                matches!(entity, MythEntity::Hero(hero) if origins.contains(&hero.origin))
+            },
+            
+            Self::AchievementContains(substring) => {
+                matches!(entity, MythEntity::Hero(hero) if any_contains(&hero.achievements, substring))
+            },
+            
+            // Creature fields
+            Self::CreatureType(creature_type) => {
+                matches!(entity, MythEntity::Creature(creature) if &creature.creature_type == creature_type)
+            },
+            
+            Self::CreatureTypeIn(types) => {
+                matches!(entity, MythEntity::Creature(creature) if types.contains(&creature.creature_type))
+            },
+            
+            Self::HabitatContains(substring) => {
+                matches!(entity, MythEntity::Creature(creature) if any_contains(&creature.habitat, substring))
+            },
+            
+            Self::AbilityContains(substring) => {
+                matches!(entity, MythEntity::Creature(creature) if any_contains(&creature.abilities, substring))
+            },
+            
+            // Artifact fields
+            Self::ArtifactType(artifact_type) => {
+                matches!(entity, MythEntity::Artifact(artifact) if &artifact.artifact_type == artifact_type)
+            },
+            
+            Self::ArtifactTypeIn(types) => {
+                matches!(entity, MythEntity::Artifact(artifact) if types.contains(&artifact.artifact_type))
+            },
+            
+            Self::PowerContains(substring) => {
+                matches!(entity, MythEntity::Artifact(artifact) if any_contains(&artifact.powers, substring))
+            },
+            
+            Self::CreatorContains(substring) => {
+                matches!(entity, MythEntity::Artifact(artifact) if any_contains(&artifact.creator, substring))
+            },
+            
+            Self::OwnerContains(substring) => {
+                matches!(entity, MythEntity::Artifact(artifact) if any_contains(&artifact.owner, substring))
+            },
+            
+            // Location fields
+            Self::LocationType(location_type) => {
+                matches!(entity, MythEntity::Location(location) if &location.location_type == location_type)
+            },
+            
+            Self::LocationTypeIn(types) => {
+                matches!(entity, MythEntity::Location(location) if types.contains(&location.location_type))
+            },
+            
+            Self::CharacteristicContains(substring) => match entity {
+                MythEntity::Location(location) => any_contains(&location.characteristics, substring),
+                MythEntity::MythologicalEra(era) => {
+                    any_contains(era.characteristics.iter().map(|c| c.value()), substring)
+                },
+                _ => false,
+            },
+            
+            // Concept fields
+            Self::ConceptType(concept_type) => {
+                matches!(entity, MythEntity::Concept(concept) if &concept.concept_type == concept_type)
+            },
+            
+            Self::ConceptTypeIn(types) => {
+                matches!(entity, MythEntity::Concept(concept) if types.contains(&concept.concept_type))
+            },
+            
+            Self::ManifestationContains(substring) => {
+                matches!(entity, MythEntity::Concept(concept) if any_contains(&concept.manifestations, substring))
+            },
+            
+            // Pantheon fields
+            Self::PantheonMember(deity_id) => {
+                matches!(entity, MythEntity::Pantheon(pantheon)
+                    if pantheon.primary_deities.contains(deity_id) || pantheon.secondary_deities.contains(deity_id))
+            },
+            
+            Self::Cosmology(cosmology) => {
+                matches!(entity, MythEntity::Pantheon(pantheon) if pantheon.cosmology.as_ref() == Some(cosmology))
+            },
+            
+            Self::FoundingMythContains(substring) => {
+                matches!(entity, MythEntity::Pantheon(pantheon) if any_contains(&pantheon.founding_myth, substring))
+            },
+            
+            // Era fields
+            Self::EraSequence { min, max } => match entity {
+                MythEntity::MythologicalEra(era) => era.sequence_order.is_some_and(|order| {
+                    min.is_none_or(|min| order >= min) && max.is_none_or(|max| order <= max)
+                }),
+                _ => false,
+            },
+            
+            Self::EndEvent(event) => {
+                matches!(entity, MythEntity::MythologicalEra(era) if era.end_event.as_ref() == Some(event))
+            },
+            
+            // Narrative fields
+            Self::Participant { id, role } => {
+                matches!(entity, MythEntity::Narrative(narrative) if narrative.has_participant(id, role.as_ref()))
+            },
+            
+            // Alternative names
+            Self::AlternativeNameContains(substring) => {
+                any_contains(entity.alternative_names(), substring)
+            },
+            
+            Self::AnyNameContains(substring) => {
+                any_contains(std::iter::once(entity.name()).chain(entity.alternative_names()), substring)
             },
             
             // Logical operators
@@ -150,6 +508,89 @@ impl QueryFilter {
         }
     }
     
+    /// Use the ontology's secondary indexes to narrow down which entities can match.
+    ///
+    /// Returns a superset of the IDs of matching entities, or `None` if the filter
+    /// cannot be answered from the indexes and every entity must be checked.
+    /// Callers still need to run [`QueryFilter::matches`] on each candidate.
+    pub fn candidates(&self, ontology: &MythOntology) -> Option<HashSet<MythId>> {
+        let index = ontology.index();
+        match self {
+            Self::EntityType(entity_type) => {
+                Some(index.ids_of_type(entity_type).cloned().unwrap_or_default())
+            },
+            
+            Self::Culture(culture) => {
+                Some(index.ids_in_culture(culture).cloned().unwrap_or_default())
+            },
+            
+            Self::NameContains(substring)
+            | Self::AlternativeNameContains(substring)
+            | Self::AnyNameContains(substring) => {
+                Some(index.ids_with_name_containing(substring))
+            },
+            
+            Self::And(left, right) => {
+                match (left.candidates(ontology), right.candidates(ontology)) {
+                    (Some(l), Some(r)) => Some(l.intersection(&r).cloned().collect()),
+                    (Some(ids), None) | (None, Some(ids)) => Some(ids),
+                    (None, None) => None,
+                }
+            },
+            
+            Self::Or(left, right) => {
+                let mut ids = left.candidates(ontology)?;
+                ids.extend(right.candidates(ontology)?);
+                Some(ids)
+            },
+            
+            Self::HasAttribute(_) | Self::AttributeEquals(_, _) | Self::Motif(_) | Self::Attested { .. }
+            | Self::Tradition(_) | Self::Claim { .. } | Self::Variant(_) | Self::Not(_) => None,
+            
+            // Typed field filters only match entities of the types carrying that field
+            Self::Domain(_) | Self::DomainIn(_) | Self::Importance(_) | Self::ImportanceIn(_)
+            | Self::Gender(_) | Self::GenderIn(_) | Self::Pantheon(_)
+            | Self::HeroOrigin(_) | Self::HeroOriginIn(_) | Self::AchievementContains(_)
+            | Self::CreatureType(_) | Self::CreatureTypeIn(_) | Self::HabitatContains(_) | Self::AbilityContains(_)
+            | Self::ArtifactType(_) | Self::ArtifactTypeIn(_) | Self::PowerContains(_)
+            | Self::CreatorContains(_) | Self::OwnerContains(_)
+            | Self::LocationType(_) | Self::LocationTypeIn(_) | Self::CharacteristicContains(_)
+            | Self::ConceptType(_) | Self::ConceptTypeIn(_) | Self::ManifestationContains(_)
+            | Self::PantheonMember(_) | Self::Cosmology(_) | Self::FoundingMythContains(_)
+            | Self::EraSequence { .. } | Self::EndEvent(_) | Self::Participant { .. } => {
+                let ids = self.applicable_types().iter()
+                    .filter_map(|entity_type| index.ids_of_type(entity_type))
+                    .flatten()
+                    .cloned()
+                    .collect();
+                Some(ids)
+            },
+        }
+    }
+    
+    /// The entity types a typed field filter can match.
+    fn applicable_types(&self) -> &'static [&'static str] {
+        match self {
+            Self::Domain(_) | Self::DomainIn(_) | Self::Importance(_) | Self::ImportanceIn(_)
+            | Self::Gender(_) | Self::GenderIn(_) | Self::Pantheon(_) => &["Deity"],
+            Self::HeroOrigin(_) | Self::HeroOriginIn(_) | Self::AchievementContains(_) => &["Hero"],
+            Self::CreatureType(_) | Self::CreatureTypeIn(_) | Self::HabitatContains(_)
+            | Self::AbilityContains(_) => &["Creature"],
+            Self::ArtifactType(_) | Self::ArtifactTypeIn(_) | Self::PowerContains(_)
+            | Self::CreatorContains(_) | Self::OwnerContains(_) => &["Artifact"],
+            Self::LocationType(_) | Self::LocationTypeIn(_) => &["Location"],
+            Self::CharacteristicContains(_) => &["Location", "MythologicalEra"],
+            Self::ConceptType(_) | Self::ConceptTypeIn(_) | Self::ManifestationContains(_) => &["Concept"],
+            Self::PantheonMember(_) | Self::Cosmology(_) | Self::FoundingMythContains(_) => &["Pantheon"],
+            Self::EraSequence { .. } | Self::EndEvent(_) => &["MythologicalEra"],
+            Self::Participant { .. } => &["Narrative"],
+            Self::EntityType(_) | Self::NameContains(_) | Self::AlternativeNameContains(_) | Self::AnyNameContains(_)
+            | Self::Culture(_) | Self::HasAttribute(_) | Self::AttributeEquals(_, _) | Self::Motif(_)
+            | Self::Attested { .. } | Self::Tradition(_) | Self::Claim { .. } | Self::Variant(_)
+            | Self::And(_, _) | Self::Or(_, _) | Self::Not(_) => &[],
+        }
+    }
+    
     /// Creates a filter that combines this filter with another using AND logic.
     ///
     /// # Arguments
@@ -216,6 +657,7 @@ impl QueryFilter {
     /// // Filter for non-Greek entities
     /// let non_greek = greek.not();
     /// ```
+    #[allow(clippy::should_implement_trait)]
     pub fn not(self) -> QueryFilter {
         QueryFilter::Not(Box::new(self))
     }
@@ -240,3 +682,87 @@ impl From<&str> for QueryFilter {
         QueryFilter::Culture(CultureId::new(culture_name))
     }
 }
+
+/// Check whether any of `values` contains `substring` (case-insensitive)
+fn any_contains<I>(values: I, substring: &str) -> bool
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let needle = substring.to_lowercase();
+    values.into_iter().any(|value| value.as_ref().to_lowercase().contains(&needle))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::examples::{create_creatures_ontology, create_norse_ontology};
+    use crate::query::QueryEngine;
+    
+    fn names(ontology: &MythOntology, filter: QueryFilter) -> Vec<String> {
+        let mut results = QueryEngine::new(ontology).query(&[filter]);
+        results.sort_by_name();
+        results.results.into_iter().map(|r| r.name).collect()
+    }
+    
+    #[test]
+    fn typed_deity_filters() {
+        let norse = create_norse_ontology();
+        
+        assert_eq!(names(&norse, QueryFilter::Importance(DeityImportance::Supreme)), ["Odin"]);
+        assert_eq!(names(&norse, QueryFilter::Domain("Thunder".into())), ["Thor"]);
+        assert_eq!(
+            names(&norse, QueryFilter::DomainIn(vec!["Thunder".into(), "Poetry".into()])),
+            ["Odin", "Thor"]
+        );
+        
+        let aesir = names(&norse, QueryFilter::Pantheon("Aesir".into()));
+        assert!(aesir.contains(&"Odin".to_string()) && aesir.contains(&"Thor".to_string()));
+        assert!(!names(&norse, QueryFilter::Pantheon("Aesir".into()).and(QueryFilter::GenderIn(vec![Gender::Male]).not()))
+            .contains(&"Thor".to_string()));
+    }
+    
+    #[test]
+    fn typed_filters_only_match_their_entity_type() {
+        let norse = create_norse_ontology();
+        
+        let weapons = names(&norse, QueryFilter::ArtifactType(ArtifactType::Weapon));
+        assert!(weapons.contains(&"Mjölnir".to_string()));
+        assert!(norse.entities().filter(|e| QueryFilter::ArtifactType(ArtifactType::Weapon).matches(e))
+            .all(|e| e.entity_type() == "Artifact"));
+        
+        assert!(names(&norse, QueryFilter::HeroOrigin(HeroOrigin::Unknown).and(QueryFilter::EntityType("Deity".to_string())))
+            .is_empty());
+    }
+    
+    #[test]
+    fn substring_and_alternative_name_filters() {
+        let creatures = create_creatures_ontology();
+        assert_eq!(names(&creatures, QueryFilter::HabitatContains("labyr".to_string())), ["Minotaur"]);
+        
+        let norse = create_norse_ontology();
+        assert_eq!(names(&norse, QueryFilter::AlternativeNameContains("wotan".to_string())), ["Odin"]);
+        assert_eq!(names(&norse, QueryFilter::AnyNameContains("DONAR".to_string())), ["Thor"]);
+        assert!(names(&norse, QueryFilter::AlternativeNameContains("thor".to_string())).is_empty());
+    }
+    
+    #[test]
+    fn era_sequence_range() {
+        use crate::cultural::MythologicalEra;
+        
+        let mut ontology = MythOntology::new();
+        for (name, order) in [("First", Some(1)), ("Second", Some(2)), ("Third", Some(3)), ("Undated", None)] {
+            let mut era = MythologicalEra::new(name, "", "Test");
+            if let Some(order) = order {
+                era.set_sequence_order(order);
+            }
+            ontology.add_entity(MythEntity::MythologicalEra(era));
+        }
+        
+        let range = |min, max| QueryFilter::EraSequence { min, max };
+        assert_eq!(names(&ontology, range(Some(2), None)), ["Second", "Third"]);
+        assert_eq!(names(&ontology, range(None, Some(2))), ["First", "Second"]);
+        assert_eq!(names(&ontology, range(Some(2), Some(2))), ["Second"]);
+        assert_eq!(names(&ontology, range(None, None)).len(), 3);
+    }
+}
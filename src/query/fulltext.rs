@@ -0,0 +1,371 @@
+//! # Full-Text Search
+//!
+//! [`TextSearch`] ranks entities by how well their free text (descriptions,
+//! achievements, powers, abilities, characteristics, founding myths, ...) matches
+//! a query, using the [`TextIndex`](crate::core::TextIndex) the ontology keeps up
+//! to date. Queries are words and `"quoted phrases"`; words are stemmed, so
+//! "slaying" matches "slay" and "slew" does not. An entity matches if it contains
+//! any word or phrase, and is scored with BM25F: each field's term frequency is
+//! normalized by the field's length and weighted by its boost (names count more
+//! than descriptions) before the usual BM25 saturation and inverse document
+//! frequency are applied.
+//!
+//! Each [`TextHit`] carries snippets of the best-matching field values with the
+//! matched words marked.
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::query::TextSearch;
+//!
+//! let greek = create_greek_ontology();
+//! let hits = TextSearch::new(&greek).include_relationships(false).search("\"twelve labors\"");
+//!
+//! assert_eq!(hits[0].name, "Heracles");
+//! assert_eq!(hits[0].snippets[0].highlighted("[", "]"), "[Twelve] [Labors] of Heracles");
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashMap};
+use crate::core::{tokenize, MythEntity, MythId, MythOntology, TextField, TextIndex};
+
+/// BM25 term frequency saturation
+const K1: f32 = 1.2;
+/// BM25 field length normalization
+const B: f32 = 0.75;
+
+/// An entity matching a full-text query
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextHit {
+    /// The matching entity
+    pub id: MythId,
+    /// The entity's name
+    pub name: String,
+    /// The entity type
+    pub entity_type: String,
+    /// BM25F relevance; only meaningful relative to other hits for the same query
+    pub score: f32,
+    /// Excerpts of the best-matching field values, best first
+    pub snippets: Vec<Snippet>,
+}
+
+/// An excerpt of a field value with the matched words marked
+#[derive(Debug, Clone, PartialEq)]
+pub struct Snippet {
+    /// The field the excerpt comes from
+    pub field: TextField,
+    /// The excerpt, with `…` where the value was cut
+    pub text: String,
+    /// Byte ranges of `text` holding matched words, in order
+    pub highlights: Vec<(usize, usize)>,
+}
+
+impl Snippet {
+    /// The excerpt with each matched word wrapped in `open` and `close`
+    pub fn highlighted(&self, open: &str, close: &str) -> String {
+        let mut result = String::with_capacity(self.text.len());
+        let mut last = 0;
+        for &(start, end) in &self.highlights {
+            result.push_str(&self.text[last..start]);
+            result.push_str(open);
+            result.push_str(&self.text[start..end]);
+            result.push_str(close);
+            last = end;
+        }
+        result.push_str(&self.text[last..]);
+        result
+    }
+}
+
+/// A parsed full-text query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextQuery {
+    clauses: Vec<Clause>,
+}
+
+/// A word, or a phrase as terms with their positions relative to its first word
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Clause {
+    Term(String),
+    Phrase(Vec<(String, u32)>),
+}
+
+impl TextQuery {
+    /// Parse a query of words and double-quoted phrases
+    ///
+    /// Stop words are dropped, and an unterminated quote runs to the end of the
+    /// query.
+    pub fn parse(query: &str) -> Self {
+        let mut clauses = Vec::new();
+        for (i, part) in query.split('"').enumerate() {
+            let tokens = tokenize(part);
+            if i % 2 == 1 && tokens.len() > 1 {
+                let first = tokens[0].position;
+                clauses.push(Clause::Phrase(tokens.into_iter().map(|t| (t.term, t.position - first)).collect()));
+            } else {
+                clauses.extend(tokens.into_iter().map(|t| Clause::Term(t.term)));
+            }
+        }
+        let mut seen = Vec::new();
+        clauses.retain(|clause| {
+            let new = !seen.contains(clause);
+            if new {
+                seen.push(clause.clone());
+            }
+            new
+        });
+        Self { clauses }
+    }
+    
+    /// Check whether the query has nothing to search for
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+}
+
+/// Where a clause matched an entity: field value index and word position
+type Matches = HashMap<MythId, Vec<(TextField, u32, u32)>>;
+
+/// Ranked full-text search over an ontology
+pub struct TextSearch<'a> {
+    ontology: &'a MythOntology,
+    boosts: HashMap<TextField, f32>,
+    limit: Option<usize>,
+    include_relationships: bool,
+    snippet_words: usize,
+    max_snippets: usize,
+}
+
+impl<'a> TextSearch<'a> {
+    /// Create a search with the default field boosts, returning every hit with up
+    /// to two snippets of about twenty words
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        Self {
+            ontology,
+            boosts: HashMap::new(),
+            limit: None,
+            include_relationships: true,
+            snippet_words: 20,
+            max_snippets: 2,
+        }
+    }
+    
+    /// Weight matches in a field by `boost` instead of its [default](TextField::default_boost)
+    pub fn boost(mut self, field: TextField, boost: f32) -> Self {
+        self.boosts.insert(field, boost);
+        self
+    }
+    
+    /// Return at most this many hits
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    
+    /// Set whether relationship entities are returned
+    pub fn include_relationships(mut self, include: bool) -> Self {
+        self.include_relationships = include;
+        self
+    }
+    
+    /// Set the number of words a snippet shows around its matches
+    pub fn snippet_words(mut self, words: usize) -> Self {
+        self.snippet_words = words.max(1);
+        self
+    }
+    
+    /// Set the number of snippets returned per hit
+    pub fn max_snippets(mut self, snippets: usize) -> Self {
+        self.max_snippets = snippets;
+        self
+    }
+    
+    /// Parse and run a query, returning hits best first
+    pub fn search(&self, query: &str) -> Vec<TextHit> {
+        self.run(&TextQuery::parse(query))
+    }
+    
+    /// Run a parsed query, returning hits best first
+    pub fn run(&self, query: &TextQuery) -> Vec<TextHit> {
+        let index = self.ontology.index().text();
+        let entities = index.entity_count() as f32;
+        let mut scores: HashMap<MythId, f32> = HashMap::new();
+        let mut matched: HashMap<MythId, BTreeSet<(u32, u32)>> = HashMap::new();
+        
+        for clause in &query.clauses {
+            let matches = clause_matches(index, clause);
+            let frequency = matches.len() as f32;
+            let idf = (1.0 + (entities - frequency + 0.5) / (frequency + 0.5)).ln();
+            // Phrase matches record every word of each occurrence
+            let width = match clause {
+                Clause::Term(_) => 1.0,
+                Clause::Phrase(terms) => terms.len() as f32,
+            };
+            
+            for (id, occurrences) in matches {
+                let mut per_field: HashMap<TextField, u32> = HashMap::new();
+                for &(field, value, position) in &occurrences {
+                    *per_field.entry(field).or_default() += 1;
+                    matched.entry(id.clone()).or_default().insert((value, position));
+                }
+                let weighted: f32 = per_field.into_iter()
+                    .map(|(field, count)| {
+                        let average = index.average_field_length(field).max(1.0);
+                        let length = index.field_length(&id, field) as f32;
+                        self.field_boost(field) * count as f32 / width / (1.0 - B + B * length / average)
+                    })
+                    .sum();
+                *scores.entry(id).or_default() += idf * weighted * (K1 + 1.0) / (K1 + weighted);
+            }
+        }
+        
+        let mut hits: Vec<TextHit> = scores.into_iter()
+            .filter_map(|(id, score)| {
+                let entity = self.ontology.get_entity(&id)?;
+                if entity.is_relationship() && !self.include_relationships {
+                    return None;
+                }
+                Some(TextHit {
+                    name: entity.name().to_string(),
+                    entity_type: entity.entity_type().to_string(),
+                    score,
+                    snippets: self.snippets(entity, &matched[&id]),
+                    id,
+                })
+            })
+            .collect();
+        hits.sort_by(|a, b| {
+            b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+        if let Some(limit) = self.limit {
+            hits.truncate(limit);
+        }
+        hits
+    }
+    
+    fn field_boost(&self, field: TextField) -> f32 {
+        self.boosts.get(&field).copied().unwrap_or_else(|| field.default_boost())
+    }
+    
+    /// Excerpts of the field values with the most matched words, weighted by boost
+    fn snippets(&self, entity: &MythEntity, matched: &BTreeSet<(u32, u32)>) -> Vec<Snippet> {
+        let fields = entity.text_fields();
+        let mut by_value: Vec<(u32, Vec<u32>)> = Vec::new();
+        for &(value, position) in matched {
+            match by_value.last_mut() {
+                Some((last, positions)) if *last == value => positions.push(position),
+                _ => by_value.push((value, vec![position])),
+            }
+        }
+        by_value.sort_by(|(a, a_positions), (b, b_positions)| {
+            let weight = |value: u32, count: usize| self.field_boost(fields[value as usize].0) * count as f32;
+            weight(*b, b_positions.len()).partial_cmp(&weight(*a, a_positions.len()))
+                .unwrap_or(Ordering::Equal)
+                .then_with(|| a.cmp(b))
+        });
+        
+        by_value.into_iter()
+            .take(self.max_snippets)
+            .filter_map(|(value, positions)| {
+                let (field, text) = *fields.get(value as usize)?;
+                Some(snippet(field, text, &positions, self.snippet_words))
+            })
+            .collect()
+    }
+}
+
+/// Find where a clause occurs, by entity
+fn clause_matches(index: &TextIndex, clause: &Clause) -> Matches {
+    match clause {
+        Clause::Term(term) => index.postings(term)
+            .map(|postings| postings.iter()
+                .map(|(id, occurrences)| {
+                    (id.clone(), occurrences.iter().map(|o| (o.field, o.value, o.position)).collect())
+                })
+                .collect())
+            .unwrap_or_default(),
+        Clause::Phrase(terms) => {
+            let Some(postings) = terms.iter().map(|(term, _)| index.postings(term)).collect::<Option<Vec<_>>>() else {
+                return Matches::new();
+            };
+            let mut matches = Matches::new();
+            for (id, starts) in postings[0] {
+                let Some(rest) = postings[1..].iter().map(|p| p.get(id)).collect::<Option<Vec<_>>>() else {
+                    continue;
+                };
+                for start in starts {
+                    let found = terms[1..].iter().zip(&rest).all(|((_, offset), occurrences)| {
+                        occurrences.iter().any(|o| {
+                            o.field == start.field && o.value == start.value && o.position == start.position + offset
+                        })
+                    });
+                    if found {
+                        matches.entry(id.clone()).or_default().extend(
+                            terms.iter().map(|(_, offset)| (start.field, start.value, start.position + offset))
+                        );
+                    }
+                }
+            }
+            matches
+        },
+    }
+}
+
+/// Cut a window of about `words` words from `text` around the matched positions
+fn snippet(field: TextField, text: &str, positions: &[u32], words: usize) -> Snippet {
+    let tokens = tokenize(text);
+    let first_match = tokens.iter().position(|t| positions.contains(&t.position)).unwrap_or(0);
+    let first = first_match.saturating_sub(words / 4).min(tokens.len().saturating_sub(words));
+    let last = (first + words).min(tokens.len());
+    
+    let start = if first == 0 { 0 } else { tokens[first].start };
+    let end = if last == tokens.len() { text.len() } else { tokens[last - 1].end };
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < text.len() { "…" } else { "" };
+    
+    let highlights = tokens[first..last].iter()
+        .filter(|t| positions.contains(&t.position))
+        .map(|t| (t.start - start + prefix.len(), t.end - start + prefix.len()))
+        .collect();
+    Snippet {
+        field,
+        text: format!("{}{}{}", prefix, &text[start..end], suffix),
+        highlights,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::{Artifact, Hero};
+    
+    #[test]
+    fn ranks_by_field_and_matches_phrases() {
+        let mut ontology = MythOntology::new();
+        let mut perseus = Hero::new("Perseus", "Slayer of the Gorgon Medusa", "Greek");
+        perseus.add_achievement("Beheaded Medusa with a borrowed sword");
+        let mut aegis = Artifact::new("Aegis", "A shield bearing the head of Medusa", "Greek");
+        aegis.add_power("Terrifies enemies");
+        ontology.add_entity(MythEntity::Hero(perseus));
+        ontology.add_entity(MythEntity::Artifact(aegis));
+        
+        let names = |hits: Vec<TextHit>| hits.into_iter().map(|h| h.name).collect::<Vec<_>>();
+        let search = TextSearch::new(&ontology);
+        assert_eq!(names(search.search("medusa")), ["Perseus", "Aegis"]);
+        assert_eq!(names(search.search("\"head of medusa\"")), ["Aegis"]);
+        assert_eq!(names(search.search("\"medusa head\"")), Vec::<String>::new());
+        assert_eq!(names(search.search("terrifying")), ["Aegis"]);
+        
+        let hit = &search.search("medusa slayer")[0];
+        assert_eq!(hit.snippets[0].highlighted("<", ">"), "<Slayer> of the Gorgon <Medusa>");
+        assert_eq!(hit.snippets[1].field, TextField::Achievement);
+    }
+    
+    #[test]
+    fn snippets_cut_long_values() {
+        let text = "one two three four five six seven eight nine ten eleven twelve";
+        let snippet = snippet(TextField::Description, text, &[6], 4);
+        assert_eq!(snippet.highlighted("[", "]"), "…six [seven] eight nine…");
+    }
+}
@@ -1,9 +1,17 @@
 //! Query functionality for the mythological ontology
 
 mod query_engine;
+mod analogues;
 mod filters;
+mod fulltext;
 mod results;
+mod search;
+pub mod syntax;
 
 pub use query_engine::*;
+pub use analogues::*;
 pub use filters::*;
+pub use fulltext::*;
 pub use results::*;
+pub use search::*;
+pub use syntax::{ParseError, Span};
@@ -1,113 +1,240 @@
+use std::borrow::Cow;
 use std::collections::HashSet;
-use crate::core::{MythId, MythOntology};
-use crate::query::{QueryFilter, QueryResult, QueryResultSet};
+use crate::core::{HistoricalDate, MythId, MythEntity, MythOntology, ResolutionPolicy};
+use crate::entities::NarrativeRole;
+use crate::motifs::MotifCode;
+use crate::query::{Analogue, AnalogueFinder, NameMatch, NameResolver, ParseError, QueryFilter, QueryResult, QueryResultSet, TextHit, TextSearch};
+use crate::relationships::Syncretism;
 
 /// Engine for querying the mythological ontology
 pub struct QueryEngine<'a> {
-    ontology: &'a MythOntology,
+    ontology: Cow<'a, MythOntology>,
 }
 
 impl<'a> QueryEngine<'a> {
     /// Create a new query engine
     pub fn new(ontology: &'a MythOntology) -> Self {
-        Self { ontology }
+        Self { ontology: Cow::Borrowed(ontology) }
+    }
+    
+    /// Query the view of the ontology in which only the claims preferred by a
+    /// policy remain; see [`MythOntology::resolved`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::core::ResolutionPolicy;
+    /// use mythologic::examples::create_greek_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let ontology = create_greek_ontology();
+    /// let query = r#"variant:"parentage of Aphrodite""#;
+    /// assert_eq!(QueryEngine::new(&ontology).query_str(query).unwrap().count(), 3);
+    /// let earliest = QueryEngine::new(&ontology).with_resolution(ResolutionPolicy::PreferEarliest);
+    /// assert_eq!(earliest.query_str(query).unwrap().count(), 2);
+    /// ```
+    pub fn with_resolution(mut self, policy: ResolutionPolicy) -> Self {
+        if policy != ResolutionPolicy::ShowAll {
+            self.ontology = Cow::Owned(self.ontology.resolved(policy));
+        }
+        self
     }
     
     /// Query entities that match the given filters
+    ///
+    /// Filters that can be answered from the ontology's secondary indexes are used
+    /// to narrow the candidate set; only the remaining candidates are checked.
     pub fn query(&self, filters: &[QueryFilter]) -> QueryResultSet {
-        let mut result_ids = HashSet::new();
-        let mut results = Vec::new();
-        
-        // Start with all entities
-        for entity in self.ontology.all_entities() {
-            let mut matches = true;
-            
-            // Check if entity matches all filters
-            for filter in filters {
-                if !filter.matches(entity) {
-                    matches = false;
-                    break;
-                }
-            }
-            
-            if matches {
-                let id = entity.id().clone();
-                if !result_ids.contains(&id) {
-                    result_ids.insert(id.clone());
-                    results.push(QueryResult {
-                        id: id,
-                        name: entity.name().to_string(),
-                        entity_type: entity.entity_type().to_string(),
-                    });
-                }
+        let mut candidates: Option<HashSet<MythId>> = None;
+        for filter in filters {
+            if let Some(ids) = filter.candidates(&self.ontology) {
+                candidates = Some(match candidates {
+                    Some(existing) => existing.intersection(&ids).cloned().collect(),
+                    None => ids,
+                });
             }
         }
         
-        QueryResultSet { results }
+        let matches = |entity: &&MythEntity| filters.iter().all(|filter| filter.matches(entity));
+        
+        match candidates {
+            Some(ids) => Self::collect_results(
+                ids.iter().filter_map(|id| self.ontology.get_entity(id)).filter(matches)
+            ),
+            None => Self::collect_results(self.ontology.entities().filter(matches)),
+        }
+    }
+    
+    /// Query entities using the text query syntax
+    ///
+    /// See [`crate::query::syntax`] for the grammar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::examples::create_greek_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let ontology = create_greek_ontology();
+    /// let engine = QueryEngine::new(&ontology);
+    /// let results = engine.query_str(r#"type:Deity AND name~"zeus""#).unwrap();
+    /// assert_eq!(results.first().map(|r| r.name.as_str()), Some("Zeus"));
+    /// ```
+    pub fn query_str(&self, query: &str) -> Result<QueryResultSet, ParseError> {
+        let filter = QueryFilter::parse(query)?;
+        Ok(self.query(&[filter]))
     }
     
     /// Find entities related to the given entity
+    ///
+    /// Entries in the entity's `relationships` list that refer to relationship
+    /// entities are followed to the entity at the other end; any other entries
+    /// are returned as-is.
     pub fn find_related(&self, entity_id: &MythId) -> QueryResultSet {
-        let mut result_ids = HashSet::new();
-        let mut results = Vec::new();
+        let Some(entity) = self.ontology.get_entity(entity_id) else {
+            return QueryResultSet::empty();
+        };
         
-        if let Some(entity) = self.ontology.get_entity(entity_id) {
-            for related_id in entity.relationships() {
-                if let Some(related_entity) = self.ontology.get_entity(&related_id) {
-                    if !result_ids.contains(&related_id) {
-                        result_ids.insert(related_id.clone());
-                        results.push(QueryResult {
-                            id: related_id,
-                            name: related_entity.name().to_string(),
-                            entity_type: related_entity.entity_type().to_string(),
-                        });
-                    }
-                }
-            }
-        }
+        let related = entity.relationships().into_iter()
+            .filter_map(|related_id| self.ontology.get_entity(&related_id))
+            .filter_map(|related| match related.relationship() {
+                Some(relationship) if &relationship.source_id == entity_id => {
+                    self.ontology.get_entity(&relationship.target_id)
+                },
+                Some(relationship) => self.ontology.get_entity(&relationship.source_id),
+                None => Some(related),
+            });
         
-        QueryResultSet { results }
+        Self::collect_results(related)
+    }
+    
+    /// Find the entities identified with the given entity in other cultures
+    ///
+    /// Syncretism relationships are followed transitively in both directions, so
+    /// the result includes equivalents of equivalents; see
+    /// [`Syncretism`](crate::relationships::Syncretism) for finer control.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::examples::create_greek_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let ontology = create_greek_ontology();
+    /// let engine = QueryEngine::new(&ontology);
+    /// let zeus = ontology.entities_named("Zeus").next().unwrap().id();
+    /// assert_eq!(engine.find_equivalents(zeus).first().map(|r| r.name.as_str()), Some("Jupiter"));
+    /// ```
+    pub fn find_equivalents(&self, entity_id: &MythId) -> QueryResultSet {
+        let equivalents = Syncretism::new(&self.ontology).equivalents(entity_id);
+        Self::collect_results(equivalents.iter().filter_map(|e| self.ontology.get_entity(&e.id)))
     }
     
     /// Find entities by name (partial match)
     pub fn find_by_name(&self, name: &str) -> QueryResultSet {
-        let name_lower = name.to_lowercase();
-        let mut result_ids = HashSet::new();
-        let mut results = Vec::new();
-        
-        for entity in self.ontology.all_entities() {
-            if entity.name().to_lowercase().contains(&name_lower) {
-                let id = entity.id().clone();
-                if !result_ids.contains(&id) {
-                    result_ids.insert(id.clone());
-                    results.push(QueryResult {
-                        id: id,
-                        name: entity.name().to_string(),
-                        entity_type: entity.entity_type().to_string(),
-                    });
-                }
-            }
-        }
-        
-        QueryResultSet { results }
+        self.query(&[QueryFilter::NameContains(name.to_string())])
+    }
+    
+    /// Search names and alternative names, ignoring accents and tolerating
+    /// misspellings, best match first
+    ///
+    /// ```
+    /// use mythologic::examples::create_locations_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let locations = create_locations_ontology();
+    /// let engine = QueryEngine::new(&locations);
+    /// assert_eq!(engine.search_names("Tir na Nog")[0].name, "Tír na nÓg");
+    /// ```
+    pub fn search_names(&self, query: &str) -> Vec<NameMatch> {
+        NameResolver::new(&self.ontology).resolve(query)
+    }
+    
+    /// Rank entities by how well their descriptions and other free text match a
+    /// query of words and `"quoted phrases"`, best first
+    ///
+    /// ```
+    /// use mythologic::examples::create_norse_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let norse = create_norse_ontology();
+    /// let engine = QueryEngine::new(&norse);
+    /// let hits = engine.search_text("wisdom");
+    /// assert!(hits.iter().any(|hit| hit.name == "Odin"));
+    /// ```
+    pub fn search_text(&self, query: &str) -> Vec<TextHit> {
+        TextSearch::new(&self.ontology).search(query)
+    }
+    
+    /// Find entities in other cultures that resemble the given entity, best first,
+    /// with a per-feature explanation of each match
+    pub fn find_analogues(&self, entity_id: &MythId) -> Vec<Analogue> {
+        AnalogueFinder::new(&self.ontology).find(entity_id)
+    }
+    
+    /// Find entities and relationships tagged with a motif or tale type, or any
+    /// code under it
+    pub fn find_by_motif(&self, code: &MotifCode) -> QueryResultSet {
+        self.query(&[QueryFilter::Motif(code.clone())])
+    }
+    
+    /// Find the narratives an entity takes part in, in the given role if one is specified
+    ///
+    /// ```
+    /// use mythologic::entities::NarrativeRole;
+    /// use mythologic::examples::create_norse_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let norse = create_norse_ontology();
+    /// let engine = QueryEngine::new(&norse);
+    /// let loki = norse.entities_named("Loki").next().unwrap().id().clone();
+    /// let mut myths = engine.find_narratives(&loki, Some(NarrativeRole::Antagonist));
+    /// myths.sort_by_name();
+    /// let names: Vec<&str> = myths.results.iter().map(|r| r.name.as_str()).collect();
+    /// assert_eq!(names, ["The Binding of Fenrir", "The Doom of the Gods", "The Theft of Brisingamen"]);
+    /// ```
+    pub fn find_narratives(&self, entity_id: &MythId, role: Option<NarrativeRole>) -> QueryResultSet {
+        self.query(&[QueryFilter::Participant { id: entity_id.clone(), role }])
+    }
+    
+    /// Find entities attested in the historical record during a date or period
+    ///
+    /// With `certain`, only entities whose attestation overlaps the years `during`
+    /// allows however the uncertainty in the attestation is resolved are returned;
+    /// otherwise any possible overlap is enough.
+    ///
+    /// ```
+    /// use mythologic::examples::create_egyptian_ontology;
+    /// use mythologic::query::QueryEngine;
+    ///
+    /// let egyptian = create_egyptian_ontology();
+    /// let engine = QueryEngine::new(&egyptian);
+    /// let mut early = engine.find_attested(&"before 1500 BCE".parse().unwrap(), true).filter_by_type("Deity");
+    /// early.sort_by_name();
+    /// let names: Vec<&str> = early.results.iter().map(|r| r.name.as_str()).collect();
+    /// assert_eq!(names, ["Horus", "Isis", "Osiris", "Ra", "Set"]);
+    /// ```
+    pub fn find_attested(&self, during: &HistoricalDate, certain: bool) -> QueryResultSet {
+        self.query(&[QueryFilter::Attested { during: during.clone(), certain }])
     }
     
     /// Find entities by type
     pub fn find_by_type(&self, entity_type: &str) -> QueryResultSet {
+        Self::collect_results(self.ontology.entities_of_type(entity_type))
+    }
+    
+    fn collect_results<'e>(entities: impl Iterator<Item = &'e MythEntity>) -> QueryResultSet {
         let mut result_ids = HashSet::new();
         let mut results = Vec::new();
         
-        for entity in self.ontology.all_entities() {
-            if entity.entity_type() == entity_type {
-                let id = entity.id().clone();
-                if !result_ids.contains(&id) {
-                    result_ids.insert(id.clone());
-                    results.push(QueryResult {
-                        id: id,
-                        name: entity.name().to_string(),
-                        entity_type: entity.entity_type().to_string(),
-                    });
-                }
+        for entity in entities {
+            let id = entity.id().clone();
+            if result_ids.insert(id.clone()) {
+                results.push(QueryResult {
+                    id,
+                    name: entity.name().to_string(),
+                    entity_type: entity.entity_type().to_string(),
+                });
             }
         }
         
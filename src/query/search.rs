@@ -0,0 +1,380 @@
+//! # Name Search
+//!
+//! [`NameResolver`] finds entities by name the way people type them: without
+//! accents, in another transliteration, or under an alternative name, epithet or
+//! label in any language. Names are folded before comparison (lowercased,
+//! diacritics, Greek accents and ligatures replaced by their base letters,
+//! punctuation treated as spaces), so "Danae" finds "Danaë" and "amun ra" finds
+//! "Amun-Ra". Every name variant of an entity is tried, and candidates are ranked:
+//!
+//! 1. exact matches of a folded name,
+//! 2. names starting with the query,
+//! 3. names containing the query,
+//! 4. names close to the query by edit distance or trigram similarity, so
+//!    "Herakles" finds "Heracles".
+//!
+//! Each [`NameMatch`] records which name variant matched and where, for
+//! highlighting.
+//!
+//! ```
+//! use mythologic::examples::{create_greek_ontology, create_heroes_ontology};
+//! use mythologic::query::{MatchKind, NameResolver};
+//!
+//! let heroes = create_heroes_ontology();
+//! let matches = NameResolver::new(&heroes).resolve("Herakles");
+//! assert_eq!(matches[0].name, "Heracles");
+//! assert_eq!(matches[0].kind, MatchKind::Fuzzy);
+//!
+//! // The Greek ontology has the Greek name and its transliteration as labels
+//! let greek = create_greek_ontology();
+//! let matches = NameResolver::new(&greek).resolve("Ηρακλης");
+//! assert_eq!(matches[0].name, "Heracles");
+//! assert_eq!(matches[0].matched, "Ἡρακλῆς");
+//! ```
+
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use crate::core::{fold_char, MythEntity, MythId, MythOntology};
+
+/// How a name variant matched the query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MatchKind {
+    /// The folded name equals the folded query
+    Exact,
+    /// The folded name starts with the folded query
+    Prefix,
+    /// The folded name contains the folded query
+    Substring,
+    /// The name is similar to the query
+    Fuzzy,
+}
+
+/// An entity found by name, with the variant that matched
+#[derive(Debug, Clone, PartialEq)]
+pub struct NameMatch {
+    /// The matching entity
+    pub id: MythId,
+    /// The entity's primary name
+    pub name: String,
+    /// The entity type
+    pub entity_type: String,
+    /// The name variant that matched, which may be an alternative name
+    pub matched: String,
+    /// Whether `matched` is an alternative name rather than the primary name
+    pub alternative: bool,
+    /// How the variant matched
+    pub kind: MatchKind,
+    /// Similarity between 0.0 and 1.0; higher is better
+    pub score: f32,
+    /// Byte range of `matched` that corresponds to the query; the whole variant
+    /// for fuzzy matches
+    pub highlight: (usize, usize),
+}
+
+impl NameMatch {
+    /// The matched variant with the matching part wrapped in `open` and `close`
+    ///
+    /// ```
+    /// use mythologic::examples::create_locations_ontology;
+    /// use mythologic::query::NameResolver;
+    ///
+    /// let locations = create_locations_ontology();
+    /// let best = NameResolver::new(&locations).best("na nog").unwrap();
+    /// assert_eq!(best.highlighted("[", "]"), "Tír [na nÓg]");
+    /// ```
+    pub fn highlighted(&self, open: &str, close: &str) -> String {
+        let (start, end) = self.highlight;
+        format!("{}{}{}{}{}", &self.matched[..start], open, &self.matched[start..end], close, &self.matched[end..])
+    }
+}
+
+/// Resolves free-text names to entities, tolerating accents and misspellings
+pub struct NameResolver<'a> {
+    ontology: &'a MythOntology,
+    min_score: f32,
+    limit: Option<usize>,
+    include_relationships: bool,
+}
+
+impl<'a> NameResolver<'a> {
+    /// Create a resolver with the default threshold of 0.5, returning every match
+    /// among non-relationship entities
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        Self {
+            ontology,
+            min_score: 0.5,
+            limit: None,
+            include_relationships: false,
+        }
+    }
+    
+    /// Set the lowest score a match may have
+    pub fn min_score(mut self, min_score: f32) -> Self {
+        self.min_score = min_score;
+        self
+    }
+    
+    /// Return at most this many matches
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    
+    /// Set whether relationship entities are searched too
+    pub fn include_relationships(mut self, include: bool) -> Self {
+        self.include_relationships = include;
+        self
+    }
+    
+    /// Find entities whose names match the query, best first
+    ///
+    /// Each entity appears once, with its best-matching name variant.
+    pub fn resolve(&self, query: &str) -> Vec<NameMatch> {
+        let query = fold_name(query);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        
+        let mut matches: Vec<NameMatch> = self.ontology.entities()
+            .filter(|entity| self.include_relationships || !entity.is_relationship())
+            .filter_map(|entity| self.best_variant(entity, &query))
+            .filter(|m| m.score >= self.min_score)
+            .collect();
+        matches.sort_by(|a, b| {
+            b.score.partial_cmp(&a.score).unwrap_or(Ordering::Equal)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.to_string().cmp(&b.id.to_string()))
+        });
+        if let Some(limit) = self.limit {
+            matches.truncate(limit);
+        }
+        matches
+    }
+    
+    /// The best match for the query, if any
+    pub fn best(&self, query: &str) -> Option<NameMatch> {
+        self.resolve(query).into_iter().next()
+    }
+    
+    fn best_variant(&self, entity: &MythEntity, query: &str) -> Option<NameMatch> {
+        let mut seen = HashSet::new();
+        std::iter::once((entity.name(), false))
+            .chain(entity.alternative_names().into_iter().map(|name| (name, true)))
+            .filter(|(name, _)| seen.insert(*name))
+            .filter_map(|(variant, alternative)| {
+                let (kind, mut score, highlight) = match_variant(variant, query)?;
+                if alternative {
+                    // Prefer the primary name when a variant matches equally well
+                    score *= 0.98;
+                }
+                Some(NameMatch {
+                    id: entity.id().clone(),
+                    name: entity.name().to_string(),
+                    entity_type: entity.entity_type().to_string(),
+                    matched: variant.to_string(),
+                    alternative,
+                    kind,
+                    score,
+                    highlight,
+                })
+            })
+            .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(Ordering::Equal))
+    }
+}
+
+/// Fold a name for comparison: lowercase, strip diacritics, expand ligatures, and
+/// collapse punctuation and whitespace to single spaces
+///
+/// ```
+/// use mythologic::query::fold_name;
+///
+/// assert_eq!(fold_name("Danaë"), "danae");
+/// assert_eq!(fold_name("Lugh Lámhfhada"), "lugh lamhfhada");
+/// assert_eq!(fold_name("Amun-Ra"), "amun ra");
+/// assert_eq!(fold_name("Ægir"), "aegir");
+/// assert_eq!(fold_name("Ἡρακλῆς"), fold_name("Ηρακλης"));
+/// ```
+pub fn fold_name(name: &str) -> String {
+    Folded::new(name).text
+}
+
+/// A folded name with, for each folded byte, the byte range of the original
+/// character it came from
+struct Folded {
+    text: String,
+    origins: Vec<(usize, usize)>,
+}
+
+impl Folded {
+    fn new(name: &str) -> Self {
+        let mut text = String::with_capacity(name.len());
+        let mut origins = Vec::with_capacity(name.len());
+        for (offset, c) in name.char_indices() {
+            let origin = (offset, offset + c.len_utf8());
+            let mut push = |s: &str, text: &mut String| {
+                text.push_str(s);
+                origins.extend(std::iter::repeat_n(origin, s.len()));
+            };
+            if !c.is_alphanumeric() {
+                if !text.is_empty() && !text.ends_with(' ') {
+                    push(" ", &mut text);
+                }
+                continue;
+            }
+            match fold_char(c) {
+                Some(folded) => push(folded, &mut text),
+                None => {
+                    for lower in c.to_lowercase() {
+                        push(lower.encode_utf8(&mut [0; 4]), &mut text);
+                    }
+                },
+            }
+        }
+        if text.ends_with(' ') {
+            text.pop();
+            origins.pop();
+        }
+        Self { text, origins }
+    }
+    
+    /// The original byte range covering folded bytes `start..end`
+    fn original_range(&self, start: usize, end: usize) -> (usize, usize) {
+        (self.origins[start].0, self.origins[end - 1].1)
+    }
+}
+
+/// Match one name variant against a folded query, returning the kind of match, its
+/// score, and the highlighted range of the variant
+fn match_variant(variant: &str, query: &str) -> Option<(MatchKind, f32, (usize, usize))> {
+    let folded = Folded::new(variant);
+    let name = folded.text.as_str();
+    if name.is_empty() {
+        return None;
+    }
+    let coverage = query.len() as f32 / name.len() as f32;
+    
+    if name == query {
+        return Some((MatchKind::Exact, 1.0, (0, variant.len())));
+    }
+    if name.starts_with(query) {
+        return Some((MatchKind::Prefix, 0.85 + 0.1 * coverage, folded.original_range(0, query.len())));
+    }
+    if let Some(start) = name.find(query) {
+        let at_word = name[..start].ends_with(' ');
+        let score = if at_word { 0.75 } else { 0.65 } + 0.1 * coverage;
+        return Some((MatchKind::Substring, score, folded.original_range(start, start + query.len())));
+    }
+    
+    // Compare against the whole name and each of its words, so "herakles" still
+    // matches "Heracles of Thebes"
+    let similarity = std::iter::once(name)
+        .chain(name.split(' ').filter(|_| name.contains(' ')))
+        .map(|candidate| edit_similarity(candidate, query).max(trigram_similarity(candidate, query)))
+        .fold(0.0, f32::max);
+    Some((MatchKind::Fuzzy, 0.75 * similarity, (0, variant.len())))
+}
+
+/// One minus the edit distance (with transpositions) relative to the longer string
+fn edit_similarity(a: &str, b: &str) -> f32 {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let longest = a.len().max(b.len());
+    if longest == 0 {
+        return 1.0;
+    }
+    
+    // Optimal string alignment distance, keeping the last two rows
+    let mut before: Vec<usize> = vec![0; b.len() + 1];
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+    for i in 1..=a.len() {
+        current[0] = i;
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            current[j] = (previous[j] + 1).min(current[j - 1] + 1).min(previous[j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                current[j] = current[j].min(before[j - 2] + 1);
+            }
+        }
+        std::mem::swap(&mut before, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    1.0 - previous[b.len()] as f32 / longest as f32
+}
+
+/// Dice coefficient of the padded character trigrams of two strings
+fn trigram_similarity(a: &str, b: &str) -> f32 {
+    let trigrams = |s: &str| -> HashSet<[char; 3]> {
+        let padded: Vec<char> = "  ".chars().chain(s.chars()).chain(" ".chars()).collect();
+        padded.windows(3).map(|w| [w[0], w[1], w[2]]).collect()
+    };
+    let (a, b) = (trigrams(a), trigrams(b));
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    2.0 * a.intersection(&b).count() as f32 / (a.len() + b.len()) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Label, NameKind};
+    use crate::entities::Deity;
+    use crate::examples::create_egyptian_ontology;
+    
+    #[test]
+    fn folds_and_ranks_name_variants() {
+        let egyptian = create_egyptian_ontology();
+        let resolver = NameResolver::new(&egyptian);
+        
+        let re = resolver.best("re").unwrap();
+        assert_eq!((re.name.as_str(), re.matched.as_str()), ("Ra", "Re"));
+        assert!(re.alternative && re.kind == MatchKind::Exact);
+        
+        let fused = resolver.best("amun ra").unwrap();
+        assert_eq!(fused.matched, "Amun-Ra");
+        
+        let mut ontology = MythOntology::new();
+        for name in ["Ægir", "Aegis", "Saga"] {
+            ontology.add_entity(MythEntity::Deity(Deity::new(name, "", "Norse")));
+        }
+        let matches = NameResolver::new(&ontology).resolve("AEG");
+        let names: Vec<&str> = matches.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, ["Aegis", "Ægir"]);
+        assert_eq!(matches[1].highlighted("<", ">"), "<Æg>ir");
+        assert_eq!(NameResolver::new(&ontology).resolve("ga")[0].highlighted("<", ">"), "Sa<ga>");
+    }
+    
+    #[test]
+    fn tolerates_misspellings() {
+        assert!(edit_similarity("heracles", "herakles") > 0.85);
+        assert_eq!(edit_similarity("thor", "htor"), 0.75);
+        assert!(trigram_similarity("poseidon", "poseidonas") > 0.7);
+        
+        let mut ontology = MythOntology::new();
+        ontology.add_entity(MythEntity::Deity(Deity::new("Poseidon", "", "Greek")));
+        let resolver = NameResolver::new(&ontology);
+        assert_eq!(resolver.best("Posiedon").map(|m| m.kind), Some(MatchKind::Fuzzy));
+        assert!(resolver.resolve("Loki").is_empty());
+    }
+    
+    #[test]
+    fn resolves_labels_in_other_scripts() {
+        let mut ontology = MythOntology::new();
+        let mut zeus = Deity::new("Zeus", "King of the gods", "Greek");
+        zeus.metadata.add_label(Label::new("Ζεύς", "Ancient Greek", NameKind::Original).script("Grek"));
+        ontology.add_entity(MythEntity::Deity(zeus));
+        let mut osiris = Deity::new("Osiris", "God of the dead", "Egyptian");
+        osiris.metadata.add_label(Label::new("Ὄσιρις", "Ancient Greek", NameKind::Exonym).script("Grek"));
+        ontology.add_entity(MythEntity::Deity(osiris));
+        
+        let resolver = NameResolver::new(&ontology);
+        let zeus = resolver.best("ΖΕΥΣ").unwrap();
+        assert_eq!((zeus.name.as_str(), zeus.matched.as_str()), ("Zeus", "Ζεύς"));
+        assert!(zeus.alternative && zeus.kind == MatchKind::Exact);
+        
+        let osiris = resolver.best("Οσιρ").unwrap();
+        assert_eq!(osiris.name, "Osiris");
+        assert_eq!(osiris.highlighted("[", "]"), "[Ὄσιρ]ις");
+    }
+}
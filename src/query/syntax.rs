@@ -0,0 +1,981 @@
+//! # Query Language
+//!
+//! This module implements a small text syntax for [`QueryFilter`], so queries can be
+//! typed on the command line or stored in configuration files. Parsing produces a
+//! `QueryFilter`, and the `Display` implementation prints a filter back in the same
+//! syntax, so `filter.to_string().parse()` yields the original filter.
+//!
+//! ## Syntax
+//!
+//! A query is a set of terms combined with `AND`, `OR`, and `NOT` (case-insensitive),
+//! grouped with parentheses. `NOT` binds tightest, then `AND`, then `OR`.
+//!
+//! | Term                            | Filter                                   |
+//! |---------------------------------|------------------------------------------|
+//! | `type:Deity`                    | [`QueryFilter::EntityType`]              |
+//! | `name~"ze"`                     | [`QueryFilter::NameContains`]            |
+//! | `culture:Greek`                 | [`QueryFilter::Culture`]                 |
+//! | `domain:Sea`                    | [`QueryFilter::Domain`]                  |
+//! | `attr:symbol`                   | [`QueryFilter::HasAttribute`]            |
+//! | `attr:symbol=eagle`             | [`QueryFilter::AttributeEquals`]         |
+//! | `motif:A1010`                   | [`QueryFilter::Motif`]                   |
+//! | `attested:"before 1500 BCE"`    | [`QueryFilter::Attested`], possibly      |
+//! | `attested_certain:"c. 700 BCE"` | [`QueryFilter::Attested`], certainly     |
+//! | `tradition:Hesiodic`            | [`QueryFilter::Tradition`]               |
+//! | `claim:heads`                   | [`QueryFilter::Claim`], any value        |
+//! | `claim:heads=50`                | [`QueryFilter::Claim`]                   |
+//! | `variant:"parentage of Eros"`   | [`QueryFilter::Variant`]                 |
+//! | `alt_name~wotan`                | [`QueryFilter::AlternativeNameContains`] |
+//! | `any_name~wotan`                | [`QueryFilter::AnyNameContains`]         |
+//!
+//! Typed fields match the structured fields of a single entity type:
+//!
+//! | Entity type       | Fields                                                         |
+//! |-------------------|----------------------------------------------------------------|
+//! | `Deity`           | `domain:`, `importance:`, `gender:`, `pantheon:`               |
+//! | `Hero`            | `origin:`, `achievement~`                                      |
+//! | `Creature`        | `creature_type:`, `habitat~`, `ability~`                       |
+//! | `Artifact`        | `artifact_type:`, `power~`, `creator~`, `owner~`               |
+//! | `Location`        | `location_type:`, `characteristic~`                            |
+//! | `Concept`         | `concept_type:`, `manifestation~`                              |
+//! | `Pantheon`        | `member:<entity id>`, `cosmology:`, `founding_myth~`           |
+//! | `MythologicalEra` | `sequence:2..4`, `characteristic~`, `end_event:`               |
+//! | `Narrative`       | `participant:<entity id>`, `participant:<entity id>=Antagonist` |
+//!
+//! Fields with `~` match a case-insensitive substring. `domain`, `importance`,
+//! `gender`, `origin`, and the `*_type` fields also accept a bracketed list, matching
+//! any of the values: `importance:[Supreme, Major]`; the empty list `[]` matches
+//! nothing. Enum values are case-insensitive. A value that is not one of the named
+//! variants falls into the catch-all `Other`, and `other:` marks a catch-all value
+//! that spells a named variant, so `gender:other:Male` is `Gender::Other("Male")`. A `sequence` is a single number or a range with either bound
+//! optional (`2..`, `..4`).
+//!
+//! Values containing spaces or punctuation, or that look like keywords, must be
+//! double-quoted; `\"` and `\\` escape quotes and backslashes inside quotes.
+//!
+//! ```
+//! use mythologic::query::QueryFilter;
+//!
+//! let filter: QueryFilter = r#"type:Deity AND culture:Greek AND (name~"ze" OR attr:symbol=eagle) AND NOT domain:Sea"#
+//!     .parse()
+//!     .unwrap();
+//!
+//! assert_eq!(filter.to_string().parse::<QueryFilter>().unwrap(), filter);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+use crate::core::{CatchAll, CultureId, DomainId, PantheonId, CosmologyId, EventId, MythId, HistoricalDate};
+use crate::motifs::MotifCode;
+use crate::query::QueryFilter;
+
+/// A byte range within a query string
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    /// Byte offset of the first character
+    pub start: usize,
+    /// Byte offset just past the last character
+    pub end: usize,
+}
+
+/// An error produced while parsing a query
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Description of what went wrong
+    pub message: String,
+    /// Where in the query the problem is
+    pub span: Span,
+}
+
+impl ParseError {
+    fn new(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            message: message.into(),
+            span,
+        }
+    }
+    
+    /// Render the error with the offending part of the query underlined
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::query::QueryFilter;
+    ///
+    /// let query = "type:Deity AND colour:red";
+    /// let error = QueryFilter::parse(query).unwrap_err();
+    /// assert_eq!(
+    ///     error.render(query).lines().last(),
+    ///     Some("               ^^^^^^")
+    /// );
+    /// ```
+    pub fn render(&self, query: &str) -> String {
+        let prefix = query.get(..self.span.start).unwrap_or(query).chars().count();
+        let width = query.get(self.span.start..self.span.end)
+            .map(|s| s.chars().count())
+            .unwrap_or(0)
+            .max(1);
+        format!("{}\n{}\n{}{}", self.message, query, " ".repeat(prefix), "^".repeat(width))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at {}..{}", self.message, self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    LParen,
+    RParen,
+    LBracket,
+    RBracket,
+    Comma,
+    Colon,
+    Tilde,
+    Equals,
+    Word(String),
+    Quoted(String),
+    End,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    span: Span,
+}
+
+impl Token {
+    fn is_keyword(&self, keyword: &str) -> bool {
+        matches!(&self.kind, TokenKind::Word(w) if w.eq_ignore_ascii_case(keyword))
+    }
+    
+    fn describe(&self) -> String {
+        self.kind.describe()
+    }
+}
+
+impl TokenKind {
+    fn describe(&self) -> String {
+        match self {
+            TokenKind::LParen => "`(`".to_string(),
+            TokenKind::RParen => "`)`".to_string(),
+            TokenKind::LBracket => "`[`".to_string(),
+            TokenKind::RBracket => "`]`".to_string(),
+            TokenKind::Comma => "`,`".to_string(),
+            TokenKind::Colon => "`:`".to_string(),
+            TokenKind::Tilde => "`~`".to_string(),
+            TokenKind::Equals => "`=`".to_string(),
+            TokenKind::Word(w) => format!("`{}`", w),
+            TokenKind::Quoted(q) => format!("\"{}\"", q),
+            TokenKind::End => "end of query".to_string(),
+        }
+    }
+}
+
+const KEYWORDS: [&str; 3] = ["AND", "OR", "NOT"];
+
+fn is_special(c: char) -> bool {
+    matches!(c, '(' | ')' | '[' | ']' | ',' | ':' | '~' | '=' | '"')
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+    
+    while let Some(&(start, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+            continue;
+        }
+        
+        let single = match c {
+            '(' => Some(TokenKind::LParen),
+            ')' => Some(TokenKind::RParen),
+            '[' => Some(TokenKind::LBracket),
+            ']' => Some(TokenKind::RBracket),
+            ',' => Some(TokenKind::Comma),
+            ':' => Some(TokenKind::Colon),
+            '~' => Some(TokenKind::Tilde),
+            '=' => Some(TokenKind::Equals),
+            _ => None,
+        };
+        if let Some(kind) = single {
+            chars.next();
+            tokens.push(Token { kind, span: Span { start, end: start + c.len_utf8() } });
+            continue;
+        }
+        
+        if c == '"' {
+            chars.next();
+            let mut value = String::new();
+            let mut end = None;
+            while let Some((i, c)) = chars.next() {
+                match c {
+                    '"' => {
+                        end = Some(i + 1);
+                        break;
+                    },
+                    '\\' => match chars.next() {
+                        Some((_, escaped @ ('"' | '\\'))) => value.push(escaped),
+                        Some((i, other)) => {
+                            return Err(ParseError::new(
+                                format!("unknown escape `\\{}`", other),
+                                Span { start: i - 1, end: i + other.len_utf8() },
+                            ));
+                        },
+                        None => break,
+                    },
+                    _ => value.push(c),
+                }
+            }
+            let Some(end) = end else {
+                return Err(ParseError::new("unterminated string", Span { start, end: input.len() }));
+            };
+            tokens.push(Token { kind: TokenKind::Quoted(value), span: Span { start, end } });
+            continue;
+        }
+        
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+            if c.is_whitespace() || is_special(c) {
+                break;
+            }
+            end = i + c.len_utf8();
+            chars.next();
+        }
+        tokens.push(Token {
+            kind: TokenKind::Word(input[start..end].to_string()),
+            span: Span { start, end },
+        });
+    }
+    
+    tokens.push(Token { kind: TokenKind::End, span: Span { start: input.len(), end: input.len() } });
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> &Token {
+        &self.tokens[self.pos]
+    }
+    
+    fn advance(&mut self) -> Token {
+        let token = self.tokens[self.pos].clone();
+        if self.pos < self.tokens.len() - 1 {
+            self.pos += 1;
+        }
+        token
+    }
+    
+    fn parse_query(&mut self) -> Result<QueryFilter, ParseError> {
+        let filter = self.parse_or()?;
+        let next = self.peek();
+        match next.kind {
+            TokenKind::End => Ok(filter),
+            TokenKind::RParen => Err(ParseError::new("unmatched `)`", next.span)),
+            _ => Err(ParseError::new(
+                format!("expected AND, OR or end of query, found {}", next.describe()),
+                next.span,
+            )),
+        }
+    }
+    
+    fn parse_or(&mut self) -> Result<QueryFilter, ParseError> {
+        let mut filter = self.parse_and()?;
+        while self.peek().is_keyword("OR") {
+            self.advance();
+            filter = filter.or(self.parse_and()?);
+        }
+        Ok(filter)
+    }
+    
+    fn parse_and(&mut self) -> Result<QueryFilter, ParseError> {
+        let mut filter = self.parse_unary()?;
+        while self.peek().is_keyword("AND") {
+            self.advance();
+            filter = filter.and(self.parse_unary()?);
+        }
+        Ok(filter)
+    }
+    
+    fn parse_unary(&mut self) -> Result<QueryFilter, ParseError> {
+        if self.peek().is_keyword("NOT") {
+            self.advance();
+            return Ok(QueryFilter::Not(Box::new(self.parse_unary()?)));
+        }
+        self.parse_primary()
+    }
+    
+    fn parse_primary(&mut self) -> Result<QueryFilter, ParseError> {
+        let token = self.advance();
+        match &token.kind {
+            TokenKind::LParen => {
+                let filter = self.parse_or()?;
+                let close = self.advance();
+                if close.kind != TokenKind::RParen {
+                    return Err(ParseError::new(
+                        format!("expected `)` to close `(` at {}, found {}", token.span.start, close.describe()),
+                        close.span,
+                    ));
+                }
+                Ok(filter)
+            },
+            TokenKind::Word(field) if !KEYWORDS.iter().any(|k| field.eq_ignore_ascii_case(k)) => {
+                self.parse_term(field, token.span)
+            },
+            _ => Err(ParseError::new(
+                format!("expected a filter such as `type:Deity`, found {}", token.describe()),
+                token.span,
+            )),
+        }
+    }
+    
+    fn parse_term(&mut self, field: &str, field_span: Span) -> Result<QueryFilter, ParseError> {
+        use TokenKind::{Colon, Tilde};
+        
+        let name = field.to_lowercase();
+        match name.as_str() {
+            "type" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::EntityType(self.parse_value()?))
+            },
+            "name" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::NameContains(self.parse_value()?))
+            },
+            "alt_name" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::AlternativeNameContains(self.parse_value()?))
+            },
+            "any_name" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::AnyNameContains(self.parse_value()?))
+            },
+            "culture" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::Culture(CultureId::new(&self.parse_value()?)))
+            },
+            "attr" => {
+                self.expect_operator(field, Colon)?;
+                let key = self.parse_value()?;
+                if self.peek().kind == TokenKind::Equals {
+                    self.advance();
+                    Ok(QueryFilter::AttributeEquals(key, self.parse_value()?))
+                } else {
+                    Ok(QueryFilter::HasAttribute(key))
+                }
+            },
+            "motif" => {
+                self.expect_operator(field, Colon)?;
+                let (value, span) = self.parse_spanned_value()?;
+                MotifCode::new(&value)
+                    .map(QueryFilter::Motif)
+                    .map_err(|e| ParseError::new(e.to_string(), span))
+            },
+            "attested" | "attested_certain" => {
+                self.expect_operator(field, Colon)?;
+                let (value, span) = self.parse_spanned_value()?;
+                let during = value.parse::<HistoricalDate>().map_err(|e| ParseError::new(e.to_string(), span))?;
+                Ok(QueryFilter::Attested { during, certain: name == "attested_certain" })
+            },
+            "tradition" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::Tradition(self.parse_value()?))
+            },
+            "claim" => {
+                self.expect_operator(field, Colon)?;
+                let field = self.parse_value()?;
+                let value = if self.peek().kind == TokenKind::Equals {
+                    self.advance();
+                    Some(self.parse_value()?)
+                } else {
+                    None
+                };
+                Ok(QueryFilter::Claim { field, value })
+            },
+            "variant" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::Variant(self.parse_value()?))
+            },
+            "domain" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::Domain, QueryFilter::DomainIn, |parser| Ok(DomainId::new(&parser.parse_value()?)))
+            },
+            "importance" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::Importance, QueryFilter::ImportanceIn, Self::parse_variant)
+            },
+            "gender" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::Gender, QueryFilter::GenderIn, Self::parse_catch_all)
+            },
+            "pantheon" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::Pantheon(PantheonId::new(&self.parse_value()?)))
+            },
+            "origin" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::HeroOrigin, QueryFilter::HeroOriginIn, Self::parse_catch_all)
+            },
+            "achievement" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::AchievementContains(self.parse_value()?))
+            },
+            "creature_type" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::CreatureType, QueryFilter::CreatureTypeIn, Self::parse_catch_all)
+            },
+            "habitat" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::HabitatContains(self.parse_value()?))
+            },
+            "ability" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::AbilityContains(self.parse_value()?))
+            },
+            "artifact_type" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::ArtifactType, QueryFilter::ArtifactTypeIn, Self::parse_catch_all)
+            },
+            "power" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::PowerContains(self.parse_value()?))
+            },
+            "creator" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::CreatorContains(self.parse_value()?))
+            },
+            "owner" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::OwnerContains(self.parse_value()?))
+            },
+            "location_type" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::LocationType, QueryFilter::LocationTypeIn, Self::parse_catch_all)
+            },
+            "characteristic" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::CharacteristicContains(self.parse_value()?))
+            },
+            "concept_type" => {
+                self.expect_operator(field, Colon)?;
+                self.parse_set(QueryFilter::ConceptType, QueryFilter::ConceptTypeIn, Self::parse_catch_all)
+            },
+            "manifestation" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::ManifestationContains(self.parse_value()?))
+            },
+            "member" => {
+                self.expect_operator(field, Colon)?;
+                let (value, span) = self.parse_spanned_value()?;
+                MythId::from_str(&value)
+                    .map(QueryFilter::PantheonMember)
+                    .map_err(|_| ParseError::new(format!("`{}` is not a valid entity ID", value), span))
+            },
+            "participant" => {
+                self.expect_operator(field, Colon)?;
+                let (value, span) = self.parse_spanned_value()?;
+                let id = MythId::from_str(&value)
+                    .map_err(|_| ParseError::new(format!("`{}` is not a valid entity ID", value), span))?;
+                let role = if self.peek().kind == TokenKind::Equals {
+                    self.advance();
+                    Some(self.parse_catch_all()?)
+                } else {
+                    None
+                };
+                Ok(QueryFilter::Participant { id, role })
+            },
+            "cosmology" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::Cosmology(CosmologyId::new(&self.parse_value()?)))
+            },
+            "founding_myth" => {
+                self.expect_operator(field, Tilde)?;
+                Ok(QueryFilter::FoundingMythContains(self.parse_value()?))
+            },
+            "sequence" => {
+                self.expect_operator(field, Colon)?;
+                let (value, span) = self.parse_spanned_value()?;
+                let (min, max) = parse_range(&value).map_err(|message| ParseError::new(message, span))?;
+                Ok(QueryFilter::EraSequence { min, max })
+            },
+            "end_event" => {
+                self.expect_operator(field, Colon)?;
+                Ok(QueryFilter::EndEvent(EventId::new(&self.parse_value()?)))
+            },
+            _ => Err(ParseError::new(
+                format!("unknown field `{}`; expected one of {}", field, FIELDS.join(", ")),
+                field_span,
+            )),
+        }
+    }
+    
+    fn expect_operator(&mut self, field: &str, expected: TokenKind) -> Result<(), ParseError> {
+        let op = self.advance();
+        if op.kind == expected {
+            Ok(())
+        } else {
+            Err(ParseError::new(
+                format!("`{}` must be followed by {}, found {}", field, expected.describe(), op.describe()),
+                op.span,
+            ))
+        }
+    }
+    
+    /// Parse either a single value or a bracketed, comma-separated and possibly
+    /// empty list of values
+    fn parse_set<T>(
+        &mut self,
+        one: fn(T) -> QueryFilter,
+        many: fn(Vec<T>) -> QueryFilter,
+        parse_item: fn(&mut Self) -> Result<T, ParseError>,
+    ) -> Result<QueryFilter, ParseError> {
+        if self.peek().kind != TokenKind::LBracket {
+            return Ok(one(parse_item(self)?));
+        }
+        
+        self.advance();
+        if self.peek().kind == TokenKind::RBracket {
+            self.advance();
+            return Ok(many(Vec::new()));
+        }
+        let mut items = vec![parse_item(self)?];
+        loop {
+            let token = self.advance();
+            match token.kind {
+                TokenKind::Comma => items.push(parse_item(self)?),
+                TokenKind::RBracket => return Ok(many(items)),
+                _ => {
+                    return Err(ParseError::new(
+                        format!("expected `,` or `]`, found {}", token.describe()),
+                        token.span,
+                    ));
+                },
+            }
+        }
+    }
+    
+    /// Parse an enum value by its variant name
+    fn parse_variant<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let (value, span) = self.parse_spanned_value()?;
+        value.parse().map_err(|e: T::Err| ParseError::new(e.to_string(), span))
+    }
+    
+    /// Parse a value of an enum with a catch-all variant, where `other:<value>`
+    /// always gives the catch-all
+    fn parse_catch_all<T>(&mut self) -> Result<T, ParseError>
+    where
+        T: FromStr + CatchAll,
+        T::Err: fmt::Display,
+    {
+        let marked = matches!(&self.peek().kind, TokenKind::Word(word) if word.eq_ignore_ascii_case("other"))
+            && self.tokens.get(self.pos + 1).is_some_and(|token| token.kind == TokenKind::Colon);
+        if marked {
+            self.advance();
+            self.advance();
+            return Ok(T::other(self.parse_value()?));
+        }
+        self.parse_variant()
+    }
+    
+    fn parse_value(&mut self) -> Result<String, ParseError> {
+        Ok(self.parse_spanned_value()?.0)
+    }
+    
+    fn parse_spanned_value(&mut self) -> Result<(String, Span), ParseError> {
+        let token = self.advance();
+        match token.kind {
+            TokenKind::Word(value) | TokenKind::Quoted(value) => Ok((value, token.span)),
+            _ => Err(ParseError::new(
+                format!("expected a value, found {}", token.describe()),
+                token.span,
+            )),
+        }
+    }
+}
+
+/// Every field name accepted by the parser
+const FIELDS: [&str; 35] = [
+    "type", "name", "alt_name", "any_name", "culture", "attr", "motif",
+    "attested", "attested_certain", "tradition", "claim", "variant", "domain", "importance",
+    "gender", "pantheon", "origin",
+    "achievement", "creature_type", "habitat", "ability", "artifact_type", "power", "creator",
+    "owner", "location_type", "characteristic", "concept_type", "manifestation", "member",
+    "cosmology", "founding_myth", "sequence", "end_event", "participant",
+];
+
+/// Parse an era sequence range: `3`, `2..4`, `2..` or `..4`
+fn parse_range(value: &str) -> Result<(Option<u32>, Option<u32>), String> {
+    let bound = |text: &str| -> Result<Option<u32>, String> {
+        if text.is_empty() {
+            Ok(None)
+        } else {
+            text.parse().map(Some).map_err(|_| format!("`{}` is not a valid sequence number", text))
+        }
+    };
+    
+    match value.split_once("..") {
+        Some((min, max)) => Ok((bound(min)?, bound(max)?)),
+        None => {
+            let exact = bound(value)?;
+            Ok((exact, exact))
+        },
+    }
+}
+
+impl QueryFilter {
+    /// Parse a filter from the text query syntax.
+    ///
+    /// See the [module documentation](crate::query::syntax) for the grammar.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mythologic::query::QueryFilter;
+    ///
+    /// let filter = QueryFilter::parse("type:Deity AND culture:Greek").unwrap();
+    /// assert_eq!(
+    ///     filter,
+    ///     QueryFilter::EntityType("Deity".to_string()).and(QueryFilter::Culture("Greek".into()))
+    /// );
+    /// ```
+    pub fn parse(query: &str) -> Result<QueryFilter, ParseError> {
+        let tokens = tokenize(query)?;
+        Parser { tokens, pos: 0 }.parse_query()
+    }
+    
+    /// Binding strength of this filter's top-level operator
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Or(_, _) => 1,
+            Self::And(_, _) => 2,
+            Self::Not(_) => 3,
+            _ => 4,
+        }
+    }
+    
+    /// Write this filter, parenthesized if it binds more loosely than `min_precedence`
+    fn write_syntax(&self, f: &mut fmt::Formatter<'_>, min_precedence: u8) -> fmt::Result {
+        let parenthesize = self.precedence() < min_precedence;
+        if parenthesize {
+            write!(f, "(")?;
+        }
+        
+        match self {
+            // Operators are left-associative, so a right operand of the same
+            // operator needs parentheses to keep its grouping
+            Self::Or(left, right) => {
+                left.write_syntax(f, 1)?;
+                write!(f, " OR ")?;
+                right.write_syntax(f, 2)?;
+            },
+            Self::And(left, right) => {
+                left.write_syntax(f, 2)?;
+                write!(f, " AND ")?;
+                right.write_syntax(f, 3)?;
+            },
+            Self::Not(inner) => {
+                write!(f, "NOT ")?;
+                inner.write_syntax(f, 3)?;
+            },
+            Self::EntityType(entity_type) => write_term(f, "type:", entity_type)?,
+            Self::NameContains(substring) => write_term(f, "name~", substring)?,
+            Self::AlternativeNameContains(substring) => write_term(f, "alt_name~", substring)?,
+            Self::AnyNameContains(substring) => write_term(f, "any_name~", substring)?,
+            Self::Culture(culture) => write_term(f, "culture:", culture)?,
+            Self::HasAttribute(key) => write_term(f, "attr:", key)?,
+            Self::AttributeEquals(key, value) => {
+                write_term(f, "attr:", key)?;
+                write_term(f, "=", value)?;
+            },
+            Self::Motif(code) => write_term(f, "motif:", code)?,
+            Self::Attested { during, certain } => {
+                write_term(f, if *certain { "attested_certain:" } else { "attested:" }, during)?;
+            },
+            Self::Tradition(tradition) => write_term(f, "tradition:", tradition)?,
+            Self::Claim { field, value } => {
+                write_term(f, "claim:", field)?;
+                if let Some(value) = value {
+                    write_term(f, "=", value)?;
+                }
+            },
+            Self::Variant(question) => write_term(f, "variant:", question)?,
+            Self::Domain(domain) => write_term(f, "domain:", domain)?,
+            Self::DomainIn(domains) => write_list(f, "domain:", domains, |f, value| write_value(f, &value.to_string()))?,
+            Self::Importance(importance) => write_variant_term(f, "importance:", importance)?,
+            Self::ImportanceIn(levels) => write_list(f, "importance:", levels, write_variant)?,
+            Self::Gender(gender) => write_variant_term(f, "gender:", gender)?,
+            Self::GenderIn(genders) => write_list(f, "gender:", genders, write_variant)?,
+            Self::Pantheon(pantheon) => write_term(f, "pantheon:", pantheon)?,
+            Self::HeroOrigin(origin) => write_variant_term(f, "origin:", origin)?,
+            Self::HeroOriginIn(origins) => write_list(f, "origin:", origins, write_variant)?,
+            Self::AchievementContains(substring) => write_term(f, "achievement~", substring)?,
+            Self::CreatureType(creature_type) => write_variant_term(f, "creature_type:", creature_type)?,
+            Self::CreatureTypeIn(types) => write_list(f, "creature_type:", types, write_variant)?,
+            Self::HabitatContains(substring) => write_term(f, "habitat~", substring)?,
+            Self::AbilityContains(substring) => write_term(f, "ability~", substring)?,
+            Self::ArtifactType(artifact_type) => write_variant_term(f, "artifact_type:", artifact_type)?,
+            Self::ArtifactTypeIn(types) => write_list(f, "artifact_type:", types, write_variant)?,
+            Self::PowerContains(substring) => write_term(f, "power~", substring)?,
+            Self::CreatorContains(substring) => write_term(f, "creator~", substring)?,
+            Self::OwnerContains(substring) => write_term(f, "owner~", substring)?,
+            Self::LocationType(location_type) => write_variant_term(f, "location_type:", location_type)?,
+            Self::LocationTypeIn(types) => write_list(f, "location_type:", types, write_variant)?,
+            Self::CharacteristicContains(substring) => write_term(f, "characteristic~", substring)?,
+            Self::ConceptType(concept_type) => write_variant_term(f, "concept_type:", concept_type)?,
+            Self::ConceptTypeIn(types) => write_list(f, "concept_type:", types, write_variant)?,
+            Self::ManifestationContains(substring) => write_term(f, "manifestation~", substring)?,
+            Self::PantheonMember(deity_id) => write_term(f, "member:", deity_id)?,
+            Self::Cosmology(cosmology) => write_term(f, "cosmology:", cosmology)?,
+            Self::FoundingMythContains(substring) => write_term(f, "founding_myth~", substring)?,
+            Self::EraSequence { min, max } => {
+                let bound = |b: &Option<u32>| b.map(|n| n.to_string()).unwrap_or_default();
+                if min.is_some() && min == max {
+                    write!(f, "sequence:{}", bound(min))?;
+                } else {
+                    write!(f, "sequence:{}..{}", bound(min), bound(max))?;
+                }
+            },
+            Self::EndEvent(event) => write_term(f, "end_event:", event)?,
+            Self::Participant { id, role } => {
+                write_term(f, "participant:", id)?;
+                if let Some(role) = role {
+                    write_variant_term(f, "=", role)?;
+                }
+            },
+        }
+        
+        if parenthesize {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+/// Write a field and operator followed by a single value
+fn write_term(f: &mut fmt::Formatter<'_>, field: &str, value: &impl fmt::Display) -> fmt::Result {
+    f.write_str(field)?;
+    write_value(f, &value.to_string())
+}
+
+/// Write a field and operator followed by a single enum value
+fn write_variant_term<T>(f: &mut fmt::Formatter<'_>, field: &str, value: &T) -> fmt::Result
+where
+    T: fmt::Display + FromStr + PartialEq,
+{
+    f.write_str(field)?;
+    write_variant(f, value)
+}
+
+/// Write a field and operator followed by a bracketed list of values
+fn write_list<T>(
+    f: &mut fmt::Formatter<'_>,
+    field: &str,
+    values: &[T],
+    write_item: fn(&mut fmt::Formatter<'_>, &T) -> fmt::Result,
+) -> fmt::Result {
+    write!(f, "{}[", field)?;
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            write!(f, ", ")?;
+        }
+        write_item(f, value)?;
+    }
+    write!(f, "]")
+}
+
+/// Write an enum value, marking a catch-all value that would read back as a named
+/// variant with `other:`
+fn write_variant<T>(f: &mut fmt::Formatter<'_>, value: &T) -> fmt::Result
+where
+    T: fmt::Display + FromStr + PartialEq,
+{
+    let text = value.to_string();
+    if text.parse::<T>().ok().as_ref() != Some(value) {
+        f.write_str("other:")?;
+    }
+    write_value(f, &text)
+}
+
+/// Write a value, quoting it if it would not read back as a single bare word
+fn write_value(f: &mut fmt::Formatter<'_>, value: &str) -> fmt::Result {
+    let bare = !value.is_empty()
+        && !value.chars().any(|c| c.is_whitespace() || is_special(c))
+        && !KEYWORDS.iter().any(|k| value.eq_ignore_ascii_case(k));
+    
+    if bare {
+        write!(f, "{}", value)
+    } else {
+        write!(f, "\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+impl fmt::Display for QueryFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_syntax(f, 0)
+    }
+}
+
+impl FromStr for QueryFilter {
+    type Err = ParseError;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn parse(query: &str) -> QueryFilter {
+        QueryFilter::parse(query).unwrap_or_else(|e| panic!("{}", e.render(query)))
+    }
+    
+    #[test]
+    fn respects_precedence() {
+        let filter = parse("type:Deity OR type:Hero AND NOT culture:Greek");
+        let expected = QueryFilter::EntityType("Deity".to_string()).or(
+            QueryFilter::EntityType("Hero".to_string())
+                .and(QueryFilter::Not(Box::new(QueryFilter::Culture("Greek".into())))),
+        );
+        assert_eq!(filter, expected);
+    }
+    
+    #[test]
+    fn parses_every_term() {
+        assert_eq!(parse("name~\"ze\""), QueryFilter::NameContains("ze".to_string()));
+        assert_eq!(parse("attr:symbol"), QueryFilter::HasAttribute("symbol".to_string()));
+        assert_eq!(
+            parse("attr:symbol = \"thunder bolt\""),
+            QueryFilter::AttributeEquals("symbol".to_string(), "thunder bolt".to_string())
+        );
+        assert_eq!(parse("domain:Sea"), QueryFilter::Domain("Sea".into()));
+        assert_eq!(parse("motif:\"atu 510a\""), QueryFilter::Motif("ATU 510A".parse().unwrap()));
+        assert_eq!(
+            parse("attested_certain:\"c. 1500 bc\""),
+            QueryFilter::Attested { during: HistoricalDate::circa(-1500), certain: true }
+        );
+        assert_eq!(
+            parse("ATTESTED_CERTAIN:\"before 1500 BCE\"").to_string(),
+            "attested_certain:\"before 1500 BCE\""
+        );
+        assert_eq!(
+            parse("claim:heads = 50"),
+            QueryFilter::Claim { field: "heads".to_string(), value: Some("50".to_string()) }
+        );
+        assert_eq!(parse("culture:\"Tuatha Dé Danann\""), QueryFilter::Culture("Tuatha Dé Danann".into()));
+        assert_eq!(parse("((type:Deity))"), QueryFilter::EntityType("Deity".to_string()));
+        assert_eq!(parse("type:\"and\""), QueryFilter::EntityType("and".to_string()));
+    }
+    
+    #[test]
+    fn parses_typed_fields() {
+        use crate::entities::{ArtifactType, DeityImportance, HeroOrigin, NarrativeRole};
+        
+        assert_eq!(parse("importance:supreme"), QueryFilter::Importance(DeityImportance::Supreme));
+        assert_eq!(
+            parse("importance:[Supreme, major]"),
+            QueryFilter::ImportanceIn(vec![DeityImportance::Supreme, DeityImportance::Major])
+        );
+        assert_eq!(parse("origin:Demigod"), QueryFilter::HeroOrigin(HeroOrigin::Demigod));
+        assert_eq!(
+            parse("artifact_type:[Weapon, \"Sacred Relic\"]"),
+            QueryFilter::ArtifactTypeIn(vec![ArtifactType::Weapon, ArtifactType::Other("Sacred Relic".to_string())])
+        );
+        assert_eq!(parse("habitat~sea"), QueryFilter::HabitatContains("sea".to_string()));
+        assert_eq!(parse("sequence:2..4"), QueryFilter::EraSequence { min: Some(2), max: Some(4) });
+        assert_eq!(parse("sequence:..4"), QueryFilter::EraSequence { min: None, max: Some(4) });
+        assert_eq!(parse("sequence:3"), QueryFilter::EraSequence { min: Some(3), max: Some(3) });
+        
+        let id = MythId::new();
+        assert_eq!(parse(&format!("member:{}", id)), QueryFilter::PantheonMember(id.clone()));
+        assert_eq!(
+            parse(&format!("participant:{}=antagonist", id)),
+            QueryFilter::Participant { id, role: Some(NarrativeRole::Antagonist) }
+        );
+    }
+    
+    #[test]
+    fn display_round_trips() {
+        use crate::entities::{CreatureType, Gender, NarrativeRole};
+        
+        for query in [
+            r#"type:Deity AND culture:Greek AND (name~ze OR attr:symbol=eagle) AND NOT domain:Sea"#,
+            r#"type:Deity AND (type:Hero AND type:Creature)"#,
+            r#"type:Deity OR (type:Hero OR type:Creature)"#,
+            r#"NOT (type:Deity OR type:Hero)"#,
+            r#"NOT NOT name~"a \"quoted\" \\ value""#,
+            r#"attr:"" AND culture:OR"#,
+            r#"domain:[Sea, "Storms, Winds"] OR importance:[Supreme]"#,
+            r#"gender:[Male, "Dual-natured"] AND NOT origin:BlessedMortal"#,
+            r#"creature_type:Dragon AND ability~fire AND habitat~"deep sea""#,
+            r#"location_type:[Underworld, Afterlife] OR concept_type:Fate OR manifestation~wheel"#,
+            r#"cosmology:Yggdrasil AND founding_myth~ymir AND pantheon:Aesir"#,
+            r#"sequence:2.. OR sequence:..1 OR sequence:3 OR end_event:Ragnarok"#,
+            r#"creator~hephaestus AND owner~zeus AND power~lightning AND achievement~labours"#,
+            r#"alt_name~wotan OR any_name~odin OR characteristic~golden"#,
+            r#"motif:A1000-A1099 OR motif:"ATU 300" OR motif:F821.1"#,
+            r#"attested:"before 1500 BCE" AND NOT attested_certain:"5th century BCE to 30 BCE""#,
+            r#"tradition:Orphic OR claim:heads OR claim:heads=50 OR variant:"parentage of Eros""#,
+        ] {
+            let filter = parse(query);
+            let printed = filter.to_string();
+            assert_eq!(parse(&printed), filter, "{} printed as {}", query, printed);
+        }
+        
+        for filter in [
+            QueryFilter::DomainIn(vec![]),
+            QueryFilter::GenderIn(vec![]).or(QueryFilter::ImportanceIn(vec![])),
+            QueryFilter::Gender(Gender::Other("Male".to_string())),
+            QueryFilter::GenderIn(vec![Gender::Male, Gender::Other("male".to_string()), Gender::Other("other".to_string())]),
+            QueryFilter::CreatureType(CreatureType::Other("Dragon".to_string())),
+            QueryFilter::Participant { id: MythId::new(), role: Some(NarrativeRole::Other("Helper".to_string())) },
+        ] {
+            let printed = filter.to_string();
+            assert_eq!(parse(&printed), filter, "{:?} printed as {}", filter, printed);
+        }
+        assert_eq!(QueryFilter::Gender(Gender::Other("Male".to_string())).to_string(), "gender:other:Male");
+        
+        assert_eq!(
+            parse("type : Deity and ( name ~ ze or attr:symbol=eagle )").to_string(),
+            "type:Deity AND (name~ze OR attr:symbol=eagle)"
+        );
+    }
+    
+    #[test]
+    fn reports_error_spans() {
+        let cases = [
+            ("type:Deity AND", "expected a filter", 14, 14),
+            ("colour:red", "unknown field `colour`", 0, 6),
+            ("name:ze", "`name` must be followed by `~`", 4, 5),
+            ("(type:Deity", "expected `)`", 11, 11),
+            ("type:Deity)", "unmatched `)`", 10, 11),
+            ("type:Deity culture:Greek", "expected AND, OR", 11, 18),
+            ("name~\"ze", "unterminated string", 5, 8),
+            ("type:", "expected a value", 5, 5),
+            ("importance:Titanic", "unknown DeityImportance `Titanic`", 11, 18),
+            ("origin:[Divine Mortal]", "expected `,` or `]`", 15, 21),
+            ("sequence:x..2", "`x` is not a valid sequence number", 9, 13),
+            ("member:zeus", "`zeus` is not a valid entity ID", 7, 11),
+        ];
+        
+        for (query, message, start, end) in cases {
+            let error = QueryFilter::parse(query).unwrap_err();
+            assert!(error.message.starts_with(message), "{}: {}", query, error.message);
+            assert_eq!(error.span, Span { start, end }, "{}", query);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::core::MythId;
+use crate::core::{MythId, variant_names};
 use crate::relationships::{Relationship, RelationshipType, Relatable, Invertible, Properties, RelationshipBuilder};
 
 /// Represents an alliance relationship between mythological entities
@@ -211,6 +211,8 @@ pub enum AllianceType {
     Other(String),
 }
 
+variant_names!(AllianceType { Military, Political, Marriage, Oath, Pact, Friendship, Mentorship, Patronage, Coalition } else Other);
+
 impl Invertible for AllianceType {
     /// Check if this type of relationship is typically bidirectional
     fn is_typically_bidirectional(&self) -> bool {
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::core::MythId;
+use crate::core::{MythId, variant_names};
 use crate::relationships::{Relationship, RelationshipType, Relatable, Invertible, Properties, RelationshipBuilder};
 
 /// Represents a conflict relationship between mythological entities
@@ -181,6 +181,8 @@ pub enum ConflictType {
     Other(String),
 }
 
+variant_names!(ConflictType { War, Battle, Duel, Rivalry, Contest, Betrayal, Curse, Punishment } else Other);
+
 impl Invertible for ConflictType {
     /// Check if this type of relationship is typically bidirectional
     fn is_typically_bidirectional(&self) -> bool {
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::core::MythId;
+use crate::core::{MythId, variant_names};
 use crate::relationships::{Relationship, RelationshipType, Relatable, Invertible, Properties, RelationshipBuilder};
 
 /// Represents a family relationship between mythological entities
@@ -164,16 +164,12 @@ pub enum FamilyRelationshipType {
     Other(String),
 }
 
+variant_names!(FamilyRelationshipType { Parent, Child, Sibling, Spouse, Ancestor, Descendant, Twin, Cousin } else Other);
+
 impl Invertible for FamilyRelationshipType {
     /// Check if this type of relationship is typically bidirectional
     fn is_typically_bidirectional(&self) -> bool {
-        match self {
-            Self::Sibling => true,
-            Self::Spouse => true,
-            Self::Twin => true,
-            Self::Cousin => true,
-            _ => false,
-        }
+        matches!(self, Self::Sibling | Self::Spouse | Self::Twin | Self::Cousin)
     }
     
     /// Get the inverse relationship type
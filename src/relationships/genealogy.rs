@@ -0,0 +1,756 @@
+//! # Genealogy
+//!
+//! [`Genealogy`] builds a family graph from the [`FamilyRelationship`] entities of an
+//! ontology and reasons over it: it infers relations that are only implied by the
+//! recorded ones (grandparents, half-siblings through a shared parent, cousins,
+//! ancestors to any depth), detects contradictions such as a parent cycle, and
+//! renders family trees.
+//!
+//! A family relationship reads "source is the `family_type` of target", so
+//! `Zeus --Parent--> Athena` records that Zeus is Athena's parent, and
+//! `Athena --Child--> Zeus` records the same fact.
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::relationships::{Genealogy, Kinship};
+//!
+//! let greek = create_greek_ontology();
+//! let genealogy = Genealogy::from_ontology(&greek);
+//! let id = |name: &str| greek.entities_named(name).next().unwrap().id().clone();
+//!
+//! // Athena and Heracles are both children of Zeus
+//! assert!(genealogy.kinships(&id("Athena"), &id("Heracles")).contains(&Kinship::Sibling));
+//! // Poseidon is Zeus's brother, so he is Athena's uncle
+//! assert!(genealogy.kinships(&id("Poseidon"), &id("Athena")).contains(&Kinship::AuntOrUncle));
+//!
+//! println!("{}", genealogy.descendant_tree(&id("Zeus"), None));
+//! ```
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use serde::{Serialize, Deserialize};
+use crate::core::{MythEntity, MythId, MythOntology};
+use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
+
+/// How one entity is related to another
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Kinship {
+    /// Direct parent
+    Parent,
+    /// Direct child
+    Child,
+    /// Parent of a parent
+    Grandparent,
+    /// Child of a child
+    Grandchild,
+    /// Ancestor beyond grandparent; `generations` is `None` when the line passes
+    /// through a recorded `Ancestor` relationship of unknown length
+    Ancestor {
+        /// Number of generations between the two entities
+        generations: Option<usize>,
+    },
+    /// Descendant beyond grandchild; see [`Kinship::Ancestor`]
+    Descendant {
+        /// Number of generations between the two entities
+        generations: Option<usize>,
+    },
+    /// Twin
+    Twin,
+    /// Sibling sharing every known parent, or recorded as a sibling
+    Sibling,
+    /// Sibling sharing some but not all known parents
+    HalfSibling,
+    /// Spouse
+    Spouse,
+    /// Sibling of a parent
+    AuntOrUncle,
+    /// Child of a sibling
+    NieceOrNephew,
+    /// Child of a parent's sibling, or recorded as a cousin
+    Cousin,
+}
+
+impl fmt::Display for Kinship {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parent => write!(f, "parent"),
+            Self::Child => write!(f, "child"),
+            Self::Grandparent => write!(f, "grandparent"),
+            Self::Grandchild => write!(f, "grandchild"),
+            Self::Ancestor { generations: Some(n) } => write!(f, "ancestor ({} generations)", n),
+            Self::Ancestor { generations: None } => write!(f, "ancestor"),
+            Self::Descendant { generations: Some(n) } => write!(f, "descendant ({} generations)", n),
+            Self::Descendant { generations: None } => write!(f, "descendant"),
+            Self::Twin => write!(f, "twin"),
+            Self::Sibling => write!(f, "sibling"),
+            Self::HalfSibling => write!(f, "half-sibling"),
+            Self::Spouse => write!(f, "spouse"),
+            Self::AuntOrUncle => write!(f, "aunt or uncle"),
+            Self::NieceOrNephew => write!(f, "niece or nephew"),
+            Self::Cousin => write!(f, "cousin"),
+        }
+    }
+}
+
+/// An ancestor or descendant together with its distance
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relative {
+    /// The related entity
+    pub id: MythId,
+    /// Number of generations away, or `None` if the line passes through a recorded
+    /// `Ancestor`/`Descendant` relationship of unknown length
+    pub generations: Option<usize>,
+}
+
+/// A contradiction in the recorded family relationships
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GenealogyIssue {
+    /// A chain of parent (or ancestor) links leads back to where it started;
+    /// each entity is a parent or ancestor of the next, and the last of the first
+    ParentCycle(Vec<MythId>),
+    /// Two entities are recorded as siblings while one descends from the other
+    AncestorIsSibling {
+        /// The ancestor
+        ancestor: MythId,
+        /// The descendant
+        descendant: MythId,
+    },
+    /// Two entities are recorded as twins but have no known parent in common
+    TwinsWithoutSharedParent(MythId, MythId),
+}
+
+impl fmt::Display for GenealogyIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ParentCycle(ids) => {
+                let chain: Vec<String> = ids.iter().chain(ids.first()).map(|id| id.to_string()).collect();
+                write!(f, "parent cycle: {}", chain.join(" -> "))
+            },
+            Self::AncestorIsSibling { ancestor, descendant } => {
+                write!(f, "{} is both a sibling and an ancestor of {}", ancestor, descendant)
+            },
+            Self::TwinsWithoutSharedParent(a, b) => {
+                write!(f, "twins {} and {} have no parent in common", a, b)
+            },
+        }
+    }
+}
+
+/// A node of a family tree, with its parents (in an ancestor tree) or children (in a descendant tree)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FamilyTreeNode {
+    /// The entity at this node
+    pub id: MythId,
+    /// Name of the entity
+    pub name: String,
+    /// Names of the entity's spouses
+    pub spouses: Vec<String>,
+    /// Parents or children of the entity, depending on the kind of tree
+    pub branches: Vec<FamilyTreeNode>,
+}
+
+impl FamilyTreeNode {
+    /// Count the nodes in this tree
+    pub fn node_count(&self) -> usize {
+        1 + self.branches.iter().map(FamilyTreeNode::node_count).sum::<usize>()
+    }
+    
+    /// Render the tree as indented text
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+        self.render_into(&mut output, "", "");
+        output
+    }
+    
+    fn render_into(&self, output: &mut String, first_prefix: &str, rest_prefix: &str) {
+        output.push_str(first_prefix);
+        output.push_str(&self.name);
+        if !self.spouses.is_empty() {
+            output.push_str(&format!(" (m. {})", self.spouses.join(", ")));
+        }
+        output.push('\n');
+        
+        for (i, branch) in self.branches.iter().enumerate() {
+            let last = i == self.branches.len() - 1;
+            let (first, rest) = if last { ("└── ", "    ") } else { ("├── ", "│   ") };
+            branch.render_into(output, &format!("{}{}", rest_prefix, first), &format!("{}{}", rest_prefix, rest));
+        }
+    }
+}
+
+impl fmt::Display for FamilyTreeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+/// Family graph built from `FamilyRelationship` entities
+#[derive(Debug, Clone, Default)]
+pub struct Genealogy {
+    names: HashMap<MythId, String>,
+    parents: HashMap<MythId, HashSet<MythId>>,
+    children: HashMap<MythId, HashSet<MythId>>,
+    ancestors: HashMap<MythId, HashSet<MythId>>,
+    descendants: HashMap<MythId, HashSet<MythId>>,
+    siblings: HashMap<MythId, HashSet<MythId>>,
+    twins: HashMap<MythId, HashSet<MythId>>,
+    spouses: HashMap<MythId, HashSet<MythId>>,
+    cousins: HashMap<MythId, HashSet<MythId>>,
+}
+
+impl Genealogy {
+    /// Create an empty genealogy
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Build the family graph from every family relationship in an ontology
+    pub fn from_ontology(ontology: &MythOntology) -> Self {
+        let mut genealogy = Self::new();
+        for entity in ontology.entities() {
+            if !entity.is_relationship() {
+                genealogy.names.insert(entity.id().clone(), entity.name().to_string());
+            }
+        }
+        for entity in ontology.entities_of_type("FamilyRelationship") {
+            if let MythEntity::FamilyRelationship(relationship) = entity {
+                genealogy.add_relationship(relationship);
+            }
+        }
+        genealogy
+    }
+    
+    /// Record a family relationship
+    ///
+    /// Relationships of type `Other` are ignored.
+    pub fn add_relationship(&mut self, relationship: &FamilyRelationship) {
+        let source = relationship.relationship.source_id.clone();
+        let target = relationship.relationship.target_id.clone();
+        
+        let link = |map: &mut HashMap<MythId, HashSet<MythId>>, from: &MythId, to: &MythId| {
+            map.entry(from.clone()).or_default().insert(to.clone());
+        };
+        
+        match &relationship.family_type {
+            FamilyRelationshipType::Parent => {
+                link(&mut self.children, &source, &target);
+                link(&mut self.parents, &target, &source);
+            },
+            FamilyRelationshipType::Child => {
+                link(&mut self.children, &target, &source);
+                link(&mut self.parents, &source, &target);
+            },
+            FamilyRelationshipType::Ancestor => {
+                link(&mut self.descendants, &source, &target);
+                link(&mut self.ancestors, &target, &source);
+            },
+            FamilyRelationshipType::Descendant => {
+                link(&mut self.descendants, &target, &source);
+                link(&mut self.ancestors, &source, &target);
+            },
+            FamilyRelationshipType::Sibling | FamilyRelationshipType::Twin => {
+                let map = if relationship.family_type == FamilyRelationshipType::Twin {
+                    &mut self.twins
+                } else {
+                    &mut self.siblings
+                };
+                link(map, &source, &target);
+                link(map, &target, &source);
+            },
+            FamilyRelationshipType::Spouse => {
+                link(&mut self.spouses, &source, &target);
+                link(&mut self.spouses, &target, &source);
+            },
+            FamilyRelationshipType::Cousin => {
+                link(&mut self.cousins, &source, &target);
+                link(&mut self.cousins, &target, &source);
+            },
+            FamilyRelationshipType::Other(_) => {},
+        }
+    }
+    
+    /// Get the direct parents of an entity
+    pub fn parents(&self, id: &MythId) -> HashSet<MythId> {
+        lookup(&self.parents, id)
+    }
+    
+    /// Get the direct children of an entity
+    pub fn children(&self, id: &MythId) -> HashSet<MythId> {
+        lookup(&self.children, id)
+    }
+    
+    /// Get the spouses of an entity
+    pub fn spouses(&self, id: &MythId) -> HashSet<MythId> {
+        lookup(&self.spouses, id)
+    }
+    
+    /// Get the parents of an entity's parents
+    pub fn grandparents(&self, id: &MythId) -> HashSet<MythId> {
+        self.parents(id).iter().flat_map(|parent| self.parents(parent)).collect()
+    }
+    
+    /// Get the children of an entity's children
+    pub fn grandchildren(&self, id: &MythId) -> HashSet<MythId> {
+        self.children(id).iter().flat_map(|child| self.children(child)).collect()
+    }
+    
+    /// Get every ancestor of an entity, nearest first
+    ///
+    /// Follows parent links and recorded `Ancestor` relationships to any depth.
+    pub fn ancestors(&self, id: &MythId) -> Vec<Relative> {
+        self.lineage(id, &self.parents, &self.ancestors)
+    }
+    
+    /// Get every descendant of an entity, nearest first
+    ///
+    /// Follows child links and recorded `Descendant` relationships to any depth.
+    pub fn descendants(&self, id: &MythId) -> Vec<Relative> {
+        self.lineage(id, &self.children, &self.descendants)
+    }
+    
+    /// Check whether `ancestor` is an ancestor of `descendant` at any depth
+    pub fn is_ancestor(&self, ancestor: &MythId, descendant: &MythId) -> bool {
+        self.ancestors(descendant).iter().any(|relative| &relative.id == ancestor)
+    }
+    
+    /// Get the siblings of an entity: recorded siblings and twins, plus everyone sharing a parent
+    pub fn siblings(&self, id: &MythId) -> HashSet<MythId> {
+        let mut siblings = lookup(&self.siblings, id);
+        siblings.extend(lookup(&self.twins, id));
+        for parent in self.parents(id) {
+            siblings.extend(self.children(&parent));
+        }
+        siblings.remove(id);
+        siblings
+    }
+    
+    /// Get the entities sharing some but not all of an entity's known parents
+    pub fn half_siblings(&self, id: &MythId) -> HashSet<MythId> {
+        let parents = self.parents(id);
+        self.siblings(id).into_iter()
+            .filter(|sibling| {
+                let theirs = self.parents(sibling);
+                !theirs.is_disjoint(&parents) && theirs != parents
+            })
+            .collect()
+    }
+    
+    /// Get the twins of an entity
+    pub fn twins(&self, id: &MythId) -> HashSet<MythId> {
+        lookup(&self.twins, id)
+    }
+    
+    /// Get the siblings of an entity's parents
+    pub fn aunts_and_uncles(&self, id: &MythId) -> HashSet<MythId> {
+        let parents = self.parents(id);
+        parents.iter()
+            .flat_map(|parent| self.siblings(parent))
+            .filter(|relative| !parents.contains(relative))
+            .collect()
+    }
+    
+    /// Get the children of an entity's siblings
+    pub fn nieces_and_nephews(&self, id: &MythId) -> HashSet<MythId> {
+        let siblings = self.siblings(id);
+        siblings.iter()
+            .flat_map(|sibling| self.children(sibling))
+            .filter(|relative| relative != id && !siblings.contains(relative))
+            .collect()
+    }
+    
+    /// Get the cousins of an entity: recorded cousins plus the children of its aunts and uncles
+    pub fn cousins(&self, id: &MythId) -> HashSet<MythId> {
+        let siblings = self.siblings(id);
+        let mut cousins = lookup(&self.cousins, id);
+        cousins.extend(self.aunts_and_uncles(id).iter().flat_map(|relative| self.children(relative)));
+        cousins.retain(|cousin| cousin != id && !siblings.contains(cousin));
+        cousins
+    }
+    
+    /// Describe how `a` is related to `b`, recorded or inferred
+    ///
+    /// An entity can be related in several ways at once, e.g. both sibling and spouse.
+    pub fn kinships(&self, a: &MythId, b: &MythId) -> Vec<Kinship> {
+        let mut kinships = Vec::new();
+        if a == b {
+            return kinships;
+        }
+        
+        if let Some(relative) = self.ancestors(b).into_iter().find(|r| &r.id == a) {
+            kinships.push(match relative.generations {
+                Some(1) => Kinship::Parent,
+                Some(2) => Kinship::Grandparent,
+                generations => Kinship::Ancestor { generations },
+            });
+        }
+        if let Some(relative) = self.descendants(b).into_iter().find(|r| &r.id == a) {
+            kinships.push(match relative.generations {
+                Some(1) => Kinship::Child,
+                Some(2) => Kinship::Grandchild,
+                generations => Kinship::Descendant { generations },
+            });
+        }
+        
+        if self.twins(b).contains(a) {
+            kinships.push(Kinship::Twin);
+        } else if self.half_siblings(b).contains(a) {
+            kinships.push(Kinship::HalfSibling);
+        } else if self.siblings(b).contains(a) {
+            kinships.push(Kinship::Sibling);
+        }
+        
+        if self.spouses(b).contains(a) {
+            kinships.push(Kinship::Spouse);
+        }
+        if self.aunts_and_uncles(b).contains(a) {
+            kinships.push(Kinship::AuntOrUncle);
+        }
+        if self.nieces_and_nephews(b).contains(a) {
+            kinships.push(Kinship::NieceOrNephew);
+        }
+        if self.cousins(b).contains(a) {
+            kinships.push(Kinship::Cousin);
+        }
+        
+        kinships
+    }
+    
+    /// Find contradictions in the recorded family relationships
+    pub fn contradictions(&self) -> Vec<GenealogyIssue> {
+        let mut issues: Vec<GenealogyIssue> = self.parent_cycles().into_iter()
+            .map(GenealogyIssue::ParentCycle)
+            .collect();
+        
+        let mut seen: HashSet<(MythId, MythId)> = HashSet::new();
+        for (a, siblings) in self.siblings.iter().chain(&self.twins) {
+            for b in siblings {
+                for (ancestor, descendant) in [(a, b), (b, a)] {
+                    if self.is_ancestor(ancestor, descendant)
+                        && seen.insert((ancestor.clone(), descendant.clone()))
+                    {
+                        issues.push(GenealogyIssue::AncestorIsSibling {
+                            ancestor: ancestor.clone(),
+                            descendant: descendant.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        
+        let mut seen: HashSet<(MythId, MythId)> = HashSet::new();
+        for (a, twins) in &self.twins {
+            let parents_a = self.parents(a);
+            for b in twins {
+                let parents_b = self.parents(b);
+                if !parents_a.is_empty() && !parents_b.is_empty() && parents_a.is_disjoint(&parents_b)
+                    && !seen.contains(&(b.clone(), a.clone()))
+                {
+                    seen.insert((a.clone(), b.clone()));
+                    issues.push(GenealogyIssue::TwinsWithoutSharedParent(a.clone(), b.clone()));
+                }
+            }
+        }
+        
+        issues
+    }
+    
+    /// Build the tree of an entity's ancestors, up to `max_depth` generations
+    pub fn ancestor_tree(&self, id: &MythId, max_depth: Option<usize>) -> FamilyTreeNode {
+        self.tree(id, &self.parents, max_depth, &mut vec![id.clone()])
+    }
+    
+    /// Build the tree of an entity's descendants, up to `max_depth` generations
+    pub fn descendant_tree(&self, id: &MythId, max_depth: Option<usize>) -> FamilyTreeNode {
+        self.tree(id, &self.children, max_depth, &mut vec![id.clone()])
+    }
+    
+    fn tree(
+        &self,
+        id: &MythId,
+        links: &HashMap<MythId, HashSet<MythId>>,
+        max_depth: Option<usize>,
+        path: &mut Vec<MythId>,
+    ) -> FamilyTreeNode {
+        let mut spouses: Vec<String> = self.spouses(id).iter().map(|spouse| self.name(spouse)).collect();
+        spouses.sort();
+        
+        let mut branches = Vec::new();
+        if max_depth.is_none_or(|depth| depth > 0) {
+            for next in self.sorted(lookup(links, id)) {
+                // Stop at a parent cycle rather than recursing forever
+                if path.contains(&next) {
+                    continue;
+                }
+                path.push(next.clone());
+                branches.push(self.tree(&next, links, max_depth.map(|depth| depth - 1), path));
+                path.pop();
+            }
+        }
+        
+        FamilyTreeNode { id: id.clone(), name: self.name(id), spouses, branches }
+    }
+    
+    fn name(&self, id: &MythId) -> String {
+        self.names.get(id).cloned().unwrap_or_else(|| id.to_string())
+    }
+    
+    fn sorted(&self, ids: HashSet<MythId>) -> Vec<MythId> {
+        let mut ids: Vec<MythId> = ids.into_iter().collect();
+        ids.sort_by_cached_key(|id| self.name(id));
+        ids
+    }
+    
+    /// Breadth-first walk over direct links (one generation each) and recorded
+    /// long-range links (unknown number of generations)
+    fn lineage(
+        &self,
+        id: &MythId,
+        direct: &HashMap<MythId, HashSet<MythId>>,
+        indirect: &HashMap<MythId, HashSet<MythId>>,
+    ) -> Vec<Relative> {
+        let mut best: HashMap<MythId, Option<usize>> = HashMap::new();
+        let mut queue: VecDeque<(MythId, Option<usize>)> = VecDeque::from([(id.clone(), Some(0))]);
+        
+        while let Some((current, generations)) = queue.pop_front() {
+            let next = lookup(direct, &current).into_iter().map(|relative| (relative, generations.map(|g| g + 1)))
+                .chain(lookup(indirect, &current).into_iter().map(|relative| (relative, None)));
+            
+            for (relative, generations) in next {
+                if &relative == id {
+                    continue;
+                }
+                // Prefer a known distance over an unknown one, and a shorter one over a longer one
+                let improves = match (best.get(&relative), generations) {
+                    (None, _) => true,
+                    (Some(None), Some(_)) => true,
+                    (Some(Some(known)), Some(new)) => new < *known,
+                    (Some(_), None) => false,
+                };
+                if improves {
+                    best.insert(relative.clone(), generations);
+                    queue.push_back((relative, generations));
+                }
+            }
+        }
+        
+        let mut relatives: Vec<Relative> = best.into_iter()
+            .map(|(id, generations)| Relative { id, generations })
+            .collect();
+        relatives.sort_by_cached_key(|relative| (relative.generations.unwrap_or(usize::MAX), self.name(&relative.id)));
+        relatives
+    }
+    
+    /// Find the cycles in the parent and recorded-ancestor links
+    fn parent_cycles(&self) -> Vec<Vec<MythId>> {
+        let mut cycles = Vec::new();
+        let mut finished: HashSet<MythId> = HashSet::new();
+        
+        let roots = self.sorted(self.children.keys().chain(self.descendants.keys()).cloned().collect());
+        
+        for root in roots {
+            let mut path = Vec::new();
+            self.find_cycles(&root, &mut path, &mut finished, &mut cycles);
+        }
+        cycles
+    }
+    
+    fn find_cycles(
+        &self,
+        id: &MythId,
+        path: &mut Vec<MythId>,
+        finished: &mut HashSet<MythId>,
+        cycles: &mut Vec<Vec<MythId>>,
+    ) {
+        if finished.contains(id) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|entry| entry == id) {
+            cycles.push(path[start..].to_vec());
+            return;
+        }
+        
+        path.push(id.clone());
+        let next = self.sorted(lookup(&self.children, id).into_iter().chain(lookup(&self.descendants, id)).collect());
+        for child in next {
+            self.find_cycles(&child, path, finished, cycles);
+        }
+        path.pop();
+        finished.insert(id.clone());
+    }
+}
+
+fn lookup(map: &HashMap<MythId, HashSet<MythId>>, id: &MythId) -> HashSet<MythId> {
+    map.get(id).cloned().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Deity;
+    
+    struct Family {
+        ontology: MythOntology,
+        ids: HashMap<&'static str, MythId>,
+    }
+    
+    impl Family {
+        fn new(names: &[&'static str]) -> Self {
+            let mut ontology = MythOntology::new();
+            let mut ids = HashMap::new();
+            for name in names {
+                let deity = Deity::new(name, "", "Greek");
+                ids.insert(*name, deity.id.clone());
+                ontology.add_entity(MythEntity::Deity(deity));
+            }
+            Self { ontology, ids }
+        }
+        
+        fn relate(&mut self, source: &str, target: &str, family_type: FamilyRelationshipType) -> &mut Self {
+            self.ontology.add_entity(MythEntity::FamilyRelationship(FamilyRelationship::new(
+                "", "", self.ids[source].clone(), self.ids[target].clone(), family_type,
+            )));
+            self
+        }
+        
+        fn id(&self, name: &str) -> MythId {
+            self.ids[name].clone()
+        }
+        
+        fn set(&self, names: &[&str]) -> HashSet<MythId> {
+            names.iter().map(|name| self.id(name)).collect()
+        }
+    }
+    
+    fn olympians() -> Family {
+        use FamilyRelationshipType::*;
+        let mut family = Family::new(&[
+            "Uranus", "Gaia", "Cronus", "Rhea", "Zeus", "Hera", "Poseidon", "Leto",
+            "Athena", "Apollo", "Artemis", "Ares", "Triton",
+        ]);
+        family
+            .relate("Uranus", "Cronus", Parent)
+            .relate("Gaia", "Cronus", Parent)
+            .relate("Cronus", "Zeus", Parent)
+            .relate("Rhea", "Zeus", Parent)
+            .relate("Zeus", "Rhea", Child)
+            .relate("Cronus", "Poseidon", Parent)
+            .relate("Poseidon", "Rhea", Child)
+            .relate("Hera", "Cronus", Child)
+            .relate("Zeus", "Hera", Spouse)
+            .relate("Zeus", "Athena", Parent)
+            .relate("Zeus", "Apollo", Parent)
+            .relate("Leto", "Apollo", Parent)
+            .relate("Zeus", "Artemis", Parent)
+            .relate("Leto", "Artemis", Parent)
+            .relate("Apollo", "Artemis", Twin)
+            .relate("Zeus", "Ares", Parent)
+            .relate("Hera", "Ares", Parent)
+            .relate("Poseidon", "Triton", Parent);
+        family
+    }
+    
+    #[test]
+    fn direct_and_inferred_relations() {
+        let family = olympians();
+        let genealogy = Genealogy::from_ontology(&family.ontology);
+        
+        assert_eq!(genealogy.parents(&family.id("Zeus")), family.set(&["Cronus", "Rhea"]));
+        assert_eq!(genealogy.grandparents(&family.id("Athena")), family.set(&["Cronus", "Rhea"]));
+        assert_eq!(genealogy.grandchildren(&family.id("Rhea")), family.set(&["Athena", "Apollo", "Artemis", "Ares", "Triton"]));
+        assert_eq!(genealogy.siblings(&family.id("Zeus")), family.set(&["Hera", "Poseidon"]));
+        assert_eq!(genealogy.half_siblings(&family.id("Zeus")), family.set(&["Hera"]));
+        assert_eq!(genealogy.half_siblings(&family.id("Apollo")), family.set(&["Athena", "Ares"]));
+        assert_eq!(genealogy.aunts_and_uncles(&family.id("Athena")), family.set(&["Hera", "Poseidon"]));
+        assert_eq!(genealogy.cousins(&family.id("Athena")), family.set(&["Triton"]));
+        assert_eq!(genealogy.nieces_and_nephews(&family.id("Poseidon")), family.set(&["Athena", "Apollo", "Artemis", "Ares"]));
+        
+        let ancestors = genealogy.ancestors(&family.id("Artemis"));
+        let uranus = ancestors.iter().find(|r| r.id == family.id("Uranus")).unwrap();
+        assert_eq!(uranus.generations, Some(3));
+        assert_eq!(ancestors.first().unwrap().generations, Some(1));
+        assert_eq!(genealogy.descendants(&family.id("Gaia")).len(), 9);
+    }
+    
+    #[test]
+    fn kinships_describe_every_relation() {
+        let family = olympians();
+        let genealogy = Genealogy::from_ontology(&family.ontology);
+        let kinships = |a: &str, b: &str| genealogy.kinships(&family.id(a), &family.id(b));
+        
+        assert_eq!(kinships("Zeus", "Hera"), vec![Kinship::HalfSibling, Kinship::Spouse]);
+        assert_eq!(kinships("Apollo", "Artemis"), vec![Kinship::Twin]);
+        assert_eq!(kinships("Cronus", "Ares"), vec![Kinship::Grandparent]);
+        assert_eq!(kinships("Uranus", "Ares"), vec![Kinship::Ancestor { generations: Some(3) }]);
+        assert_eq!(kinships("Ares", "Rhea"), vec![Kinship::Grandchild]);
+        assert_eq!(kinships("Hera", "Ares"), vec![Kinship::Parent]);
+        assert_eq!(kinships("Poseidon", "Ares"), vec![Kinship::AuntOrUncle]);
+        assert_eq!(kinships("Triton", "Athena"), vec![Kinship::Cousin]);
+        assert!(kinships("Leto", "Hera").is_empty());
+    }
+    
+    #[test]
+    fn recorded_ancestors_have_unknown_distance() {
+        let mut family = Family::new(&["Chaos", "Gaia", "Cronus", "Zeus"]);
+        family
+            .relate("Chaos", "Gaia", FamilyRelationshipType::Ancestor)
+            .relate("Gaia", "Cronus", FamilyRelationshipType::Parent)
+            .relate("Zeus", "Gaia", FamilyRelationshipType::Descendant)
+            .relate("Cronus", "Zeus", FamilyRelationshipType::Parent);
+        let genealogy = Genealogy::from_ontology(&family.ontology);
+        
+        let ancestors = genealogy.ancestors(&family.id("Zeus"));
+        let distance = |name: &str| ancestors.iter().find(|r| r.id == family.id(name)).unwrap().generations;
+        assert_eq!(distance("Cronus"), Some(1));
+        assert_eq!(distance("Gaia"), Some(2));
+        assert_eq!(distance("Chaos"), None);
+        assert_eq!(genealogy.kinships(&family.id("Chaos"), &family.id("Zeus")), vec![Kinship::Ancestor { generations: None }]);
+    }
+    
+    #[test]
+    fn detects_contradictions() {
+        let family = olympians();
+        assert!(Genealogy::from_ontology(&family.ontology).contradictions().is_empty());
+        
+        let mut family = Family::new(&["A", "B", "C", "D", "E", "F"]);
+        family
+            .relate("A", "B", FamilyRelationshipType::Parent)
+            .relate("B", "C", FamilyRelationshipType::Parent)
+            .relate("A", "C", FamilyRelationshipType::Child)
+            .relate("C", "D", FamilyRelationshipType::Sibling)
+            .relate("D", "E", FamilyRelationshipType::Parent)
+            .relate("E", "D", FamilyRelationshipType::Sibling)
+            .relate("A", "F", FamilyRelationshipType::Twin)
+            .relate("E", "F", FamilyRelationshipType::Parent);
+        let issues = Genealogy::from_ontology(&family.ontology).contradictions();
+        
+        assert!(issues.contains(&GenealogyIssue::ParentCycle(vec![family.id("A"), family.id("B"), family.id("C")])));
+        assert!(issues.contains(&GenealogyIssue::AncestorIsSibling { ancestor: family.id("D"), descendant: family.id("E") }));
+        assert!(issues.iter().any(|issue| matches!(issue, GenealogyIssue::TwinsWithoutSharedParent(_, _))));
+        assert_eq!(issues.len(), 3);
+    }
+    
+    #[test]
+    fn renders_family_trees() {
+        let family = olympians();
+        let genealogy = Genealogy::from_ontology(&family.ontology);
+        
+        let tree = genealogy.descendant_tree(&family.id("Cronus"), None);
+        assert_eq!(tree.render(), "\
+Cronus
+├── Hera (m. Zeus)
+│   └── Ares
+├── Poseidon
+│   └── Triton
+└── Zeus (m. Hera)
+    ├── Apollo
+    ├── Ares
+    ├── Artemis
+    └── Athena
+");
+
+        let tree = genealogy.ancestor_tree(&family.id("Zeus"), Some(1));
+        assert_eq!(tree.branches.len(), 2);
+        assert!(tree.branches.iter().all(|branch| branch.branches.is_empty()));
+        assert_eq!(tree.node_count(), 3);
+    }
+}
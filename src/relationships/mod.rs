@@ -2,7 +2,7 @@
 //!
 //! This module provides a unified system for modeling various types of relationships
 //! between mythological entities, such as family connections, alliances, conflicts,
-//! and transformations.
+//! transformations, and identifications across cultures.
 //!
 //! ## Architecture
 //!
@@ -46,9 +46,13 @@ mod family;
 mod conflict;
 mod alliance;
 mod transformation;
+mod syncretism;
+mod genealogy;
 
 pub use relationship::*;
 pub use family::*;
 pub use conflict::*;
 pub use alliance::*;
 pub use transformation::*;
+pub use syncretism::*;
+pub use genealogy::*;
@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::fmt::Debug;
-use crate::core::{MythId, Metadata};
+use crate::core::{MythId, Metadata, variant_names};
 
 /// Common trait for all relationship types
 pub trait Relatable: Debug + Clone {
@@ -151,7 +151,7 @@ impl Relationship {
     
     /// Set the relationship strength
     pub fn set_strength(&mut self, strength: f32) {
-        self.strength = strength.max(0.0).min(1.0);
+        self.strength = strength.clamp(0.0, 1.0);
     }
     
     /// Get the relationship strength
@@ -208,7 +208,7 @@ impl RelationshipBuilder {
 }
 
 /// Type of relationship between entities
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum RelationshipType {
     Family,
     Alliance,
@@ -219,6 +219,9 @@ pub enum RelationshipType {
     Possession,
     Guardianship,
     Teaching,
+    Syncretism,
     Unknown,
     Other(String),
 }
+
+variant_names!(RelationshipType { Family, Alliance, Conflict, Creation, Transformation, Worship, Possession, Guardianship, Teaching, Syncretism, Unknown } else Other);
@@ -0,0 +1,358 @@
+//! # Syncretism
+//!
+//! A [`SyncretismRelationship`] records that two entities of different cultures were
+//! identified with each other: the Roman reading of Greek gods (*interpretatio
+//! romana*), partial identifications such as Zeus with Amun, fusions such as
+//! Amun-Ra, and outright borrowings. It reads "source is the `syncretism_type` of
+//! target", so `Jupiter --Equivalence--> Zeus` records that Jupiter is the Roman
+//! equivalent of Zeus, `Amun --SyncreticFusion--> Amun-Ra` that Amun is one of the
+//! gods fused into Amun-Ra, and `Apollo (Roman) --Borrowing--> Apollo (Greek)` that
+//! the Roman god was borrowed from the Greek one. The texts attesting an
+//! identification are cited by the relationship.
+//!
+//! [`Syncretism`] answers questions over these links, following them transitively
+//! in either direction:
+//!
+//! ```
+//! use mythologic::examples::create_greek_ontology;
+//! use mythologic::relationships::Syncretism;
+//!
+//! let greek = create_greek_ontology();
+//! let zeus = greek.entities_named("Zeus").next().unwrap().id();
+//! let syncretism = Syncretism::new(&greek);
+//!
+//! let equivalents = syncretism.equivalents(zeus);
+//! assert!(equivalents.iter().any(|e| greek.get_entity(&e.id).unwrap().name() == "Jupiter"));
+//! assert_eq!(syncretism.equivalents_in(zeus, &"Roman".into()).len(), 1);
+//! ```
+
+use serde::{Serialize, Deserialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use crate::core::{Citation, CultureId, MythEntity, MythId, MythOntology, variant_names};
+use crate::relationships::{Relationship, RelationshipType, Relatable, Invertible, Properties, RelationshipBuilder};
+
+/// Represents the identification of an entity with one from another culture
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SyncretismRelationship {
+    /// Base relationship
+    pub relationship: Relationship,
+    /// How the two entities were identified
+    pub syncretism_type: SyncretismType,
+    /// Additional properties for extensibility
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+impl SyncretismRelationship {
+    /// Create a new syncretism relationship
+    pub fn new(
+        name: &str,
+        description: &str,
+        source_id: MythId,
+        target_id: MythId,
+        syncretism_type: SyncretismType
+    ) -> Self {
+        let relationship = RelationshipBuilder::new(
+            name,
+            description,
+            source_id,
+            target_id,
+            RelationshipType::Syncretism
+        )
+        .bidirectional(syncretism_type.is_typically_bidirectional())
+        .build();
+        
+        Self {
+            relationship,
+            syncretism_type,
+            properties: HashMap::new(),
+        }
+    }
+    
+    /// Get the syncretism type
+    pub fn syncretism_type(&self) -> &SyncretismType {
+        &self.syncretism_type
+    }
+    
+    /// Set the syncretism type
+    pub fn set_syncretism_type(&mut self, syncretism_type: SyncretismType) {
+        let is_bidirectional = syncretism_type.is_typically_bidirectional();
+        self.syncretism_type = syncretism_type;
+        self.set_bidirectional(is_bidirectional);
+    }
+    
+    /// Get the citations of the sources attesting this identification
+    pub fn attestations(&self) -> &[Citation] {
+        &self.relationship.metadata.citations
+    }
+    
+    /// Cite a source attesting this identification
+    pub fn add_attestation(&mut self, citation: Citation) {
+        self.relationship.metadata.add_citation(citation);
+    }
+    
+    /// Create a builder for configuring a new syncretism relationship
+    pub fn builder(
+        name: &str,
+        description: &str,
+        source_id: MythId,
+        target_id: MythId,
+        syncretism_type: SyncretismType
+    ) -> SyncretismRelationshipBuilder {
+        let is_bidirectional = syncretism_type.is_typically_bidirectional();
+        SyncretismRelationshipBuilder {
+            name: name.to_string(),
+            description: description.to_string(),
+            source_id,
+            target_id,
+            syncretism_type,
+            bidirectional: is_bidirectional,
+            strength: 0.5,
+            attestations: Vec::new(),
+            properties: HashMap::new(),
+        }
+    }
+}
+
+/// Builder for syncretism relationships
+pub struct SyncretismRelationshipBuilder {
+    name: String,
+    description: String,
+    source_id: MythId,
+    target_id: MythId,
+    syncretism_type: SyncretismType,
+    bidirectional: bool,
+    strength: f32,
+    attestations: Vec<Citation>,
+    properties: HashMap<String, String>,
+}
+
+impl SyncretismRelationshipBuilder {
+    /// Set the relationship strength
+    pub fn strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+    
+    /// Set whether the identification was made in both directions
+    pub fn bidirectional(mut self, bidirectional: bool) -> Self {
+        self.bidirectional = bidirectional;
+        self
+    }
+    
+    /// Cite a source attesting the identification
+    pub fn attestation(mut self, citation: Citation) -> Self {
+        self.attestations.push(citation);
+        self
+    }
+    
+    /// Add a custom property
+    pub fn property(mut self, key: &str, value: &str) -> Self {
+        self.properties.insert(key.to_string(), value.to_string());
+        self
+    }
+    
+    /// Build the final relationship
+    pub fn build(self) -> SyncretismRelationship {
+        let mut relationship = RelationshipBuilder::new(
+            &self.name,
+            &self.description,
+            self.source_id,
+            self.target_id,
+            RelationshipType::Syncretism
+        )
+        .bidirectional(self.bidirectional)
+        .strength(self.strength)
+        .build();
+        
+        for citation in self.attestations {
+            relationship.metadata.add_citation(citation);
+        }
+        
+        SyncretismRelationship {
+            relationship,
+            syncretism_type: self.syncretism_type,
+            properties: self.properties,
+        }
+    }
+}
+
+impl Relatable for SyncretismRelationship {
+    fn base(&self) -> &Relationship {
+        &self.relationship
+    }
+    
+    fn base_mut(&mut self) -> &mut Relationship {
+        &mut self.relationship
+    }
+}
+
+impl Properties for SyncretismRelationship {
+    fn get_property(&self, name: &str) -> Option<&str> {
+        self.properties.get(name).map(|s| s.as_str())
+    }
+    
+    fn set_property(&mut self, name: &str, value: &str) {
+        self.properties.insert(name.to_string(), value.to_string());
+    }
+}
+
+/// How two entities of different cultures were identified
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SyncretismType {
+    /// The two are regarded as the same figure under different names
+    Equivalence,
+    /// The two are identified in some respects or cults but not others
+    PartialIdentification,
+    /// The source is one of the figures fused into the target
+    SyncreticFusion,
+    /// The source was borrowed from the target
+    Borrowing,
+    Other(String),
+}
+
+variant_names!(SyncretismType { Equivalence, PartialIdentification, SyncreticFusion, Borrowing } else Other);
+
+impl Invertible for SyncretismType {
+    /// Check if this type of relationship is typically bidirectional
+    fn is_typically_bidirectional(&self) -> bool {
+        matches!(self, Self::Equivalence | Self::PartialIdentification)
+    }
+    
+    /// Get the inverse relationship type
+    fn inverse(&self) -> Self {
+        // Fusions and borrowings have no named inverse
+        self.clone()
+    }
+}
+
+/// An entity identified with another, directly or through other identifications
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Equivalent {
+    /// The identified entity
+    pub id: MythId,
+    /// Its culture, if it has one
+    pub culture: Option<CultureId>,
+    /// The syncretism relationships linking the two, in order
+    pub path: Vec<MythId>,
+    /// Whether every link along the path is an [`SyncretismType::Equivalence`]
+    pub exact: bool,
+}
+
+/// Cross-cultural identifications between the entities of an ontology
+pub struct Syncretism<'a> {
+    ontology: &'a MythOntology,
+    links: HashMap<MythId, Vec<(MythId, &'a SyncretismRelationship)>>,
+}
+
+impl<'a> Syncretism<'a> {
+    /// Collect the syncretism relationships of an ontology
+    pub fn new(ontology: &'a MythOntology) -> Self {
+        let mut links: HashMap<MythId, Vec<(MythId, &SyncretismRelationship)>> = HashMap::new();
+        for entity in ontology.entities_of_type("SyncretismRelationship") {
+            if let MythEntity::SyncretismRelationship(syncretism) = entity {
+                let (source, target) = (syncretism.source_id(), syncretism.target_id());
+                links.entry(source.clone()).or_default().push((target.clone(), syncretism));
+                links.entry(target.clone()).or_default().push((source.clone(), syncretism));
+            }
+        }
+        
+        // Order each entity's links so traversal, and thus the paths found, is stable
+        for entity_links in links.values_mut() {
+            entity_links.sort_by_key(|(other, relationship)| (other.to_string(), relationship.id().to_string()));
+        }
+        
+        Self { ontology, links }
+    }
+    
+    /// The entities directly identified with an entity, with the relationship recording each
+    pub fn identifications(&self, id: &MythId) -> Vec<(&MythId, &'a SyncretismRelationship)> {
+        self.links.get(id)
+            .map(|links| links.iter().map(|(other, relationship)| (other, *relationship)).collect())
+            .unwrap_or_default()
+    }
+    
+    /// Every entity identified with an entity through any chain of syncretism
+    /// relationships, nearest first
+    pub fn equivalents(&self, id: &MythId) -> Vec<Equivalent> {
+        self.equivalents_via(id, |_| true)
+    }
+    
+    /// Every entity identified with an entity through chains of
+    /// [`SyncretismType::Equivalence`] relationships only
+    pub fn exact_equivalents(&self, id: &MythId) -> Vec<Equivalent> {
+        self.equivalents_via(id, |t| *t == SyncretismType::Equivalence)
+    }
+    
+    /// The equivalents of an entity that belong to the given culture
+    pub fn equivalents_in(&self, id: &MythId, culture: &CultureId) -> Vec<Equivalent> {
+        self.equivalents(id).into_iter()
+            .filter(|equivalent| equivalent.culture.as_ref() == Some(culture))
+            .collect()
+    }
+    
+    /// Breadth-first search over the links whose type satisfies `follow`
+    fn equivalents_via(&self, id: &MythId, follow: impl Fn(&SyncretismType) -> bool) -> Vec<Equivalent> {
+        let mut visited: HashSet<&MythId> = HashSet::from([id]);
+        let mut queue: VecDeque<(&MythId, Vec<MythId>, bool)> = VecDeque::from([(id, Vec::new(), true)]);
+        let mut equivalents = Vec::new();
+        
+        while let Some((current, path, exact)) = queue.pop_front() {
+            for (other, relationship) in self.links.get(current).into_iter().flatten() {
+                if !follow(&relationship.syncretism_type) || !visited.insert(other) {
+                    continue;
+                }
+                let mut path = path.clone();
+                path.push(relationship.id().clone());
+                let exact = exact && relationship.syncretism_type == SyncretismType::Equivalence;
+                equivalents.push(Equivalent {
+                    id: other.clone(),
+                    culture: self.ontology.get_entity(other).and_then(|e| e.culture()).cloned(),
+                    path: path.clone(),
+                    exact,
+                });
+                queue.push_back((other, path, exact));
+            }
+        }
+        equivalents
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::Deity;
+    
+    #[test]
+    fn follows_identifications_across_cultures() {
+        let mut ontology = MythOntology::new();
+        let zeus = Deity::new("Zeus", "", "Greek");
+        let jupiter = Deity::new("Jupiter", "", "Roman");
+        let amun = Deity::new("Amun", "", "Egyptian");
+        let amun_ra = Deity::new("Amun-Ra", "", "Egyptian");
+        let ids: Vec<MythId> = [&zeus, &jupiter, &amun, &amun_ra].iter().map(|d| d.id.clone()).collect();
+        for deity in [zeus, jupiter, amun, amun_ra] {
+            ontology.add_entity(MythEntity::Deity(deity));
+        }
+        let (zeus, jupiter, amun, amun_ra) = (&ids[0], &ids[1], &ids[2], &ids[3]);
+        
+        let link = |source: &MythId, target: &MythId, syncretism_type| {
+            MythEntity::SyncretismRelationship(SyncretismRelationship::new("", "", source.clone(), target.clone(), syncretism_type))
+        };
+        ontology.add_entity(link(jupiter, zeus, SyncretismType::Equivalence));
+        ontology.add_entity(link(zeus, amun, SyncretismType::PartialIdentification));
+        ontology.add_entity(link(amun, amun_ra, SyncretismType::SyncreticFusion));
+        
+        let syncretism = Syncretism::new(&ontology);
+        let equivalents = syncretism.equivalents(jupiter);
+        let found: Vec<&MythId> = equivalents.iter().map(|e| &e.id).collect();
+        assert_eq!(found, [zeus, amun, amun_ra]);
+        assert!(equivalents[0].exact && !equivalents[1].exact);
+        assert_eq!(equivalents[2].path.len(), 3);
+        
+        assert_eq!(syncretism.exact_equivalents(amun_ra), []);
+        assert_eq!(syncretism.exact_equivalents(zeus).len(), 1);
+        assert_eq!(syncretism.equivalents_in(amun_ra, &"Roman".into())[0].id, *jupiter);
+        assert_eq!(syncretism.identifications(zeus).len(), 2);
+    }
+}
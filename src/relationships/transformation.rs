@@ -1,6 +1,6 @@
 use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
-use crate::core::MythId;
+use crate::core::{MythId, variant_names};
 use crate::relationships::{Relationship, RelationshipType, Relatable, Invertible, Properties, RelationshipBuilder};
 
 /// Represents a transformation relationship between mythological entities
@@ -233,6 +233,8 @@ pub enum TransformationType {
     Other(String),
 }
 
+variant_names!(TransformationType { Shapeshifting, Petrification, Apotheosis, Curse, Blessing, Reincarnation, Metamorphosis } else Other);
+
 impl Invertible for TransformationType {
     /// Check if this type of relationship is typically bidirectional
     fn is_typically_bidirectional(&self) -> bool {
@@ -1,4 +1,4 @@
-use crate::core::{MythOntology, MythEntity};
+use crate::core::{MythOntology, MythEntity, HistoricalDate, Citation};
 use crate::entities::{Deity, Gender, DeityImportance};
 use crate::cultural::{Pantheon, Culture, TimePeriod};
 use crate::relationships::{FamilyRelationship, FamilyRelationshipType};
@@ -57,25 +57,25 @@ pub fn create_greek_example() -> MythOntology {
     athena.set_pantheon("Olympian");
     athena.add_alternative_name("Minerva");
     
-    // Add source to metadata
-    let source = crate::core::Source {
+    // Add source to the bibliography
+    let source = ontology.add_source(crate::core::Source {
         title: "Theogony".to_string(),
         author: Some("Hesiod".to_string()),
-        year: Some(-700),
+        date: Some(HistoricalDate::Year(-700)),
         source_type: crate::core::SourceType::PrimaryText,
         url: None,
         notes: Some("Ancient Greek poem describing the origins of the gods".to_string()),
-    };
+    });
     
     // Convert to MythEntity enum
     let mut zeus_entity = MythEntity::Deity(zeus);
     let mut hera_entity = MythEntity::Deity(hera);
     let mut athena_entity = MythEntity::Deity(athena);
     
-    // Add source to metadata
-    zeus_entity.metadata_mut().add_source(source.clone());
-    hera_entity.metadata_mut().add_source(source.clone());
-    athena_entity.metadata_mut().add_source(source);
+    // Cite the source in metadata
+    zeus_entity.metadata_mut().add_citation(Citation::new(source.clone()));
+    hera_entity.metadata_mut().add_citation(Citation::new(source.clone()));
+    athena_entity.metadata_mut().add_citation(Citation::new(source));
     
     // Get IDs
     let zeus_id = zeus_entity.id().clone();
@@ -125,15 +125,15 @@ pub fn create_greek_example() -> MythOntology {
     // Add time periods
     greek_culture.add_time_period(TimePeriod {
         name: "Archaic Period".to_string(),
-        start_year: Some(-800),
-        end_year: Some(-480),
+        start: Some(HistoricalDate::Year(-800)),
+        end: Some(HistoricalDate::Year(-480)),
         description: Some("Period of ancient Greek history from the end of the Greek Dark Ages to the Persian Wars".to_string()),
     });
     
     greek_culture.add_time_period(TimePeriod {
         name: "Classical Period".to_string(),
-        start_year: Some(-480),
-        end_year: Some(-323),
+        start: Some(HistoricalDate::Year(-480)),
+        end: Some(HistoricalDate::Year(-323)),
         description: Some("Period of ancient Greek history from the Persian Wars to the death of Alexander the Great".to_string()),
     });
     
@@ -200,25 +200,25 @@ pub fn create_norse_example() -> MythOntology {
     freyja.set_importance(DeityImportance::Major);
     freyja.set_pantheon("Vanir");
     
-    // Add source to metadata
-    let source = crate::core::Source {
+    // Add source to the bibliography
+    let source = ontology.add_source(crate::core::Source {
         title: "Poetic Edda".to_string(),
         author: None,
-        year: Some(1200),
+        date: Some(HistoricalDate::Year(1200)),
         source_type: crate::core::SourceType::PrimaryText,
         url: None,
         notes: Some("Collection of Old Norse poems from the Icelandic medieval manuscript Codex Regius".to_string()),
-    };
+    });
     
     // Convert to MythEntity enum
     let mut odin_entity = MythEntity::Deity(odin);
     let mut thor_entity = MythEntity::Deity(thor);
     let mut freyja_entity = MythEntity::Deity(freyja);
     
-    // Add source to metadata
-    odin_entity.metadata_mut().add_source(source.clone());
-    thor_entity.metadata_mut().add_source(source.clone());
-    freyja_entity.metadata_mut().add_source(source);
+    // Cite the source in metadata
+    odin_entity.metadata_mut().add_citation(Citation::new(source.clone()));
+    thor_entity.metadata_mut().add_citation(Citation::new(source.clone()));
+    freyja_entity.metadata_mut().add_citation(Citation::new(source));
     
     // Get IDs
     let odin_id = odin_entity.id().clone();
@@ -255,8 +255,8 @@ pub fn create_norse_example() -> MythOntology {
     // Add time period
     norse_culture.add_time_period(TimePeriod {
         name: "Viking Age".to_string(),
-        start_year: Some(793),
-        end_year: Some(1066),
+        start: Some(HistoricalDate::Year(793)),
+        end: Some(HistoricalDate::Year(1066)),
         description: Some("Period in European history when Scandinavian Norsemen explored, raided, and settled throughout Europe".to_string()),
     });
     
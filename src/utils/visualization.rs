@@ -1,628 +1,1074 @@
-use crate::core::MythOntology;
-use crate::core::MythEntity;
-use std::collections::{HashMap, HashSet};
-use std::fs::File;
-use std::io::Write;
+//! # HTML Visualization
+//!
+//! Renders an ontology as a single self-contained HTML page: an interactive force
+//! layout of entities and relationships, a searchable entity list, and a detail
+//! panel showing every structured field and source of the selected entity.
+//! Where sources disagree, a selector switches between showing every variant and
+//! keeping only those a [`ResolutionPolicy`] prefers, and another shows entities
+//! under their preferred label in a chosen language.
+//!
+//! The page loads nothing from the network. The layout is plain JavaScript embedded
+//! in the page, a Content-Security-Policy forbids external fetches, and the ontology
+//! is embedded as a JSON data island that the script reads and renders with
+//! `textContent`, so entity text is never interpreted as markup.
+
+use std::collections::HashSet;
+use std::fs;
 use std::path::Path;
+use serde_json::{json, Map, Value};
+use crate::core::{ClaimResolver, MythEntity, MythId, MythOntology, ResolutionPolicy};
+
+/// Title used by [`generate_html_visualization`]
+const DEFAULT_TITLE: &str = "Mythological Ontology Visualization";
 
 /// Generate an HTML visualization of a MythOntology
 pub fn generate_html_visualization(ontology: &MythOntology, output_path: &Path) -> std::io::Result<()> {
-    let entities = ontology.all_entities();
-    let mut file = File::create(output_path)?;
-    
-    // Count entity types
-    let mut entity_type_counts: HashMap<&'static str, usize> = HashMap::new();
-    for entity in &entities {
-        *entity_type_counts.entry(entity.entity_type()).or_insert(0) += 1;
-    }
+    fs::write(output_path, render_html_visualization(ontology, DEFAULT_TITLE))
+}
+
+/// Render an ontology as a self-contained HTML page with the given title
+pub fn render_html_visualization(ontology: &MythOntology, title: &str) -> String {
+    let title = escape_html(title);
+    let data = script_json(&visualization_data(ontology));
+    fill_template(PAGE, &[("TITLE", &title), ("DATA", &data)])
+}
+
+/// Build the JSON data island: graph nodes, graph links, every entity's full
+/// serialized form keyed by ID, the bibliography keyed by source ID, and the
+/// variants and claims each resolution policy rejects
+///
+/// Each node carries the preferred label in every language it has one in.
+fn visualization_data(ontology: &MythOntology) -> Value {
+    let mut entities: Vec<&MythEntity> = ontology.entities().collect();
+    entities.sort_by(|a, b| a.entity_type().cmp(b.entity_type()).then_with(|| a.name().cmp(b.name())));
     
-    // Create connections for graph
-    let mut connections: Vec<(String, String, String)> = Vec::new();
-    let mut processed_entities: HashSet<String> = HashSet::new();
+    let is_node = |id: &MythId| ontology.get_entity(id).is_some_and(|e| !e.is_relationship());
+    let mut details = Map::new();
+    let mut nodes = Vec::new();
+    let mut links = Vec::new();
+    let mut direct_links = HashSet::new();
     
-    // First pass: collect all entities and their relationships
     for entity in &entities {
-        let entity_id = entity.id().to_string();
-        processed_entities.insert(entity_id.clone());
+        let serialized = serde_json::to_value(entity).unwrap_or(Value::Null);
         
-        // Check for relationship vectors in entity
-        for related_id in entity.relationships() {
-            if let Some(related_entity) = ontology.get_entity(&related_id) {
-                connections.push((
-                    entity.id().to_string(),
-                    related_entity.id().to_string(),
-                    format!("{} → {}", entity.entity_type(), related_entity.entity_type())
-                ));
+        if let Some(relationship) = entity.relationship() {
+            if is_node(&relationship.source_id) && is_node(&relationship.target_id) {
+                links.push(json!({
+                    "id": entity.id().to_string(),
+                    "source": relationship.source_id.to_string(),
+                    "target": relationship.target_id.to_string(),
+                    "type": entity.entity_type(),
+                    "kind": entity.relationship_kind(),
+                    "label": entity.name(),
+                    "bidirectional": relationship.bidirectional,
+                    "variant": entity.metadata().variant.as_ref().map(|variant| &variant.question),
+                }));
             }
-        }
-    }
-    
-    // Second pass: extract relationships directly from relationship entities
-    for entity in &entities {
-        match entity {
-            MythEntity::FamilyRelationship(rel) => {
-                let source_id = rel.relationship.source_id.clone();
-                let target_id = rel.relationship.target_id.clone();
-                
-                if let (Some(source), Some(target)) = (ontology.get_entity(&source_id), ontology.get_entity(&target_id)) {
-                    connections.push((
-                        source.id().to_string(),
-                        target.id().to_string(),
-                        format!("{} → Family", entity.name())
-                    ));
-                    
-                    // If bidirectional, add the reverse relationship too
-                    if rel.relationship.bidirectional {
-                        connections.push((
-                            target.id().to_string(),
-                            source.id().to_string(),
-                            format!("{} → Family", entity.name())
-                        ));
-                    }
-                }
-            },
-            MythEntity::AllianceRelationship(rel) => {
-                let source_id = rel.relationship.source_id.clone();
-                let target_id = rel.relationship.target_id.clone();
-                
-                if let (Some(source), Some(target)) = (ontology.get_entity(&source_id), ontology.get_entity(&target_id)) {
-                    connections.push((
-                        source.id().to_string(),
-                        target.id().to_string(),
-                        format!("{} → Alliance", entity.name())
-                    ));
-                    
-                    // If bidirectional, add the reverse relationship too
-                    if rel.relationship.bidirectional {
-                        connections.push((
-                            target.id().to_string(),
-                            source.id().to_string(),
-                            format!("{} → Alliance", entity.name())
-                        ));
-                    }
-                }
-            },
-            MythEntity::ConflictRelationship(rel) => {
-                let source_id = rel.relationship.source_id.clone();
-                let target_id = rel.relationship.target_id.clone();
-                
-                if let (Some(source), Some(target)) = (ontology.get_entity(&source_id), ontology.get_entity(&target_id)) {
-                    connections.push((
-                        source.id().to_string(),
-                        target.id().to_string(),
-                        format!("{} → Conflict", entity.name())
-                    ));
+        } else {
+            let labels: Map<String, Value> = entity.metadata().labels.iter()
+                .map(|label| &label.language)
+                .map(|language| (language.to_string(), json!(entity.name_in(language))))
+                .collect();
+            nodes.push(json!({
+                "id": entity.id().to_string(),
+                "name": entity.name(),
+                "names": entity.alternative_names(),
+                "labels": labels,
+                "type": entity.entity_type(),
+                "culture": entity.culture_name(),
+                "description": serialized["data"]["description"],
+            }));
+            
+            // Direct links stored on the entity itself, drawn once per pair
+            for related in entity.relationships() {
+                if !is_node(&related) {
+                    continue;
                 }
-            },
-            MythEntity::TransformationRelationship(rel) => {
-                let source_id = rel.relationship.source_id.clone();
-                let target_id = rel.relationship.target_id.clone();
-                
-                if let (Some(source), Some(target)) = (ontology.get_entity(&source_id), ontology.get_entity(&target_id)) {
-                    connections.push((
-                        source.id().to_string(),
-                        target.id().to_string(),
-                        format!("{} → Transformation", entity.name())
-                    ));
+                let (source, target) = (entity.id().to_string(), related.to_string());
+                let pair = if source < target { (source.clone(), target.clone()) } else { (target.clone(), source.clone()) };
+                if direct_links.insert(pair) {
+                    links.push(json!({
+                        "source": source,
+                        "target": target,
+                        "type": "Direct",
+                        "label": "Related",
+                        "direct": true,
+                    }));
                 }
-            },
-            _ => {}
+            }
         }
+        
+        details.insert(entity.id().to_string(), serialized);
     }
     
-    // Generate HTML content
-    let html_content = format!(r#"<!DOCTYPE html>
+    let sources: Map<String, Value> = ontology.bibliography().iter()
+        .map(|(id, source)| (id.to_string(), serde_json::to_value(source).unwrap_or(Value::Null)))
+        .collect();
+    
+    let resolutions: Map<String, Value> = ResolutionPolicy::PREFERENCES.iter()
+        .map(|&policy| {
+            let resolver = ClaimResolver::new(ontology, policy);
+            let mut variants: Vec<String> = resolver.rejected_variants().iter().map(ToString::to_string).collect();
+            variants.sort();
+            let claims: Map<String, Value> = entities.iter()
+                .map(|entity| (entity.id().to_string(), resolver.rejected_claims(entity.metadata())))
+                .filter(|(_, rejected)| !rejected.is_empty())
+                .map(|(id, rejected)| (id, json!(rejected)))
+                .collect();
+            (policy.to_string(), json!({ "variants": variants, "claims": claims }))
+        })
+        .collect();
+    
+    json!({ "nodes": nodes, "links": links, "entities": details, "sources": sources, "resolutions": resolutions })
+}
+
+/// Escape text for use in HTML element content or attribute values
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Serialize JSON for embedding in a `<script>` element
+///
+/// Markup characters are written as `\u` escapes, which are only valid inside JSON
+/// strings, so the data cannot close the element or open a comment.
+fn script_json(value: &Value) -> String {
+    let json = value.to_string();
+    let mut escaped = String::with_capacity(json.len());
+    for c in json.chars() {
+        match c {
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            '\u{2028}' => escaped.push_str("\\u2028"),
+            '\u{2029}' => escaped.push_str("\\u2029"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Substitute `%%KEY%%` placeholders in a single pass, so substituted values are
+/// never themselves searched for placeholders
+fn fill_template(template: &str, values: &[(&str, &str)]) -> String {
+    let mut output = String::with_capacity(template.len() + values.iter().map(|(_, v)| v.len()).sum::<usize>());
+    let mut rest = template;
+    while let Some(start) = rest.find("%%") {
+        output.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+        let end = after.find("%%").expect("unterminated template placeholder");
+        let key = &after[..end];
+        let value = values.iter()
+            .find(|(k, _)| *k == key)
+            .unwrap_or_else(|| panic!("unknown template placeholder {}", key));
+        output.push_str(value.1);
+        rest = &after[end + 2..];
+    }
+    output.push_str(rest);
+    output
+}
+
+/// The page template; `%%TITLE%%` and `%%DATA%%` are filled in by [`fill_template`]
+const PAGE: &str = r##"<!DOCTYPE html>
 <html lang="en">
 <head>
     <meta charset="UTF-8">
+    <meta http-equiv="Content-Security-Policy" content="default-src 'none'; style-src 'unsafe-inline'; script-src 'unsafe-inline'">
     <meta name="viewport" content="width=device-width, initial-scale=1.0">
-    <title>Myth Ontology Visualization</title>
+    <title>%%TITLE%%</title>
     <style>
-        body {{
+        * { box-sizing: border-box; }
+        body {
             font-family: 'Segoe UI', Tahoma, Geneva, Verdana, sans-serif;
             margin: 0;
-            padding: 0;
             color: #333;
             background-color: #f9f9f9;
-        }}
-        .container {{
-            width: 95%;
-            margin: 0 auto;
-            padding: 20px;
-        }}
-        h1, h2, h3 {{
-            color: #2c3e50;
-        }}
-        .summary-card {{
-            background: white;
-            border-radius: 8px;
-            box-shadow: 0 4px 6px rgba(0,0,0,0.1);
-            padding: 20px;
-            margin-bottom: 20px;
-        }}
-        .entity-grid {{
-            display: grid;
-            grid-template-columns: repeat(auto-fill, minmax(300px, 1fr));
-            gap: 20px;
-            margin-top: 20px;
-        }}
-        .entity-card {{
-            background: white;
-            border-radius: 8px;
-            box-shadow: 0 4px 6px rgba(0,0,0,0.1);
-            padding: 15px;
-            transition: transform 0.3s ease;
-        }}
-        .entity-card:hover {{
-            transform: translateY(-5px);
-        }}
-        .entity-type {{
-            font-size: 0.8em;
-            color: #7f8c8d;
-            text-transform: uppercase;
-            letter-spacing: 1px;
-            margin-bottom: 5px;
-        }}
-        .entity-name {{
-            font-size: 1.2em;
-            font-weight: bold;
-            margin-bottom: 10px;
-            color: #2980b9;
-        }}
-        .entity-description {{
-            font-size: 0.9em;
-            line-height: 1.5;
-        }}
-        .entity-metadata {{
-            font-size: 0.8em;
-            margin-top: 10px;
-            color: #7f8c8d;
-        }}
-        .chart-container {{
-            width: 100%;
-            height: 300px;
-            margin-bottom: 20px;
-        }}
-        .tabs {{
-            display: flex;
-            border-bottom: 1px solid #ddd;
-            margin-bottom: 20px;
-        }}
-        .tab {{
+        }
+        header { padding: 20px 20px 0; }
+        h1, h2, h3 { color: #2c3e50; margin: 0 0 8px; }
+        .legend { display: flex; flex-wrap: wrap; gap: 12px; margin: 10px 0; font-size: 0.9em; }
+        .legend-item { display: flex; align-items: center; }
+        .legend-color { width: 14px; height: 14px; border-radius: 50%; margin-right: 5px; }
+        .tabs { display: flex; border-bottom: 1px solid #ddd; padding: 0 20px; }
+        .tab {
             padding: 10px 20px;
             cursor: pointer;
             border: 1px solid transparent;
-        }}
-        .tab.active {{
+            background: none;
+            font: inherit;
+        }
+        .tab.active {
             border: 1px solid #ddd;
-            border-bottom: 1px solid white;
+            border-bottom: 1px solid #f9f9f9;
             border-radius: 5px 5px 0 0;
             margin-bottom: -1px;
-            background-color: white;
-        }}
-        .tab-content {{
-            display: none;
-        }}
-        .tab-content.active {{
-            display: block;
-        }}
-        .relationship-list {{
-            list-style-type: none;
-            padding: 0;
-        }}
-        .relationship-list li {{
-            padding: 5px 0;
-            border-bottom: 1px dashed #eee;
-        }}
-        .relationship-list li:last-child {{
-            border-bottom: none;
-        }}
-        .search-container {{
-            margin-bottom: 20px;
-        }}
-        #searchInput {{
+            background-color: #f9f9f9;
+        }
+        .layout { display: flex; gap: 20px; padding: 20px; align-items: flex-start; }
+        main { flex: 1; min-width: 0; }
+        .tab-content { display: none; }
+        .tab-content.active { display: block; }
+        .toolbar { display: flex; gap: 10px; margin-bottom: 12px; }
+        .toolbar input { flex: 1; padding: 8px; border: 1px solid #ddd; border-radius: 4px; font-size: 15px; }
+        .locale { font-size: 0.9em; }
+        .locale select, .toolbar select { padding: 8px; border: 1px solid #ddd; border-radius: 4px; background: white; }
+        .toolbar button { padding: 8px 12px; border: 1px solid #ddd; border-radius: 4px; background: white; cursor: pointer; }
+        svg {
             width: 100%;
-            padding: 10px;
+            height: 70vh;
             border: 1px solid #ddd;
-            border-radius: 4px;
-            font-size: 16px;
-        }}
-        .culture-pill {{
+            border-radius: 8px;
+            background-color: white;
+            cursor: grab;
+            touch-action: none;
+            user-select: none;
+        }
+        .node { cursor: pointer; }
+        .node circle { stroke: white; stroke-width: 1.5; }
+        .node text { font-size: 11px; fill: #333; pointer-events: none; }
+        .node.match circle { stroke: #2c3e50; stroke-width: 3; }
+        .node.selected circle { stroke: #000; stroke-width: 3; }
+        .link { stroke-width: 1.5; stroke-opacity: 0.7; cursor: pointer; }
+        .link.direct { stroke-dasharray: 2 3; stroke-opacity: 0.4; }
+        .link.variant { stroke-dasharray: 8 4; }
+        .link.rejected { display: none; }
+        .faded { opacity: 0.12; }
+        .entity-grid { display: grid; grid-template-columns: repeat(auto-fill, minmax(260px, 1fr)); gap: 16px; }
+        .entity-card {
+            background: white;
+            border-radius: 8px;
+            box-shadow: 0 2px 5px rgba(0,0,0,0.1);
+            padding: 14px;
+            cursor: pointer;
+            border-left: 5px solid #95a5a6;
+        }
+        .entity-type { font-size: 0.75em; color: #7f8c8d; text-transform: uppercase; letter-spacing: 1px; }
+        .entity-name { font-size: 1.15em; font-weight: bold; color: #2980b9; margin: 4px 0 8px; }
+        .entity-description { font-size: 0.9em; line-height: 1.5; }
+        .culture-pill {
             display: inline-block;
             background-color: #3498db;
             color: white;
-            padding: 2px 8px;
+            padding: 1px 8px;
             border-radius: 12px;
-            font-size: 0.75em;
-            margin-right: 5px;
-        }}
-        svg {{
-            width: 100%;
-            height: 600px;
-            border: 1px solid #ddd;
+            font-size: 0.9em;
+            margin-left: 5px;
+            text-transform: none;
+            letter-spacing: 0;
+        }
+        .details {
+            width: 380px;
+            flex-shrink: 0;
+            background: white;
             border-radius: 8px;
-            background-color: white;
-        }}
-        .node {{
-            cursor: pointer;
-        }}
-        .node text {{
-            font-size: 12px;
-        }}
-        .link {{
-            stroke: #999;
-            stroke-opacity: 0.6;
-        }}
-        .node-deity {{ fill: #e74c3c; }}
-        .node-hero {{ fill: #3498db; }}
-        .node-creature {{ fill: #2ecc71; }}
-        .node-artifact {{ fill: #f39c12; }}
-        .node-location {{ fill: #9b59b6; }}
-        .node-concept {{ fill: #1abc9c; }}
-        .node-culture {{ fill: #34495e; }}
-        .node-pantheon {{ fill: #16a085; }}
-        .node-relationship {{ fill: #95a5a6; }}
-        .legend {{
-            display: flex;
-            flex-wrap: wrap;
-            margin-top: 10px;
-            gap: 10px;
-        }}
-        .legend-item {{
-            display: flex;
-            align-items: center;
-            margin-right: 15px;
-        }}
-        .legend-color {{
-            width: 15px;
-            height: 15px;
-            border-radius: 50%;
-            margin-right: 5px;
-        }}
+            box-shadow: 0 2px 5px rgba(0,0,0,0.1);
+            padding: 16px;
+            max-height: 85vh;
+            overflow-y: auto;
+            font-size: 0.9em;
+        }
+        .details .hint { color: #7f8c8d; }
+        .details dl { margin: 0 0 12px; }
+        .details dt { font-weight: bold; color: #2c3e50; margin-top: 6px; }
+        .details dd { margin: 2px 0 0 12px; word-wrap: break-word; }
+        .details dl.nested dt { font-weight: normal; font-style: italic; }
+        .details ul, .details ol { margin: 2px 0; padding-left: 18px; }
+        .details h3 { margin-top: 16px; font-size: 1em; border-bottom: 1px solid #eee; padding-bottom: 4px; }
+        .details .muted { color: #95a5a6; }
+        a.entity-link { color: #2980b9; cursor: pointer; text-decoration: underline; }
     </style>
 </head>
 <body>
-    <div class="container">
-        <h1>Mythological Ontology Visualization</h1>
-        
-        <div class="summary-card">
-            <h2>Ontology Summary</h2>
-            <p>Total entities: <strong>{}</strong></p>
-            <div class="legend">
-                {}
-            </div>
-        </div>
-
-        <div class="tabs">
-            <div class="tab active" onclick="openTab(event, 'graph')">Relationship Graph</div>
-            <div class="tab" onclick="openTab(event, 'entities')">Entity List</div>
-        </div>
-
-        <div id="graph" class="tab-content active">
-            <div class="search-container">
-                <input type="text" id="searchInput" placeholder="Search entities..." onkeyup="filterGraph()">
-            </div>
-            <svg id="ontology-graph"></svg>
-        </div>
-
-        <div id="entities" class="tab-content">
-            <div class="search-container">
-                <input type="text" id="entitySearchInput" placeholder="Search entities..." onkeyup="filterEntities()">
-            </div>
-            <div class="entity-grid" id="entityGrid">
-                {}
-            </div>
-        </div>
+    <header>
+        <h1>%%TITLE%%</h1>
+        <div id="summary"></div>
+        <label id="locale-choice" class="locale">Names in
+            <select id="locale">
+                <option value="">Primary names</option>
+            </select>
+        </label>
+        <div id="legend" class="legend"></div>
+    </header>
+    
+    <nav class="tabs">
+        <button class="tab active" data-tab="graph">Relationship Graph</button>
+        <button class="tab" data-tab="entities">Entity List</button>
+    </nav>
+    
+    <div class="layout">
+        <main>
+            <section id="graph" class="tab-content active">
+                <div class="toolbar">
+                    <input type="search" id="graph-search" placeholder="Search entities...">
+                    <select id="resolution" title="Conflicting variants">
+                        <option value="">Show all variants</option>
+                    </select>
+                    <button id="reset-view">Reset view</button>
+                </div>
+                <svg id="ontology-graph" viewBox="0 0 1000 700">
+                    <g id="viewport"><g id="links"></g><g id="nodes"></g></g>
+                </svg>
+            </section>
+            
+            <section id="entities" class="tab-content">
+                <div class="toolbar">
+                    <input type="search" id="entity-search" placeholder="Search entities...">
+                </div>
+                <div id="entity-grid" class="entity-grid"></div>
+            </section>
+        </main>
+        
+        <aside id="details" class="details">
+            <p class="hint">Select an entity or relationship to see its details.</p>
+        </aside>
     </div>
-
+    
+    <script type="application/json" id="ontology-data">%%DATA%%</script>
     <script>
-        // Tab functionality
-        function openTab(evt, tabName) {{
-            var i, tabcontent, tablinks;
-            tabcontent = document.getElementsByClassName("tab-content");
-            for (i = 0; i < tabcontent.length; i++) {{
-                tabcontent[i].className = tabcontent[i].className.replace(" active", "");
-            }}
-            tablinks = document.getElementsByClassName("tab");
-            for (i = 0; i < tablinks.length; i++) {{
-                tablinks[i].className = tablinks[i].className.replace(" active", "");
-            }}
-            document.getElementById(tabName).className += " active";
-            evt.currentTarget.className += " active";
+    (function () {
+        "use strict";
+        
+        var data = JSON.parse(document.getElementById("ontology-data").textContent);
+        var SVG_NS = "http://www.w3.org/2000/svg";
+        var WIDTH = 1000, HEIGHT = 700;
+        var NODE_COLORS = {
+            Deity: "#e74c3c", Hero: "#3498db", Creature: "#2ecc71", Artifact: "#f39c12",
+            Location: "#9b59b6", Concept: "#1abc9c", Culture: "#34495e", Pantheon: "#16a085",
+            Narrative: "#c0392b", Event: "#e67e22", MythologicalEra: "#d35400", MythologicalRegion: "#7f8c8d"
+        };
+        var NODE_SIZES = { Deity: 13, Culture: 13, Pantheon: 12, Hero: 11, Location: 11 };
+        var LINK_COLORS = {
+            FamilyRelationship: "#c0392b", AllianceRelationship: "#27ae60",
+            ConflictRelationship: "#e67e22", TransformationRelationship: "#8e44ad",
+            SyncretismRelationship: "#2980b9"
+        };
+        
+        function nodeColor(type) { return NODE_COLORS[type] || "#95a5a6"; }
+        function nodeSize(type) { return NODE_SIZES[type] || 9; }
+        
+        function el(tag, className, text) {
+            var element = document.createElement(tag);
+            if (className) element.className = className;
+            if (text !== undefined && text !== null) element.textContent = String(text);
+            return element;
+        }
+        
+        function svgEl(tag, attributes) {
+            var element = document.createElementNS(SVG_NS, tag);
+            Object.keys(attributes || {}).forEach(function (key) { element.setAttribute(key, attributes[key]); });
+            return element;
+        }
+        
+        function fieldLabel(key) {
+            var words = key.replace(/_/g, " ");
+            return words.charAt(0).toUpperCase() + words.slice(1);
+        }
+        
+        // Relationship variants keep their name, description and metadata in a nested
+        // base relationship
+        function baseFields(entity) {
+            return entity.data.relationship || entity.data;
+        }
+        
+        function entityName(id) {
+            var node = nodeIndex[id];
+            if (locale && node && node.data.labels[locale]) return node.data.labels[locale];
+            var entity = data.entities[id];
+            return entity ? baseFields(entity).name : id;
+        }
+        
+        function entityLink(id) {
+            var link = el("a", "entity-link", entityName(id));
+            link.addEventListener("click", function () { showDetails(id); });
+            return link;
+        }
+        
+        // Summary and legend
+        
+        var counts = {};
+        data.nodes.forEach(function (node) { counts[node.type] = (counts[node.type] || 0) + 1; });
+        document.getElementById("summary").textContent =
+            "Total entities: " + data.nodes.length + " · Connections: " + data.links.length;
+        var legend = document.getElementById("legend");
+        Object.keys(counts).sort().forEach(function (type) {
+            var item = el("span", "legend-item");
+            var swatch = el("span", "legend-color");
+            swatch.style.backgroundColor = nodeColor(type);
+            item.appendChild(swatch);
+            item.appendChild(document.createTextNode(type + ": " + counts[type]));
+            legend.appendChild(item);
+        });
+        
+        document.querySelectorAll(".tab").forEach(function (tab) {
+            tab.addEventListener("click", function () { openTab(tab.dataset.tab); });
+        });
+        
+        function openTab(name) {
+            document.querySelectorAll(".tab, .tab-content").forEach(function (element) {
+                element.classList.remove("active");
+            });
+            document.querySelector('.tab[data-tab="' + name + '"]').classList.add("active");
+            document.getElementById(name).classList.add("active");
+        }
+        
+        // Force layout
+        
+        var nodes = data.nodes.map(function (node, i) {
+            var angle = i * 2.39996, radius = 14 * Math.sqrt(i + 1);
+            return {
+                data: node,
+                x: WIDTH / 2 + radius * Math.cos(angle),
+                y: HEIGHT / 2 + radius * Math.sin(angle),
+                vx: 0, vy: 0, fixed: false
+            };
+        });
+        var nodeIndex = {};
+        nodes.forEach(function (node, i) { nodeIndex[node.data.id] = node; });
+        var links = data.links.map(function (link) {
+            return { data: link, source: nodeIndex[link.source], target: nodeIndex[link.target] };
+        });
+        
+        var alpha = 1, running = false;
+        
+        function tick() {
+            var i, j, a, b, dx, dy, d2, d, force;
+            for (i = 0; i < nodes.length; i++) {
+                a = nodes[i];
+                for (j = i + 1; j < nodes.length; j++) {
+                    b = nodes[j];
+                    dx = b.x - a.x;
+                    dy = b.y - a.y;
+                    d2 = dx * dx + dy * dy;
+                    if (d2 < 0.01) {
+                        dx = Math.random() - 0.5;
+                        dy = Math.random() - 0.5;
+                        d2 = dx * dx + dy * dy;
+                    }
+                    if (d2 > 160000) continue;
+                    force = 800 * alpha / d2;
+                    a.vx -= dx * force; a.vy -= dy * force;
+                    b.vx += dx * force; b.vy += dy * force;
+                }
+            }
+            links.forEach(function (link) {
+                dx = link.target.x - link.source.x;
+                dy = link.target.y - link.source.y;
+                d = Math.sqrt(dx * dx + dy * dy) || 1;
+                force = (d - 90) / d * 0.06 * alpha;
+                link.source.vx += dx * force; link.source.vy += dy * force;
+                link.target.vx -= dx * force; link.target.vy -= dy * force;
+            });
+            nodes.forEach(function (node) {
+                if (node.fixed) {
+                    node.vx = node.vy = 0;
+                    return;
+                }
+                node.vx = (node.vx + (WIDTH / 2 - node.x) * 0.004 * alpha) * 0.6;
+                node.vy = (node.vy + (HEIGHT / 2 - node.y) * 0.004 * alpha) * 0.6;
+                node.x += node.vx;
+                node.y += node.vy;
+            });
+            alpha *= 0.985;
+        }
+        
+        function restart(minimumAlpha) {
+            alpha = Math.max(alpha, minimumAlpha);
+            if (!running) {
+                running = true;
+                requestAnimationFrame(frame);
+            }
+        }
+        
+        function frame() {
+            tick();
+            draw();
+            if (alpha > 0.005 || drag) {
+                requestAnimationFrame(frame);
+            } else {
+                running = false;
+            }
+        }
+        
+        // Rendering
+        
+        var svg = document.getElementById("ontology-graph");
+        var viewport = document.getElementById("viewport");
+        var linkLayer = document.getElementById("links");
+        var nodeLayer = document.getElementById("nodes");
+        var drag = null, selected = null;
+        var view = { x: 0, y: 0, k: 1 };
+        
+        var linkElements = links.map(function (link) {
+            var line = svgEl("line", {
+                "class": link.data.direct ? "link direct" : link.data.variant ? "link variant" : "link",
+                stroke: LINK_COLORS[link.data.type] || "#999"
+            });
+            var title = svgEl("title");
+            title.textContent = link.data.label + (link.data.kind ? " (" + link.data.kind + ")" : "") +
+                (link.data.variant ? " — variant of " + link.data.variant : "");
+            line.appendChild(title);
+            line.addEventListener("pointerdown", function (event) { startDrag(event, { link: link }); });
+            linkLayer.appendChild(line);
+            return line;
+        });
+        
+        var nodeElements = nodes.map(function (node) {
+            var size = nodeSize(node.data.type);
+            var group = svgEl("g", { "class": "node" });
+            var circle = svgEl("circle", { r: size, fill: nodeColor(node.data.type) });
+            var title = svgEl("title");
+            title.textContent = node.data.name + " (" + node.data.type + ")";
+            circle.appendChild(title);
+            var label = svgEl("text", { x: size + 4, dy: "0.35em" });
+            label.textContent = node.data.name;
+            group.appendChild(circle);
+            group.appendChild(label);
+            node.label = label;
+            group.addEventListener("pointerdown", function (event) { startDrag(event, { node: node }); });
+            nodeLayer.appendChild(group);
+            node.element = group;
+            return group;
+        });
+        
+        function draw() {
+            links.forEach(function (link, i) {
+                var line = linkElements[i];
+                line.setAttribute("x1", link.source.x);
+                line.setAttribute("y1", link.source.y);
+                line.setAttribute("x2", link.target.x);
+                line.setAttribute("y2", link.target.y);
+            });
+            nodes.forEach(function (node, i) {
+                nodeElements[i].setAttribute("transform", "translate(" + node.x + "," + node.y + ")");
+            });
+        }
+        
+        function applyView() {
+            viewport.setAttribute("transform", "translate(" + view.x + "," + view.y + ") scale(" + view.k + ")");
+        }
+        
+        // Pointer position in viewBox coordinates, before panning and zooming
+        function svgPoint(event) {
+            return new DOMPoint(event.clientX, event.clientY).matrixTransform(svg.getScreenCTM().inverse());
+        }
+        
+        // Dragging, panning, zooming and selection
+        
+        function startDrag(event, target) {
+            event.stopPropagation();
+            var point = svgPoint(event);
+            drag = target;
+            drag.startX = event.clientX;
+            drag.startY = event.clientY;
+            drag.offsetX = point.x - view.x;
+            drag.offsetY = point.y - view.y;
+            drag.moved = false;
+            svg.setPointerCapture(event.pointerId);
+            if (drag.node) {
+                drag.node.fixed = true;
+            }
+        }
+        
+        svg.addEventListener("pointerdown", function (event) { startDrag(event, { pan: true }); });
+        
+        svg.addEventListener("pointermove", function (event) {
+            if (!drag) return;
+            if (Math.abs(event.clientX - drag.startX) + Math.abs(event.clientY - drag.startY) > 3) {
+                drag.moved = true;
+            }
+            if (!drag.moved) return;
+            var point = svgPoint(event);
+            if (drag.node) {
+                drag.node.x = (point.x - view.x) / view.k;
+                drag.node.y = (point.y - view.y) / view.k;
+                restart(0.3);
+            } else if (drag.pan) {
+                view.x = point.x - drag.offsetX;
+                view.y = point.y - drag.offsetY;
+                applyView();
+            }
+        });
+        
+        svg.addEventListener("pointerup", function () {
+            if (!drag) return;
+            if (drag.node) {
+                drag.node.fixed = false;
+            }
+            if (!drag.moved) {
+                if (drag.node) showDetails(drag.node.data.id);
+                if (drag.link && drag.link.data.id) showDetails(drag.link.data.id);
+            }
+            drag = null;
+        });
+        
+        svg.addEventListener("wheel", function (event) {
+            event.preventDefault();
+            var point = svgPoint(event);
+            var k = Math.min(8, Math.max(0.2, view.k * (event.deltaY < 0 ? 1.15 : 1 / 1.15)));
+            view.x = point.x - (point.x - view.x) * k / view.k;
+            view.y = point.y - (point.y - view.y) * k / view.k;
+            view.k = k;
+            applyView();
+        }, { passive: false });
+        
+        document.getElementById("reset-view").addEventListener("click", function () {
+            view = { x: 0, y: 0, k: 1 };
+            applyView();
+            restart(0.5);
+        });
+        
+        // Resolution of conflicting variants
+        
+        var NOTHING_REJECTED = { variants: [], claims: {} };
+        var rejected = NOTHING_REJECTED, current = null;
+        
+        function isRejected(id) { return rejected.variants.indexOf(id) > -1; }
+        function isRejectedClaim(id, index) { return (rejected.claims[id] || []).indexOf(index) > -1; }
+        
+        function policyLabel(policy) {
+            return policy.replace(/[A-Z]/g, function (letter, i) { return i ? " " + letter.toLowerCase() : letter; });
+        }
+        
+        var resolution = document.getElementById("resolution");
+        var contested = data.links.some(function (link) { return link.variant; }) ||
+            Object.keys(data.entities).some(function (id) {
+                return (baseFields(data.entities[id]).metadata || {}).claims;
+            });
+        if (contested) {
+            Object.keys(data.resolutions).forEach(function (policy) {
+                var option = el("option", null, policyLabel(policy));
+                option.value = policy;
+                resolution.appendChild(option);
+            });
+        } else {
+            resolution.style.display = "none";
+        }
+        resolution.addEventListener("change", function () {
+            rejected = data.resolutions[resolution.value] || NOTHING_REJECTED;
+            links.forEach(function (link, i) {
+                linkElements[i].classList.toggle("rejected", !!link.data.id && isRejected(link.data.id));
+            });
+            if (current) showDetails(current);
+        });
+        
+        // Names in other languages
+        
+        var locale = "";
+        var languages = {};
+        data.nodes.forEach(function (node) {
+            Object.keys(node.labels).forEach(function (language) { languages[language] = true; });
+        });
+        var localeSelect = document.getElementById("locale");
+        Object.keys(languages).sort().forEach(function (language) {
+            var option = el("option", null, language);
+            option.value = language;
+            localeSelect.appendChild(option);
+        });
+        if (!Object.keys(languages).length) {
+            document.getElementById("locale-choice").style.display = "none";
+        }
+        localeSelect.addEventListener("change", function () {
+            locale = localeSelect.value;
+            nodes.forEach(function (node) { node.label.textContent = entityName(node.data.id); });
+            data.nodes.forEach(function (node, i) { cardNames[i].textContent = entityName(node.id); });
+            if (current) showDetails(current);
+        });
+        
+        // Search
+        
+        function matches(node, query) {
+            return [node.name].concat(node.names || []).some(function (name) {
+                return name.toLowerCase().indexOf(query) > -1;
+            });
+        }
+        
+        document.getElementById("graph-search").addEventListener("input", function (event) {
+            var query = event.target.value.trim().toLowerCase();
+            var matched = {}, visible = {};
+            nodes.forEach(function (node) {
+                if (query && matches(node.data, query)) {
+                    matched[node.data.id] = visible[node.data.id] = true;
+                }
+            });
+            links.forEach(function (link, i) {
+                var hit = matched[link.source.data.id] || matched[link.target.data.id];
+                if (hit) {
+                    visible[link.source.data.id] = visible[link.target.data.id] = true;
+                }
+                linkElements[i].classList.toggle("faded", !!query && !hit);
+            });
+            nodes.forEach(function (node, i) {
+                nodeElements[i].classList.toggle("faded", !!query && !visible[node.data.id]);
+                nodeElements[i].classList.toggle("match", !!matched[node.data.id]);
+            });
+        });
+        
+        // Entity list
+        
+        var grid = document.getElementById("entity-grid");
+        var cardNames = [];
+        var cards = data.nodes.map(function (node) {
+            var card = el("div", "entity-card");
+            card.style.borderLeftColor = nodeColor(node.type);
+            var type = el("div", "entity-type", node.type);
+            if (node.culture) {
+                type.appendChild(el("span", "culture-pill", node.culture));
+            }
+            card.appendChild(type);
+            var name = el("div", "entity-name", node.name);
+            cardNames.push(name);
+            card.appendChild(name);
+            card.appendChild(el("div", "entity-description", node.description || "No description available."));
+            card.addEventListener("click", function () { showDetails(node.id); });
+            grid.appendChild(card);
+            return card;
+        });
+        
+        document.getElementById("entity-search").addEventListener("input", function (event) {
+            var query = event.target.value.trim().toLowerCase();
+            data.nodes.forEach(function (node, i) {
+                cards[i].style.display = !query || matches(node, query) ? "" : "none";
+            });
+        });
+        
+        // Detail panel
+        
+        var HIDDEN_FIELDS = { id: true, name: true, description: true, metadata: true, relationship: true };
+        
+        function renderValue(value) {
+            if (value === null || value === undefined || value === "" || (Array.isArray(value) && !value.length)) {
+                return el("span", "muted", "—");
+            }
+            if (Array.isArray(value)) {
+                var list = el("ul");
+                value.forEach(function (item) {
+                    var entry = el("li");
+                    entry.appendChild(renderValue(item));
+                    list.appendChild(entry);
+                });
+                return list;
+            }
+            if (typeof value === "object") {
+                return renderFields(value, {}, "nested");
+            }
+            if (typeof value === "string" && data.entities[value]) {
+                return entityLink(value);
+            }
+            return document.createTextNode(String(value));
+        }
+        
+        function renderFields(fields, hidden, className) {
+            var list = el("dl", className);
+            Object.keys(fields).sort().forEach(function (key) {
+                if (hidden[key]) return;
+                list.appendChild(el("dt", null, fieldLabel(key)));
+                var value = el("dd");
+                value.appendChild(renderValue(fields[key]));
+                list.appendChild(value);
+            });
+            return list;
+        }
+        
+        function renderCitation(citation) {
+            var entry = el("li");
+            var source = data.sources[citation.source];
+            if (!source) {
+                entry.appendChild(el("span", "muted", "Unknown source " + citation.source));
+                return entry;
+            }
+            entry.appendChild(el("strong", null, source.title));
+            var details = [];
+            if (source.author) details.push(source.author);
+            if (source.date) details.push(source.date);
+            var kind = typeof source.source_type === "string" ? source.source_type : JSON.stringify(source.source_type);
+            details.push(kind);
+            entry.appendChild(document.createTextNode(" — " + details.join(", ")));
+            if (source.url) {
+                entry.appendChild(el("div", "muted", source.url));
+            }
+            var locator = citation.locator || {};
+            var where = ["book", "chapter", "line", "page"].filter(function (part) {
+                return locator[part];
+            }).map(function (part) {
+                return part + " " + locator[part];
+            });
+            if (citation.field) where.push("supports " + citation.field);
+            if (where.length) {
+                entry.appendChild(el("div", null, where.join(", ")));
+            }
+            if (citation.note) {
+                entry.appendChild(el("div", null, citation.note));
+            }
+            if (source.notes) {
+                entry.appendChild(el("div", "muted", source.notes));
+            }
+            return entry;
+        }
+        
+        function renderClaim(claim) {
+            var entry = el("div");
+            if (claim.tradition) {
+                entry.appendChild(el("div", null, claim.tradition + " tradition"));
+            }
+            if (claim.note) {
+                entry.appendChild(el("div", null, claim.note));
+            }
+            var citation = el("ul");
+            citation.appendChild(renderCitation(claim.citation));
+            entry.appendChild(citation);
+            return entry;
+        }
+        
+        function showDetails(id) {
+            var entity = data.entities[id];
+            if (!entity) return;
+            current = id;
+            var fields = entity.data;
+            var base = baseFields(entity);
+            var panel = document.getElementById("details");
+            panel.textContent = "";
             
-            if (tabName === 'graph') {{
-                renderGraph();
-            }}
-        }}
-
-        // Entity filtering
-        function filterEntities() {{
-            var input, filter, grid, cards, name, i;
-            input = document.getElementById("entitySearchInput");
-            filter = input.value.toUpperCase();
-            grid = document.getElementById("entityGrid");
-            cards = grid.getElementsByClassName("entity-card");
+            var metadata = base.metadata || {};
+            panel.appendChild(el("div", "entity-type", entity.type));
+            panel.appendChild(el("h2", null, entityName(id)));
+            if (entityName(id) !== base.name) {
+                panel.appendChild(el("div", "muted", base.name));
+            }
+            if (base.description) {
+                panel.appendChild(el("p", null, base.description));
+            }
             
-            for (i = 0; i < cards.length; i++) {{
-                name = cards[i].getElementsByClassName("entity-name")[0];
-                if (name.innerHTML.toUpperCase().indexOf(filter) > -1) {{
-                    cards[i].style.display = "";
-                }} else {{
-                    cards[i].style.display = "none";
-                }}
-            }}
-        }}
-
-        // Graph filtering
-        function filterGraph() {{
-            var input = document.getElementById("searchInput");
-            var filter = input.value.toUpperCase();
-            var nodes = document.querySelectorAll('.node');
+            var labels = metadata.labels || [];
+            if (labels.length) {
+                panel.appendChild(el("h3", null, "Names"));
+                var preferred = nodeIndex[id] ? nodeIndex[id].data.labels : {};
+                var nameList = el("ul");
+                labels.forEach(function (label) {
+                    var entry = el("li");
+                    entry.appendChild(el("strong", null, label.text));
+                    var about = [label.language];
+                    if (label.script) about.push(label.script);
+                    about.push(label.kind);
+                    if (preferred[label.language] === label.text) about.push("preferred");
+                    entry.appendChild(document.createTextNode(" — " + about.join(", ")));
+                    nameList.appendChild(entry);
+                });
+                panel.appendChild(nameList);
+            }
+            
+            if (fields.relationship) {
+                panel.appendChild(el("h3", null, "Relationship"));
+                panel.appendChild(renderFields(fields.relationship, HIDDEN_FIELDS));
+            }
             
-            if (filter === '') {{
-                // Show all nodes and links
-                nodes.forEach(function(node) {{
-                    node.style.opacity = 1;
-                }});
-                document.querySelectorAll('.link').forEach(function(link) {{
-                    link.style.opacity = 0.6;
-                }});
-                return;
-            }}
+            var shown = renderFields(fields, HIDDEN_FIELDS);
+            if (shown.childNodes.length) {
+                panel.appendChild(el("h3", null, "Fields"));
+                panel.appendChild(shown);
+            }
             
-            // First, make all nodes and links semi-transparent
-            nodes.forEach(function(node) {{
-                node.style.opacity = 0.2;
-            }});
-            document.querySelectorAll('.link').forEach(function(link) {{
-                link.style.opacity = 0.1;
-            }});
+            if (metadata.variant) {
+                var question = metadata.variant.question;
+                panel.appendChild(el("h3", null, "Variant"));
+                panel.appendChild(el("p", null, "One account of the " + question +
+                    (isRejected(id) ? ", not preferred by the selected policy." : ".")));
+                panel.appendChild(renderClaim(metadata.variant.claim));
+                var others = data.links.filter(function (link) {
+                    return link.variant === question && link.id !== id;
+                });
+                if (others.length) {
+                    panel.appendChild(el("div", null, "Other accounts:"));
+                    var otherList = el("ul");
+                    others.forEach(function (link) {
+                        var entry = el("li");
+                        entry.appendChild(entityLink(link.id));
+                        if (isRejected(link.id)) {
+                            entry.appendChild(el("span", "muted", " (not preferred)"));
+                        }
+                        otherList.appendChild(entry);
+                    });
+                    panel.appendChild(otherList);
+                }
+            }
             
-            // Then highlight matching nodes and their connections
-            nodes.forEach(function(node) {{
-                var name = node.querySelector('text').textContent;
-                if (name.toUpperCase().indexOf(filter) > -1) {{
-                    node.style.opacity = 1;
-                    
-                    // Highlight connected nodes and links
-                    var nodeId = node.id;
-                    document.querySelectorAll('.link[data-source="' + nodeId + '"], .link[data-target="' + nodeId + '"]').forEach(function(link) {{
-                        link.style.opacity = 0.8;
-                        var sourceId = link.getAttribute('data-source');
-                        var targetId = link.getAttribute('data-target');
-                        document.getElementById(sourceId).style.opacity = 1;
-                        document.getElementById(targetId).style.opacity = 1;
-                    }});
-                }}
-            }});
-        }}
-
-        // D3.js graph visualization
-        function renderGraph() {{
-            const nodes = {{}};
-            const links = {{}};
+            var claims = metadata.claims || [];
+            if (claims.length) {
+                panel.appendChild(el("h3", null, "Claims"));
+                var claimList = el("dl");
+                claims.forEach(function (claim, i) {
+                    if (isRejectedClaim(id, i)) return;
+                    claimList.appendChild(el("dt", null, fieldLabel(claim.field) + ": " + claim.value));
+                    var value = el("dd");
+                    value.appendChild(renderClaim(claim.claim));
+                    claimList.appendChild(value);
+                });
+                panel.appendChild(claimList);
+            }
             
-            // Wait for D3.js to load
-            setTimeout(function() {{
-                const svg = document.getElementById('ontology-graph');
-                
-                // Clear existing content
-                while (svg.firstChild) {{
-                    svg.removeChild(svg.firstChild);
-                }}
-                
-                const width = svg.clientWidth;
-                const height = svg.clientHeight;
-                const centerX = width / 2;
-                const centerY = height / 2;
-                
-                // Create nodes
-                {}
-                
-                // Create links
-                {}
-                
-                // Initialize simulation
-                const simulation = d3.forceSimulation(Object.values(nodes))
-                    .force("link", d3.forceLink(Object.values(links)).id(function(d) {{ return d.id; }}).distance(100))
-                    .force("charge", d3.forceManyBody().strength(-200))
-                    .force("center", d3.forceCenter(centerX, centerY))
-                    .force("collide", d3.forceCollide().radius(30));
-                
-                // Create links
-                const link = d3.select(svg)
-                    .selectAll("line")
-                    .data(Object.values(links))
-                    .enter()
-                    .append("line")
-                    .attr("class", "link")
-                    .attr("stroke-width", 1.5)
-                    .attr("data-source", function(d) {{ return d.source.id; }})
-                    .attr("data-target", function(d) {{ return d.target.id; }});
-                
-                // Create node groups
-                const node = d3.select(svg)
-                    .selectAll(".node")
-                    .data(Object.values(nodes))
-                    .enter()
-                    .append("g")
-                    .attr("class", "node")
-                    .attr("id", function(d) {{ return d.id; }})
-                    .call(d3.drag()
-                        .on("start", dragstarted)
-                        .on("drag", dragged)
-                        .on("end", dragended));
-                
-                // Add circles to nodes
-                node.append("circle")
-                    .attr("r", function(d) {{ return getNodeSize(d.type); }})
-                    .attr("class", function(d) {{ return "node-" + d.type.toLowerCase(); }})
-                    .append("title")
-                    .text(function(d) {{ return d.label; }});
-                
-                // Add text labels to nodes
-                node.append("text")
-                    .attr("dy", ".35em")
-                    .attr("x", function(d) {{ return getNodeSize(d.type) + 5; }})
-                    .text(function(d) {{ return d.label; }});
-                
-                // Update positions on each tick
-                simulation.on("tick", function() {{
-                    link
-                        .attr("x1", function(d) {{ return d.source.x; }})
-                        .attr("y1", function(d) {{ return d.source.y; }})
-                        .attr("x2", function(d) {{ return d.target.x; }})
-                        .attr("y2", function(d) {{ return d.target.y; }});
-                    
-                    node
-                        .attr("transform", function(d) {{ return "translate(" + d.x + "," + d.y + ")"; }});
-                }});
-                
-                // Drag functions
-                function dragstarted(event, d) {{
-                    if (!event.active) simulation.alphaTarget(0.3).restart();
-                    d.fx = d.x;
-                    d.fy = d.y;
-                }}
-                
-                function dragged(event, d) {{
-                    d.fx = event.x;
-                    d.fy = event.y;
-                }}
-                
-                function dragended(event, d) {{
-                    if (!event.active) simulation.alphaTarget(0);
-                    d.fx = null;
-                    d.fy = null;
-                }}
-                
-                // Node size based on type
-                function getNodeSize(type) {{
-                    switch(type.toLowerCase()) {{
-                        case 'deity': return 15;
-                        case 'hero': return 12;
-                        case 'creature': return 10;
-                        case 'artifact': return 10;
-                        case 'location': return 12;
-                        case 'concept': return 8;
-                        case 'culture': return 15;
-                        case 'pantheon': return 12;
-                        default: return 8;
-                    }}
-                }}
-            }}, 100);
-        }}
-        
-        // Load D3.js dynamically
-        function loadD3() {{
-            return new Promise(function(resolve, reject) {{
-                const script = document.createElement('script');
-                script.src = 'https://d3js.org/d3.v7.min.js';
-                script.onload = resolve;
-                script.onerror = reject;
-                document.head.appendChild(script);
-            }});
-        }}
-        
-        // Initialize on page load
-        window.onload = function() {{
-            loadD3().then(function() {{
-                renderGraph();
-            }});
-        }};
+            var connected = data.links.filter(function (link) {
+                return link.id && !isRejected(link.id) && (link.source === id || link.target === id);
+            });
+            if (connected.length) {
+                panel.appendChild(el("h3", null, "Relationships"));
+                var list = el("ul");
+                connected.forEach(function (link) {
+                    var entry = el("li");
+                    entry.appendChild(entityLink(link.source));
+                    var label = el("a", "entity-link", " " + (link.kind || link.label) + " ");
+                    label.addEventListener("click", function () { showDetails(link.id); });
+                    entry.appendChild(document.createTextNode(link.bidirectional ? " ↔" : " →"));
+                    entry.appendChild(label);
+                    entry.appendChild(entityLink(link.target));
+                    if (link.variant) {
+                        entry.appendChild(el("span", "muted", " (variant)"));
+                    }
+                    list.appendChild(entry);
+                });
+                panel.appendChild(list);
+            }
+            
+            var citations = metadata.citations || [];
+            panel.appendChild(el("h3", null, "Sources"));
+            if (citations.length) {
+                var sourceList = el("ol");
+                citations.forEach(function (citation) { sourceList.appendChild(renderCitation(citation)); });
+                panel.appendChild(sourceList);
+            } else {
+                panel.appendChild(el("p", "muted", "No sources recorded."));
+            }
+            
+            var attributes = metadata.attributes || {};
+            if (Object.keys(attributes).length || metadata.confidence !== null && metadata.confidence !== undefined) {
+                panel.appendChild(el("h3", null, "Metadata"));
+                var extra = {};
+                Object.keys(attributes).forEach(function (key) { extra[key] = attributes[key]; });
+                if (metadata.confidence !== null && metadata.confidence !== undefined) {
+                    extra.confidence = metadata.confidence;
+                }
+                panel.appendChild(renderFields(extra, {}));
+            }
+            panel.appendChild(el("p", "muted", "ID: " + id));
+            
+            if (selected) selected.classList.remove("selected");
+            selected = nodeIndex[id] ? nodeIndex[id].element : null;
+            if (selected) selected.classList.add("selected");
+        }
+        
+        draw();
+        restart(1);
+    })();
     </script>
 </body>
 </html>
-"#,
-        // Total entities
-        entities.len(),
-        
-        // Entity type legend
-        entity_type_counts.iter()
-            .map(|(entity_type, count)| {
-                let color_class = format!("node-{}", entity_type.to_lowercase());
-                format!(
-                    r#"<div class="legend-item">
-                        <div class="legend-color {}" style="background-color: var(--{}, #999);"></div>
-                        <span>{}: {}</span>
-                    </div>"#,
-                    color_class, color_class, entity_type, count
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n                "),
-        
-        // Entity cards
-        entities.iter()
-            .map(|entity| {
-                let culture_display = entity.culture_name()
-                    .map(|c| format!(r#"<span class="culture-pill">{}</span>"#, c))
-                    .unwrap_or_default();
-                
-                format!(
-                    r#"<div class="entity-card" data-type="{}">
-                        <div class="entity-type">{} {}</div>
-                        <div class="entity-name">{}</div>
-                        <div class="entity-description">{}</div>
-                        <div class="entity-metadata">
-                            <div>ID: {}</div>
-                            <div>Relationships: {}</div>
-                        </div>
-                    </div>"#,
-                    entity.entity_type().to_lowercase(),
-                    entity.entity_type(),
-                    culture_display,
-                    entity.name(),
-                    entity.metadata().attributes.get("description")
-                        .unwrap_or(&String::from("No description available.")).clone(),
-                    entity.id(),
-                    entity.relationships().len()
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n                "),
-        
-        // D3 Node Creation
-        entities.iter()
-            .map(|entity| {
-                format!(
-                    r#"nodes['{}'] = {{ 
-                        id: '{}', 
-                        name: '{}', 
-                        label: '{}',
-                        type: '{}',
-                        relationships: {}
-                    }};"#,
-                    entity.id(),
-                    entity.id(),
-                    entity.name().replace("'", "\\'"),
-                    entity.name().replace("'", "\\'"),
-                    entity.entity_type(),
-                    entity.relationships().len()
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n                "),
-        
-        // D3 Link Creation
-        connections.iter()
-            .enumerate()
-            .map(|(idx, (source, target, _))| {
-                format!(
-                    r#"links['link{}'] = {{ 
-                        source: '{}', 
-                        target: '{}',
-                        value: 1
-                    }};"#,
-                    idx,
-                    source,
-                    target
-                )
-            })
-            .collect::<Vec<_>>()
-            .join("\n                ")
-    );
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Citation, Label, NameKind, Source, SourceType};
+    use crate::entities::Deity;
+    use crate::examples::create_greek_ontology;
+    
+    fn data_island(page: &str) -> Value {
+        let start = page.find(r#"id="ontology-data">"#).unwrap() + r#"id="ontology-data">"#.len();
+        let end = start + page[start..].find("</script>").unwrap();
+        serde_json::from_str(&page[start..end]).unwrap()
+    }
     
-    file.write_all(html_content.as_bytes())?;
-    Ok(())
-}
\ No newline at end of file
+    #[test]
+    fn page_is_self_contained() {
+        let page = render_html_visualization(&create_greek_ontology(), "Greek");
+        assert!(!page.contains("d3js"));
+        assert!(!page.contains(" src="));
+        assert!(!page.contains("<link"));
+        assert!(page.contains("default-src 'none'"));
+        
+        let data = data_island(&page);
+        let zeus = data["nodes"].as_array().unwrap().iter().find(|n| n["name"] == "Zeus").unwrap();
+        let details = &data["entities"][zeus["id"].as_str().unwrap()];
+        assert_eq!(details["type"], "Deity");
+        assert!(details["data"]["domains"].is_array());
+        assert_eq!(zeus["labels"]["Ancient Greek"], "Ζεύς");
+        assert!(zeus["names"].as_array().unwrap().contains(&json!("Κρονίδης")));
+        assert!(!data["links"].as_array().unwrap().is_empty());
+    }
+    
+    #[test]
+    fn lists_rejected_variants_by_policy() {
+        let page = render_html_visualization(&create_greek_ontology(), "Greek");
+        let data = data_island(&page);
+        let links = data["links"].as_array().unwrap();
+        let ouranos = links.iter().find(|l| l["label"] == "Ouranos parent of Aphrodite").unwrap();
+        assert_eq!(ouranos["variant"], "parentage of Aphrodite");
+        
+        let rejected = &data["resolutions"]["PreferEarliest"];
+        assert_eq!(rejected["variants"], json!([ouranos["id"]]));
+        let cerberus = data["nodes"].as_array().unwrap().iter().find(|n| n["name"] == "Cerberus").unwrap();
+        assert_eq!(rejected["claims"][cerberus["id"].as_str().unwrap()], json!([1, 2]));
+        assert!(data["resolutions"].get("ShowAll").is_none());
+    }
+    
+    #[test]
+    fn shows_preferred_names_by_language() {
+        let mut ontology = MythOntology::new();
+        let mut thor = Deity::new("Thor", "God of thunder", "Norse");
+        thor.metadata.add_label(Label::new("Hlórriði", "Old Norse", NameKind::Epithet));
+        thor.metadata.add_label(Label::new("Þórr", "Old Norse", NameKind::Original));
+        thor.metadata.add_label(Label::new("Þunor", "Old English", NameKind::Exonym));
+        thor.metadata.add_label(Label::new("Donar", "Old High German", NameKind::Epithet));
+        ontology.add_entity(MythEntity::Deity(thor));
+        ontology.add_entity(MythEntity::Deity(Deity::new("Sif", "Wife of Thor", "Norse")));
+        
+        let data = data_island(&render_html_visualization(&ontology, "Norse"));
+        let nodes = data["nodes"].as_array().unwrap();
+        let thor = nodes.iter().find(|n| n["name"] == "Thor").unwrap();
+        assert_eq!(thor["labels"], json!({
+            "Old Norse": "Þórr",
+            "Old English": "Þunor",
+            "Old High German": "Thor",
+        }));
+        assert_eq!(thor["names"], json!(["Hlórriði", "Þórr", "Þunor", "Donar"]));
+        let sif = nodes.iter().find(|n| n["name"] == "Sif").unwrap();
+        assert_eq!(sif["labels"], json!({}));
+    }
+    
+    #[test]
+    fn escapes_entity_text() {
+        let hostile = "</script><script>alert('x')</script>";
+        let mut ontology = MythOntology::new();
+        let mut deity = Deity::new(hostile, "<b>bold</b> & \u{2028}", "Greek");
+        let source = ontology.add_source(Source {
+            title: hostile.to_string(),
+            author: None,
+            date: None,
+            source_type: SourceType::Other("<i>".to_string()),
+            url: Some("javascript:alert(1)".to_string()),
+            notes: None,
+        });
+        deity.metadata.add_citation(Citation::new(source.clone()).chapter(hostile));
+        ontology.add_entity(MythEntity::Deity(deity));
+        
+        let page = render_html_visualization(&ontology, "<Title> & %%DATA%%");
+        assert_eq!(page.matches("</script>").count(), 2);
+        assert!(!page.contains("<b>"));
+        assert!(!page.contains('\u{2028}'));
+        assert!(page.contains("<title>&lt;Title&gt; &amp; %%DATA%%</title>"));
+        
+        let data = data_island(&page);
+        assert_eq!(data["nodes"][0]["name"], hostile);
+        assert_eq!(data["nodes"][0]["description"], "<b>bold</b> & \u{2028}");
+        let id = data["nodes"][0]["id"].as_str().unwrap();
+        assert_eq!(data["entities"][id]["data"]["metadata"]["citations"][0]["locator"]["chapter"], hostile);
+        assert_eq!(data["sources"][source.to_string()]["title"], hostile);
+    }
+}